@@ -0,0 +1,604 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Static Arbitrage Scanner
+//!
+//! Checks that the quotes loaded into an [`OptionChain`] or an
+//! [`OptionSeries`] are internally consistent before strategies are built on
+//! them. Every check is performed on executable prices — buying at the ask
+//! and selling at the bid — so a reported violation carries a positive
+//! `edge` that survives the bid/ask spread.
+//!
+//! The following conditions are checked:
+//!
+//! - **Crossed markets**: a bid above the ask on either side.
+//! - **Put–call parity**: conversions and reversals priced against the
+//!   chain's forward, `C − P = S·e^{−qT} − K·e^{−rT}` for European options.
+//! - **Vertical spreads**: call prices must not increase and put prices must
+//!   not decrease with strike, and a vertical can never be worth more than
+//!   the discounted strike distance.
+//! - **Butterflies**: prices must be convex in strike, so a long butterfly
+//!   on adjacent strikes can never be bought for a credit.
+//! - **Calendars** (series only): for a common strike, the far expiry may
+//!   not trade below the near expiry.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use optionstratlib::chains::{ArbitrageParameters, OptionChain};
+//! use positive::{pos_or_panic, spos, Positive};
+//! use rust_decimal_macros::dec;
+//!
+//! let mut chain = OptionChain::new("TEST", Positive::HUNDRED, "2030-01-01".to_string(), None, None);
+//! chain.add_option(pos_or_panic!(95.0), spos!(6.0), spos!(6.2), spos!(1.0), spos!(1.2), pos_or_panic!(0.2), None, None, None, None, None, None);
+//! // Call bid above ask and call price increasing with strike.
+//! chain.add_option(pos_or_panic!(100.0), spos!(7.0), spos!(6.8), spos!(2.0), spos!(2.2), pos_or_panic!(0.2), None, None, None, None, None, None);
+//!
+//! let report = chain.scan_static_arbitrage(&ArbitrageParameters::default()).unwrap();
+//! assert!(!report.is_clean());
+//! ```
+
+use crate::chains::chain::OptionChain;
+use crate::chains::optiondata::OptionData;
+use crate::error::ChainError;
+use crate::model::OptionStyle;
+use crate::series::OptionSeries;
+use positive::Positive;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use rust_decimal::{Decimal, MathematicalOps};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use utoipa::ToSchema;
+
+/// Type of static-arbitrage condition that a quote violates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ToSchema, Serialize, Deserialize)]
+pub enum ArbitrageKind {
+    /// Bid above the ask on one side of the strike
+    CrossedMarket,
+    /// Conversion or reversal locks in a profit against the forward
+    PutCallParity,
+    /// Option price moves the wrong way with strike
+    VerticalMonotonicity,
+    /// Vertical spread priced above the discounted strike distance
+    VerticalBound,
+    /// Long butterfly available for a credit
+    Butterfly,
+    /// Far expiry trading below the near expiry at the same strike
+    Calendar,
+}
+
+impl fmt::Display for ArbitrageKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArbitrageKind::CrossedMarket => write!(f, "Crossed Market"),
+            ArbitrageKind::PutCallParity => write!(f, "Put-Call Parity"),
+            ArbitrageKind::VerticalMonotonicity => write!(f, "Vertical Monotonicity"),
+            ArbitrageKind::VerticalBound => write!(f, "Vertical Bound"),
+            ArbitrageKind::Butterfly => write!(f, "Butterfly"),
+            ArbitrageKind::Calendar => write!(f, "Calendar"),
+        }
+    }
+}
+
+/// A single static-arbitrage violation.
+#[derive(DebugPretty, DisplaySimple, Clone, PartialEq, ToSchema, Serialize, Deserialize)]
+pub struct ArbitrageViolation {
+    /// Condition violated
+    pub kind: ArbitrageKind,
+    /// Strike of the offending `OptionData` (the middle strike for
+    /// butterflies, the lower strike for verticals)
+    pub strike: Positive,
+    /// Expiration of the chain holding the quote
+    pub expiration: String,
+    /// Side of the market, if the check is specific to calls or puts
+    pub style: Option<OptionStyle>,
+    /// Profit per unit locked in by trading at the bid and ask
+    pub edge: Decimal,
+    /// Human-readable description of the trade capturing the edge
+    pub description: String,
+}
+
+/// Configuration for the static-arbitrage scan.
+#[derive(DebugPretty, DisplaySimple, Clone, ToSchema, Serialize, Deserialize)]
+pub struct ArbitrageParameters {
+    /// Minimum executable edge for a violation to be reported
+    pub tolerance: Decimal,
+    /// Check put–call parity (disable for American options)
+    pub check_parity: bool,
+}
+
+impl Default for ArbitrageParameters {
+    fn default() -> Self {
+        Self {
+            tolerance: Decimal::ZERO,
+            check_parity: true,
+        }
+    }
+}
+
+/// Result of a static-arbitrage scan.
+#[derive(DebugPretty, DisplaySimple, Clone, Default, ToSchema, Serialize, Deserialize)]
+pub struct ArbitrageReport {
+    /// Violations found, in the order they were detected
+    pub violations: Vec<ArbitrageViolation>,
+}
+
+impl ArbitrageReport {
+    /// Returns `true` when no violation was found.
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Violations grouped by strike.
+    #[must_use]
+    pub fn by_strike(&self) -> BTreeMap<Positive, Vec<&ArbitrageViolation>> {
+        let mut grouped: BTreeMap<Positive, Vec<&ArbitrageViolation>> = BTreeMap::new();
+        for violation in &self.violations {
+            grouped.entry(violation.strike).or_default().push(violation);
+        }
+        grouped
+    }
+
+    /// Violations of a given kind.
+    pub fn of_kind(&self, kind: ArbitrageKind) -> impl Iterator<Item = &ArbitrageViolation> {
+        self.violations.iter().filter(move |v| v.kind == kind)
+    }
+
+    /// Largest executable edge in the report.
+    #[must_use]
+    pub fn max_edge(&self) -> Option<Decimal> {
+        self.violations.iter().map(|v| v.edge).max()
+    }
+}
+
+struct Scanner<'a> {
+    params: &'a ArbitrageParameters,
+    expiration: String,
+    violations: Vec<ArbitrageViolation>,
+}
+
+impl Scanner<'_> {
+    fn push(
+        &mut self,
+        kind: ArbitrageKind,
+        strike: Positive,
+        style: Option<OptionStyle>,
+        edge: Decimal,
+        description: String,
+    ) {
+        if edge > self.params.tolerance {
+            self.violations.push(ArbitrageViolation {
+                kind,
+                strike,
+                expiration: self.expiration.clone(),
+                style,
+                edge,
+                description,
+            });
+        }
+    }
+}
+
+fn quotes(option: &OptionData, style: OptionStyle) -> Option<(Decimal, Decimal)> {
+    match style {
+        OptionStyle::Call => Some((option.call_bid?.to_dec(), option.call_ask?.to_dec())),
+        OptionStyle::Put => Some((option.put_bid?.to_dec(), option.put_ask?.to_dec())),
+    }
+}
+
+impl OptionChain {
+    /// Scans the chain for crossed markets, put–call parity breaks,
+    /// vertical spread violations and negative butterflies.
+    ///
+    /// Strikes without a two-sided quote on a given side are skipped for the
+    /// checks that need it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChainError::ChainBuildError`] when parity is checked and the
+    /// chain's expiration cannot be converted to a time to expiry.
+    pub fn scan_static_arbitrage(
+        &self,
+        params: &ArbitrageParameters,
+    ) -> Result<ArbitrageReport, ChainError> {
+        let mut scanner = Scanner {
+            params,
+            expiration: self.get_expiration_date(),
+            violations: Vec::new(),
+        };
+        let options: Vec<&OptionData> = self.options.iter().collect();
+        let years = self
+            .get_expiration()
+            .and_then(|expiration| expiration.get_years().ok())
+            .map(|years| years.to_dec());
+        let discount = |rate: Decimal| match years {
+            Some(years) => (-rate * years).exp(),
+            None => Decimal::ONE,
+        };
+        let rate_discount = discount(self.risk_free_rate.unwrap_or(Decimal::ZERO));
+
+        for option in &options {
+            for style in [OptionStyle::Call, OptionStyle::Put] {
+                if let Some((bid, ask)) = quotes(option, style) {
+                    scanner.push(
+                        ArbitrageKind::CrossedMarket,
+                        option.strike_price,
+                        Some(style),
+                        bid - ask,
+                        format!("buy at ask {ask} and sell at bid {bid}"),
+                    );
+                }
+            }
+        }
+
+        if params.check_parity {
+            if years.is_none() {
+                return Err(ChainError::invalid_parameters(
+                    "expiration_date",
+                    &format!("invalid chain expiration {}", self.get_expiration_date()),
+                ));
+            }
+            let dividend_discount =
+                discount(self.dividend_yield.unwrap_or(Positive::ZERO).to_dec());
+            let spot_pv = self.underlying_price.to_dec() * dividend_discount;
+            for option in &options {
+                let (Some((call_bid, call_ask)), Some((put_bid, put_ask))) = (
+                    quotes(option, OptionStyle::Call),
+                    quotes(option, OptionStyle::Put),
+                ) else {
+                    continue;
+                };
+                let parity = spot_pv - option.strike_price.to_dec() * rate_discount;
+                scanner.push(
+                    ArbitrageKind::PutCallParity,
+                    option.strike_price,
+                    None,
+                    call_bid - put_ask - parity,
+                    "reversal: sell call, buy put, buy forward".to_string(),
+                );
+                scanner.push(
+                    ArbitrageKind::PutCallParity,
+                    option.strike_price,
+                    None,
+                    parity - (call_ask - put_bid),
+                    "conversion: buy call, sell put, sell forward".to_string(),
+                );
+            }
+        }
+
+        for pair in options.windows(2) {
+            let [low, high] = pair else { continue };
+            let width = (high.strike_price - low.strike_price).to_dec() * rate_discount;
+            if let (Some((low_bid, low_ask)), Some((high_bid, high_ask))) = (
+                quotes(low, OptionStyle::Call),
+                quotes(high, OptionStyle::Call),
+            ) {
+                scanner.push(
+                    ArbitrageKind::VerticalMonotonicity,
+                    low.strike_price,
+                    Some(OptionStyle::Call),
+                    high_bid - low_ask,
+                    format!(
+                        "buy {} call, sell {} call",
+                        low.strike_price, high.strike_price
+                    ),
+                );
+                scanner.push(
+                    ArbitrageKind::VerticalBound,
+                    low.strike_price,
+                    Some(OptionStyle::Call),
+                    low_bid - high_ask - width,
+                    format!(
+                        "sell {} call, buy {} call",
+                        low.strike_price, high.strike_price
+                    ),
+                );
+            }
+            if let (Some((low_bid, low_ask)), Some((high_bid, high_ask))) = (
+                quotes(low, OptionStyle::Put),
+                quotes(high, OptionStyle::Put),
+            ) {
+                scanner.push(
+                    ArbitrageKind::VerticalMonotonicity,
+                    low.strike_price,
+                    Some(OptionStyle::Put),
+                    low_bid - high_ask,
+                    format!(
+                        "sell {} put, buy {} put",
+                        low.strike_price, high.strike_price
+                    ),
+                );
+                scanner.push(
+                    ArbitrageKind::VerticalBound,
+                    low.strike_price,
+                    Some(OptionStyle::Put),
+                    high_bid - low_ask - width,
+                    format!(
+                        "buy {} put, sell {} put",
+                        low.strike_price, high.strike_price
+                    ),
+                );
+            }
+        }
+
+        for triple in options.windows(3) {
+            let [low, mid, high] = triple else { continue };
+            let span = (high.strike_price - low.strike_price).to_dec();
+            let low_weight = (high.strike_price - mid.strike_price).to_dec() / span;
+            let high_weight = (mid.strike_price - low.strike_price).to_dec() / span;
+            for style in [OptionStyle::Call, OptionStyle::Put] {
+                let (Some((_, low_ask)), Some((mid_bid, _)), Some((_, high_ask))) =
+                    (quotes(low, style), quotes(mid, style), quotes(high, style))
+                else {
+                    continue;
+                };
+                let cost = low_weight * low_ask + high_weight * high_ask - mid_bid;
+                scanner.push(
+                    ArbitrageKind::Butterfly,
+                    mid.strike_price,
+                    Some(style),
+                    -cost,
+                    format!(
+                        "buy {} {style} butterfly {}/{}/{}",
+                        low_weight.round_dp(4),
+                        low.strike_price,
+                        mid.strike_price,
+                        high.strike_price
+                    ),
+                );
+            }
+        }
+
+        Ok(ArbitrageReport {
+            violations: scanner.violations,
+        })
+    }
+}
+
+impl OptionSeries {
+    /// Scans every chain of the series with
+    /// [`OptionChain::scan_static_arbitrage`] and adds calendar checks
+    /// between consecutive expirations at common strikes.
+    ///
+    /// Calendars are only checked on out-of-the-money strikes (calls above
+    /// and puts below both forwards), since carry can legitimately make
+    /// in-the-money European options lose value with maturity.
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors from the per-chain scan.
+    pub fn scan_static_arbitrage(
+        &self,
+        params: &ArbitrageParameters,
+    ) -> Result<ArbitrageReport, ChainError> {
+        let mut report = ArbitrageReport::default();
+        for chain in self.chains.values() {
+            report
+                .violations
+                .extend(chain.scan_static_arbitrage(params)?.violations);
+        }
+
+        let chains: Vec<&OptionChain> = self.chains.values().collect();
+        for pair in chains.windows(2) {
+            let [near, far] = pair else { continue };
+            let near_forward = forward(near);
+            let far_forward = forward(far);
+            let mut scanner = Scanner {
+                params,
+                expiration: far.get_expiration_date(),
+                violations: Vec::new(),
+            };
+            for near_option in &near.options {
+                let Some(far_option) = far
+                    .options
+                    .iter()
+                    .find(|option| option.strike_price == near_option.strike_price)
+                else {
+                    continue;
+                };
+                let strike = near_option.strike_price.to_dec();
+                for style in [OptionStyle::Call, OptionStyle::Put] {
+                    let out_of_the_money = match style {
+                        OptionStyle::Call => strike >= near_forward.max(far_forward),
+                        OptionStyle::Put => strike <= near_forward.min(far_forward),
+                    };
+                    if !out_of_the_money {
+                        continue;
+                    }
+                    let (Some((near_bid, _)), Some((_, far_ask))) =
+                        (quotes(near_option, style), quotes(far_option, style))
+                    else {
+                        continue;
+                    };
+                    scanner.push(
+                        ArbitrageKind::Calendar,
+                        near_option.strike_price,
+                        Some(style),
+                        near_bid - far_ask,
+                        format!(
+                            "sell {} {style}, buy {} {style}",
+                            near.get_expiration_date(),
+                            far.get_expiration_date()
+                        ),
+                    );
+                }
+            }
+            report.violations.extend(scanner.violations);
+        }
+        Ok(report)
+    }
+}
+
+/// Forward `S · e^{(r − q)T}` implied by the chain's rate and dividend yield.
+fn forward(chain: &OptionChain) -> Decimal {
+    let years = chain
+        .get_expiration()
+        .and_then(|expiration| expiration.get_years().ok())
+        .map_or(Decimal::ZERO, |years| years.to_dec());
+    let carry = chain.risk_free_rate.unwrap_or(Decimal::ZERO)
+        - chain.dividend_yield.unwrap_or(Positive::ZERO).to_dec();
+    chain.underlying_price.to_dec() * (carry * years).exp()
+}
+
+#[cfg(test)]
+mod tests_arbitrage {
+    use super::*;
+    use crate::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
+    use crate::model::ExpirationDate;
+    use positive::{pos_or_panic, spos};
+    use rust_decimal_macros::dec;
+
+    fn chain() -> OptionChain {
+        chain_with_days(60.0)
+    }
+
+    fn chain_with_days(days: f64) -> OptionChain {
+        let params = OptionChainBuildParams::new(
+            "TEST".to_string(),
+            None,
+            10,
+            spos!(5.0),
+            dec!(-0.2),
+            dec!(0.1),
+            pos_or_panic!(0.02),
+            2,
+            OptionDataPriceParams::new(
+                Some(Box::new(Positive::HUNDRED)),
+                Some(ExpirationDate::Days(pos_or_panic!(days))),
+                Some(dec!(0.03)),
+                spos!(0.01),
+                Some("TEST".to_string()),
+            ),
+            pos_or_panic!(0.25),
+        );
+        OptionChain::build_chain(&params).unwrap()
+    }
+
+    fn replace(chain: &mut OptionChain, strike: Positive, update: impl FnOnce(&mut OptionData)) {
+        let mut option = chain
+            .options
+            .iter()
+            .find(|option| option.strike_price == strike)
+            .cloned()
+            .unwrap();
+        chain.options.remove(&option);
+        update(&mut option);
+        chain.options.insert(option);
+    }
+
+    #[test]
+    fn test_clean_chain() {
+        let report = chain()
+            .scan_static_arbitrage(&ArbitrageParameters::default())
+            .unwrap();
+        assert!(report.is_clean(), "{:?}", report.violations);
+        assert_eq!(report.max_edge(), None);
+    }
+
+    #[test]
+    fn test_crossed_market() {
+        let mut chain = chain();
+        replace(&mut chain, Positive::HUNDRED, |option| {
+            option.put_bid = option.put_ask.map(|ask| ask + pos_or_panic!(0.5));
+        });
+        let report = chain
+            .scan_static_arbitrage(&ArbitrageParameters::default())
+            .unwrap();
+        let crossed: Vec<_> = report.of_kind(ArbitrageKind::CrossedMarket).collect();
+        assert_eq!(crossed.len(), 1);
+        assert_eq!(crossed[0].strike, Positive::HUNDRED);
+        assert_eq!(crossed[0].style, Some(OptionStyle::Put));
+        assert_eq!(crossed[0].edge, dec!(0.5));
+    }
+
+    #[test]
+    fn test_parity_and_butterfly_break() {
+        let mut chain = chain();
+        replace(&mut chain, Positive::HUNDRED, |option| {
+            option.call_bid = option.call_bid.map(|bid| bid + pos_or_panic!(3.0));
+            option.call_ask = option.call_ask.map(|ask| ask + pos_or_panic!(3.0));
+        });
+        let report = chain
+            .scan_static_arbitrage(&ArbitrageParameters::default())
+            .unwrap();
+        let parity: Vec<_> = report.of_kind(ArbitrageKind::PutCallParity).collect();
+        assert_eq!(parity.len(), 1);
+        assert_eq!(parity[0].strike, Positive::HUNDRED);
+        assert!(parity[0].edge > dec!(2.5));
+        assert!(
+            report
+                .of_kind(ArbitrageKind::Butterfly)
+                .any(|v| v.strike == Positive::HUNDRED && v.style == Some(OptionStyle::Call))
+        );
+        assert!(report.by_strike().contains_key(&Positive::HUNDRED));
+    }
+
+    #[test]
+    fn test_vertical_monotonicity() {
+        let mut chain = chain();
+        replace(&mut chain, pos_or_panic!(105.0), |option| {
+            option.call_bid = spos!(20.0);
+            option.call_ask = spos!(20.1);
+        });
+        let params = ArbitrageParameters {
+            check_parity: false,
+            ..Default::default()
+        };
+        let report = chain.scan_static_arbitrage(&params).unwrap();
+        assert!(
+            report
+                .of_kind(ArbitrageKind::VerticalMonotonicity)
+                .any(|v| v.strike == Positive::HUNDRED && v.style == Some(OptionStyle::Call))
+        );
+        assert!(
+            report
+                .of_kind(ArbitrageKind::PutCallParity)
+                .next()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_tolerance_filters_small_edges() {
+        let mut chain = chain();
+        replace(&mut chain, Positive::HUNDRED, |option| {
+            option.put_bid = option.put_ask.map(|ask| ask + pos_or_panic!(0.05));
+        });
+        let params = ArbitrageParameters {
+            tolerance: dec!(0.1),
+            ..Default::default()
+        };
+        assert!(chain.scan_static_arbitrage(&params).unwrap().is_clean());
+    }
+
+    #[test]
+    fn test_series_calendar() {
+        let mut series = OptionSeries::new("TEST".to_string(), Positive::HUNDRED);
+        for days in [30.0, 90.0] {
+            series.chains.insert(
+                ExpirationDate::Days(pos_or_panic!(days)),
+                chain_with_days(days),
+            );
+        }
+        let params = ArbitrageParameters::default();
+        let clean = series.scan_static_arbitrage(&params).unwrap();
+        assert!(clean.is_clean(), "{:?}", clean.violations);
+
+        let far = series.chains.values_mut().last().unwrap();
+        replace(far, pos_or_panic!(110.0), |option| {
+            option.call_bid = spos!(0.01);
+            option.call_ask = spos!(0.02);
+        });
+        let report = series.scan_static_arbitrage(&params).unwrap();
+        assert!(
+            report
+                .of_kind(ArbitrageKind::Calendar)
+                .any(|v| v.strike == pos_or_panic!(110.0) && v.style == Some(OptionStyle::Call))
+        );
+    }
+}
@@ -5,7 +5,8 @@
 ******************************************************************************/
 use crate::chains::utils::{
     OptionChainBuildParams, OptionChainParams, OptionDataPriceParams, RandomPositionsParams,
-    adjust_volatility, default_empty_string, rounder, strike_step,
+    adjust_volatility, default_empty_string, parse_side_greeks, rounder, side_greeks_columns,
+    strike_step,
 };
 use crate::chains::{OptionData, OptionsInStrike, RNDAnalysis, RNDParameters, RNDResult};
 use crate::curves::{BasicCurves, Curve, Point2D};
@@ -17,9 +18,10 @@ use crate::metrics::{
     BidAskSpreadCurve, CharmCurve, CharmSurface, ColorCurve, ColorSurface, DeltaGammaProfileCurve,
     DeltaGammaProfileSurface, DollarGammaCurve, ImpliedVolatilityCurve, ImpliedVolatilitySurface,
     OpenInterestCurve, PriceShockCurve, PriceShockSurface, PutCallRatioCurve, RiskReversalCurve,
-    SmileDynamicsCurve, SmileDynamicsSurface, StrikeConcentrationCurve, ThetaCurve, ThetaSurface,
-    TimeDecayCurve, TimeDecaySurface, VannaVolgaSurface, VolatilitySensitivityCurve,
-    VolatilitySensitivitySurface, VolatilitySkewCurve, VolumeProfileCurve, VolumeProfileSurface,
+    SmileAwarePriceShock, SmileAwareTimeDecay, SmileAwareVolatilitySensitivity, SmileDynamicsCurve,
+    SmileDynamicsSurface, StrikeConcentrationCurve, ThetaCurve, ThetaSurface, TimeDecayCurve,
+    TimeDecaySurface, VannaVolgaSurface, VolatilitySensitivityCurve, VolatilitySensitivitySurface,
+    VolatilitySkewCurve, VolumeProfileCurve, VolumeProfileSurface,
 };
 use crate::model::decimal::d_add;
 use crate::model::{
@@ -29,7 +31,7 @@ use crate::strategies::utils::FindOptimalSide;
 use crate::surfaces::{BasicSurfaces, Point3D, Surface};
 use crate::utils::Len;
 use crate::utils::others::get_random_element;
-use crate::volatility::VolatilitySmile;
+use crate::volatility::{SmileDynamics, StickySmile, VolatilitySmile};
 use chrono::{NaiveDate, Utc};
 use num_traits::{FromPrimitive, ToPrimitive};
 use positive::Positive;
@@ -492,8 +494,7 @@ impl OptionChain {
             match option_data.calculate_prices(Some(p.spread)) {
                 Ok(()) => {
                     option_data.apply_spread(p.spread, p.decimal_places);
-                    option_data.calculate_delta();
-                    option_data.calculate_gamma();
+                    option_data.calculate_greeks();
                 }
                 Err(e) => {
                     warn!(
@@ -1050,12 +1051,12 @@ impl OptionChain {
         self.options = modified_options;
     }
 
-    /// Calculates and updates the delta and gamma Greeks for all options in the chain.
+    /// Calculates and updates the Greeks for all options in the chain.
     ///
-    /// This method computes the delta and gamma values for each option in the chain based on
-    /// the current market parameters. Delta measures the rate of change of the option price
-    /// with respect to the underlying asset's price, while gamma measures the rate of change
-    /// of delta with respect to the underlying asset's price.
+    /// This method computes the full per-side Greek set (delta, gamma, vega, theta, rho,
+    /// vanna and charm) of each option in the chain based on the current market parameters
+    /// and stores it in `call_greeks` and `put_greeks`. The `delta_call`, `delta_put` and
+    /// `gamma` fields are updated from the same values.
     ///
     /// The original options in the chain are replaced with the ones containing the updated Greeks.
     pub fn update_greeks(&mut self) {
@@ -1064,8 +1065,7 @@ impl OptionChain {
             .iter()
             .map(|option| {
                 let mut option = option.clone(); // Create a clone we can modify
-                option.calculate_delta();
-                option.calculate_gamma();
+                option.calculate_greeks();
                 option
             })
             .collect();
@@ -1113,9 +1113,21 @@ impl OptionChain {
             "Gamma",
             "Volume",
             "Open Interest",
+            "Call Gamma",
+            "Call Vega",
+            "Call Theta",
+            "Call Rho",
+            "Call Vanna",
+            "Call Charm",
+            "Put Gamma",
+            "Put Vega",
+            "Put Theta",
+            "Put Rho",
+            "Put Vanna",
+            "Put Charm",
         ])?;
         for option in &self.options {
-            wtr.write_record(&[
+            let mut record = vec![
                 option.strike_price.to_string(),
                 default_empty_string(option.call_bid),
                 default_empty_string(option.call_ask),
@@ -1127,7 +1139,11 @@ impl OptionChain {
                 default_empty_string(option.gamma),
                 default_empty_string(option.volume),
                 default_empty_string(option.open_interest),
-            ])?;
+            ];
+            for greeks in [option.call_greeks, option.put_greeks] {
+                record.extend(side_greeks_columns(greeks));
+            }
+            wtr.write_record(&record)?;
         }
         wtr.flush()?;
         Ok(())
@@ -1268,6 +1284,9 @@ impl OptionChain {
                 open_interest: parse(field(10)?),
                 ..Default::default()
             };
+            // Files written before per-side Greeks were stored end at column 10.
+            option_data.call_greeks = parse_side_greeks(&record, 11, option_data.delta_call);
+            option_data.put_greeks = parse_side_greeks(&record, 17, option_data.delta_put);
             option_data.set_mid_prices();
             options.insert(option_data);
         }
@@ -1338,10 +1357,8 @@ impl OptionChain {
         let mut option_chain: OptionChain = serde_json::from_reader(file)?;
         option_chain.set_optiondata_extra_params()?;
         option_chain.mutate_single_options(|option| {
-            option.implied_volatility = if option.implied_volatility >= Positive::ONE {
-                option.implied_volatility / Positive::HUNDRED
-            } else {
-                option.implied_volatility
+            if option.implied_volatility >= Positive::ONE {
+                option.set_volatility(&(option.implied_volatility / Positive::HUNDRED));
             }
         });
 
@@ -2030,17 +2047,26 @@ impl OptionChain {
     ///
     /// # Note
     ///
-    /// This method requires options greeks to be initialized first by calling the `update_greeks` method.
+    /// Reads the per-side Greeks stored by `update_greeks`; options without them are
+    /// recomputed from their pricing parameters. The setters that change pricing inputs
+    /// clear the stored Greeks, but after writing `OptionData` fields directly
+    /// `update_greeks` must be re-run.
     pub fn vega_exposure(&self) -> Result<Decimal, ChainError> {
         let mut vega_exposure = Decimal::ZERO;
         for option_data in &self.options {
-            let vega = option_data
-                .get_option(Side::Long, OptionStyle::Call)?
-                .vega()?;
+            let vega = match option_data.call_greeks {
+                Some(greeks) => greeks.vega,
+                None => option_data
+                    .get_option(Side::Long, OptionStyle::Call)?
+                    .vega()?,
+            };
             vega_exposure = d_add(vega_exposure, vega, "chains::vega_exposure::call")?;
-            let vega = option_data
-                .get_option(Side::Long, OptionStyle::Put)?
-                .vega()?;
+            let vega = match option_data.put_greeks {
+                Some(greeks) => greeks.vega,
+                None => option_data
+                    .get_option(Side::Long, OptionStyle::Put)?
+                    .vega()?,
+            };
             vega_exposure = d_add(vega_exposure, vega, "chains::vega_exposure::put")?;
         }
         Ok(vega_exposure)
@@ -2064,17 +2090,26 @@ impl OptionChain {
     ///
     /// # Note
     ///
-    /// This method requires options greeks to be initialized first by calling the `update_greeks` method.
+    /// Reads the per-side Greeks stored by `update_greeks`; options without them are
+    /// recomputed from their pricing parameters. The setters that change pricing inputs
+    /// clear the stored Greeks, but after writing `OptionData` fields directly
+    /// `update_greeks` must be re-run.
     pub fn theta_exposure(&self) -> Result<Decimal, ChainError> {
         let mut theta_exposure = Decimal::ZERO;
         for option_data in &self.options {
-            let theta = option_data
-                .get_option(Side::Long, OptionStyle::Call)?
-                .theta()?;
+            let theta = match option_data.call_greeks {
+                Some(greeks) => greeks.theta,
+                None => option_data
+                    .get_option(Side::Long, OptionStyle::Call)?
+                    .theta()?,
+            };
             theta_exposure = d_add(theta_exposure, theta, "chains::theta_exposure::call")?;
-            let theta = option_data
-                .get_option(Side::Long, OptionStyle::Put)?
-                .theta()?;
+            let theta = match option_data.put_greeks {
+                Some(greeks) => greeks.theta,
+                None => option_data
+                    .get_option(Side::Long, OptionStyle::Put)?
+                    .theta()?,
+            };
             theta_exposure = d_add(theta_exposure, theta, "chains::theta_exposure::put")?;
         }
         Ok(theta_exposure)
@@ -2098,17 +2133,26 @@ impl OptionChain {
     ///
     /// # Note
     ///
-    /// This method requires options greeks to be initialized first by calling the `update_greeks` method.
+    /// Reads the per-side Greeks stored by `update_greeks`; options without them are
+    /// recomputed from their pricing parameters. The setters that change pricing inputs
+    /// clear the stored Greeks, but after writing `OptionData` fields directly
+    /// `update_greeks` must be re-run.
     pub fn vanna_exposure(&self) -> Result<Decimal, ChainError> {
         let mut vanna_exposure = Decimal::ZERO;
         for option_data in &self.options {
-            let vanna = option_data
-                .get_option(Side::Long, OptionStyle::Call)?
-                .vanna()?;
+            let vanna = match option_data.call_greeks {
+                Some(greeks) => greeks.vanna,
+                None => option_data
+                    .get_option(Side::Long, OptionStyle::Call)?
+                    .vanna()?,
+            };
             vanna_exposure = d_add(vanna_exposure, vanna, "chains::vanna_exposure::call")?;
-            let vanna = option_data
-                .get_option(Side::Long, OptionStyle::Put)?
-                .vanna()?;
+            let vanna = match option_data.put_greeks {
+                Some(greeks) => greeks.vanna,
+                None => option_data
+                    .get_option(Side::Long, OptionStyle::Put)?
+                    .vanna()?,
+            };
             vanna_exposure = d_add(vanna_exposure, vanna, "chains::vanna_exposure::put")?;
         }
         Ok(vanna_exposure)
@@ -2197,18 +2241,26 @@ impl OptionChain {
     ///
     /// # Note
     ///
-    /// This method requires options greeks to be initialized first by calling
-    /// the `update_greeks` method.
+    /// Reads the per-side Greeks stored by `update_greeks`; options without them are
+    /// recomputed from their pricing parameters. The setters that change pricing inputs
+    /// clear the stored Greeks, but after writing `OptionData` fields directly
+    /// `update_greeks` must be re-run.
     pub fn charm_exposure(&self) -> Result<Decimal, ChainError> {
         let mut charm_exposure = Decimal::ZERO;
         for option_data in &self.options {
-            let charm = option_data
-                .get_option(Side::Long, OptionStyle::Call)?
-                .charm()?;
+            let charm = match option_data.call_greeks {
+                Some(greeks) => greeks.charm,
+                None => option_data
+                    .get_option(Side::Long, OptionStyle::Call)?
+                    .charm()?,
+            };
             charm_exposure = d_add(charm_exposure, charm, "chains::charm_exposure::call")?;
-            let charm = option_data
-                .get_option(Side::Long, OptionStyle::Put)?
-                .charm()?;
+            let charm = match option_data.put_greeks {
+                Some(greeks) => greeks.charm,
+                None => option_data
+                    .get_option(Side::Long, OptionStyle::Put)?
+                    .charm()?,
+            };
             charm_exposure = d_add(charm_exposure, charm, "chains::charm_exposure::put")?;
         }
         Ok(charm_exposure)
@@ -2652,6 +2704,9 @@ impl OptionChain {
         // Create a new set of options with updated expiration dates
         let mut updated_options = BTreeSet::new();
         for mut option in self.options.iter().cloned() {
+            if option.expiration_date != expiration {
+                option.clear_side_greeks();
+            }
             option.expiration_date = expiration;
             updated_options.insert(option);
         }
@@ -3245,6 +3300,9 @@ impl OptionChain {
             Cell::new("C-Delta").with_style(Attr::ForegroundColor(color::GREEN)),
             Cell::new("P-Delta").with_style(Attr::ForegroundColor(color::GREEN)),
             Cell::new("Gamma").with_style(Attr::ForegroundColor(color::GREEN)),
+            Cell::new("Vega").with_style(Attr::ForegroundColor(color::GREEN)),
+            Cell::new("C-Theta").with_style(Attr::ForegroundColor(color::GREEN)),
+            Cell::new("P-Theta").with_style(Attr::ForegroundColor(color::GREEN)),
             Cell::new("Vol.").with_style(Attr::ForegroundColor(color::GREEN)),
             Cell::new("OI").with_style(Attr::ForegroundColor(color::GREEN)),
         ]));
@@ -3284,6 +3342,18 @@ impl OptionChain {
                     "{:.4}",
                     option.gamma.unwrap_or(Decimal::ZERO) * Decimal::ONE_HUNDRED
                 )),
+                Cell::new(&format!(
+                    "{:.3}",
+                    option.call_greeks.map_or(Decimal::ZERO, |g| g.vega)
+                )),
+                Cell::new(&format!(
+                    "{:.3}",
+                    option.call_greeks.map_or(Decimal::ZERO, |g| g.theta)
+                )),
+                Cell::new(&format!(
+                    "{:.3}",
+                    option.put_greeks.map_or(Decimal::ZERO, |g| g.theta)
+                )),
                 Cell::new(&default_empty_string(option.volume)),
                 Cell::new(&default_empty_string(option.open_interest)),
             ];
@@ -4754,6 +4824,374 @@ impl PriceShockSurface for OptionChain {
     }
 }
 
+impl SmileAwarePriceShock for OptionChain {
+    /// Computes the full-revaluation P&L of a long call at each strike for a
+    /// price shock, re-reading the implied volatility from the chain smile.
+    ///
+    /// # Parameters
+    ///
+    /// - `shock_pct`: Price shock as a decimal (e.g., -0.10 for -10%)
+    /// - `dynamics`: Smile regime applied after the shock
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Curve)`: The shock curve with strike on x-axis and P&L on y-axis
+    /// - `Err(CurveError)`: If the smile cannot be built or nothing can be priced
+    fn price_shock_curve_with_dynamics(
+        &self,
+        shock_pct: Decimal,
+        dynamics: SmileDynamics,
+    ) -> Result<Curve, CurveError> {
+        let smile = StickySmile::from_chain(self, dynamics)
+            .map_err(|e| CurveError::ConstructionError(e.to_string()))?;
+        let shocked_price =
+            Positive::new_decimal(self.underlying_price.to_dec() * (Decimal::ONE + shock_pct))
+                .map_err(|e| CurveError::ConstructionError(e.to_string()))?;
+
+        let points: BTreeSet<Point2D> = self
+            .get_single_iter()
+            .filter_map(|opt| {
+                let option = opt.get_option(Side::Long, OptionStyle::Call).ok()?;
+                let base_value = option.calculate_price_black_scholes().ok()?;
+                let mut shocked = option.clone();
+                shocked.underlying_price = shocked_price;
+                shocked.implied_volatility =
+                    smile.volatility(opt.strike_price, shocked_price).ok()?;
+                let shocked_value = shocked.calculate_price_black_scholes().ok()?;
+                Some(Point2D::new(
+                    opt.strike_price.to_dec(),
+                    shocked_value - base_value,
+                ))
+            })
+            .collect();
+
+        if points.is_empty() {
+            return Err(CurveError::ConstructionError(
+                "No options could be repriced for the price shock".to_string(),
+            ));
+        }
+
+        Ok(Curve::new(points))
+    }
+
+    /// Computes long call values across underlying prices and strikes, with
+    /// volatilities re-read from the chain smile at each price.
+    ///
+    /// # Parameters
+    ///
+    /// - `price_range`: Tuple of (min_price, max_price) for the underlying
+    /// - `price_steps`: Number of steps along the price axis
+    /// - `dynamics`: Smile regime applied at each price
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Surface)`: Price on x-axis, strike on y-axis, value on z-axis
+    /// - `Err(SurfaceError)`: If the smile cannot be built or nothing can be priced
+    fn price_shock_surface_with_dynamics(
+        &self,
+        price_range: (Positive, Positive),
+        price_steps: usize,
+        dynamics: SmileDynamics,
+    ) -> Result<Surface, SurfaceError> {
+        let smile = StickySmile::from_chain(self, dynamics)
+            .map_err(|e| SurfaceError::ConstructionError(e.to_string()))?;
+        let price_step = if price_steps > 0 {
+            (price_range.1 - price_range.0).to_dec() / Decimal::from(price_steps)
+        } else {
+            Decimal::ZERO
+        };
+        let options: Vec<Options> = self
+            .get_single_iter()
+            .filter_map(|opt| opt.get_option(Side::Long, OptionStyle::Call).ok())
+            .collect();
+
+        let mut points = BTreeSet::new();
+        for p in 0..=price_steps {
+            let price = price_range.0.to_dec() + price_step * Decimal::from(p);
+            let Ok(price_pos) = Positive::new_decimal(price) else {
+                continue;
+            };
+            for option in &options {
+                let Ok(vol) = smile.volatility(option.strike_price, price_pos) else {
+                    continue;
+                };
+                let mut shocked = option.clone();
+                shocked.underlying_price = price_pos;
+                shocked.implied_volatility = vol;
+                if let Ok(value) = shocked.calculate_price_black_scholes() {
+                    points.insert(Point3D::new(price, option.strike_price.to_dec(), value));
+                }
+            }
+        }
+
+        if points.is_empty() {
+            return Err(SurfaceError::ConstructionError(
+                "No valid points for smile-aware price shock surface".to_string(),
+            ));
+        }
+
+        Ok(Surface::new(points))
+    }
+}
+
+impl SmileAwareVolatilitySensitivity for OptionChain {
+    /// Computes the vega of a long call at each strike after a price shock,
+    /// with the implied volatility re-read from the chain smile.
+    ///
+    /// # Parameters
+    ///
+    /// - `shock_pct`: Price shock as a decimal (e.g., -0.10 for -10%)
+    /// - `dynamics`: Smile regime applied after the shock
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Curve)`: The vega curve with strike on x-axis and vega on y-axis
+    /// - `Err(CurveError)`: If the smile cannot be built or no vega is valid
+    fn volatility_sensitivity_curve_with_dynamics(
+        &self,
+        shock_pct: Decimal,
+        dynamics: SmileDynamics,
+    ) -> Result<Curve, CurveError> {
+        let smile = StickySmile::from_chain(self, dynamics)
+            .map_err(|e| CurveError::ConstructionError(e.to_string()))?;
+        let shocked_price =
+            Positive::new_decimal(self.underlying_price.to_dec() * (Decimal::ONE + shock_pct))
+                .map_err(|e| CurveError::ConstructionError(e.to_string()))?;
+
+        let points: BTreeSet<Point2D> = self
+            .get_single_iter()
+            .filter_map(|opt| {
+                let option = opt.get_option(Side::Long, OptionStyle::Call).ok()?;
+                let years = option.expiration_date.get_years().ok()?;
+                let shocked = smile_repriced(&option, &smile, shocked_price, years, Decimal::ZERO)?;
+                Some(Point2D::new(
+                    opt.strike_price.to_dec(),
+                    shocked.vega().ok()?,
+                ))
+            })
+            .collect();
+
+        if points.is_empty() {
+            return Err(CurveError::ConstructionError(
+                "No options with valid vega data".to_string(),
+            ));
+        }
+
+        Ok(Curve::new(points))
+    }
+
+    /// Computes the value of a representative long call across underlying
+    /// prices and parallel shifts of the chain smile.
+    ///
+    /// # Parameters
+    ///
+    /// - `price_range`: Tuple of (min_price, max_price) for the underlying
+    /// - `vol_shift_range`: Tuple of (min_shift, max_shift) added to the smile
+    /// - `price_steps`: Number of steps along the price axis
+    /// - `vol_steps`: Number of steps along the shift axis
+    /// - `dynamics`: Smile regime applied at each price
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Surface)`: Price on x-axis, volatility shift on y-axis, value on z-axis
+    /// - `Err(SurfaceError)`: If the smile cannot be built or nothing can be priced
+    fn volatility_sensitivity_surface_with_dynamics(
+        &self,
+        price_range: (Positive, Positive),
+        vol_shift_range: (Decimal, Decimal),
+        price_steps: usize,
+        vol_steps: usize,
+        dynamics: SmileDynamics,
+    ) -> Result<Surface, SurfaceError> {
+        let smile = StickySmile::from_chain(self, dynamics)
+            .map_err(|e| SurfaceError::ConstructionError(e.to_string()))?;
+        let template = self
+            .get_single_iter()
+            .find_map(|opt| opt.get_option(Side::Long, OptionStyle::Call).ok())
+            .ok_or_else(|| {
+                SurfaceError::ConstructionError("No valid options in chain".to_string())
+            })?;
+        let years = template
+            .expiration_date
+            .get_years()
+            .map_err(|e| SurfaceError::ConstructionError(e.to_string()))?;
+        let price_step = if price_steps > 0 {
+            (price_range.1 - price_range.0).to_dec() / Decimal::from(price_steps)
+        } else {
+            Decimal::ZERO
+        };
+        let vol_step = if vol_steps > 0 {
+            (vol_shift_range.1 - vol_shift_range.0) / Decimal::from(vol_steps)
+        } else {
+            Decimal::ZERO
+        };
+
+        let mut points = BTreeSet::new();
+        for p in 0..=price_steps {
+            let price = price_range.0.to_dec() + price_step * Decimal::from(p);
+            let Ok(price_pos) = Positive::new_decimal(price) else {
+                continue;
+            };
+            for v in 0..=vol_steps {
+                let shift = vol_shift_range.0 + vol_step * Decimal::from(v);
+                let Some(shocked) = smile_repriced(&template, &smile, price_pos, years, shift)
+                else {
+                    continue;
+                };
+                if let Ok(value) = shocked.calculate_price_black_scholes() {
+                    points.insert(Point3D::new(price, shift, value));
+                }
+            }
+        }
+
+        if points.is_empty() {
+            return Err(SurfaceError::ConstructionError(
+                "No valid points for smile-aware volatility sensitivity surface".to_string(),
+            ));
+        }
+
+        Ok(Surface::new(points))
+    }
+}
+
+impl SmileAwareTimeDecay for OptionChain {
+    /// Computes the theta of a long call at each strike after a price shock,
+    /// with the implied volatility re-read from the chain smile.
+    ///
+    /// # Parameters
+    ///
+    /// - `shock_pct`: Price shock as a decimal (e.g., -0.10 for -10%)
+    /// - `dynamics`: Smile regime applied after the shock
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Curve)`: The theta curve with strike on x-axis and theta on y-axis
+    /// - `Err(CurveError)`: If the smile cannot be built or no theta is valid
+    fn time_decay_curve_with_dynamics(
+        &self,
+        shock_pct: Decimal,
+        dynamics: SmileDynamics,
+    ) -> Result<Curve, CurveError> {
+        let smile = StickySmile::from_chain(self, dynamics)
+            .map_err(|e| CurveError::ConstructionError(e.to_string()))?;
+        let shocked_price =
+            Positive::new_decimal(self.underlying_price.to_dec() * (Decimal::ONE + shock_pct))
+                .map_err(|e| CurveError::ConstructionError(e.to_string()))?;
+
+        let points: BTreeSet<Point2D> = self
+            .get_single_iter()
+            .filter_map(|opt| {
+                let option = opt.get_option(Side::Long, OptionStyle::Call).ok()?;
+                let years = option.expiration_date.get_years().ok()?;
+                let shocked = smile_repriced(&option, &smile, shocked_price, years, Decimal::ZERO)?;
+                Some(Point2D::new(
+                    opt.strike_price.to_dec(),
+                    shocked.theta().ok()?,
+                ))
+            })
+            .collect();
+
+        if points.is_empty() {
+            return Err(CurveError::ConstructionError(
+                "No options with valid theta data".to_string(),
+            ));
+        }
+
+        Ok(Curve::new(points))
+    }
+
+    /// Computes the value of a representative long call across underlying
+    /// prices and days to expiration, with the volatility re-read from the
+    /// chain smile at each price and remaining time.
+    ///
+    /// # Parameters
+    ///
+    /// - `price_range`: Tuple of (min_price, max_price) for the underlying
+    /// - `days_to_expiry`: Vector of days to expiration values
+    /// - `price_steps`: Number of steps along the price axis
+    /// - `dynamics`: Smile regime applied at each point
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Surface)`: Price on x-axis, days on y-axis, value on z-axis
+    /// - `Err(SurfaceError)`: If the smile cannot be built or nothing can be priced
+    fn time_decay_surface_with_dynamics(
+        &self,
+        price_range: (Positive, Positive),
+        days_to_expiry: Vec<Positive>,
+        price_steps: usize,
+        dynamics: SmileDynamics,
+    ) -> Result<Surface, SurfaceError> {
+        let smile = StickySmile::from_chain(self, dynamics)
+            .map_err(|e| SurfaceError::ConstructionError(e.to_string()))?;
+        let template = self
+            .get_single_iter()
+            .find_map(|opt| opt.get_option(Side::Long, OptionStyle::Call).ok())
+            .ok_or_else(|| {
+                SurfaceError::ConstructionError("No valid options in chain".to_string())
+            })?;
+        let price_step = if price_steps > 0 {
+            (price_range.1 - price_range.0).to_dec() / Decimal::from(price_steps)
+        } else {
+            Decimal::ZERO
+        };
+
+        let mut points = BTreeSet::new();
+        for days in &days_to_expiry {
+            let mut dated = template.clone();
+            dated.expiration_date = ExpirationDate::Days(*days);
+            let Ok(years) = dated.expiration_date.get_years() else {
+                continue;
+            };
+            for p in 0..=price_steps {
+                let price = price_range.0.to_dec() + price_step * Decimal::from(p);
+                let Ok(price_pos) = Positive::new_decimal(price) else {
+                    continue;
+                };
+                let Some(shocked) = smile_repriced(&dated, &smile, price_pos, years, Decimal::ZERO)
+                else {
+                    continue;
+                };
+                if let Ok(value) = shocked.calculate_price_black_scholes() {
+                    points.insert(Point3D::new(price, days.to_dec(), value));
+                }
+            }
+        }
+
+        if points.is_empty() {
+            return Err(SurfaceError::ConstructionError(
+                "No valid points for smile-aware time decay surface".to_string(),
+            ));
+        }
+
+        Ok(Surface::new(points))
+    }
+}
+
+/// Clones `option` at `underlying_price` with the volatility read from
+/// `smile` for `years` to expiry, shifted by `volatility_shift`. Returns
+/// `None` when the smile fails or the shifted volatility is not positive.
+fn smile_repriced(
+    option: &Options,
+    smile: &StickySmile,
+    underlying_price: Positive,
+    years: Positive,
+    volatility_shift: Decimal,
+) -> Option<Options> {
+    let volatility = smile
+        .volatility_at(option.strike_price, underlying_price, years)
+        .ok()?
+        .to_dec()
+        + volatility_shift;
+    if volatility <= Decimal::ZERO {
+        return None;
+    }
+    let mut repriced = option.clone();
+    repriced.underlying_price = underlying_price;
+    repriced.implied_volatility = Positive::new_decimal(volatility).ok()?;
+    Some(repriced)
+}
+
 impl ThetaCurve for OptionChain {
     /// Computes the theta curve by strike price for this option chain.
     ///
@@ -8809,6 +9247,8 @@ mod tests_serialization {
             delta_call: Some(dec!(0.5)),
             delta_put: Some(dec!(-0.5)),
             gamma: Some(dec!(0.1)),
+            call_greeks: None,
+            put_greeks: None,
             volume: spos!(1000.0),
             open_interest: Some(500),
             symbol: None,
@@ -8876,6 +9316,8 @@ mod tests_serialization {
             delta_call: None,
             delta_put: None,
             gamma: None,
+            call_greeks: None,
+            put_greeks: None,
             volume: None,
             open_interest: None,
             symbol: None,
@@ -8917,6 +9359,8 @@ mod tests_option_data_serde {
             delta_call: Some(dec!(0.5)),
             delta_put: Some(dec!(-0.5)),
             gamma: Some(dec!(0.1)),
+            call_greeks: None,
+            put_greeks: None,
             volume: spos!(1000.0),
             open_interest: Some(500),
             symbol: None,
@@ -8956,6 +9400,8 @@ mod tests_option_data_serde {
             delta_call: None,
             delta_put: None,
             gamma: None,
+            call_greeks: None,
+            put_greeks: None,
             volume: None,
             open_interest: None,
             symbol: None,
@@ -13374,3 +13820,137 @@ mod tests_price_metrics_traits {
         assert_decimal_eq!(strike_concentration_vec[4].y, dec!(1.31928), epsilon);
     }
 }
+
+#[cfg(test)]
+mod tests_side_greeks {
+    use super::*;
+    use crate::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
+    use positive::spos;
+    use rust_decimal_macros::dec;
+
+    fn params() -> OptionChainBuildParams {
+        OptionChainBuildParams::new(
+            "SPY".to_string(),
+            spos!(1000.0),
+            10,
+            spos!(5.0),
+            dec!(-0.2),
+            dec!(0.1),
+            pos_or_panic!(0.02),
+            2,
+            OptionDataPriceParams::new(
+                Some(Box::new(Positive::HUNDRED)),
+                Some(ExpirationDate::Days(pos_or_panic!(30.0))),
+                Some(dec!(0.05)),
+                spos!(0.01),
+                Some("SPY".to_string()),
+            ),
+            pos_or_panic!(0.2),
+        )
+    }
+
+    fn chain() -> OptionChain {
+        let mut chain = OptionChain::build_chain(&params()).unwrap();
+        chain.update_greeks();
+        chain
+    }
+
+    #[test]
+    fn test_build_chain_populates_side_greeks() {
+        let built = OptionChain::build_chain(&params()).unwrap();
+        let refreshed = chain();
+        for (option, expected) in built.get_single_iter().zip(refreshed.get_single_iter()) {
+            assert!(option.call_greeks.is_some() && option.put_greeks.is_some());
+            assert_eq!(option.call_greeks, expected.call_greeks);
+            assert_eq!(option.put_greeks, expected.put_greeks);
+        }
+    }
+
+    #[test]
+    fn test_repricing_inputs_clear_side_greeks() {
+        let mut chain = chain();
+        chain.set_optiondata_extra_params().unwrap();
+        chain.update_greeks();
+        // Re-applying unchanged parameters keeps the stored Greeks.
+        chain.set_optiondata_extra_params().unwrap();
+        assert!(chain.get_single_iter().all(|o| o.call_greeks.is_some()));
+        let before = chain.vega_exposure().unwrap();
+
+        chain.underlying_price = pos_or_panic!(110.0);
+        chain.set_optiondata_extra_params().unwrap();
+        assert!(
+            chain
+                .get_single_iter()
+                .all(|o| o.call_greeks.is_none() && o.put_greeks.is_none())
+        );
+        let after = chain.vega_exposure().unwrap();
+        assert_ne!(before, after);
+        let mut refreshed = chain.clone();
+        refreshed.update_greeks();
+        assert!((refreshed.vega_exposure().unwrap() - after).abs() < dec!(1e-9));
+
+        let mut option = refreshed.get_single_iter().next().unwrap().clone();
+        option.set_volatility(&option.implied_volatility.clone());
+        assert!(option.call_greeks.is_some());
+        option.set_volatility(&pos_or_panic!(0.3));
+        assert!(option.call_greeks.is_none() && option.put_greeks.is_none());
+    }
+
+    #[test]
+    fn test_update_greeks_populates_both_sides() {
+        let chain = chain();
+        for option in chain.get_single_iter() {
+            let call = option.call_greeks.unwrap();
+            let put = option.put_greeks.unwrap();
+            assert_eq!(option.delta_call, Some(call.delta));
+            assert_eq!(option.delta_put, Some(put.delta));
+            assert!(call.vanna != Decimal::ZERO || call.charm != Decimal::ZERO);
+        }
+    }
+
+    #[test]
+    fn test_exposures_read_stored_greeks() {
+        let chain = chain();
+        let stored: Decimal = chain
+            .options
+            .iter()
+            .map(|o| o.call_greeks.unwrap().vega + o.put_greeks.unwrap().vega)
+            .sum();
+        assert_eq!(chain.vega_exposure().unwrap(), stored);
+        let stored: Decimal = chain
+            .options
+            .iter()
+            .map(|o| o.call_greeks.unwrap().theta + o.put_greeks.unwrap().theta)
+            .sum();
+        assert_eq!(chain.theta_exposure().unwrap(), stored);
+
+        // Exposures still work when the stored Greeks are missing.
+        let mut recomputed = chain.clone();
+        recomputed.options = chain
+            .options
+            .iter()
+            .cloned()
+            .map(|mut option| {
+                option.call_greeks = None;
+                option.put_greeks = None;
+                option
+            })
+            .collect();
+        let difference = recomputed.vega_exposure().unwrap() - chain.vega_exposure().unwrap();
+        assert!(difference.abs() < dec!(0.000001));
+    }
+
+    #[test]
+    fn test_csv_round_trip_keeps_side_greeks() {
+        let chain = chain();
+        let dir = tempfile::tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap();
+        chain.save_to_csv(dir_path).unwrap();
+        let file = format!("{}/{}.csv", dir_path, chain.get_title());
+        let loaded = OptionChain::load_from_csv(&file).unwrap();
+        for (original, loaded) in chain.options.iter().zip(loaded.options.iter()) {
+            assert_eq!(original.call_greeks, loaded.call_greeks);
+            assert_eq!(original.put_greeks, loaded.put_greeks);
+        }
+    }
+}
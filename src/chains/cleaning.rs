@@ -0,0 +1,619 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Quote Cleaning
+//!
+//! Loaded chains routinely carry quotes that break Greeks and curve
+//! construction: zero bids, crossed markets, strikes nobody trades and
+//! missing implied volatilities. [`OptionChain::clean`] runs a configurable
+//! pipeline over the chain and returns the cleaned copy together with an
+//! audit log of every modification:
+//!
+//! 1. **Quote rules**, evaluated per side (call and put): crossed market,
+//!    zero bid, mid below a minimum price and bid/ask spread wider than a
+//!    fraction of the mid. Each rule maps to a [`QuoteAction`]: drop the
+//!    whole strike, clear the offending side's quote, or only flag it.
+//! 2. **Stale strikes**, without volume or open interest, handled with
+//!    their own action when enabled.
+//! 3. **IV recomputation** from the mid of the out-of-the-money side,
+//!    falling back to the other side when it has no quote.
+//! 4. **IV interpolation**: strikes still without an implied volatility
+//!    take the value of the chain's smile, linear in strike between the
+//!    neighbouring quotes and flat beyond them.
+//!
+//! Greeks are recalculated on the cleaned chain. The source chain is never
+//! modified.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use optionstratlib::chains::{CleaningParameters, QuoteIssue};
+//! use optionstratlib::chains::chain::OptionChain;
+//! use positive::{pos_or_panic, spos, Positive};
+//!
+//! let mut chain = OptionChain::new("XYZ", Positive::HUNDRED, "2030-01-18".to_string(), None, None);
+//! chain.add_option(Positive::HUNDRED, spos!(5.0), spos!(5.2), spos!(4.0), spos!(4.2), pos_or_panic!(0.2), None, None, None, None, None, None);
+//! // Crossed call market.
+//! chain.add_option(pos_or_panic!(105.0), spos!(3.4), spos!(3.0), spos!(6.0), spos!(6.2), pos_or_panic!(0.2), None, None, None, None, None, None);
+//!
+//! let cleaned = chain.clean(&CleaningParameters::default()).unwrap();
+//! assert_eq!(cleaned.chain.options.len(), 1);
+//! assert!(cleaned.audit.iter().any(|record| record.issue == QuoteIssue::CrossedMarket));
+//! ```
+
+use crate::chains::chain::OptionChain;
+use crate::chains::optiondata::OptionData;
+use crate::chains::utils::OptionDataPriceParams;
+use crate::error::chains::ChainError;
+use crate::model::{OptionStyle, Side};
+use crate::volatility::{SmileDynamics, StickySmile};
+use positive::Positive;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use tracing::debug;
+use utoipa::ToSchema;
+
+/// Minimum change, in volatility points, for a recomputed IV to be logged.
+const IV_CHANGE_THRESHOLD: Decimal = dec!(0.000001);
+
+/// What the pipeline does with a quote that breaks a rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ToSchema, Serialize, Deserialize)]
+pub enum QuoteAction {
+    /// Remove the whole strike from the chain
+    Drop,
+    /// Remove the offending side's bid, ask and mid
+    Clear,
+    /// Keep the quote and record the issue
+    Flag,
+}
+
+/// Problem detected by the cleaning pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ToSchema, Serialize, Deserialize)]
+pub enum QuoteIssue {
+    /// Bid above ask
+    CrossedMarket,
+    /// Bid of zero
+    ZeroBid,
+    /// Mid below the configured minimum price
+    BelowMinPrice,
+    /// Spread wider than the configured fraction of the mid
+    WideSpread,
+    /// Strike without volume or open interest
+    Stale,
+    /// Implied volatility replaced by the one implied by the mid
+    ImpliedVolatilityRecomputed,
+    /// Missing implied volatility filled from the smile
+    ImpliedVolatilityInterpolated,
+    /// Implied volatility missing and not recoverable
+    MissingImpliedVolatility,
+}
+
+impl fmt::Display for QuoteIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            QuoteIssue::CrossedMarket => "crossed market",
+            QuoteIssue::ZeroBid => "zero bid",
+            QuoteIssue::BelowMinPrice => "below minimum price",
+            QuoteIssue::WideSpread => "wide spread",
+            QuoteIssue::Stale => "stale strike",
+            QuoteIssue::ImpliedVolatilityRecomputed => "implied volatility recomputed",
+            QuoteIssue::ImpliedVolatilityInterpolated => "implied volatility interpolated",
+            QuoteIssue::MissingImpliedVolatility => "missing implied volatility",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// What happened to the chain as a consequence of an issue.
+#[derive(Debug, Clone, Copy, PartialEq, ToSchema, Serialize, Deserialize)]
+pub enum CleaningOutcome {
+    /// The strike was removed
+    Dropped,
+    /// The side's quote was removed
+    Cleared,
+    /// Nothing was changed
+    Flagged,
+    /// A value was replaced
+    Replaced {
+        /// Value before cleaning
+        before: Decimal,
+        /// Value after cleaning
+        after: Decimal,
+    },
+}
+
+impl From<QuoteAction> for CleaningOutcome {
+    fn from(action: QuoteAction) -> Self {
+        match action {
+            QuoteAction::Drop => CleaningOutcome::Dropped,
+            QuoteAction::Clear => CleaningOutcome::Cleared,
+            QuoteAction::Flag => CleaningOutcome::Flagged,
+        }
+    }
+}
+
+/// One entry of the cleaning audit log.
+#[derive(DebugPretty, DisplaySimple, Clone, PartialEq, ToSchema, Serialize, Deserialize)]
+pub struct CleaningRecord {
+    /// Strike the record refers to
+    pub strike: Positive,
+    /// Side the issue was found on, `None` for strike-level issues
+    pub style: Option<OptionStyle>,
+    /// Detected issue
+    pub issue: QuoteIssue,
+    /// Resulting modification
+    pub outcome: CleaningOutcome,
+}
+
+/// Rules and thresholds of the cleaning pipeline.
+#[derive(DebugPretty, DisplaySimple, Clone, PartialEq, ToSchema, Serialize, Deserialize)]
+pub struct CleaningParameters {
+    /// Action for crossed markets (default `Drop`)
+    pub crossed_market: QuoteAction,
+    /// Action for zero bids (default `Clear`)
+    pub zero_bid: QuoteAction,
+    /// Minimum mid price and the action for quotes below it
+    pub min_price: Option<(Positive, QuoteAction)>,
+    /// Maximum spread as a fraction of the mid and the action for wider
+    /// quotes
+    pub max_spread: Option<(Positive, QuoteAction)>,
+    /// Action for strikes without volume or open interest; not checked
+    /// when `None` (default)
+    pub stale: Option<QuoteAction>,
+    /// Recompute implied volatility from mids (default `true`)
+    pub recompute_iv: bool,
+    /// Fill missing implied volatilities from the smile (default `true`)
+    pub interpolate_missing_iv: bool,
+}
+
+impl Default for CleaningParameters {
+    fn default() -> Self {
+        Self {
+            crossed_market: QuoteAction::Drop,
+            zero_bid: QuoteAction::Clear,
+            min_price: None,
+            max_spread: None,
+            stale: None,
+            recompute_iv: true,
+            interpolate_missing_iv: true,
+        }
+    }
+}
+
+/// A cleaned chain and the log of the modifications made to it.
+#[derive(Debug, Clone)]
+pub struct CleanedChain {
+    /// Chain after cleaning
+    pub chain: OptionChain,
+    /// Every issue found, in pipeline order
+    pub audit: Vec<CleaningRecord>,
+}
+
+impl CleanedChain {
+    /// Records of one issue kind.
+    pub fn of_issue(&self, issue: QuoteIssue) -> impl Iterator<Item = &CleaningRecord> {
+        self.audit
+            .iter()
+            .filter(move |record| record.issue == issue)
+    }
+
+    /// Number of strikes removed by the pipeline.
+    #[must_use]
+    pub fn dropped(&self) -> usize {
+        self.audit
+            .iter()
+            .filter(|record| record.outcome == CleaningOutcome::Dropped)
+            .count()
+    }
+}
+
+fn quote_mut(
+    option: &mut OptionData,
+    style: OptionStyle,
+) -> (
+    &mut Option<Positive>,
+    &mut Option<Positive>,
+    &mut Option<Positive>,
+) {
+    match style {
+        OptionStyle::Call => (
+            &mut option.call_bid,
+            &mut option.call_ask,
+            &mut option.call_middle,
+        ),
+        OptionStyle::Put => (
+            &mut option.put_bid,
+            &mut option.put_ask,
+            &mut option.put_middle,
+        ),
+    }
+}
+
+impl CleaningParameters {
+    /// First rule broken by one side's quote, with its action.
+    fn quote_issue(
+        &self,
+        bid: Option<Positive>,
+        ask: Option<Positive>,
+    ) -> Option<(QuoteIssue, QuoteAction)> {
+        let (bid, ask) = (bid?, ask?);
+        if bid > ask {
+            return Some((QuoteIssue::CrossedMarket, self.crossed_market));
+        }
+        if bid.is_zero() {
+            return Some((QuoteIssue::ZeroBid, self.zero_bid));
+        }
+        let mid = (bid.to_dec() + ask.to_dec()) / Decimal::TWO;
+        if let Some((min_price, action)) = self.min_price
+            && mid < min_price.to_dec()
+        {
+            return Some((QuoteIssue::BelowMinPrice, action));
+        }
+        if let Some((max_spread, action)) = self.max_spread
+            && ask.to_dec() - bid.to_dec() > max_spread.to_dec() * mid
+        {
+            return Some((QuoteIssue::WideSpread, action));
+        }
+        None
+    }
+
+    /// Applies the quote and staleness rules to one strike. Returns `false`
+    /// when the strike must be dropped.
+    fn apply_quote_rules(&self, option: &mut OptionData, audit: &mut Vec<CleaningRecord>) -> bool {
+        let strike = option.strike_price;
+        for style in [OptionStyle::Call, OptionStyle::Put] {
+            let (bid, ask, middle) = quote_mut(option, style);
+            let Some((issue, action)) = self.quote_issue(*bid, *ask) else {
+                continue;
+            };
+            audit.push(CleaningRecord {
+                strike,
+                style: Some(style),
+                issue,
+                outcome: action.into(),
+            });
+            match action {
+                QuoteAction::Drop => return false,
+                QuoteAction::Clear => {
+                    *bid = None;
+                    *ask = None;
+                    *middle = None;
+                }
+                QuoteAction::Flag => {}
+            }
+        }
+
+        if let Some(action) = self.stale {
+            let traded = option.volume.is_some_and(|volume| !volume.is_zero())
+                || option.open_interest.is_some_and(|oi| oi > 0);
+            if !traded {
+                audit.push(CleaningRecord {
+                    strike,
+                    style: None,
+                    issue: QuoteIssue::Stale,
+                    outcome: action.into(),
+                });
+                match action {
+                    QuoteAction::Drop => return false,
+                    QuoteAction::Clear => {
+                        for style in [OptionStyle::Call, OptionStyle::Put] {
+                            let (bid, ask, middle) = quote_mut(option, style);
+                            *bid = None;
+                            *ask = None;
+                            *middle = None;
+                        }
+                    }
+                    QuoteAction::Flag => {}
+                }
+            }
+        }
+        true
+    }
+}
+
+/// Implied volatility of the out-of-the-money side's mid, falling back to
+/// the other side.
+fn implied_volatility_from_mids(
+    option: &OptionData,
+    underlying_price: Positive,
+) -> Option<Positive> {
+    let sides = if option.strike_price >= underlying_price {
+        [
+            (OptionStyle::Call, option.call_middle),
+            (OptionStyle::Put, option.put_middle),
+        ]
+    } else {
+        [
+            (OptionStyle::Put, option.put_middle),
+            (OptionStyle::Call, option.call_middle),
+        ]
+    };
+    sides.into_iter().find_map(|(style, mid)| {
+        let mid = mid.filter(|mid| !mid.is_zero())?;
+        let contract = option.get_option(Side::Long, style).ok()?;
+        contract
+            .calculate_implied_volatility(mid.to_dec())
+            .inspect_err(|e| debug!("clean: no IV for {} {style:?}: {e}", option.strike_price))
+            .ok()
+    })
+}
+
+impl OptionChain {
+    /// Runs the cleaning pipeline described in the module documentation and
+    /// returns the cleaned chain with its audit log.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChainError::ChainBuildError` if `recompute_iv` is set and
+    /// the chain has no valid expiration to price against.
+    pub fn clean(&self, params: &CleaningParameters) -> Result<CleanedChain, ChainError> {
+        let expiration = self.get_expiration();
+        if params.recompute_iv && expiration.is_none() {
+            return Err(ChainError::invalid_parameters(
+                "expiration_date",
+                "implied volatility recomputation requires a valid chain expiration",
+            ));
+        }
+        let price_params = OptionDataPriceParams::new(
+            Some(Box::new(self.underlying_price)),
+            expiration,
+            self.risk_free_rate,
+            self.dividend_yield,
+            Some(self.symbol.clone()),
+        );
+
+        let mut audit = Vec::new();
+        let mut options = Vec::with_capacity(self.options.len());
+        for option in &self.options {
+            let mut option = option.clone();
+            if !params.apply_quote_rules(&mut option, &mut audit) {
+                continue;
+            }
+            option.set_mid_prices();
+            option.set_extra_params(price_params.clone());
+
+            if params.recompute_iv
+                && let Some(iv) = implied_volatility_from_mids(&option, self.underlying_price)
+            {
+                let before = option.implied_volatility;
+                if (iv.to_dec() - before.to_dec()).abs() > IV_CHANGE_THRESHOLD {
+                    audit.push(CleaningRecord {
+                        strike: option.strike_price,
+                        style: None,
+                        issue: QuoteIssue::ImpliedVolatilityRecomputed,
+                        outcome: CleaningOutcome::Replaced {
+                            before: before.to_dec(),
+                            after: iv.to_dec(),
+                        },
+                    });
+                    option.implied_volatility = iv;
+                }
+            }
+            options.push(option);
+        }
+
+        if params.interpolate_missing_iv {
+            let quoted = options
+                .iter()
+                .map(|option| (option.strike_price, option.implied_volatility))
+                .collect();
+            // Only the unshifted smile is read, so the regime is irrelevant.
+            let smile = StickySmile::new(
+                self.underlying_price,
+                Positive::ZERO,
+                self.risk_free_rate.unwrap_or(Decimal::ZERO),
+                self.dividend_yield.unwrap_or(Positive::ZERO),
+                quoted,
+                SmileDynamics::StickyStrike,
+            )
+            .ok();
+            for option in options
+                .iter_mut()
+                .filter(|o| o.implied_volatility.is_zero())
+            {
+                let Some(smile) = &smile else { break };
+                let iv = smile.reference_volatility(option.strike_price);
+                audit.push(CleaningRecord {
+                    strike: option.strike_price,
+                    style: None,
+                    issue: QuoteIssue::ImpliedVolatilityInterpolated,
+                    outcome: CleaningOutcome::Replaced {
+                        before: Decimal::ZERO,
+                        after: iv.to_dec(),
+                    },
+                });
+                option.implied_volatility = iv;
+            }
+        }
+
+        let mut chain = OptionChain::new(
+            &self.symbol,
+            self.underlying_price,
+            self.get_expiration_date(),
+            self.risk_free_rate,
+            self.dividend_yield,
+        );
+        for option in options {
+            if option.implied_volatility.is_zero() {
+                audit.push(CleaningRecord {
+                    strike: option.strike_price,
+                    style: None,
+                    issue: QuoteIssue::MissingImpliedVolatility,
+                    outcome: CleaningOutcome::Flagged,
+                });
+            }
+            chain.options.insert(option);
+        }
+        chain.update_greeks();
+
+        Ok(CleanedChain { chain, audit })
+    }
+}
+
+#[cfg(test)]
+mod tests_chain_cleaning {
+    use super::*;
+    use positive::{pos_or_panic, spos};
+
+    fn chain() -> OptionChain {
+        let mut chain = OptionChain::new(
+            "XYZ",
+            Positive::HUNDRED,
+            "2030-01-18".to_string(),
+            Some(dec!(0.02)),
+            spos!(0.0),
+        );
+        let quotes = [
+            (90.0, (12.0, 12.4), (1.9, 2.1), 0.25),
+            (95.0, (8.0, 8.3), (3.0, 3.2), 0.0),
+            (100.0, (5.0, 5.2), (4.4, 4.6), 0.2),
+            (105.0, (3.4, 3.0), (6.0, 6.2), 0.18),
+            (110.0, (0.0, 0.4), (10.0, 10.4), 0.17),
+        ];
+        for (strike, (cb, ca), (pb, pa), iv) in quotes {
+            chain.add_option(
+                pos_or_panic!(strike),
+                spos!(cb),
+                spos!(ca),
+                spos!(pb),
+                spos!(pa),
+                pos_or_panic!(iv),
+                None,
+                None,
+                None,
+                None,
+                Some(if strike == 90.0 { 0 } else { 100 }),
+                None,
+            );
+        }
+        chain
+    }
+
+    fn no_iv_steps() -> CleaningParameters {
+        CleaningParameters {
+            recompute_iv: false,
+            interpolate_missing_iv: false,
+            ..CleaningParameters::default()
+        }
+    }
+
+    #[test]
+    fn test_crossed_market_dropped_and_zero_bid_cleared() {
+        let cleaned = chain().clean(&no_iv_steps()).unwrap();
+        let strikes: Vec<_> = cleaned
+            .chain
+            .options
+            .iter()
+            .map(|o| o.strike_price)
+            .collect();
+        assert!(!strikes.contains(&pos_or_panic!(105.0)));
+        assert_eq!(cleaned.dropped(), 1);
+
+        let cleared = cleaned
+            .chain
+            .options
+            .iter()
+            .find(|o| o.strike_price == pos_or_panic!(110.0))
+            .unwrap();
+        assert!(cleared.call_bid.is_none() && cleared.call_middle.is_none());
+        assert!(cleared.put_bid.is_some());
+        let record = cleaned.of_issue(QuoteIssue::ZeroBid).next().unwrap();
+        assert_eq!(record.style, Some(OptionStyle::Call));
+        assert_eq!(record.outcome, CleaningOutcome::Cleared);
+    }
+
+    #[test]
+    fn test_flag_keeps_quote() {
+        let params = CleaningParameters {
+            crossed_market: QuoteAction::Flag,
+            ..no_iv_steps()
+        };
+        let cleaned = chain().clean(&params).unwrap();
+        assert_eq!(cleaned.chain.options.len(), 5);
+        assert_eq!(cleaned.of_issue(QuoteIssue::CrossedMarket).count(), 1);
+    }
+
+    #[test]
+    fn test_min_price_max_spread_and_stale() {
+        let params = CleaningParameters {
+            min_price: Some((pos_or_panic!(2.5), QuoteAction::Flag)),
+            max_spread: Some((pos_or_panic!(0.05), QuoteAction::Flag)),
+            stale: Some(QuoteAction::Drop),
+            ..no_iv_steps()
+        };
+        let cleaned = chain().clean(&params).unwrap();
+        // The 90 strike has no open interest.
+        assert_eq!(
+            cleaned.of_issue(QuoteIssue::Stale).next().unwrap().strike,
+            pos_or_panic!(90.0)
+        );
+        assert!(
+            cleaned
+                .of_issue(QuoteIssue::WideSpread)
+                .any(|r| r.strike == pos_or_panic!(95.0) && r.style == Some(OptionStyle::Put))
+        );
+        let below: Vec<_> = cleaned.of_issue(QuoteIssue::BelowMinPrice).collect();
+        assert_eq!(below.len(), 1);
+        assert_eq!(below[0].strike, pos_or_panic!(90.0));
+        assert_eq!(below[0].style, Some(OptionStyle::Put));
+    }
+
+    #[test]
+    fn test_missing_iv_interpolated_from_smile() {
+        let params = CleaningParameters {
+            recompute_iv: false,
+            ..CleaningParameters::default()
+        };
+        let cleaned = chain().clean(&params).unwrap();
+        let option = cleaned
+            .chain
+            .options
+            .iter()
+            .find(|o| o.strike_price == pos_or_panic!(95.0))
+            .unwrap();
+        assert!((option.implied_volatility.to_f64() - 0.225).abs() < 1e-12);
+        let record = cleaned
+            .of_issue(QuoteIssue::ImpliedVolatilityInterpolated)
+            .next()
+            .unwrap();
+        assert_eq!(record.strike, pos_or_panic!(95.0));
+        assert!(matches!(
+            record.outcome,
+            CleaningOutcome::Replaced { before, .. } if before.is_zero()
+        ));
+    }
+
+    #[test]
+    fn test_iv_recomputed_from_mids_and_greeks_updated() {
+        let cleaned = chain().clean(&CleaningParameters::default()).unwrap();
+        assert!(
+            cleaned
+                .of_issue(QuoteIssue::ImpliedVolatilityRecomputed)
+                .count()
+                > 0
+        );
+        for option in &cleaned.chain.options {
+            assert!(!option.implied_volatility.is_zero());
+            assert!(option.delta_call.is_some());
+        }
+        assert_eq!(
+            cleaned
+                .of_issue(QuoteIssue::MissingImpliedVolatility)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_recompute_requires_expiration() {
+        let mut chain = chain();
+        chain.update_expiration_date("not a date".to_string());
+        assert!(chain.clean(&CleaningParameters::default()).is_err());
+    }
+}
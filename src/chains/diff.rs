@@ -0,0 +1,369 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Option Chain Diff
+//!
+//! Compares two snapshots of the same underlying's [`OptionChain`] and
+//! reports what moved between them: per-strike changes in mid prices,
+//! implied volatility, deltas, volume and open interest, strikes listed or
+//! delisted, and the smile shift decomposed into level, skew and curvature.
+//!
+//! The smile decomposition fits the implied volatility change of the common
+//! strikes against log-moneyness `x = ln(K / S)` of the newer chain,
+//!
+//! ```text
+//! Δσ(x) ≈ level + skew · x + curvature · x²
+//! ```
+//!
+//! so `level` is the ATM volatility move, `skew` the tilt of the smile and
+//! `curvature` the change in its convexity.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use optionstratlib::chains::OptionChain;
+//! use positive::{pos_or_panic, spos, Positive};
+//!
+//! let mut before = OptionChain::new("SPY", Positive::HUNDRED, "2030-01-18".to_string(), None, None);
+//! let mut after = OptionChain::new("SPY", pos_or_panic!(101.0), "2030-01-18".to_string(), None, None);
+//! for (chain, iv) in [(&mut before, 0.20), (&mut after, 0.22)] {
+//!     for strike in [95.0, 100.0, 105.0] {
+//!         chain.add_option(pos_or_panic!(strike), spos!(5.0), spos!(5.2), spos!(4.0), spos!(4.2), pos_or_panic!(iv), None, None, None, None, None, None);
+//!     }
+//! }
+//!
+//! let diff = before.diff(&after).unwrap();
+//! assert_eq!(diff.changes.len(), 3);
+//! let shift = diff.smile_shift.unwrap();
+//! assert!((shift.level - rust_decimal_macros::dec!(0.02)).abs() < rust_decimal_macros::dec!(1e-9));
+//! ```
+
+use crate::chains::chain::OptionChain;
+use crate::chains::optiondata::OptionData;
+use crate::error::ChainError;
+use positive::Positive;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::FromPrimitive;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Change of a single strike between two chain snapshots.
+///
+/// Each field is `newer − older` and is `None` when either snapshot lacks
+/// the value.
+#[derive(DebugPretty, DisplaySimple, Clone, PartialEq, ToSchema, Serialize, Deserialize)]
+pub struct StrikeChange {
+    /// Strike price
+    pub strike: Positive,
+    /// Change in the call mid price
+    pub call_mid: Option<Decimal>,
+    /// Change in the put mid price
+    pub put_mid: Option<Decimal>,
+    /// Change in implied volatility
+    pub implied_volatility: Decimal,
+    /// Change in call delta
+    pub delta_call: Option<Decimal>,
+    /// Change in put delta
+    pub delta_put: Option<Decimal>,
+    /// Change in traded volume
+    pub volume: Option<Decimal>,
+    /// Change in open interest
+    pub open_interest: Option<i64>,
+}
+
+/// Implied volatility smile shift decomposed into level, skew and
+/// curvature against log-moneyness.
+#[derive(DebugPretty, DisplaySimple, Clone, Copy, PartialEq, ToSchema, Serialize, Deserialize)]
+pub struct SmileShift {
+    /// Parallel (ATM) volatility change
+    pub level: Decimal,
+    /// Change in smile slope per unit of log-moneyness
+    pub skew: Decimal,
+    /// Change in smile convexity per unit of squared log-moneyness
+    pub curvature: Decimal,
+    /// Root mean squared residual of the fit
+    pub rmse: Decimal,
+}
+
+/// Differences between two snapshots of the same underlying's chain.
+#[derive(DebugPretty, DisplaySimple, Clone, PartialEq, ToSchema, Serialize, Deserialize)]
+pub struct ChainDiff {
+    /// Underlying symbol
+    pub symbol: String,
+    /// Change in underlying price
+    pub underlying_change: Decimal,
+    /// Per-strike changes for strikes present in both chains
+    pub changes: Vec<StrikeChange>,
+    /// Strikes only present in the newer chain
+    pub listed: Vec<Positive>,
+    /// Strikes only present in the older chain
+    pub delisted: Vec<Positive>,
+    /// Smile shift, when at least three common strikes carry a volatility
+    pub smile_shift: Option<SmileShift>,
+}
+
+impl ChainDiff {
+    /// The `n` strikes with the largest absolute implied volatility change.
+    #[must_use]
+    pub fn top_iv_movers(&self, n: usize) -> Vec<&StrikeChange> {
+        let mut changes: Vec<&StrikeChange> = self.changes.iter().collect();
+        changes.sort_by_key(|change| std::cmp::Reverse(change.implied_volatility.abs()));
+        changes.truncate(n);
+        changes
+    }
+}
+
+impl OptionChain {
+    /// Compares this chain with a newer snapshot of the same underlying.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChainError::ChainBuildError`] when the chains belong to
+    /// different symbols.
+    pub fn diff(&self, newer: &OptionChain) -> Result<ChainDiff, ChainError> {
+        if self.symbol != newer.symbol {
+            return Err(ChainError::invalid_parameters(
+                "symbol",
+                &format!("cannot diff {} against {}", self.symbol, newer.symbol),
+            ));
+        }
+
+        let mut changes = Vec::new();
+        let mut delisted = Vec::new();
+        for old in &self.options {
+            match newer.options.get(old) {
+                Some(new) => changes.push(strike_change(old, new)),
+                None => delisted.push(old.strike_price),
+            }
+        }
+        let listed = newer
+            .options
+            .iter()
+            .filter(|new| !self.options.contains(*new))
+            .map(|new| new.strike_price)
+            .collect();
+
+        let spot = newer.underlying_price.to_f64();
+        let points: Vec<(f64, f64)> = self
+            .options
+            .iter()
+            .filter_map(|old| {
+                let new = newer.options.get(old)?;
+                if old.implied_volatility.is_zero() || new.implied_volatility.is_zero() {
+                    return None;
+                }
+                let x = (old.strike_price.to_f64() / spot).ln();
+                let dv = new.implied_volatility.to_f64() - old.implied_volatility.to_f64();
+                Some((x, dv))
+            })
+            .collect();
+
+        Ok(ChainDiff {
+            symbol: self.symbol.clone(),
+            underlying_change: newer.underlying_price.to_dec() - self.underlying_price.to_dec(),
+            changes,
+            listed,
+            delisted,
+            smile_shift: fit_smile_shift(&points),
+        })
+    }
+}
+
+fn strike_change(old: &OptionData, new: &OptionData) -> StrikeChange {
+    let positive = |a: Option<Positive>, b: Option<Positive>| Some(b?.to_dec() - a?.to_dec());
+    let decimal = |a: Option<Decimal>, b: Option<Decimal>| Some(b? - a?);
+    StrikeChange {
+        strike: old.strike_price,
+        call_mid: positive(old.call_middle, new.call_middle),
+        put_mid: positive(old.put_middle, new.put_middle),
+        implied_volatility: new.implied_volatility.to_dec() - old.implied_volatility.to_dec(),
+        delta_call: decimal(old.delta_call, new.delta_call),
+        delta_put: decimal(old.delta_put, new.delta_put),
+        volume: positive(old.volume, new.volume),
+        open_interest: match (old.open_interest, new.open_interest) {
+            (Some(a), Some(b)) => Some(b as i64 - a as i64),
+            _ => None,
+        },
+    }
+}
+
+/// Least-squares fit of `dv = a + b·x + c·x²` through the normal
+/// equations, solved with Cramer's rule. Moneyness is centred and scaled
+/// to unit variance before the fit, so the singularity check does not
+/// depend on the width of the strike grid. Returns `None` when fewer than
+/// three distinct moneyness points make the system singular.
+fn fit_smile_shift(points: &[(f64, f64)]) -> Option<SmileShift> {
+    if points.len() < 3 {
+        return None;
+    }
+    let n = points.len() as f64;
+    let mean = points.iter().map(|&(x, _)| x).sum::<f64>() / n;
+    let sd = (points.iter().map(|&(x, _)| (x - mean).powi(2)).sum::<f64>() / n).sqrt();
+    if !sd.is_finite() || sd <= f64::EPSILON * mean.abs().max(1.0) {
+        return None;
+    }
+    let (mut s0, mut s1, mut s2, mut s3, mut s4) = (0.0, 0.0, 0.0, 0.0, 0.0);
+    let (mut t0, mut t1, mut t2) = (0.0, 0.0, 0.0);
+    for &(x, y) in points {
+        let x = (x - mean) / sd;
+        let x2 = x * x;
+        s0 += 1.0;
+        s1 += x;
+        s2 += x2;
+        s3 += x2 * x;
+        s4 += x2 * x2;
+        t0 += y;
+        t1 += x * y;
+        t2 += x2 * y;
+    }
+    let det3 = |c0: [f64; 3], c1: [f64; 3], c2: [f64; 3]| {
+        let [a, d, g] = c0;
+        let [b, e, h] = c1;
+        let [c, f, i] = c2;
+        a * (e * i - f * h) - b * (d * i - f * g) + c * (d * h - e * g)
+    };
+    let (c0, c1, c2, rhs) = ([s0, s1, s2], [s1, s2, s3], [s2, s3, s4], [t0, t1, t2]);
+    let det = det3(c0, c1, c2);
+    let scale = s0.max(s2).max(s4).powi(3);
+    if !det.is_finite() || det.abs() <= 1e-12 * scale {
+        return None;
+    }
+    // Coefficients in the scaled variable u = (x - mean) / sd, mapped back to x.
+    let a_u = det3(rhs, c1, c2) / det;
+    let b_u = det3(c0, rhs, c2) / det;
+    let c_u = det3(c0, c1, rhs) / det;
+    let c = c_u / (sd * sd);
+    let b = b_u / sd - 2.0 * c * mean;
+    let a = a_u - b_u * mean / sd + c * mean * mean;
+
+    let sse: f64 = points
+        .iter()
+        .map(|&(x, y)| (y - a - b * x - c * x * x).powi(2))
+        .sum();
+    let rmse = (sse / points.len() as f64).sqrt();
+    Some(SmileShift {
+        level: Decimal::from_f64(a)?,
+        skew: Decimal::from_f64(b)?,
+        curvature: Decimal::from_f64(c)?,
+        rmse: Decimal::from_f64(rmse)?,
+    })
+}
+
+#[cfg(test)]
+mod tests_diff {
+    use super::*;
+    use positive::{pos_or_panic, spos};
+    use rust_decimal_macros::dec;
+
+    fn chain(price: f64, strikes: &[f64], iv: impl Fn(f64) -> f64) -> OptionChain {
+        let mut chain = OptionChain::new(
+            "SPY",
+            pos_or_panic!(price),
+            "2030-01-18".to_string(),
+            None,
+            None,
+        );
+        for &strike in strikes {
+            chain.add_option(
+                pos_or_panic!(strike),
+                spos!(5.0),
+                spos!(5.2),
+                spos!(4.0),
+                spos!(4.2),
+                pos_or_panic!(iv(strike)),
+                Some(dec!(0.5)),
+                Some(dec!(-0.5)),
+                None,
+                spos!(100.0),
+                Some(1_000),
+                None,
+            );
+        }
+        chain
+    }
+
+    #[test]
+    fn test_listed_delisted_and_changes() {
+        let before = chain(100.0, &[90.0, 95.0, 100.0, 105.0], |_| 0.2);
+        let mut after = chain(102.0, &[95.0, 100.0, 105.0, 110.0], |_| 0.25);
+        let strike = Positive::HUNDRED;
+        let mut option = after
+            .options
+            .iter()
+            .find(|o| o.strike_price == strike)
+            .cloned()
+            .unwrap();
+        after.options.remove(&option);
+        option.call_bid = spos!(6.0);
+        option.call_ask = spos!(6.2);
+        option.set_mid_prices();
+        option.open_interest = Some(900);
+        after.options.insert(option);
+
+        let diff = before.diff(&after).unwrap();
+        assert_eq!(diff.underlying_change, dec!(2));
+        assert_eq!(diff.listed, vec![pos_or_panic!(110.0)]);
+        assert_eq!(diff.delisted, vec![pos_or_panic!(90.0)]);
+        assert_eq!(diff.changes.len(), 3);
+
+        let atm = diff.changes.iter().find(|c| c.strike == strike).unwrap();
+        assert_eq!(atm.call_mid, Some(dec!(1.0)));
+        assert_eq!(atm.put_mid, Some(Decimal::ZERO));
+        assert_eq!(atm.implied_volatility, dec!(0.05));
+        assert_eq!(atm.delta_call, Some(Decimal::ZERO));
+        assert_eq!(atm.volume, Some(Decimal::ZERO));
+        assert_eq!(atm.open_interest, Some(-100));
+    }
+
+    #[test]
+    fn test_smile_shift_decomposition() {
+        let strikes = [80.0, 90.0, 95.0, 100.0, 105.0, 110.0, 120.0];
+        let before = chain(100.0, &strikes, |_| 0.2);
+        let after = chain(100.0, &strikes, |k| {
+            let x = (k / 100.0f64).ln();
+            0.2 + 0.01 - 0.1 * x + 0.5 * x * x
+        });
+        let shift = before.diff(&after).unwrap().smile_shift.unwrap();
+        assert!((shift.level - dec!(0.01)).abs() < dec!(1e-6));
+        assert!((shift.skew - dec!(-0.1)).abs() < dec!(1e-6));
+        assert!((shift.curvature - dec!(0.5)).abs() < dec!(1e-6));
+        assert!(shift.rmse < dec!(1e-9));
+    }
+
+    #[test]
+    fn test_smile_shift_on_narrow_strike_grid() {
+        let strikes = [99.0, 99.5, 100.0, 100.5, 101.0];
+        let before = chain(100.0, &strikes, |_| 0.2);
+        let after = chain(100.0, &strikes, |k| {
+            let x = (k / 100.0f64).ln();
+            0.2 + 0.01 - 0.1 * x + 0.5 * x * x
+        });
+        let shift = before.diff(&after).unwrap().smile_shift.unwrap();
+        assert!((shift.level - dec!(0.01)).abs() < dec!(1e-6));
+        assert!((shift.skew - dec!(-0.1)).abs() < dec!(1e-4));
+        assert!((shift.curvature - dec!(0.5)).abs() < dec!(1e-2));
+    }
+
+    #[test]
+    fn test_top_movers_and_errors() {
+        let before = chain(100.0, &[95.0, 100.0, 105.0], |_| 0.2);
+        let after = chain(100.0, &[95.0, 100.0, 105.0], |k| {
+            0.2 + (k - 100.0).abs() / 100.0
+        });
+        let diff = before.diff(&after).unwrap();
+        let movers = diff.top_iv_movers(2);
+        assert_eq!(movers.len(), 2);
+        assert!(movers.iter().all(|c| c.strike != Positive::HUNDRED));
+
+        let two = chain(100.0, &[95.0, 100.0], |_| 0.2);
+        assert!(two.diff(&two).unwrap().smile_shift.is_none());
+
+        let mut other = before.clone();
+        other.symbol = "QQQ".to_string();
+        assert!(before.diff(&other).is_err());
+    }
+}
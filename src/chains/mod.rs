@@ -227,14 +227,61 @@ mod options;
 /// * `rnd` - Private module for random number generation and stochastic processes
 mod rnd;
 
+/// * `query` - Private module with the declarative chain and series query builder
+mod query;
+
+/// * `cleaning` - Private module with the quote cleaning and IV gap-filling pipeline
+mod cleaning;
+
+/// * `roll` - Private module projecting chains and series to a future date
+mod roll;
+
+/// * `diff` - Private module comparing two snapshots of a chain
+mod diff;
+
+/// * `arbitrage` - Private module scanning chains and series for static arbitrage
+mod arbitrage;
+
+/// * `positioning` - Private module estimating dealer gamma and delta positioning
+mod positioning;
+
+/// * `parity` - Private module extracting implied forward and carry via put–call parity
+mod parity;
+
 mod optiondata;
 
+/// * `snapshot` - Private module with time-stamped chain snapshots
+mod snapshot;
+
+/// * `store` - Private module with the directory-backed chain snapshot store
+mod store;
+
+/// * `parquet` - Private module with Parquet import and export (`parquet` feature)
+#[cfg(feature = "parquet")]
+mod parquet;
+
 mod generators;
 
+pub use arbitrage::{ArbitrageKind, ArbitrageParameters, ArbitrageReport, ArbitrageViolation};
 pub use chain::OptionChain;
+pub use cleaning::{
+    CleanedChain, CleaningOutcome, CleaningParameters, CleaningRecord, QuoteAction, QuoteIssue,
+};
+pub use diff::{ChainDiff, SmileShift, StrikeChange};
 pub use generators::{generator_optionchain, generator_positive};
 pub use legs::StrategyLegs;
-pub use optiondata::OptionData;
+pub use optiondata::{OptionData, SideGreeks};
 pub use options::{DeltasInStrike, OptionsInStrike};
+pub use parity::{ImpliedCarry, ParityParameters};
+#[cfg(feature = "parquet")]
+pub use parquet::{ParquetFilter, read_chain_history_parquet, write_chain_history_parquet};
+pub use positioning::{
+    DealerConvention, DealerPositioning, OpenInterestSplit, PositioningParameters,
+    PositioningTermStructure,
+};
+pub use query::{ChainQuery, LegSelection, QuerySort, QueryTarget};
 pub use rnd::{RNDAnalysis, RNDParameters, RNDResult};
+pub use roll::ChainRollParameters;
+pub use snapshot::ChainSnapshot;
+pub use store::{ChainSnapshotStore, SnapshotIndexEntry};
 pub use utils::OptionChainBuildParams;
@@ -7,7 +7,7 @@ use crate::chains::utils::{OptionDataPriceParams, default_empty_string, empty_st
 use crate::chains::{DeltasInStrike, OptionsInStrike};
 use crate::error::ChainError;
 use crate::error::chains::OptionDataErrorKind;
-use crate::greeks::{delta, gamma};
+use crate::greeks::{charm, delta, gamma, rho, theta, vanna, vega};
 use crate::model::Position;
 use crate::strategies::{BasicAble, FindOptimalSide};
 use crate::{ExpirationDate, OptionStyle, Options, Side};
@@ -128,6 +128,14 @@ pub struct OptionData {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gamma: Option<Decimal>,
 
+    /// Full Greek set of the call, populated by [`OptionData::calculate_greeks`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call_greeks: Option<SideGreeks>,
+
+    /// Full Greek set of the put, populated by [`OptionData::calculate_greeks`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub put_greeks: Option<SideGreeks>,
+
     /// The trading volume of the option, indicating market activity.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub volume: Option<Positive>,
@@ -222,6 +230,8 @@ impl OptionData {
             delta_call,
             delta_put,
             gamma,
+            call_greeks: None,
+            put_greeks: None,
             volume,
             open_interest,
             symbol,
@@ -376,10 +386,16 @@ impl OptionData {
 
     /// Sets the implied volatility for this option contract.
     ///
+    /// A change of volatility clears `call_greeks` and `put_greeks`, which
+    /// were computed with the previous value.
+    ///
     /// # Arguments
     /// * `volatility` - A positive decimal value representing the implied volatility.
     #[inline]
     pub fn set_volatility(&mut self, volatility: &Positive) {
+        if self.implied_volatility != *volatility {
+            self.clear_side_greeks();
+        }
         self.implied_volatility = *volatility;
     }
 
@@ -387,29 +403,46 @@ impl OptionData {
     ///
     /// This method updates the option data with the provided pricing parameters,
     /// including underlying symbol, price, expiration date, risk-free rate, and dividend yield.
+    /// When the price, expiration, rate or yield changes, `call_greeks` and
+    /// `put_greeks` are cleared until [`OptionData::calculate_greeks`] runs again.
     ///
     /// # Arguments
     /// * `params` - The pricing parameters to set.
     pub fn set_extra_params(&mut self, params: OptionDataPriceParams) {
+        let mut repriced = false;
         if let Some(symbol) = params.underlying_symbol {
             self.symbol = Some(symbol);
         };
 
         if let Some(expiration_date) = params.expiration_date {
+            repriced |= self.expiration_date != Some(expiration_date);
             self.expiration_date = Some(expiration_date);
         };
 
         if let Some(underlying_price) = params.underlying_price {
+            repriced |= self.underlying_price.as_deref() != Some(&*underlying_price);
             self.underlying_price = Some(underlying_price);
         };
 
         if let Some(risk_free_rate) = params.risk_free_rate {
+            repriced |= self.risk_free_rate != Some(risk_free_rate);
             self.risk_free_rate = Some(risk_free_rate);
         };
 
         if let Some(dividend_yield) = params.dividend_yield {
+            repriced |= self.dividend_yield != Some(dividend_yield);
             self.dividend_yield = Some(dividend_yield);
         };
+
+        if repriced {
+            self.clear_side_greeks();
+        }
+    }
+
+    /// Drops the cached per-side Greeks after a pricing input changed.
+    pub(crate) fn clear_side_greeks(&mut self) {
+        self.call_greeks = None;
+        self.put_greeks = None;
     }
 
     /// Validates the option data to ensure it meets the required criteria for calculations.
@@ -960,6 +993,35 @@ impl OptionData {
         }
     }
 
+    /// Calculates the full Greek set of both sides and stores it in
+    /// `call_greeks` and `put_greeks`.
+    ///
+    /// `delta_call`, `delta_put` and `gamma` are refreshed first through
+    /// [`OptionData::calculate_delta`] and [`OptionData::calculate_gamma`], so
+    /// they keep their usual semantics. A side whose full Greek set cannot be
+    /// computed (e.g. an expired option) is set to `None`.
+    pub fn calculate_greeks(&mut self) {
+        self.calculate_delta();
+        self.calculate_gamma();
+        let side_greeks = |style: OptionStyle| {
+            self.get_option(Side::Long, style)
+                .and_then(|option| SideGreeks::from_option(&option))
+                .inspect_err(|e| {
+                    debug!(
+                        "Greeks calculation failed for {} {:?}: {}",
+                        self.strike_price, style, e
+                    )
+                })
+                .ok()
+        };
+        let (call_greeks, put_greeks) = (
+            side_greeks(OptionStyle::Call),
+            side_greeks(OptionStyle::Put),
+        );
+        self.call_greeks = call_greeks;
+        self.put_greeks = put_greeks;
+    }
+
     /// Calculates the gamma of the option and stores it in the option data.
     ///
     /// Gamma measures the rate of change of delta with respect to changes in the
@@ -1140,6 +1202,48 @@ impl OptionData {
     }
 }
 
+/// Greeks of one side (call or put) of an [`OptionData`] strike, for a
+/// long position of one contract.
+///
+/// Delta, theta, rho and charm differ between calls and puts, so each side
+/// carries its own set instead of sharing a single value.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize, ToSchema)]
+pub struct SideGreeks {
+    /// Sensitivity to the underlying price
+    pub delta: Decimal,
+    /// Rate of change of delta with the underlying price
+    pub gamma: Decimal,
+    /// Sensitivity to implied volatility
+    pub vega: Decimal,
+    /// Time decay
+    pub theta: Decimal,
+    /// Sensitivity to the risk-free rate
+    pub rho: Decimal,
+    /// Rate of change of delta with implied volatility
+    pub vanna: Decimal,
+    /// Rate of change of delta with time
+    pub charm: Decimal,
+}
+
+impl SideGreeks {
+    /// Computes the Greek set of `option`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ChainError` if any of the Greeks cannot be computed.
+    pub fn from_option(option: &Options) -> Result<Self, ChainError> {
+        Ok(Self {
+            delta: delta(option)?,
+            gamma: gamma(option)?,
+            vega: vega(option)?,
+            theta: theta(option)?,
+            rho: rho(option)?,
+            vanna: vanna(option)?,
+            charm: charm(option)?,
+        })
+    }
+}
+
 impl Default for OptionData {
     fn default() -> Self {
         OptionData {
@@ -1154,6 +1258,8 @@ impl Default for OptionData {
             delta_call: None,
             delta_put: None,
             gamma: None,
+            call_greeks: None,
+            put_greeks: None,
             volume: None,
             open_interest: None,
             symbol: None,
@@ -3242,3 +3348,97 @@ mod tests_validate_option_data {
         assert!(!is_valid);
     }
 }
+
+#[cfg(test)]
+mod tests_calculate_greeks {
+    use super::*;
+    use positive::{pos_or_panic, spos};
+    use rust_decimal::MathematicalOps;
+    use rust_decimal_macros::dec;
+
+    fn option_data(dividend_yield: Positive) -> OptionData {
+        let mut option_data = OptionData::new(
+            Positive::HUNDRED,
+            spos!(5.0),
+            spos!(5.5),
+            spos!(4.5),
+            spos!(5.0),
+            pos_or_panic!(0.2),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        option_data.set_extra_params(OptionDataPriceParams::new(
+            Some(Box::new(Positive::HUNDRED)),
+            Some(ExpirationDate::Days(pos_or_panic!(73.0))),
+            Some(dec!(0.05)),
+            Some(dividend_yield),
+            Some("XYZ".to_string()),
+        ));
+        option_data
+    }
+
+    #[test]
+    fn test_both_sides_populated_and_legacy_fields_synced() {
+        let mut option_data = option_data(Positive::ZERO);
+        option_data.calculate_greeks();
+        let call = option_data.call_greeks.unwrap();
+        let put = option_data.put_greeks.unwrap();
+        assert_eq!(option_data.delta_call, Some(call.delta));
+        assert_eq!(option_data.delta_put, Some(put.delta));
+        assert_eq!(option_data.gamma, Some(call.gamma));
+        assert!(call.delta > Decimal::ZERO && put.delta < Decimal::ZERO);
+        assert!(call.rho > Decimal::ZERO && put.rho < Decimal::ZERO);
+        assert!(call.vega > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_put_call_delta_relation_with_dividends() {
+        let mut option_data = option_data(pos_or_panic!(0.03));
+        option_data.calculate_greeks();
+        let call = option_data.call_greeks.unwrap();
+        let put = option_data.put_greeks.unwrap();
+        // Δc − Δp = e^{−qT}
+        let expected = (dec!(-0.03) * dec!(0.2)).exp();
+        assert!((call.delta - put.delta - expected).abs() < dec!(0.001));
+        assert!((call.gamma - put.gamma).abs() < dec!(0.0001));
+        assert_ne!(call.theta, put.theta);
+    }
+
+    #[test]
+    fn test_missing_params_leave_legacy_fields() {
+        let mut option_data = OptionData {
+            strike_price: Positive::HUNDRED,
+            delta_call: Some(dec!(0.5)),
+            ..Default::default()
+        };
+        option_data.calculate_greeks();
+        assert!(option_data.call_greeks.is_none());
+        assert_eq!(option_data.delta_call, Some(dec!(0.5)));
+    }
+
+    #[test]
+    fn test_side_greeks_serialization_round_trip() {
+        let mut option_data = option_data(Positive::ZERO);
+        option_data.calculate_greeks();
+        let json = serde_json::to_string(&option_data).unwrap();
+        assert!(json.contains("put_greeks"));
+        let restored: OptionData = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.put_greeks, option_data.put_greeks);
+
+        let mut plain = option_data.clone();
+        plain.call_greeks = None;
+        plain.put_greeks = None;
+        let json = serde_json::to_string(&plain).unwrap();
+        assert!(!json.contains("call_greeks"));
+    }
+}
@@ -0,0 +1,419 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Implied Carry via Put–Call Parity
+//!
+//! Under put–call parity a European call and put on the same strike satisfy
+//!
+//! ```text
+//! C(K) − P(K) = DF · (F − K)
+//! ```
+//!
+//! where `DF = e^{−rT}` is the discount factor and `F = S · e^{(r − q)T}` is
+//! the forward. Regressing `call_middle − put_middle` on the strike across a
+//! chain therefore yields a slope of `−DF` and an intercept of `DF · F`, from
+//! which the implied rate `r`, the implied forward and the implied carry
+//! `q` are recovered without trusting the rate or dividend yield the chain
+//! was built with.
+//!
+//! Stale or crossed quotes are removed by iteratively discarding strikes
+//! whose parity residual exceeds a multiple of the regression's residual
+//! standard deviation. When a dividend yield is known, the part of the
+//! implied carry not explained by it is reported as the borrow rate.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use optionstratlib::chains::{OptionChain, OptionChainBuildParams, ParityParameters};
+//! use optionstratlib::chains::utils::OptionDataPriceParams;
+//! use optionstratlib::ExpirationDate;
+//! use positive::{pos_or_panic, spos, Positive};
+//! use rust_decimal_macros::dec;
+//!
+//! let params = OptionChainBuildParams::new(
+//!     "SPY".to_string(),
+//!     None,
+//!     15,
+//!     spos!(5.0),
+//!     dec!(0.0),
+//!     dec!(0.0),
+//!     Positive::ZERO,
+//!     4,
+//!     OptionDataPriceParams::new(
+//!         Some(Box::new(Positive::HUNDRED)),
+//!         Some(ExpirationDate::Days(pos_or_panic!(365.0))),
+//!         Some(dec!(0.04)),
+//!         spos!(0.01),
+//!         Some("SPY".to_string()),
+//!     ),
+//!     pos_or_panic!(0.2),
+//! );
+//! let mut chain = OptionChain::build_chain(&params).unwrap();
+//!
+//! let carry = chain.implied_carry(&ParityParameters::default()).unwrap();
+//! assert!((carry.risk_free_rate - dec!(0.04)).abs() < dec!(0.001));
+//! chain.apply_implied_carry(&carry).unwrap();
+//! ```
+
+use crate::chains::chain::OptionChain;
+use crate::error::ChainError;
+use positive::Positive;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use utoipa::ToSchema;
+
+/// Configuration for the put–call parity regression.
+#[derive(DebugPretty, DisplaySimple, Clone, ToSchema, Serialize, Deserialize)]
+pub struct ParityParameters {
+    /// Residuals larger than this many standard deviations are rejected
+    pub outlier_threshold: Positive,
+    /// Maximum number of rejection passes
+    pub max_iterations: usize,
+    /// Minimum number of strikes kept in the regression
+    pub min_strikes: usize,
+    /// Known risk-free rate; when set only the forward is fitted
+    pub risk_free_rate: Option<Decimal>,
+    /// Known dividend yield; the remaining carry is reported as borrow
+    pub dividend_yield: Option<Positive>,
+}
+
+impl Default for ParityParameters {
+    fn default() -> Self {
+        Self {
+            outlier_threshold: Positive::THREE,
+            max_iterations: 10,
+            min_strikes: 3,
+            risk_free_rate: None,
+            dividend_yield: None,
+        }
+    }
+}
+
+/// Forward, rates and diagnostics implied by put–call parity.
+#[derive(DebugPretty, DisplaySimple, Clone, ToSchema, Serialize, Deserialize)]
+pub struct ImpliedCarry {
+    /// Implied forward price of the underlying
+    pub forward: Positive,
+    /// Implied discount factor `e^{−rT}`
+    pub discount_factor: Decimal,
+    /// Implied continuously compounded risk-free rate
+    pub risk_free_rate: Decimal,
+    /// Total implied carry `q`, i.e. dividend yield plus borrow
+    pub implied_carry: Decimal,
+    /// Dividend yield component of the carry
+    pub dividend_yield: Decimal,
+    /// Borrow rate component of the carry
+    pub borrow_rate: Decimal,
+    /// Root mean squared parity residual of the kept strikes
+    pub rmse: Decimal,
+    /// Strikes kept in the final regression
+    pub strikes_used: Vec<Positive>,
+    /// Strikes rejected as outliers
+    pub strikes_rejected: Vec<Positive>,
+}
+
+struct ParityFit {
+    discount_factor: f64,
+    forward: f64,
+    residuals: Vec<f64>,
+}
+
+impl OptionChain {
+    /// Extracts the implied forward, rate, dividend yield and borrow rate
+    /// from the call and put mid prices of the chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChainError::invalid_parameters`] when the chain has fewer than
+    /// `min_strikes` strikes quoted on both sides, an unusable expiration,
+    /// or when the regression implies a non-positive discount factor or
+    /// forward.
+    pub fn implied_carry(&self, params: &ParityParameters) -> Result<ImpliedCarry, ChainError> {
+        let min_strikes = params.min_strikes.max(2);
+        let years = self
+            .get_expiration()
+            .and_then(|expiration| expiration.get_years().ok())
+            .filter(|years| *years > Positive::ZERO)
+            .ok_or_else(|| {
+                ChainError::invalid_parameters(
+                    "expiration_date",
+                    &format!("invalid chain expiration {}", self.get_expiration_date()),
+                )
+            })?
+            .to_f64();
+
+        let mut points: Vec<(Positive, f64)> = self
+            .options
+            .iter()
+            .filter_map(|option| match (option.call_middle, option.put_middle) {
+                (Some(call), Some(put)) => {
+                    Some((option.strike_price, call.to_f64() - put.to_f64()))
+                }
+                _ => None,
+            })
+            .collect();
+        if points.len() < min_strikes {
+            return Err(ChainError::invalid_parameters(
+                "options",
+                &format!(
+                    "{} strikes quoted on both sides, at least {min_strikes} required",
+                    points.len()
+                ),
+            ));
+        }
+
+        let fixed_df = params
+            .risk_free_rate
+            .map(|rate| (-rate.to_f64().unwrap_or(0.0) * years).exp());
+        let threshold = params.outlier_threshold.to_f64();
+        let mut rejected: Vec<Positive> = Vec::new();
+        let mut fit = fit_parity(&points, fixed_df)?;
+
+        for _ in 0..params.max_iterations {
+            let dof = points
+                .len()
+                .saturating_sub(if fixed_df.is_some() { 1 } else { 2 });
+            if dof == 0 {
+                break;
+            }
+            let sse: f64 = fit.residuals.iter().map(|r| r * r).sum();
+            let sigma = (sse / dof as f64).sqrt();
+            if sigma <= f64::EPSILON {
+                break;
+            }
+            let (kept, dropped): (Vec<_>, Vec<_>) = points
+                .iter()
+                .zip(fit.residuals.iter())
+                .partition(|(_, residual)| residual.abs() <= threshold * sigma);
+            if dropped.is_empty() || kept.len() < min_strikes {
+                break;
+            }
+            rejected.extend(dropped.iter().map(|((strike, _), _)| *strike));
+            points = kept.into_iter().map(|(point, _)| *point).collect();
+            fit = fit_parity(&points, fixed_df)?;
+        }
+        rejected.sort();
+
+        let spot = self.underlying_price.to_f64();
+        let rate = -fit.discount_factor.ln() / years;
+        let carry = rate - (fit.forward / spot).ln() / years;
+        let rmse =
+            (fit.residuals.iter().map(|r| r * r).sum::<f64>() / fit.residuals.len() as f64).sqrt();
+
+        let to_decimal = |name: &str, value: f64| {
+            Decimal::from_f64_retain(value).ok_or_else(|| {
+                ChainError::invalid_parameters(name, &format!("non-finite value {value}"))
+            })
+        };
+        let implied_carry = to_decimal("implied_carry", carry)?;
+        let dividend_yield = params
+            .dividend_yield
+            .map_or(implied_carry, |dividend| dividend.to_dec());
+        Ok(ImpliedCarry {
+            forward: Positive::new(fit.forward)?,
+            discount_factor: to_decimal("discount_factor", fit.discount_factor)?,
+            risk_free_rate: to_decimal("risk_free_rate", rate)?,
+            implied_carry,
+            dividend_yield,
+            borrow_rate: implied_carry - dividend_yield,
+            rmse: to_decimal("rmse", rmse)?,
+            strikes_used: points.iter().map(|(strike, _)| *strike).collect(),
+            strikes_rejected: rejected,
+        })
+    }
+
+    /// Writes an implied carry back into the chain so that subsequent
+    /// implied volatility and Greek calculations are parity-consistent.
+    ///
+    /// The chain stores a single non-negative yield, so the total carry
+    /// (dividend plus borrow) is written as the dividend yield and a
+    /// negative carry is floored at zero.
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors from [`OptionChain::set_optiondata_extra_params`].
+    pub fn apply_implied_carry(&mut self, carry: &ImpliedCarry) -> Result<(), ChainError> {
+        if carry.implied_carry < Decimal::ZERO {
+            warn!(
+                "Implied carry {} is negative, flooring the chain dividend yield at zero",
+                carry.implied_carry
+            );
+        }
+        self.risk_free_rate = Some(carry.risk_free_rate);
+        self.dividend_yield = Some(Positive::new_decimal(
+            carry.implied_carry.max(Decimal::ZERO),
+        )?);
+        self.set_optiondata_extra_params()?;
+        self.update_greeks();
+        Ok(())
+    }
+}
+
+/// Fits `C − P = DF · (F − K)` by least squares, optionally with a fixed
+/// discount factor.
+fn fit_parity(points: &[(Positive, f64)], fixed_df: Option<f64>) -> Result<ParityFit, ChainError> {
+    let n = points.len() as f64;
+    let mean_k = points.iter().map(|(k, _)| k.to_f64()).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| *y).sum::<f64>() / n;
+
+    let discount_factor = match fixed_df {
+        Some(df) => df,
+        None => {
+            let (sxy, sxx) = points.iter().fold((0.0, 0.0), |(sxy, sxx), (k, y)| {
+                let dk = k.to_f64() - mean_k;
+                (sxy + dk * (y - mean_y), sxx + dk * dk)
+            });
+            if sxx <= f64::EPSILON {
+                return Err(ChainError::invalid_parameters(
+                    "options",
+                    "parity regression needs at least two distinct strikes",
+                ));
+            }
+            -sxy / sxx
+        }
+    };
+    if !discount_factor.is_finite() || discount_factor <= 0.0 {
+        return Err(ChainError::invalid_parameters(
+            "discount_factor",
+            &format!("parity regression implies non-positive discount factor {discount_factor}"),
+        ));
+    }
+
+    let forward = mean_k + mean_y / discount_factor;
+    if !forward.is_finite() || forward <= 0.0 {
+        return Err(ChainError::invalid_parameters(
+            "forward",
+            &format!("parity regression implies non-positive forward {forward}"),
+        ));
+    }
+
+    let residuals = points
+        .iter()
+        .map(|(k, y)| y - discount_factor * (forward - k.to_f64()))
+        .collect();
+    Ok(ParityFit {
+        discount_factor,
+        forward,
+        residuals,
+    })
+}
+
+#[cfg(test)]
+mod tests_parity {
+    use super::*;
+    use crate::chains::optiondata::OptionData;
+    use crate::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
+    use crate::model::ExpirationDate;
+    use positive::{pos_or_panic, spos};
+    use rust_decimal_macros::dec;
+
+    fn chain(rate: Decimal, dividend: Positive) -> OptionChain {
+        let params = OptionChainBuildParams::new(
+            "TEST".to_string(),
+            None,
+            15,
+            spos!(5.0),
+            dec!(-0.2),
+            dec!(0.1),
+            Positive::ZERO,
+            4,
+            OptionDataPriceParams::new(
+                Some(Box::new(Positive::HUNDRED)),
+                Some(ExpirationDate::Days(pos_or_panic!(365.0))),
+                Some(rate),
+                Some(dividend),
+                Some("TEST".to_string()),
+            ),
+            pos_or_panic!(0.25),
+        );
+        OptionChain::build_chain(&params).unwrap()
+    }
+
+    #[test]
+    fn test_recovers_rate_and_carry() {
+        let chain = chain(dec!(0.05), pos_or_panic!(0.02));
+        let carry = chain.implied_carry(&ParityParameters::default()).unwrap();
+        assert!((carry.risk_free_rate - dec!(0.05)).abs() < dec!(0.001));
+        assert!((carry.implied_carry - dec!(0.02)).abs() < dec!(0.001));
+        assert_eq!(carry.borrow_rate, Decimal::ZERO);
+        let expected_forward = 100.0 * (0.03f64).exp();
+        assert!((carry.forward.to_f64() - expected_forward).abs() < 0.05);
+        assert!(carry.strikes_rejected.is_empty());
+        assert!(carry.rmse < dec!(0.01));
+    }
+
+    #[test]
+    fn test_borrow_split_with_known_dividend() {
+        let chain = chain(dec!(0.05), pos_or_panic!(0.03));
+        let params = ParityParameters {
+            dividend_yield: spos!(0.01),
+            ..Default::default()
+        };
+        let carry = chain.implied_carry(&params).unwrap();
+        assert_eq!(carry.dividend_yield, dec!(0.01));
+        assert!((carry.borrow_rate - dec!(0.02)).abs() < dec!(0.001));
+    }
+
+    #[test]
+    fn test_fixed_rate_fits_forward_only() {
+        let chain = chain(dec!(0.05), pos_or_panic!(0.02));
+        let params = ParityParameters {
+            risk_free_rate: Some(dec!(0.05)),
+            ..Default::default()
+        };
+        let carry = chain.implied_carry(&params).unwrap();
+        assert!((carry.risk_free_rate - dec!(0.05)).abs() < dec!(0.000001));
+        assert!((carry.implied_carry - dec!(0.02)).abs() < dec!(0.001));
+    }
+
+    #[test]
+    fn test_rejects_outlier_strike() {
+        let mut chain = chain(dec!(0.05), pos_or_panic!(0.02));
+        let target = pos_or_panic!(105.0);
+        let mut option: OptionData = chain
+            .options
+            .iter()
+            .find(|option| option.strike_price == target)
+            .cloned()
+            .unwrap();
+        chain.options.remove(&option);
+        option.call_middle = option.call_middle.map(|mid| mid + pos_or_panic!(3.0));
+        chain.options.insert(option);
+
+        let carry = chain.implied_carry(&ParityParameters::default()).unwrap();
+        assert_eq!(carry.strikes_rejected, vec![target]);
+        assert!((carry.risk_free_rate - dec!(0.05)).abs() < dec!(0.001));
+        assert!((carry.implied_carry - dec!(0.02)).abs() < dec!(0.001));
+    }
+
+    #[test]
+    fn test_too_few_strikes() {
+        let chain = OptionChain::new(
+            "TEST",
+            Positive::HUNDRED,
+            "2030-01-01".to_string(),
+            None,
+            None,
+        );
+        assert!(chain.implied_carry(&ParityParameters::default()).is_err());
+    }
+
+    #[test]
+    fn test_apply_writes_back() {
+        let mut chain = chain(dec!(0.01), pos_or_panic!(0.0));
+        let reference = self::chain(dec!(0.05), pos_or_panic!(0.02));
+        let carry = reference
+            .implied_carry(&ParityParameters::default())
+            .unwrap();
+        chain.apply_implied_carry(&carry).unwrap();
+        assert_eq!(chain.risk_free_rate, Some(carry.risk_free_rate));
+        let dividend = chain.dividend_yield.unwrap().to_dec();
+        assert!((dividend - dec!(0.02)).abs() < dec!(0.001));
+    }
+}
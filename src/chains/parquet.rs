@@ -0,0 +1,774 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Parquet Import and Export
+//!
+//! Columnar storage for [`OptionChain`], [`OptionSeries`] and time-stamped
+//! chain history, available with the `parquet` cargo feature.
+//!
+//! Every file uses the same long layout, one row per strike, side and
+//! snapshot:
+//!
+//! | Column              | Type                  | Nullable |
+//! |---------------------|-----------------------|----------|
+//! | `snapshot`          | timestamp (ms, UTC)   | no       |
+//! | `symbol`            | utf8                  | no       |
+//! | `expiration`        | utf8                  | no       |
+//! | `underlying_price`  | float64               | no       |
+//! | `risk_free_rate`    | float64               | yes      |
+//! | `dividend_yield`    | float64               | yes      |
+//! | `strike`            | float64               | no       |
+//! | `style`             | utf8 (`Call`/`Put`)   | no       |
+//! | `bid`, `ask`, `mid` | float64               | yes      |
+//! | `implied_volatility`| float64               | no       |
+//! | `delta`             | float64               | yes      |
+//! | `gamma`             | float64               | yes      |
+//! | `vega`, `theta`     | float64               | yes      |
+//! | `rho`, `vanna`      | float64               | yes      |
+//! | `charm`             | float64               | yes      |
+//! | `volume`            | float64               | yes      |
+//! | `open_interest`     | uint64                | yes      |
+//! | `epic`              | utf8                  | yes      |
+//!
+//! Each chain snapshot is written as its own row group, so a
+//! [`ParquetFilter`] on symbol, expiration or snapshot time skips whole row
+//! groups using their column statistics before any data is decoded.
+//! Prices and rates are stored as `float64`, so values round-trip to double
+//! precision; `extra_fields` are not persisted.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use chrono::Utc;
+//! use optionstratlib::chains::{
+//!     read_chain_history_parquet, write_chain_history_parquet, ChainSnapshot, OptionChain,
+//!     ParquetFilter,
+//! };
+//! use positive::Positive;
+//!
+//! let chain = OptionChain::new("SPY", Positive::HUNDRED, "2030-01-18".to_string(), None, None);
+//! let history = vec![ChainSnapshot::new(Utc::now(), chain)];
+//! write_chain_history_parquet("history.parquet", &history).unwrap();
+//!
+//! let filter = ParquetFilter::default().with_symbol("SPY");
+//! let loaded = read_chain_history_parquet("history.parquet", &filter).unwrap();
+//! assert_eq!(loaded.len(), 1);
+//! ```
+
+use crate::ExpirationDate;
+use crate::chains::chain::OptionChain;
+use crate::chains::optiondata::{OptionData, SideGreeks};
+use crate::chains::snapshot::ChainSnapshot;
+use crate::chains::utils::OptionDataPriceParams;
+use crate::error::ChainError;
+use crate::model::OptionStyle;
+use crate::series::OptionSeries;
+use arrow_array::{
+    Array, ArrayRef, Float64Array, RecordBatch, StringArray, TimestampMillisecondArray, UInt64Array,
+};
+use arrow_schema::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use chrono::{DateTime, Utc};
+use parquet::arrow::ArrowWriter;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+use parquet::file::statistics::Statistics;
+use positive::Positive;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::sync::Arc;
+
+const SNAPSHOT: &str = "snapshot";
+const SYMBOL: &str = "symbol";
+const EXPIRATION: &str = "expiration";
+
+/// Row predicate applied when reading Parquet chain files.
+///
+/// Empty lists and unset bounds match everything. Snapshot bounds are
+/// inclusive.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParquetFilter {
+    /// Symbols to keep
+    pub symbols: Vec<String>,
+    /// Expiration strings to keep, as stored by [`OptionChain::get_expiration_date`]
+    pub expirations: Vec<String>,
+    /// Earliest snapshot to keep
+    pub start: Option<DateTime<Utc>>,
+    /// Latest snapshot to keep
+    pub end: Option<DateTime<Utc>>,
+}
+
+impl ParquetFilter {
+    /// Keeps rows for `symbol`, in addition to any symbol already selected.
+    #[must_use]
+    pub fn with_symbol(mut self, symbol: &str) -> Self {
+        self.symbols.push(symbol.to_string());
+        self
+    }
+
+    /// Keeps rows for `expiration`, in addition to any expiration already
+    /// selected.
+    #[must_use]
+    pub fn with_expiration(mut self, expiration: &str) -> Self {
+        self.expirations.push(expiration.to_string());
+        self
+    }
+
+    /// Keeps snapshots taken between `start` and `end`, inclusive.
+    #[must_use]
+    pub fn with_range(mut self, start: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>) -> Self {
+        self.start = start;
+        self.end = end;
+        self
+    }
+
+    fn matches(&self, snapshot: i64, symbol: &str, expiration: &str) -> bool {
+        (self.symbols.is_empty() || self.symbols.iter().any(|s| s == symbol))
+            && (self.expirations.is_empty() || self.expirations.iter().any(|e| e == expiration))
+            && self
+                .start
+                .is_none_or(|start| snapshot >= start.timestamp_millis())
+            && self
+                .end
+                .is_none_or(|end| snapshot <= end.timestamp_millis())
+    }
+
+    /// Returns `false` when the row group statistics prove no row can match.
+    fn may_match(&self, statistics: &[Option<&Statistics>; 3]) -> bool {
+        let [snapshot, symbol, expiration] = statistics;
+        if let Some(Statistics::Int64(stats)) = snapshot {
+            if let (Some(start), Some(max)) = (self.start, stats.max_opt())
+                && *max < start.timestamp_millis()
+            {
+                return false;
+            }
+            if let (Some(end), Some(min)) = (self.end, stats.min_opt())
+                && *min > end.timestamp_millis()
+            {
+                return false;
+            }
+        }
+        let in_range = |values: &[String], stats: &Option<&Statistics>| {
+            let Some(Statistics::ByteArray(stats)) = stats else {
+                return true;
+            };
+            let (Some(min), Some(max)) = (stats.min_opt(), stats.max_opt()) else {
+                return true;
+            };
+            values.is_empty()
+                || values
+                    .iter()
+                    .any(|value| min.data() <= value.as_bytes() && value.as_bytes() <= max.data())
+        };
+        in_range(&self.symbols, symbol) && in_range(&self.expirations, expiration)
+    }
+}
+
+impl OptionChain {
+    /// Saves the chain to `{file_path}/{title}.parquet`, stamped with the
+    /// current time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChainError::FileError`] when the file cannot be created or
+    /// the Parquet writer fails.
+    pub fn save_to_parquet(&self, file_path: &str) -> Result<(), ChainError> {
+        let full_path = format!("{}/{}.parquet", file_path, self.get_title());
+        write_snapshots(&full_path, [(Utc::now(), self)])
+    }
+
+    /// Loads the most recent chain snapshot stored in a Parquet file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChainError::FileError`] when the file cannot be read or
+    /// does not follow the chain layout, and
+    /// [`ChainError::ChainBuildError`] when it holds no chain.
+    pub fn load_from_parquet(file_path: &str) -> Result<Self, ChainError> {
+        read_chain_history_parquet(file_path, &ParquetFilter::default())?
+            .into_iter()
+            .max_by_key(|snapshot| snapshot.timestamp)
+            .map(|snapshot| snapshot.chain)
+            .ok_or_else(|| ChainError::invalid_parameters("file_path", "no chain in file"))
+    }
+}
+
+impl OptionSeries {
+    /// Saves every chain of the series to a single Parquet file at
+    /// `file_path`, stamped with the current time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChainError::FileError`] when the file cannot be created or
+    /// the Parquet writer fails.
+    pub fn save_to_parquet(&self, file_path: &str) -> Result<(), ChainError> {
+        let now = Utc::now();
+        write_snapshots(file_path, self.chains.values().map(|chain| (now, chain)))
+    }
+
+    /// Loads a series from a Parquet file, keeping the latest snapshot of
+    /// each expiration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChainError::FileError`] when the file cannot be read and
+    /// [`ChainError::ChainBuildError`] when it is empty, holds more than one
+    /// symbol, or an expiration cannot be parsed.
+    pub fn load_from_parquet(file_path: &str) -> Result<Self, ChainError> {
+        let snapshots = read_chain_history_parquet(file_path, &ParquetFilter::default())?;
+        let first = snapshots
+            .first()
+            .ok_or_else(|| ChainError::invalid_parameters("file_path", "no chain in file"))?;
+        let mut series =
+            OptionSeries::new(first.chain.symbol.clone(), first.chain.underlying_price);
+        series.risk_free_rate = first.chain.risk_free_rate;
+        series.dividend_yield = first.chain.dividend_yield;
+
+        let mut latest: BTreeMap<String, ChainSnapshot> = BTreeMap::new();
+        for snapshot in snapshots {
+            if snapshot.chain.symbol != series.symbol {
+                return Err(ChainError::invalid_parameters(
+                    "symbol",
+                    &format!(
+                        "series file mixes {} and {}",
+                        series.symbol, snapshot.chain.symbol
+                    ),
+                ));
+            }
+            let key = snapshot.chain.get_expiration_date();
+            if latest
+                .get(&key)
+                .is_none_or(|current| current.timestamp <= snapshot.timestamp)
+            {
+                latest.insert(key, snapshot);
+            }
+        }
+        for (expiration, snapshot) in latest {
+            let date = ExpirationDate::from_string(&expiration)?;
+            series.chains.insert(date, snapshot.chain);
+        }
+        Ok(series)
+    }
+}
+
+/// Writes time-stamped chain snapshots to a single Parquet file, one row
+/// group per snapshot.
+///
+/// # Errors
+///
+/// Returns [`ChainError::FileError`] when the file cannot be created or the
+/// Parquet writer fails.
+pub fn write_chain_history_parquet(
+    file_path: &str,
+    history: &[ChainSnapshot],
+) -> Result<(), ChainError> {
+    write_snapshots(
+        file_path,
+        history
+            .iter()
+            .map(|snapshot| (snapshot.timestamp, &snapshot.chain)),
+    )
+}
+
+/// Reads chain snapshots from a Parquet file, keeping only rows matching
+/// `filter`. Snapshots are returned ordered by timestamp, symbol and
+/// expiration.
+///
+/// # Errors
+///
+/// Returns [`ChainError::FileError`] when the file cannot be read or does
+/// not follow the chain layout, and [`ChainError::OptionDataError`] for
+/// out-of-range values.
+pub fn read_chain_history_parquet(
+    file_path: &str,
+    filter: &ParquetFilter,
+) -> Result<Vec<ChainSnapshot>, ChainError> {
+    let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(file_path)?)?;
+    let schema = builder.parquet_schema();
+    let column_index = |name: &str| {
+        schema
+            .columns()
+            .iter()
+            .position(|column| column.name() == name)
+    };
+    let indices = [
+        column_index(SNAPSHOT),
+        column_index(SYMBOL),
+        column_index(EXPIRATION),
+    ];
+    let row_groups: Vec<usize> = builder
+        .metadata()
+        .row_groups()
+        .iter()
+        .enumerate()
+        .filter(|(_, row_group)| {
+            let statistics =
+                indices.map(|index| index.and_then(|i| row_group.column(i).statistics()));
+            filter.may_match(&statistics)
+        })
+        .map(|(index, _)| index)
+        .collect();
+    let reader = builder.with_row_groups(row_groups).build()?;
+
+    type Key = (i64, String, String);
+    let mut chains: BTreeMap<Key, OptionChain> = BTreeMap::new();
+    let mut strikes: BTreeMap<Key, BTreeMap<Positive, OptionData>> = BTreeMap::new();
+    for batch in reader {
+        let batch = batch?;
+        let columns = Columns::new(&batch)?;
+        for row in 0..batch.num_rows() {
+            let snapshot = columns.snapshot.value(row);
+            let symbol = columns.symbol.value(row);
+            let expiration = columns.expiration.value(row);
+            if !filter.matches(snapshot, symbol, expiration) {
+                continue;
+            }
+            let key = (snapshot, symbol.to_string(), expiration.to_string());
+            if !chains.contains_key(&key) {
+                let chain = OptionChain::new(
+                    symbol,
+                    positive(columns.underlying_price.value(row))?,
+                    expiration.to_string(),
+                    float(columns.risk_free_rate, row).and_then(Decimal::from_f64),
+                    float(columns.dividend_yield, row)
+                        .map(positive)
+                        .transpose()?,
+                );
+                chains.insert(key.clone(), chain);
+            }
+            let strike = positive(columns.strike.value(row))?;
+            let option = strikes
+                .entry(key)
+                .or_default()
+                .entry(strike)
+                .or_insert_with(|| OptionData {
+                    strike_price: strike,
+                    ..Default::default()
+                });
+            columns.fill(row, option)?;
+        }
+    }
+
+    let mut snapshots = Vec::with_capacity(chains.len());
+    for (key, mut chain) in chains {
+        let params = OptionDataPriceParams::new(
+            Some(Box::new(chain.underlying_price)),
+            chain.get_expiration(),
+            chain.risk_free_rate,
+            chain.dividend_yield,
+            Some(chain.symbol.clone()),
+        );
+        if let Some(options) = strikes.remove(&key) {
+            chain.options = options
+                .into_values()
+                .map(|mut option| {
+                    option.set_extra_params(params.clone());
+                    option
+                })
+                .collect();
+        }
+        let timestamp = DateTime::from_timestamp_millis(key.0).ok_or_else(|| {
+            ChainError::invalid_parameters(SNAPSHOT, &format!("invalid timestamp {}", key.0))
+        })?;
+        snapshots.push(ChainSnapshot::new(timestamp, chain));
+    }
+    Ok(snapshots)
+}
+
+fn schema() -> SchemaRef {
+    let float = |name: &str, nullable: bool| Field::new(name, DataType::Float64, nullable);
+    let text = |name: &str, nullable: bool| Field::new(name, DataType::Utf8, nullable);
+    Arc::new(Schema::new(vec![
+        Field::new(
+            SNAPSHOT,
+            DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+            false,
+        ),
+        text(SYMBOL, false),
+        text(EXPIRATION, false),
+        float("underlying_price", false),
+        float("risk_free_rate", true),
+        float("dividend_yield", true),
+        float("strike", false),
+        text("style", false),
+        float("bid", true),
+        float("ask", true),
+        float("mid", true),
+        float("implied_volatility", false),
+        float("delta", true),
+        float("gamma", true),
+        float("vega", true),
+        float("theta", true),
+        float("rho", true),
+        float("vanna", true),
+        float("charm", true),
+        float("volume", true),
+        Field::new("open_interest", DataType::UInt64, true),
+        text("epic", true),
+    ]))
+}
+
+fn write_snapshots<'a>(
+    file_path: &str,
+    snapshots: impl IntoIterator<Item = (DateTime<Utc>, &'a OptionChain)>,
+) -> Result<(), ChainError> {
+    let schema = schema();
+    let properties = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+    let mut writer =
+        ArrowWriter::try_new(File::create(file_path)?, schema.clone(), Some(properties))?;
+    for (timestamp, chain) in snapshots {
+        writer.write(&chain_batch(&schema, timestamp, chain)?)?;
+        writer.flush()?;
+    }
+    writer.close()?;
+    Ok(())
+}
+
+fn chain_batch(
+    schema: &SchemaRef,
+    timestamp: DateTime<Utc>,
+    chain: &OptionChain,
+) -> Result<RecordBatch, ChainError> {
+    let rows: Vec<(&OptionData, OptionStyle)> = chain
+        .options
+        .iter()
+        .flat_map(|option| [(option, OptionStyle::Call), (option, OptionStyle::Put)])
+        .collect();
+    let n = rows.len();
+    let to_f64 = |value: Decimal| value.to_f64();
+    let column = |f: &dyn Fn(&OptionData, OptionStyle) -> Option<f64>| -> ArrayRef {
+        Arc::new(Float64Array::from(
+            rows.iter()
+                .map(|(option, style)| f(option, *style))
+                .collect::<Vec<_>>(),
+        ))
+    };
+    let greeks = |option: &OptionData, style: OptionStyle| match style {
+        OptionStyle::Call => option.call_greeks,
+        OptionStyle::Put => option.put_greeks,
+    };
+    let side = |style: OptionStyle, call: Option<Positive>, put: Option<Positive>| match style {
+        OptionStyle::Call => call.map(|v| v.to_f64()),
+        OptionStyle::Put => put.map(|v| v.to_f64()),
+    };
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(
+            TimestampMillisecondArray::from(vec![timestamp.timestamp_millis(); n])
+                .with_timezone("UTC"),
+        ),
+        Arc::new(StringArray::from(vec![chain.symbol.as_str(); n])),
+        Arc::new(StringArray::from(vec![chain.get_expiration_date(); n])),
+        Arc::new(Float64Array::from(vec![chain.underlying_price.to_f64(); n])),
+        Arc::new(Float64Array::from(vec![
+            chain
+                .risk_free_rate
+                .and_then(to_f64);
+            n
+        ])),
+        Arc::new(Float64Array::from(vec![
+            chain
+                .dividend_yield
+                .map(|v| v.to_f64());
+            n
+        ])),
+        column(&|option, _| Some(option.strike_price.to_f64())),
+        Arc::new(StringArray::from(
+            rows.iter()
+                .map(|(_, style)| style.to_string())
+                .collect::<Vec<_>>(),
+        )),
+        column(&|option, style| side(style, option.call_bid, option.put_bid)),
+        column(&|option, style| side(style, option.call_ask, option.put_ask)),
+        column(&|option, style| side(style, option.call_middle, option.put_middle)),
+        column(&|option, _| Some(option.implied_volatility.to_f64())),
+        column(&|option, style| match style {
+            OptionStyle::Call => option.delta_call.and_then(to_f64),
+            OptionStyle::Put => option.delta_put.and_then(to_f64),
+        }),
+        column(&|option, style| {
+            greeks(option, style)
+                .map(|g| g.gamma)
+                .or(option.gamma)
+                .and_then(to_f64)
+        }),
+        column(&|option, style| greeks(option, style).and_then(|g| to_f64(g.vega))),
+        column(&|option, style| greeks(option, style).and_then(|g| to_f64(g.theta))),
+        column(&|option, style| greeks(option, style).and_then(|g| to_f64(g.rho))),
+        column(&|option, style| greeks(option, style).and_then(|g| to_f64(g.vanna))),
+        column(&|option, style| greeks(option, style).and_then(|g| to_f64(g.charm))),
+        column(&|option, _| option.volume.map(|v| v.to_f64())),
+        Arc::new(UInt64Array::from(
+            rows.iter()
+                .map(|(option, _)| option.open_interest)
+                .collect::<Vec<_>>(),
+        )),
+        Arc::new(StringArray::from(
+            rows.iter()
+                .map(|(option, _)| option.epic.clone())
+                .collect::<Vec<_>>(),
+        )),
+    ];
+    Ok(RecordBatch::try_new(schema.clone(), columns)?)
+}
+
+struct Columns<'a> {
+    snapshot: &'a TimestampMillisecondArray,
+    symbol: &'a StringArray,
+    expiration: &'a StringArray,
+    underlying_price: &'a Float64Array,
+    risk_free_rate: &'a Float64Array,
+    dividend_yield: &'a Float64Array,
+    strike: &'a Float64Array,
+    style: &'a StringArray,
+    bid: &'a Float64Array,
+    ask: &'a Float64Array,
+    mid: &'a Float64Array,
+    implied_volatility: &'a Float64Array,
+    delta: &'a Float64Array,
+    gamma: &'a Float64Array,
+    vega: &'a Float64Array,
+    theta: &'a Float64Array,
+    rho: &'a Float64Array,
+    vanna: &'a Float64Array,
+    charm: &'a Float64Array,
+    volume: &'a Float64Array,
+    open_interest: &'a UInt64Array,
+    epic: &'a StringArray,
+}
+
+impl<'a> Columns<'a> {
+    fn new(batch: &'a RecordBatch) -> Result<Self, ChainError> {
+        Ok(Self {
+            snapshot: column(batch, SNAPSHOT)?,
+            symbol: column(batch, SYMBOL)?,
+            expiration: column(batch, EXPIRATION)?,
+            underlying_price: column(batch, "underlying_price")?,
+            risk_free_rate: column(batch, "risk_free_rate")?,
+            dividend_yield: column(batch, "dividend_yield")?,
+            strike: column(batch, "strike")?,
+            style: column(batch, "style")?,
+            bid: column(batch, "bid")?,
+            ask: column(batch, "ask")?,
+            mid: column(batch, "mid")?,
+            implied_volatility: column(batch, "implied_volatility")?,
+            delta: column(batch, "delta")?,
+            gamma: column(batch, "gamma")?,
+            vega: column(batch, "vega")?,
+            theta: column(batch, "theta")?,
+            rho: column(batch, "rho")?,
+            vanna: column(batch, "vanna")?,
+            charm: column(batch, "charm")?,
+            volume: column(batch, "volume")?,
+            open_interest: column(batch, "open_interest")?,
+            epic: column(batch, "epic")?,
+        })
+    }
+
+    fn fill(&self, row: usize, option: &mut OptionData) -> Result<(), ChainError> {
+        let price = |array: &Float64Array| float(array, row).map(positive).transpose();
+        let (bid, ask, mid) = (price(self.bid)?, price(self.ask)?, price(self.mid)?);
+        let decimal = |array: &Float64Array| float(array, row).and_then(Decimal::from_f64);
+        let delta = decimal(self.delta);
+        let gamma = decimal(self.gamma);
+        let greeks = (|| {
+            Some(SideGreeks {
+                delta: delta?,
+                gamma: gamma?,
+                vega: decimal(self.vega)?,
+                theta: decimal(self.theta)?,
+                rho: decimal(self.rho)?,
+                vanna: decimal(self.vanna)?,
+                charm: decimal(self.charm)?,
+            })
+        })();
+        match self.style.value(row) {
+            "Call" => {
+                option.call_bid = bid;
+                option.call_ask = ask;
+                option.call_middle = mid;
+                option.delta_call = delta;
+                option.gamma = gamma;
+                option.call_greeks = greeks;
+            }
+            "Put" => {
+                option.put_bid = bid;
+                option.put_ask = ask;
+                option.put_middle = mid;
+                option.delta_put = delta;
+                option.put_greeks = greeks;
+            }
+            other => {
+                return Err(ChainError::invalid_parameters(
+                    "style",
+                    &format!("unknown option style '{other}'"),
+                ));
+            }
+        }
+        option.implied_volatility = positive(self.implied_volatility.value(row))?;
+        option.volume = price(self.volume)?;
+        option.open_interest =
+            (!self.open_interest.is_null(row)).then(|| self.open_interest.value(row));
+        option.epic = (!self.epic.is_null(row)).then(|| self.epic.value(row).to_string());
+        Ok(())
+    }
+}
+
+fn column<'a, T: 'static>(batch: &'a RecordBatch, name: &str) -> Result<&'a T, ChainError> {
+    batch
+        .column_by_name(name)
+        .and_then(|array| array.as_any().downcast_ref::<T>())
+        .ok_or_else(|| {
+            ChainError::invalid_parameters(name, "column missing or of an unexpected type")
+        })
+}
+
+fn float(array: &Float64Array, row: usize) -> Option<f64> {
+    (!array.is_null(row)).then(|| array.value(row))
+}
+
+fn positive(value: f64) -> Result<Positive, ChainError> {
+    Ok(Positive::new(value)?)
+}
+
+#[cfg(test)]
+mod tests_parquet {
+    use super::*;
+    use crate::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
+    use chrono::TimeZone;
+    use positive::{pos_or_panic, spos};
+    use rust_decimal_macros::dec;
+    use tempfile::tempdir;
+
+    fn chain(symbol: &str, days: f64, price: Positive) -> OptionChain {
+        let params = OptionChainBuildParams::new(
+            symbol.to_string(),
+            spos!(100.0),
+            5,
+            spos!(5.0),
+            dec!(-0.2),
+            dec!(0.1),
+            pos_or_panic!(0.02),
+            2,
+            OptionDataPriceParams::new(
+                Some(Box::new(price)),
+                Some(ExpirationDate::Days(pos_or_panic!(days))),
+                Some(dec!(0.05)),
+                spos!(0.01),
+                Some(symbol.to_string()),
+            ),
+            pos_or_panic!(0.25),
+        );
+        OptionChain::build_chain(&params).unwrap()
+    }
+
+    fn assert_same_quotes(left: &OptionChain, right: &OptionChain) {
+        assert_eq!(left.symbol, right.symbol);
+        assert_eq!(left.get_expiration_date(), right.get_expiration_date());
+        assert_eq!(left.underlying_price, right.underlying_price);
+        assert_eq!(left.risk_free_rate, right.risk_free_rate);
+        assert_eq!(left.dividend_yield, right.dividend_yield);
+        assert_eq!(left.options.len(), right.options.len());
+        for (a, b) in left.options.iter().zip(right.options.iter()) {
+            assert_eq!(a.strike_price, b.strike_price);
+            assert_eq!(a.call_bid, b.call_bid);
+            assert_eq!(a.call_ask, b.call_ask);
+            assert_eq!(a.put_bid, b.put_bid);
+            assert_eq!(a.put_ask, b.put_ask);
+            let close = |x: Option<Positive>, y: Option<Positive>| match (x, y) {
+                (Some(x), Some(y)) => (x.to_f64() - y.to_f64()).abs() < 1e-9,
+                (x, y) => x == y,
+            };
+            assert!(close(a.call_middle, b.call_middle));
+            assert!(close(a.put_middle, b.put_middle));
+            assert!(close(
+                Some(a.implied_volatility),
+                Some(b.implied_volatility)
+            ));
+            assert_eq!(a.volume, b.volume);
+            assert_eq!(a.open_interest, b.open_interest);
+        }
+    }
+
+    #[test]
+    fn test_chain_round_trip() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().to_str().unwrap();
+        let original = chain("SPY", 30.0, Positive::HUNDRED);
+        original.save_to_parquet(path).unwrap();
+
+        let file = format!("{path}/{}.parquet", original.get_title());
+        let loaded = OptionChain::load_from_parquet(&file).unwrap();
+        assert_same_quotes(&original, &loaded);
+    }
+
+    #[test]
+    fn test_series_round_trip() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("series.parquet");
+        let file = file.to_str().unwrap();
+        let mut series = OptionSeries::new("SPY".to_string(), Positive::HUNDRED);
+        for days in [30.0, 60.0] {
+            let chain = chain("SPY", days, Positive::HUNDRED);
+            let expiration = ExpirationDate::from_string(&chain.get_expiration_date()).unwrap();
+            series.chains.insert(expiration, chain);
+        }
+        series.save_to_parquet(file).unwrap();
+
+        let loaded = OptionSeries::load_from_parquet(file).unwrap();
+        assert_eq!(loaded.symbol, "SPY");
+        assert_eq!(loaded.chains.len(), 2);
+        for (original, loaded) in series.chains.values().zip(loaded.chains.values()) {
+            assert_same_quotes(original, loaded);
+        }
+    }
+
+    #[test]
+    fn test_history_filtering() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("history.parquet");
+        let file = file.to_str().unwrap();
+        let day = |d: u32| Utc.with_ymd_and_hms(2026, 10, d, 20, 0, 0).unwrap();
+        let history = vec![
+            ChainSnapshot::new(day(1), chain("SPY", 30.0, Positive::HUNDRED)),
+            ChainSnapshot::new(day(1), chain("QQQ", 30.0, pos_or_panic!(400.0))),
+            ChainSnapshot::new(day(2), chain("SPY", 30.0, pos_or_panic!(101.0))),
+            ChainSnapshot::new(day(3), chain("SPY", 60.0, pos_or_panic!(102.0))),
+        ];
+        write_chain_history_parquet(file, &history).unwrap();
+
+        let all = read_chain_history_parquet(file, &ParquetFilter::default()).unwrap();
+        assert_eq!(all.len(), 4);
+
+        let spy = ParquetFilter::default().with_symbol("SPY");
+        let loaded = read_chain_history_parquet(file, &spy).unwrap();
+        assert_eq!(loaded.len(), 3);
+        assert!(loaded.iter().all(|s| s.chain.symbol == "SPY"));
+        assert_eq!(loaded[1].timestamp, day(2));
+        assert_same_quotes(&history[2].chain, &loaded[1].chain);
+
+        let ranged = spy.clone().with_range(Some(day(2)), None);
+        assert_eq!(read_chain_history_parquet(file, &ranged).unwrap().len(), 2);
+
+        let expiration = history[3].chain.get_expiration_date();
+        let by_expiry = spy.with_expiration(&expiration);
+        let loaded = read_chain_history_parquet(file, &by_expiry).unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].timestamp, day(3));
+
+        let none = ParquetFilter::default().with_symbol("IWM");
+        assert!(read_chain_history_parquet(file, &none).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_missing_file() {
+        assert!(OptionChain::load_from_parquet("/nonexistent/chain.parquet").is_err());
+    }
+}
@@ -0,0 +1,658 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Dealer Positioning
+//!
+//! Estimates how option dealers are positioned from the open interest of an
+//! [`OptionChain`] or an [`OptionSeries`], and derives the levels commonly
+//! watched by gamma-hedging desks:
+//!
+//! - **Gamma exposure (GEX)** per strike and across a ladder of spot prices,
+//!   in currency per 1% move of the underlying: `Γ · OI · size · S² · 0.01`.
+//! - **Delta exposure (DEX)** per strike and across the same ladder, in
+//!   currency per unit of underlying: `Δ · OI · size · S`.
+//! - **Gamma flip**: the spot where the ladder GEX changes sign, closest to
+//!   the current spot.
+//! - **Call and put walls**: the strikes holding the largest call and put
+//!   gamma exposure.
+//! - **Max pain**: the strike at which the options expire with the smallest
+//!   total payout to holders.
+//!
+//! Exposures are signed from the dealer's side according to a
+//! [`DealerConvention`]. The chain stores a single open interest per strike,
+//! so it is attributed to the calls and puts of that strike following an
+//! [`OpenInterestSplit`]; by default it goes to the out-of-the-money side,
+//! where most open interest sits in practice.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use optionstratlib::chains::{OptionChain, PositioningParameters};
+//! use positive::{pos_or_panic, spos, Positive};
+//!
+//! let mut chain = OptionChain::new("TEST", Positive::HUNDRED, "30".to_string(), None, None);
+//! for (strike, oi) in [(90.0, 20_000), (100.0, 1_000), (110.0, 20_000)] {
+//!     chain.add_option(pos_or_panic!(strike), spos!(1.0), spos!(1.1), spos!(1.0), spos!(1.1), pos_or_panic!(0.2), None, None, None, None, Some(oi), None);
+//! }
+//!
+//! let positioning = chain.dealer_positioning(&PositioningParameters::default()).unwrap();
+//! assert_eq!(positioning.put_wall, Some(pos_or_panic!(90.0)));
+//! assert_eq!(positioning.call_wall, Some(pos_or_panic!(110.0)));
+//! assert!(positioning.gamma_flip.is_some());
+//! ```
+
+use crate::chains::chain::OptionChain;
+use crate::curves::{Curve, Point2D};
+use crate::error::ChainError;
+use crate::greeks::{delta, gamma};
+use crate::model::types::{OptionStyle, Side};
+use crate::series::OptionSeries;
+use positive::Positive;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use tracing::debug;
+use utoipa::ToSchema;
+
+/// Assumed position of dealers against the open interest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ToSchema, Serialize, Deserialize)]
+pub enum DealerConvention {
+    /// Dealers are long the calls sold by overwriters and short the puts
+    /// bought as protection (the usual GEX convention)
+    #[default]
+    LongCallsShortPuts,
+    /// Dealers are short calls and long puts
+    ShortCallsLongPuts,
+    /// Dealers are short every option (customers are net long)
+    ShortAll,
+    /// Dealers are long every option (customers are net short)
+    LongAll,
+}
+
+impl DealerConvention {
+    /// Sign applied to call and put exposures, in that order.
+    fn signs(&self) -> (Decimal, Decimal) {
+        match self {
+            DealerConvention::LongCallsShortPuts => (Decimal::ONE, Decimal::NEGATIVE_ONE),
+            DealerConvention::ShortCallsLongPuts => (Decimal::NEGATIVE_ONE, Decimal::ONE),
+            DealerConvention::ShortAll => (Decimal::NEGATIVE_ONE, Decimal::NEGATIVE_ONE),
+            DealerConvention::LongAll => (Decimal::ONE, Decimal::ONE),
+        }
+    }
+}
+
+/// How the single open interest of a strike is attributed to its call and
+/// put.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ToSchema, Serialize, Deserialize)]
+pub enum OpenInterestSplit {
+    /// Calls above the spot and puts below it; a strike at the spot is split
+    /// evenly
+    #[default]
+    OutOfTheMoney,
+    /// The full open interest is applied to both the call and the put
+    Both,
+}
+
+impl OpenInterestSplit {
+    /// Open interest attributed to the call and the put, in that order.
+    fn split(&self, strike: Positive, spot: Positive, open_interest: u64) -> (Decimal, Decimal) {
+        let open_interest = Decimal::from(open_interest);
+        match self {
+            OpenInterestSplit::Both => (open_interest, open_interest),
+            OpenInterestSplit::OutOfTheMoney if strike > spot => (open_interest, Decimal::ZERO),
+            OpenInterestSplit::OutOfTheMoney if strike < spot => (Decimal::ZERO, open_interest),
+            OpenInterestSplit::OutOfTheMoney => {
+                let half = open_interest / Decimal::TWO;
+                (half, half)
+            }
+        }
+    }
+}
+
+/// Configuration for the dealer positioning analysis.
+#[derive(DebugPretty, DisplaySimple, Clone, ToSchema, Serialize, Deserialize)]
+pub struct PositioningParameters {
+    /// Assumed dealer position against the open interest
+    pub convention: DealerConvention,
+    /// Attribution of each strike's open interest to calls and puts
+    pub open_interest_split: OpenInterestSplit,
+    /// Units of underlying per contract
+    pub contract_size: Positive,
+    /// Half-width of the spot ladder as a fraction of the spot
+    pub spot_range: Positive,
+    /// Number of intervals in the spot ladder
+    pub spot_steps: usize,
+}
+
+impl Default for PositioningParameters {
+    fn default() -> Self {
+        Self {
+            convention: DealerConvention::default(),
+            open_interest_split: OpenInterestSplit::default(),
+            contract_size: Positive::HUNDRED,
+            spot_range: Positive::new_decimal(dec!(0.1)).unwrap_or(Positive::ONE),
+            spot_steps: 40,
+        }
+    }
+}
+
+impl PositioningParameters {
+    /// Returns the parameters with a different dealer convention.
+    #[must_use]
+    pub fn with_convention(mut self, convention: DealerConvention) -> Self {
+        self.convention = convention;
+        self
+    }
+
+    /// Returns the parameters with a different open interest attribution.
+    #[must_use]
+    pub fn with_open_interest_split(mut self, split: OpenInterestSplit) -> Self {
+        self.open_interest_split = split;
+        self
+    }
+
+    /// Returns the parameters with a different contract size.
+    #[must_use]
+    pub fn with_contract_size(mut self, contract_size: Positive) -> Self {
+        self.contract_size = contract_size;
+        self
+    }
+
+    /// Returns the parameters with a different spot ladder, spanning
+    /// `spot · (1 ± range)` in `steps` intervals.
+    #[must_use]
+    pub fn with_spot_ladder(mut self, range: Positive, steps: usize) -> Self {
+        self.spot_range = range;
+        self.spot_steps = steps;
+        self
+    }
+}
+
+/// Dealer exposure profiles and key levels of a chain or series.
+#[derive(Debug, Clone, ToSchema, Serialize, Deserialize)]
+pub struct DealerPositioning {
+    /// Gamma exposure at the current spot, by strike
+    pub gamma_by_strike: Curve,
+    /// Delta exposure at the current spot, by strike
+    pub delta_by_strike: Curve,
+    /// Total gamma exposure across the spot ladder
+    pub gamma_by_spot: Curve,
+    /// Total delta exposure across the spot ladder
+    pub delta_by_spot: Curve,
+    /// Total payout to option holders if settled at each strike
+    pub pain_by_strike: Curve,
+    /// Total gamma exposure at the current spot
+    pub total_gamma: Decimal,
+    /// Total delta exposure at the current spot
+    pub total_delta: Decimal,
+    /// Spot where the ladder gamma exposure changes sign, closest to the
+    /// current spot
+    pub gamma_flip: Option<Positive>,
+    /// Strike with the largest call gamma exposure, `None` when no call
+    /// carries exposure
+    pub call_wall: Option<Positive>,
+    /// Strike with the largest put gamma exposure, `None` when no put
+    /// carries exposure
+    pub put_wall: Option<Positive>,
+    /// Strike minimising the total payout to option holders
+    pub max_pain: Option<Positive>,
+}
+
+/// Key dealer levels by days to expiration across an [`OptionSeries`].
+///
+/// Each curve has days to expiration on the x-axis; expirations where a
+/// level does not exist (e.g. no gamma flip inside the ladder) are omitted.
+#[derive(Debug, Clone, ToSchema, Serialize, Deserialize)]
+pub struct PositioningTermStructure {
+    /// Gamma flip level by expiration
+    pub gamma_flip: Curve,
+    /// Call wall by expiration
+    pub call_wall: Curve,
+    /// Put wall by expiration
+    pub put_wall: Curve,
+    /// Max pain strike by expiration
+    pub max_pain: Curve,
+    /// Total gamma exposure by expiration
+    pub total_gamma: Curve,
+}
+
+impl OptionChain {
+    /// Estimates dealer gamma and delta exposure from the chain's open
+    /// interest and derives the gamma flip, call and put walls and max pain.
+    ///
+    /// Greeks are computed from each strike's implied volatility, repricing
+    /// the underlying along the spot ladder for the `*_by_spot` profiles.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChainError` if no strike carries open interest or the ladder
+    /// parameters are invalid.
+    pub fn dealer_positioning(
+        &self,
+        params: &PositioningParameters,
+    ) -> Result<DealerPositioning, ChainError> {
+        positioning(&[self], self.underlying_price, params)
+    }
+}
+
+impl OptionSeries {
+    /// Aggregates dealer positioning across every expiration of the series,
+    /// as [`OptionChain::dealer_positioning`] does for a single chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChainError` if no strike of any chain carries open interest
+    /// or the ladder parameters are invalid.
+    pub fn dealer_positioning(
+        &self,
+        params: &PositioningParameters,
+    ) -> Result<DealerPositioning, ChainError> {
+        let chains: Vec<&OptionChain> = self.chains.values().collect();
+        positioning(&chains, self.underlying_price, params)
+    }
+
+    /// Computes the dealer positioning of each expiration and returns how the
+    /// key levels move with days to expiration.
+    ///
+    /// Chains without open interest are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChainError` if no chain carries open interest, the ladder
+    /// parameters are invalid or an expiration cannot be resolved.
+    pub fn positioning_term_structure(
+        &self,
+        params: &PositioningParameters,
+    ) -> Result<PositioningTermStructure, ChainError> {
+        let mut gamma_flip = BTreeSet::new();
+        let mut call_wall = BTreeSet::new();
+        let mut put_wall = BTreeSet::new();
+        let mut max_pain = BTreeSet::new();
+        let mut total_gamma = BTreeSet::new();
+        for (expiration, chain) in &self.chains {
+            if !has_open_interest(chain) {
+                continue;
+            }
+            let days = expiration.get_days()?.to_dec();
+            let levels = positioning(&[chain], self.underlying_price, params)?;
+            let push = |set: &mut BTreeSet<Point2D>, level: Option<Positive>| {
+                if let Some(level) = level {
+                    set.insert(Point2D::new(days, level.to_dec()));
+                }
+            };
+            push(&mut gamma_flip, levels.gamma_flip);
+            push(&mut call_wall, levels.call_wall);
+            push(&mut put_wall, levels.put_wall);
+            push(&mut max_pain, levels.max_pain);
+            total_gamma.insert(Point2D::new(days, levels.total_gamma));
+        }
+        if total_gamma.is_empty() {
+            return Err(ChainError::invalid_parameters(
+                "open_interest",
+                "no chain of the series carries open interest",
+            ));
+        }
+        Ok(PositioningTermStructure {
+            gamma_flip: Curve::new(gamma_flip),
+            call_wall: Curve::new(call_wall),
+            put_wall: Curve::new(put_wall),
+            max_pain: Curve::new(max_pain),
+            total_gamma: Curve::new(total_gamma),
+        })
+    }
+}
+
+fn has_open_interest(chain: &OptionChain) -> bool {
+    chain
+        .options
+        .iter()
+        .any(|option| option.open_interest.unwrap_or(0) > 0)
+}
+
+/// Dealer gamma and delta exposure of a set of chains at a given spot,
+/// aggregated by strike as `(call gamma, put gamma, delta)`. Open interest is
+/// attributed to calls and puts relative to the `reference` spot.
+fn exposures_at(
+    chains: &[&OptionChain],
+    reference: Positive,
+    spot: Positive,
+    params: &PositioningParameters,
+) -> BTreeMap<Positive, (Decimal, Decimal, Decimal)> {
+    let (call_sign, put_sign) = params.convention.signs();
+    let gamma_scale = spot.to_dec() * spot.to_dec() * dec!(0.01);
+    let mut by_strike: BTreeMap<Positive, (Decimal, Decimal, Decimal)> = BTreeMap::new();
+    for chain in chains {
+        for option_data in &chain.options {
+            let open_interest = option_data.open_interest.unwrap_or(0);
+            if open_interest == 0 {
+                continue;
+            }
+            let (call_oi, put_oi) = params.open_interest_split.split(
+                option_data.strike_price,
+                reference,
+                open_interest,
+            );
+            let entry = by_strike.entry(option_data.strike_price).or_default();
+            for (style, sign, side_oi) in [
+                (OptionStyle::Call, call_sign, call_oi),
+                (OptionStyle::Put, put_sign, put_oi),
+            ] {
+                if side_oi.is_zero() {
+                    continue;
+                }
+                let contracts = side_oi * params.contract_size.to_dec();
+                let greeks = option_data
+                    .get_option(Side::Long, style)
+                    .and_then(|mut option| {
+                        option.underlying_price = spot;
+                        Ok((gamma(&option)?, delta(&option)?))
+                    });
+                let (option_gamma, option_delta) = match greeks {
+                    Ok(greeks) => greeks,
+                    Err(e) => {
+                        debug!(
+                            "Skipping {} {:?} in dealer positioning: {}",
+                            option_data.strike_price, style, e
+                        );
+                        continue;
+                    }
+                };
+                let gamma_exposure = sign * option_gamma * contracts * gamma_scale;
+                match style {
+                    OptionStyle::Call => entry.0 += gamma_exposure,
+                    OptionStyle::Put => entry.1 += gamma_exposure,
+                }
+                entry.2 += sign * option_delta * contracts * spot.to_dec();
+            }
+        }
+    }
+    by_strike
+}
+
+fn positioning(
+    chains: &[&OptionChain],
+    spot: Positive,
+    params: &PositioningParameters,
+) -> Result<DealerPositioning, ChainError> {
+    if params.spot_steps == 0 || params.spot_range >= Positive::ONE {
+        return Err(ChainError::invalid_parameters(
+            "spot_range",
+            "the spot ladder needs at least one step and a range below 100%",
+        ));
+    }
+
+    let at_spot = exposures_at(chains, spot, spot, params);
+    if at_spot.is_empty() {
+        return Err(ChainError::invalid_parameters(
+            "open_interest",
+            "no strike carries open interest",
+        ));
+    }
+
+    let mut gamma_by_strike = BTreeSet::new();
+    let mut delta_by_strike = BTreeSet::new();
+    let (mut total_gamma, mut total_delta) = (Decimal::ZERO, Decimal::ZERO);
+    let mut call_wall: Option<(Positive, Decimal)> = None;
+    let mut put_wall: Option<(Positive, Decimal)> = None;
+    for (&strike, &(call_gamma, put_gamma, strike_delta)) in &at_spot {
+        gamma_by_strike.insert(Point2D::new(strike.to_dec(), call_gamma + put_gamma));
+        delta_by_strike.insert(Point2D::new(strike.to_dec(), strike_delta));
+        total_gamma += call_gamma + put_gamma;
+        total_delta += strike_delta;
+        if !call_gamma.is_zero() && call_wall.is_none_or(|(_, wall)| call_gamma.abs() > wall) {
+            call_wall = Some((strike, call_gamma.abs()));
+        }
+        if !put_gamma.is_zero() && put_wall.is_none_or(|(_, wall)| put_gamma.abs() > wall) {
+            put_wall = Some((strike, put_gamma.abs()));
+        }
+    }
+
+    let low = spot.to_dec() * (Decimal::ONE - params.spot_range.to_dec());
+    let step = spot.to_dec() * params.spot_range.to_dec() * Decimal::TWO
+        / Decimal::from(params.spot_steps);
+    let mut ladder = Vec::with_capacity(params.spot_steps + 1);
+    for i in 0..=params.spot_steps {
+        let ladder_spot = Positive::new_decimal(low + step * Decimal::from(i))?;
+        let (gamma_total, delta_total) = exposures_at(chains, spot, ladder_spot, params)
+            .values()
+            .fold((Decimal::ZERO, Decimal::ZERO), |(g, d), (c, p, delta)| {
+                (g + c + p, d + delta)
+            });
+        ladder.push((ladder_spot.to_dec(), gamma_total, delta_total));
+    }
+
+    let gamma_flip = ladder
+        .windows(2)
+        .filter_map(|pair| {
+            let [(x0, g0, _), (x1, g1, _)] = pair else {
+                return None;
+            };
+            if g0.is_sign_negative() == g1.is_sign_negative() || g0 == g1 {
+                return None;
+            }
+            Some(x0 + (x1 - x0) * g0.abs() / (g1 - g0).abs())
+        })
+        .min_by_key(|flip| (flip - spot.to_dec()).abs())
+        .map(Positive::new_decimal)
+        .transpose()?;
+
+    let pain_by_strike = pain_by_strike(chains, spot, params);
+    let max_pain = pain_by_strike
+        .iter()
+        .min_by_key(|point| point.y)
+        .map(|point| Positive::new_decimal(point.x))
+        .transpose()?;
+
+    Ok(DealerPositioning {
+        gamma_by_strike: Curve::new(gamma_by_strike),
+        delta_by_strike: Curve::new(delta_by_strike),
+        gamma_by_spot: Curve::new(
+            ladder
+                .iter()
+                .map(|(x, g, _)| Point2D::new(*x, *g))
+                .collect(),
+        ),
+        delta_by_spot: Curve::new(
+            ladder
+                .iter()
+                .map(|(x, _, d)| Point2D::new(*x, *d))
+                .collect(),
+        ),
+        pain_by_strike: Curve::new(pain_by_strike),
+        total_gamma,
+        total_delta,
+        gamma_flip,
+        call_wall: call_wall.map(|(strike, _)| strike),
+        put_wall: put_wall.map(|(strike, _)| strike),
+        max_pain,
+    })
+}
+
+/// Total intrinsic value paid to call and put holders if the underlying
+/// settles at each strike carrying open interest.
+fn pain_by_strike(
+    chains: &[&OptionChain],
+    spot: Positive,
+    params: &PositioningParameters,
+) -> BTreeSet<Point2D> {
+    let open_interest: Vec<(Decimal, Decimal, Decimal)> = chains
+        .iter()
+        .flat_map(|chain| chain.options.iter())
+        .filter_map(|option| match option.open_interest {
+            Some(oi) if oi > 0 => {
+                let (call_oi, put_oi) =
+                    params
+                        .open_interest_split
+                        .split(option.strike_price, spot, oi);
+                let size = params.contract_size.to_dec();
+                Some((option.strike_price.to_dec(), call_oi * size, put_oi * size))
+            }
+            _ => None,
+        })
+        .collect();
+    open_interest
+        .iter()
+        .map(|&(settlement, _, _)| {
+            let pain: Decimal = open_interest
+                .iter()
+                .map(|&(strike, calls, puts)| {
+                    let call = (settlement - strike).max(Decimal::ZERO);
+                    let put = (strike - settlement).max(Decimal::ZERO);
+                    call * calls + put * puts
+                })
+                .sum();
+            Point2D::new(settlement, pain)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests_dealer_positioning {
+    use super::*;
+    use crate::ExpirationDate;
+    use positive::{pos_or_panic, spos};
+
+    fn chain(expiration: &str, open_interest: &[(f64, u64)]) -> OptionChain {
+        let mut chain = OptionChain::new(
+            "TEST",
+            Positive::HUNDRED,
+            expiration.to_string(),
+            Some(dec!(0.03)),
+            None,
+        );
+        for &(strike, oi) in open_interest {
+            chain.add_option(
+                pos_or_panic!(strike),
+                spos!(1.0),
+                spos!(1.1),
+                spos!(1.0),
+                spos!(1.1),
+                pos_or_panic!(0.2),
+                None,
+                None,
+                None,
+                None,
+                Some(oi),
+                None,
+            );
+        }
+        chain
+    }
+
+    #[test]
+    fn test_walls_flip_and_totals() {
+        let chain = chain(
+            "30",
+            &[
+                (90.0, 10_000),
+                (95.0, 1_000),
+                (100.0, 1_000),
+                (110.0, 4_000),
+            ],
+        );
+        let positioning = chain
+            .dealer_positioning(&PositioningParameters::default())
+            .unwrap();
+
+        assert_eq!(positioning.call_wall, Some(pos_or_panic!(110.0)));
+        assert_eq!(positioning.put_wall, Some(pos_or_panic!(90.0)));
+        // Short puts dominate below spot, long calls above it.
+        let flip = positioning.gamma_flip.unwrap();
+        assert!(flip > pos_or_panic!(90.0) && flip < pos_or_panic!(110.0));
+        let first = positioning.gamma_by_spot.points.first().unwrap();
+        let last = positioning.gamma_by_spot.points.last().unwrap();
+        assert!(first.y < Decimal::ZERO && last.y > Decimal::ZERO);
+
+        let sum: Decimal = positioning.gamma_by_strike.points.iter().map(|p| p.y).sum();
+        assert_eq!(sum, positioning.total_gamma);
+        assert_eq!(positioning.gamma_by_spot.points.len(), 41);
+    }
+
+    #[test]
+    fn test_one_sided_exposure_leaves_other_wall_empty() {
+        let chain = chain("30", &[(105.0, 2_000), (110.0, 4_000)]);
+        let positioning = chain
+            .dealer_positioning(&PositioningParameters::default())
+            .unwrap();
+        assert!(positioning.call_wall.is_some());
+        assert_eq!(positioning.put_wall, None);
+    }
+
+    #[test]
+    fn test_max_pain_is_open_interest_weighted() {
+        let chain = chain("30", &[(90.0, 100), (100.0, 5_000), (110.0, 100)]);
+        let positioning = chain
+            .dealer_positioning(&PositioningParameters::default())
+            .unwrap();
+        assert_eq!(positioning.max_pain, Some(Positive::HUNDRED));
+        let pain = &positioning.pain_by_strike.points;
+        assert_eq!(pain.len(), 3);
+        // Settling at 90 pays 10 on the 2 500 puts attributed to the 100 strike.
+        assert_eq!(pain.first().unwrap().y, dec!(25_000) * dec!(100));
+    }
+
+    #[test]
+    fn test_short_all_convention_has_no_flip() {
+        let chain = chain("30", &[(90.0, 1_000), (100.0, 1_000), (110.0, 1_000)]);
+        let params = PositioningParameters::default().with_convention(DealerConvention::ShortAll);
+        let positioning = chain.dealer_positioning(&params).unwrap();
+        assert!(positioning.total_gamma < Decimal::ZERO);
+        assert!(
+            positioning
+                .gamma_by_spot
+                .points
+                .iter()
+                .all(|p| p.y <= Decimal::ZERO)
+        );
+        assert_eq!(positioning.gamma_flip, None);
+    }
+
+    #[test]
+    fn test_missing_open_interest_and_bad_ladder_fail() {
+        let empty = chain("30", &[(100.0, 0)]);
+        assert!(
+            empty
+                .dealer_positioning(&PositioningParameters::default())
+                .is_err()
+        );
+        let chain = chain("30", &[(100.0, 10)]);
+        let params = PositioningParameters::default().with_spot_ladder(Positive::ONE, 10);
+        assert!(chain.dealer_positioning(&params).is_err());
+    }
+
+    #[test]
+    fn test_series_aggregate_and_term_structure() {
+        let mut series = OptionSeries::new("TEST".to_string(), Positive::HUNDRED);
+        let near = chain("10", &[(90.0, 3_000), (100.0, 1_000), (110.0, 2_000)]);
+        let far = chain("60", &[(95.0, 1_000), (105.0, 4_000)]);
+        let params = PositioningParameters::default();
+        let near_total = near.dealer_positioning(&params).unwrap().total_gamma;
+        let far_total = far.dealer_positioning(&params).unwrap().total_gamma;
+        series
+            .chains
+            .insert(ExpirationDate::Days(pos_or_panic!(10.0)), near);
+        series
+            .chains
+            .insert(ExpirationDate::Days(pos_or_panic!(60.0)), far);
+        series.chains.insert(
+            ExpirationDate::Days(pos_or_panic!(90.0)),
+            chain("90", &[(100.0, 0)]),
+        );
+
+        let aggregate = series.dealer_positioning(&params).unwrap();
+        assert_eq!(aggregate.total_gamma, near_total + far_total);
+        assert_eq!(aggregate.call_wall, Some(pos_or_panic!(105.0)));
+
+        let term = series.positioning_term_structure(&params).unwrap();
+        assert_eq!(term.total_gamma.points.len(), 2);
+        assert_eq!(term.max_pain.points.len(), 2);
+        let days: Vec<Decimal> = term.call_wall.points.iter().map(|p| p.x).collect();
+        assert_eq!(days, vec![dec!(10), dec!(60)]);
+        assert_eq!(term.call_wall.points.last().unwrap().y, dec!(105));
+    }
+}
@@ -0,0 +1,866 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Chain Queries
+//!
+//! Declarative selection of options from an [`OptionChain`] or an
+//! [`OptionSeries`]. A [`ChainQuery`] combines filters (delta range, days to
+//! expiration, moneyness, implied volatility, bid/ask spread width, minimum
+//! open interest and volume), an optional sort key and a result limit.
+//! Queries are evaluated against one option style, so the same strike row
+//! can be selected as a call by one query and as a put by another.
+//!
+//! Results are either the matching [`OptionData`] rows, the row nearest to a
+//! [`QueryTarget`], or ready-made [`Position`]s. A slice of [`LegSelection`]s
+//! turns a chain straight into the positions of a strategy, and
+//! [`OptionChain::build_strategy`] hands them to the strategy's
+//! [`StrategyConstructor`].
+//!
+//! Delta bounds and delta targets apply to the absolute delta, so a
+//! `0.20..=0.30` range selects 20–30 delta calls and 20–30 delta puts alike.
+//! Moneyness is `K / S`.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use optionstratlib::chains::{ChainQuery, QuerySort, QueryTarget};
+//! use optionstratlib::chains::chain::OptionChain;
+//! use optionstratlib::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
+//! use optionstratlib::ExpirationDate;
+//! use positive::{pos_or_panic, spos, Positive};
+//! use rust_decimal_macros::dec;
+//!
+//! let params = OptionChainBuildParams::new(
+//!     "SPY".to_string(),
+//!     spos!(1000.0),
+//!     20,
+//!     spos!(2.0),
+//!     dec!(-0.2),
+//!     dec!(0.1),
+//!     pos_or_panic!(0.02),
+//!     2,
+//!     OptionDataPriceParams::new(
+//!         Some(Box::new(Positive::HUNDRED)),
+//!         Some(ExpirationDate::Days(pos_or_panic!(30.0))),
+//!         Some(dec!(0.05)),
+//!         spos!(0.0),
+//!         Some("SPY".to_string()),
+//!     ),
+//!     pos_or_panic!(0.2),
+//! );
+//! let chain = OptionChain::build_chain(&params).unwrap();
+//!
+//! let query = ChainQuery::puts()
+//!     .with_delta(dec!(0.1), dec!(0.4))
+//!     .sort_by_desc(QuerySort::OpenInterest);
+//! assert!(!chain.select(&query).is_empty());
+//!
+//! let put = chain.select_nearest(&query, QueryTarget::Delta(dec!(0.25))).unwrap();
+//! assert!(put.strike_price < Positive::HUNDRED);
+//! ```
+
+use crate::chains::chain::OptionChain;
+use crate::chains::optiondata::OptionData;
+use crate::error::chains::{ChainError, StrategyErrorKind};
+use crate::model::{ExpirationDate, OptionStyle, Position, Side};
+use crate::series::OptionSeries;
+use crate::strategies::StrategyConstructor;
+use positive::Positive;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use utoipa::ToSchema;
+
+/// Field used to order the results of a [`ChainQuery`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ToSchema, Serialize, Deserialize)]
+pub enum QuerySort {
+    /// Strike price
+    Strike,
+    /// Absolute delta of the queried style
+    Delta,
+    /// Implied volatility
+    ImpliedVolatility,
+    /// Bid/ask spread width of the queried style
+    Spread,
+    /// Open interest
+    OpenInterest,
+    /// Traded volume
+    Volume,
+    /// Moneyness `K / S`
+    Moneyness,
+    /// Days to expiration of the containing chain
+    DaysToExpiration,
+}
+
+/// Value an option is measured against when selecting the nearest match.
+#[derive(Debug, Clone, Copy, PartialEq, ToSchema, Serialize, Deserialize)]
+pub enum QueryTarget {
+    /// Absolute delta of the queried style
+    Delta(Decimal),
+    /// Strike price
+    Strike(Positive),
+    /// Moneyness `K / S`
+    Moneyness(Decimal),
+    /// Implied volatility
+    ImpliedVolatility(Positive),
+    /// Days to expiration of the containing chain
+    DaysToExpiration(Positive),
+}
+
+/// Composable filter, sort and limit over the options of a chain or series.
+///
+/// Every filter is optional; an option missing the data a filter needs (for
+/// example no delta when a delta range is set) is excluded.
+#[derive(Debug, Clone, PartialEq, ToSchema, Serialize, Deserialize)]
+pub struct ChainQuery {
+    /// Option style the query is evaluated against
+    pub option_style: OptionStyle,
+    /// Inclusive absolute delta range
+    pub delta: Option<(Decimal, Decimal)>,
+    /// Inclusive days-to-expiration range
+    pub days_to_expiration: Option<(Positive, Positive)>,
+    /// Inclusive moneyness (`K / S`) range
+    pub moneyness: Option<(Decimal, Decimal)>,
+    /// Inclusive implied volatility range
+    pub implied_volatility: Option<(Positive, Positive)>,
+    /// Maximum bid/ask spread width
+    pub max_spread: Option<Positive>,
+    /// Minimum open interest
+    pub min_open_interest: Option<u64>,
+    /// Minimum traded volume
+    pub min_volume: Option<Positive>,
+    /// Sort key and whether the order is descending
+    pub sort: Option<(QuerySort, bool)>,
+    /// Maximum number of results
+    pub limit: Option<usize>,
+}
+
+impl ChainQuery {
+    /// Creates an unfiltered query over `option_style`.
+    #[must_use]
+    pub fn new(option_style: OptionStyle) -> Self {
+        Self {
+            option_style,
+            delta: None,
+            days_to_expiration: None,
+            moneyness: None,
+            implied_volatility: None,
+            max_spread: None,
+            min_open_interest: None,
+            min_volume: None,
+            sort: None,
+            limit: None,
+        }
+    }
+
+    /// Creates an unfiltered query over calls.
+    #[must_use]
+    pub fn calls() -> Self {
+        Self::new(OptionStyle::Call)
+    }
+
+    /// Creates an unfiltered query over puts.
+    #[must_use]
+    pub fn puts() -> Self {
+        Self::new(OptionStyle::Put)
+    }
+
+    /// Keeps options whose absolute delta lies between the absolute values
+    /// of `min` and `max`, in either order, so put deltas such as
+    /// `(-0.4, -0.2)` select the same band as `(0.2, 0.4)`.
+    #[must_use]
+    pub fn with_delta(mut self, min: Decimal, max: Decimal) -> Self {
+        let (a, b) = (min.abs(), max.abs());
+        self.delta = Some((a.min(b), a.max(b)));
+        self
+    }
+
+    /// Keeps options whose days to expiration lie in `[min, max]`.
+    #[must_use]
+    pub fn with_days_to_expiration(mut self, min: Positive, max: Positive) -> Self {
+        self.days_to_expiration = Some((min, max));
+        self
+    }
+
+    /// Keeps options whose moneyness `K / S` lies in `[min, max]`.
+    #[must_use]
+    pub fn with_moneyness(mut self, min: Decimal, max: Decimal) -> Self {
+        self.moneyness = Some((min, max));
+        self
+    }
+
+    /// Keeps options whose implied volatility lies in `[min, max]`.
+    #[must_use]
+    pub fn with_implied_volatility(mut self, min: Positive, max: Positive) -> Self {
+        self.implied_volatility = Some((min, max));
+        self
+    }
+
+    /// Keeps options whose bid/ask spread is at most `max`.
+    #[must_use]
+    pub fn with_max_spread(mut self, max: Positive) -> Self {
+        self.max_spread = Some(max);
+        self
+    }
+
+    /// Keeps options with at least `min` open interest.
+    #[must_use]
+    pub fn with_min_open_interest(mut self, min: u64) -> Self {
+        self.min_open_interest = Some(min);
+        self
+    }
+
+    /// Keeps options with at least `min` traded volume.
+    #[must_use]
+    pub fn with_min_volume(mut self, min: Positive) -> Self {
+        self.min_volume = Some(min);
+        self
+    }
+
+    /// Orders results by `key`, ascending.
+    #[must_use]
+    pub fn sort_by(mut self, key: QuerySort) -> Self {
+        self.sort = Some((key, false));
+        self
+    }
+
+    /// Orders results by `key`, descending.
+    #[must_use]
+    pub fn sort_by_desc(mut self, key: QuerySort) -> Self {
+        self.sort = Some((key, true));
+        self
+    }
+
+    /// Returns at most `limit` results.
+    #[must_use]
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    fn abs_delta(&self, option: &OptionData) -> Option<Decimal> {
+        match self.option_style {
+            OptionStyle::Call => option.delta_call,
+            OptionStyle::Put => option.delta_put,
+        }
+        .map(|delta| delta.abs())
+    }
+
+    fn spread(&self, option: &OptionData) -> Option<Decimal> {
+        let (bid, ask) = match self.option_style {
+            OptionStyle::Call => (option.call_bid, option.call_ask),
+            OptionStyle::Put => (option.put_bid, option.put_ask),
+        };
+        Some(ask?.to_dec() - bid?.to_dec())
+    }
+
+    fn matches(&self, option: &OptionData, ctx: &QueryContext) -> bool {
+        fn within<T: PartialOrd>(value: Option<T>, range: Option<(T, T)>) -> bool {
+            match (range, value) {
+                (None, _) => true,
+                (Some((min, max)), Some(value)) => value >= min && value <= max,
+                (Some(_), None) => false,
+            }
+        }
+
+        within(self.abs_delta(option), self.delta)
+            && within(ctx.days, self.days_to_expiration)
+            && within(ctx.moneyness(option), self.moneyness)
+            && within(Some(option.implied_volatility), self.implied_volatility)
+            && self.max_spread.is_none_or(|max| {
+                self.spread(option)
+                    .is_some_and(|spread| spread <= max.to_dec())
+            })
+            && self
+                .min_open_interest
+                .is_none_or(|min| option.open_interest.is_some_and(|oi| oi >= min))
+            && self
+                .min_volume
+                .is_none_or(|min| option.volume.is_some_and(|volume| volume >= min))
+    }
+
+    fn sort_value(&self, key: QuerySort, option: &OptionData, ctx: &QueryContext) -> Option<f64> {
+        match key {
+            QuerySort::Strike => Some(option.strike_price.to_f64()),
+            QuerySort::Delta => self.abs_delta(option).and_then(|d| d.to_f64()),
+            QuerySort::ImpliedVolatility => Some(option.implied_volatility.to_f64()),
+            QuerySort::Spread => self.spread(option).and_then(|s| s.to_f64()),
+            QuerySort::OpenInterest => option.open_interest.map(|oi| oi as f64),
+            QuerySort::Volume => option.volume.map(|v| v.to_f64()),
+            QuerySort::Moneyness => ctx.moneyness(option).and_then(|m| m.to_f64()),
+            QuerySort::DaysToExpiration => ctx.days.map(|d| d.to_f64()),
+        }
+    }
+
+    fn distance(
+        &self,
+        target: QueryTarget,
+        option: &OptionData,
+        ctx: &QueryContext,
+    ) -> Option<Decimal> {
+        match target {
+            QueryTarget::Delta(delta) => self.abs_delta(option).map(|d| (d - delta.abs()).abs()),
+            QueryTarget::Strike(strike) => {
+                Some((option.strike_price.to_dec() - strike.to_dec()).abs())
+            }
+            QueryTarget::Moneyness(moneyness) => {
+                ctx.moneyness(option).map(|m| (m - moneyness).abs())
+            }
+            QueryTarget::ImpliedVolatility(iv) => {
+                Some((option.implied_volatility.to_dec() - iv.to_dec()).abs())
+            }
+            QueryTarget::DaysToExpiration(days) => {
+                ctx.days.map(|d| (d.to_dec() - days.to_dec()).abs())
+            }
+        }
+    }
+
+    /// Filters, sorts and truncates `candidates` according to the query.
+    fn run<'a, T>(
+        &self,
+        candidates: Vec<(T, &'a OptionData, QueryContext)>,
+    ) -> Vec<(T, &'a OptionData, QueryContext)> {
+        let mut selected: Vec<_> = candidates
+            .into_iter()
+            .filter(|(_, option, ctx)| self.matches(option, ctx))
+            .collect();
+        if let Some((key, descending)) = self.sort {
+            // Options without a value for the key always sort last.
+            selected.sort_by(|(_, a, ctx_a), (_, b, ctx_b)| {
+                match (
+                    self.sort_value(key, a, ctx_a),
+                    self.sort_value(key, b, ctx_b),
+                ) {
+                    (Some(a), Some(b)) => {
+                        let ordering = a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+                        if descending {
+                            ordering.reverse()
+                        } else {
+                            ordering
+                        }
+                    }
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                }
+            });
+        }
+        if let Some(limit) = self.limit {
+            selected.truncate(limit);
+        }
+        selected
+    }
+
+    /// Returns the candidate matching the query that is nearest to `target`.
+    fn nearest<'a, T>(
+        &self,
+        candidates: Vec<(T, &'a OptionData, QueryContext)>,
+        target: QueryTarget,
+    ) -> Option<(T, &'a OptionData)> {
+        self.run(candidates)
+            .into_iter()
+            .filter_map(|(key, option, ctx)| {
+                self.distance(target, option, &ctx)
+                    .map(|distance| (distance, key, option))
+            })
+            .min_by(|(a, _, _), (b, _, _)| a.cmp(b))
+            .map(|(_, key, option)| (key, option))
+    }
+}
+
+/// One leg of a strategy expressed as a chain query.
+#[derive(Debug, Clone, PartialEq, ToSchema, Serialize, Deserialize)]
+pub struct LegSelection {
+    /// Query the leg's option must satisfy
+    pub query: ChainQuery,
+    /// Value the leg's option should be nearest to
+    pub target: QueryTarget,
+    /// Side of the resulting position
+    pub side: Side,
+    /// Number of contracts
+    pub quantity: Positive,
+}
+
+impl LegSelection {
+    /// Creates a one-contract leg.
+    #[must_use]
+    pub fn new(query: ChainQuery, target: QueryTarget, side: Side) -> Self {
+        Self {
+            query,
+            target,
+            side,
+            quantity: Positive::ONE,
+        }
+    }
+
+    /// Sets the number of contracts of the leg.
+    #[must_use]
+    pub fn with_quantity(mut self, quantity: Positive) -> Self {
+        self.quantity = quantity;
+        self
+    }
+
+    fn to_position(&self, option: &OptionData) -> Result<Position, ChainError> {
+        let mut position =
+            option.get_position(self.side, self.query.option_style, None, None, None)?;
+        position.option.quantity = self.quantity;
+        Ok(position)
+    }
+}
+
+/// Chain-level values a query needs besides the option row itself.
+#[derive(Debug, Clone, Copy)]
+struct QueryContext {
+    underlying_price: Positive,
+    days: Option<Positive>,
+}
+
+impl QueryContext {
+    fn new(underlying_price: Positive, expiration: Option<&ExpirationDate>) -> Self {
+        Self {
+            underlying_price,
+            days: expiration.and_then(|expiration| expiration.get_days().ok()),
+        }
+    }
+
+    fn moneyness(&self, option: &OptionData) -> Option<Decimal> {
+        if self.underlying_price.is_zero() {
+            return None;
+        }
+        Some(option.strike_price.to_dec() / self.underlying_price.to_dec())
+    }
+}
+
+fn no_match(legs: usize, found: usize, leg: usize, symbol: &str) -> ChainError {
+    ChainError::invalid_legs(
+        legs,
+        found,
+        &format!("no option in {symbol} matches leg {leg}"),
+    )
+}
+
+impl OptionChain {
+    fn query_candidates(&self) -> Vec<((), &OptionData, QueryContext)> {
+        let expiration = self.get_expiration();
+        let ctx = QueryContext::new(self.underlying_price, expiration.as_ref());
+        self.get_single_iter()
+            .map(|option| ((), option, ctx))
+            .collect()
+    }
+
+    /// Returns the options matching `query`, sorted and truncated as the
+    /// query specifies (strike order when no sort key is set).
+    #[must_use]
+    pub fn select(&self, query: &ChainQuery) -> Vec<&OptionData> {
+        query
+            .run(self.query_candidates())
+            .into_iter()
+            .map(|(_, option, _)| option)
+            .collect()
+    }
+
+    /// Returns the option matching `query` that is nearest to `target`.
+    ///
+    /// Ties resolve to the lowest strike.
+    #[must_use]
+    pub fn select_nearest(&self, query: &ChainQuery, target: QueryTarget) -> Option<&OptionData> {
+        query
+            .nearest(self.query_candidates(), target)
+            .map(|(_, option)| option)
+    }
+
+    /// Builds a one-contract position on `side` for every option matching
+    /// `query`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ChainError` if a matching option lacks the pricing
+    /// parameters needed to build its position.
+    pub fn select_positions(
+        &self,
+        query: &ChainQuery,
+        side: Side,
+    ) -> Result<Vec<Position>, ChainError> {
+        self.select(query)
+            .into_iter()
+            .map(|option| option.get_position(side, query.option_style, None, None, None))
+            .collect()
+    }
+
+    /// Resolves each leg to the option nearest its target and returns the
+    /// resulting positions in leg order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChainError::StrategyError` if a leg matches no option, or a
+    /// `ChainError` if a position cannot be built from the selected option.
+    pub fn select_legs(&self, legs: &[LegSelection]) -> Result<Vec<Position>, ChainError> {
+        legs.iter()
+            .enumerate()
+            .map(|(index, leg)| {
+                let option = self
+                    .select_nearest(&leg.query, leg.target)
+                    .ok_or_else(|| no_match(legs.len(), index, index, &self.symbol))?;
+                leg.to_position(option)
+            })
+            .collect()
+    }
+
+    /// Selects the legs and builds strategy `S` from them through
+    /// [`StrategyConstructor::get_strategy`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`OptionChain::select_legs`], or
+    /// `ChainError::StrategyError` if the selected positions do not form a
+    /// valid `S`.
+    pub fn build_strategy<S: StrategyConstructor>(
+        &self,
+        legs: &[LegSelection],
+    ) -> Result<S, ChainError> {
+        let positions = self.select_legs(legs)?;
+        S::get_strategy(&positions).map_err(|e| strategy_error::<S>(e.to_string()))
+    }
+}
+
+fn strategy_error<S>(reason: String) -> ChainError {
+    let type_name = std::any::type_name::<S>();
+    ChainError::StrategyError(StrategyErrorKind::InvalidCombination {
+        strategy_type: type_name
+            .rsplit("::")
+            .next()
+            .unwrap_or(type_name)
+            .to_string(),
+        reason,
+    })
+}
+
+impl OptionSeries {
+    fn query_candidates(&self) -> Vec<(&ExpirationDate, &OptionData, QueryContext)> {
+        self.chains
+            .iter()
+            .flat_map(|(expiration, chain)| {
+                let ctx = QueryContext::new(chain.underlying_price, Some(expiration));
+                chain
+                    .get_single_iter()
+                    .map(move |option| (expiration, option, ctx))
+            })
+            .collect()
+    }
+
+    /// Returns the options matching `query` across all expirations, paired
+    /// with their expiration. Without a sort key results are ordered by
+    /// expiration, then strike.
+    #[must_use]
+    pub fn select(&self, query: &ChainQuery) -> Vec<(&ExpirationDate, &OptionData)> {
+        query
+            .run(self.query_candidates())
+            .into_iter()
+            .map(|(expiration, option, _)| (expiration, option))
+            .collect()
+    }
+
+    /// Returns the option matching `query` across all expirations that is
+    /// nearest to `target`, paired with its expiration.
+    ///
+    /// Ties resolve to the nearest expiration, then the lowest strike.
+    #[must_use]
+    pub fn select_nearest(
+        &self,
+        query: &ChainQuery,
+        target: QueryTarget,
+    ) -> Option<(&ExpirationDate, &OptionData)> {
+        query.nearest(self.query_candidates(), target)
+    }
+
+    /// Builds a one-contract position on `side` for every option matching
+    /// `query` across all expirations.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ChainError` if a matching option lacks the pricing
+    /// parameters needed to build its position.
+    pub fn select_positions(
+        &self,
+        query: &ChainQuery,
+        side: Side,
+    ) -> Result<Vec<Position>, ChainError> {
+        self.select(query)
+            .into_iter()
+            .map(|(_, option)| option.get_position(side, query.option_style, None, None, None))
+            .collect()
+    }
+
+    /// Resolves each leg to the option nearest its target across all
+    /// expirations, so legs may land on different expirations.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChainError::StrategyError` if a leg matches no option, or a
+    /// `ChainError` if a position cannot be built from the selected option.
+    pub fn select_legs(&self, legs: &[LegSelection]) -> Result<Vec<Position>, ChainError> {
+        legs.iter()
+            .enumerate()
+            .map(|(index, leg)| {
+                let (_, option) = self
+                    .select_nearest(&leg.query, leg.target)
+                    .ok_or_else(|| no_match(legs.len(), index, index, &self.symbol))?;
+                leg.to_position(option)
+            })
+            .collect()
+    }
+
+    /// Selects the legs across expirations and builds strategy `S` from
+    /// them through [`StrategyConstructor::get_strategy`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`OptionSeries::select_legs`], or
+    /// `ChainError::StrategyError` if the selected positions do not form a
+    /// valid `S`.
+    pub fn build_strategy<S: StrategyConstructor>(
+        &self,
+        legs: &[LegSelection],
+    ) -> Result<S, ChainError> {
+        let positions = self.select_legs(legs)?;
+        S::get_strategy(&positions).map_err(|e| strategy_error::<S>(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests_chain_query {
+    use super::*;
+    use crate::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
+    use crate::strategies::BullCallSpread;
+    use positive::{pos_or_panic, spos};
+    use rust_decimal_macros::dec;
+
+    fn chain(days: f64) -> OptionChain {
+        let params = OptionChainBuildParams::new(
+            "SPY".to_string(),
+            spos!(1000.0),
+            20,
+            spos!(2.0),
+            dec!(-0.2),
+            dec!(0.1),
+            pos_or_panic!(0.02),
+            2,
+            OptionDataPriceParams::new(
+                Some(Box::new(Positive::HUNDRED)),
+                Some(ExpirationDate::Days(pos_or_panic!(days))),
+                Some(dec!(0.05)),
+                spos!(0.0),
+                Some("SPY".to_string()),
+            ),
+            pos_or_panic!(0.2),
+        );
+        OptionChain::build_chain(&params).unwrap()
+    }
+
+    #[test]
+    fn test_unfiltered_query_returns_every_strike() {
+        let chain = chain(30.0);
+        assert_eq!(
+            chain.select(&ChainQuery::calls()).len(),
+            chain.get_single_iter().count()
+        );
+    }
+
+    #[test]
+    fn test_delta_range_uses_absolute_delta() {
+        let chain = chain(30.0);
+        let puts = chain.select(&ChainQuery::puts().with_delta(dec!(-0.2), dec!(-0.4)));
+        assert!(!puts.is_empty());
+        for option in puts {
+            let delta = option.delta_put.unwrap().abs();
+            assert!(delta >= dec!(0.2) && delta <= dec!(0.4));
+            assert!(option.strike_price < Positive::HUNDRED);
+        }
+    }
+
+    #[test]
+    fn test_delta_range_accepts_signed_put_bounds() {
+        let chain = chain(30.0);
+        let signed = chain.select(&ChainQuery::puts().with_delta(dec!(-0.4), dec!(-0.2)));
+        let absolute = chain.select(&ChainQuery::puts().with_delta(dec!(0.2), dec!(0.4)));
+        assert!(!signed.is_empty());
+        assert_eq!(signed, absolute);
+    }
+
+    #[test]
+    fn test_moneyness_and_limit() {
+        let chain = chain(30.0);
+        let query = ChainQuery::calls()
+            .with_moneyness(dec!(1.0), dec!(1.2))
+            .sort_by_desc(QuerySort::Strike)
+            .with_limit(2);
+        let selected = chain.select(&query);
+        assert_eq!(selected.len(), 2);
+        assert!(selected[0].strike_price > selected[1].strike_price);
+        assert!(selected[0].strike_price <= pos_or_panic!(120.0));
+    }
+
+    #[test]
+    fn test_min_open_interest_excludes_missing_values() {
+        let mut chain = OptionChain::new(
+            "XYZ",
+            Positive::HUNDRED,
+            "2030-01-18".to_string(),
+            None,
+            None,
+        );
+        chain.add_option(
+            Positive::HUNDRED,
+            spos!(5.0),
+            spos!(5.2),
+            spos!(4.0),
+            spos!(4.2),
+            pos_or_panic!(0.2),
+            None,
+            None,
+            None,
+            None,
+            Some(50),
+            None,
+        );
+        chain.add_option(
+            pos_or_panic!(105.0),
+            spos!(3.0),
+            spos!(3.2),
+            spos!(6.0),
+            spos!(6.2),
+            pos_or_panic!(0.2),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let selected = chain.select(&ChainQuery::calls().with_min_open_interest(10));
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].strike_price, Positive::HUNDRED);
+        assert!(
+            chain
+                .select(&ChainQuery::calls().with_delta(dec!(0.0), dec!(1.0)))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_max_spread() {
+        let mut chain = OptionChain::new(
+            "XYZ",
+            Positive::HUNDRED,
+            "2030-01-18".to_string(),
+            None,
+            None,
+        );
+        chain.add_option(
+            Positive::HUNDRED,
+            spos!(5.0),
+            spos!(5.1),
+            spos!(4.0),
+            spos!(4.8),
+            pos_or_panic!(0.2),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(
+            chain
+                .select(&ChainQuery::calls().with_max_spread(pos_or_panic!(0.2)))
+                .len(),
+            1
+        );
+        assert!(
+            chain
+                .select(&ChainQuery::puts().with_max_spread(pos_or_panic!(0.2)))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_select_nearest_strike() {
+        let chain = chain(30.0);
+        let option = chain
+            .select_nearest(
+                &ChainQuery::calls(),
+                QueryTarget::Strike(pos_or_panic!(101.0)),
+            )
+            .unwrap();
+        assert_eq!(option.strike_price, Positive::HUNDRED);
+    }
+
+    #[test]
+    fn test_build_strategy_from_legs() {
+        let chain = chain(30.0);
+        let legs = [
+            LegSelection::new(
+                ChainQuery::calls(),
+                QueryTarget::Delta(dec!(0.5)),
+                Side::Long,
+            ),
+            LegSelection::new(
+                ChainQuery::calls(),
+                QueryTarget::Delta(dec!(0.3)),
+                Side::Short,
+            ),
+        ];
+        let positions = chain.select_legs(&legs).unwrap();
+        assert_eq!(positions.len(), 2);
+        assert!(positions[0].option.strike_price < positions[1].option.strike_price);
+        let strategy: BullCallSpread = chain.build_strategy(&legs).unwrap();
+        assert_eq!(
+            strategy.long_call.option.strike_price,
+            positions[0].option.strike_price
+        );
+    }
+
+    #[test]
+    fn test_unmatched_leg_is_an_error() {
+        let chain = chain(30.0);
+        let legs = [LegSelection::new(
+            ChainQuery::calls().with_implied_volatility(pos_or_panic!(5.0), pos_or_panic!(6.0)),
+            QueryTarget::Delta(dec!(0.5)),
+            Side::Long,
+        )];
+        assert!(matches!(
+            chain.select_legs(&legs),
+            Err(ChainError::StrategyError(
+                StrategyErrorKind::InvalidLegs { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_series_query_filters_by_days_to_expiration() {
+        let mut series = OptionSeries::new("SPY".to_string(), Positive::HUNDRED);
+        for days in [10.0, 40.0, 90.0] {
+            series
+                .chains
+                .insert(ExpirationDate::Days(pos_or_panic!(days)), chain(days));
+        }
+        let query = ChainQuery::calls()
+            .with_days_to_expiration(pos_or_panic!(30.0), pos_or_panic!(60.0))
+            .with_moneyness(dec!(1.0), dec!(1.0));
+        let selected = series.select(&query);
+        assert_eq!(selected.len(), 1);
+        assert_eq!(*selected[0].0, ExpirationDate::Days(pos_or_panic!(40.0)));
+
+        let (expiration, _) = series
+            .select_nearest(
+                &ChainQuery::calls(),
+                QueryTarget::DaysToExpiration(pos_or_panic!(80.0)),
+            )
+            .unwrap();
+        assert_eq!(*expiration, ExpirationDate::Days(pos_or_panic!(90.0)));
+    }
+}
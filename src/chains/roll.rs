@@ -0,0 +1,430 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Chain Roll-Forward
+//!
+//! Projects an [`OptionChain`] to a future valuation date. Rolling a chain
+//! forward advances time by a number of days, moves the underlying by a
+//! relative change and re-reads every strike's implied volatility from the
+//! chain's own smile through a [`StickySmile`] with the chosen
+//! [`SmileDynamics`]. Each `OptionData` is then repriced with Black–Scholes
+//! (mids, and bid/ask keeping the quoted width) and its Greeks are
+//! recalculated, so the market smile survives the projection instead of
+//! being replaced by the synthetic one of `OptionChainBuildParams`.
+//!
+//! The rolled chain expresses its expiration as the days remaining at the
+//! horizon (`ExpirationDate::Days`), which keeps every downstream pricing
+//! call consistent with the projected date. Volume and open interest are
+//! carried over unchanged.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use optionstratlib::chains::ChainRollParameters;
+//! use optionstratlib::chains::chain::OptionChain;
+//! use optionstratlib::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
+//! use optionstratlib::volatility::SmileDynamics;
+//! use optionstratlib::ExpirationDate;
+//! use positive::{pos_or_panic, spos, Positive};
+//! use rust_decimal_macros::dec;
+//!
+//! let params = OptionChainBuildParams::new(
+//!     "SPY".to_string(),
+//!     spos!(1000.0),
+//!     10,
+//!     spos!(5.0),
+//!     dec!(-0.2),
+//!     dec!(0.1),
+//!     pos_or_panic!(0.02),
+//!     2,
+//!     OptionDataPriceParams::new(
+//!         Some(Box::new(Positive::HUNDRED)),
+//!         Some(ExpirationDate::Days(pos_or_panic!(30.0))),
+//!         Some(dec!(0.05)),
+//!         spos!(0.0),
+//!         Some("SPY".to_string()),
+//!     ),
+//!     pos_or_panic!(0.2),
+//! );
+//! let chain = OptionChain::build_chain(&params).unwrap();
+//!
+//! // Ten days later, with spot 5% lower and the smile moving with the spot.
+//! let roll = ChainRollParameters::new(pos_or_panic!(10.0), dec!(-0.05), SmileDynamics::StickyMoneyness);
+//! let rolled = chain.roll_forward(&roll).unwrap();
+//! assert_eq!(rolled.underlying_price, pos_or_panic!(95.0));
+//! let days = |chain: &OptionChain| chain.get_expiration().unwrap().get_days().unwrap().to_f64();
+//! assert!((days(&chain) - days(&rolled) - 10.0).abs() < 1e-6);
+//! ```
+
+use crate::chains::chain::OptionChain;
+use crate::chains::utils::OptionDataPriceParams;
+use crate::error::chains::ChainError;
+use crate::model::ExpirationDate;
+use crate::series::OptionSeries;
+use crate::volatility::{SmileDynamics, StickySmile};
+use positive::Positive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tracing::debug;
+use utoipa::ToSchema;
+
+/// Horizon, spot move and smile regime of a chain roll-forward.
+#[derive(Debug, Clone, Copy, PartialEq, ToSchema, Serialize, Deserialize)]
+pub struct ChainRollParameters {
+    /// Days to advance the valuation date by
+    pub days: Positive,
+    /// Relative change of the underlying, e.g. `-0.05` for a 5% drop
+    pub spot_change: Decimal,
+    /// Rule used to re-read the smile at the new spot and horizon
+    pub dynamics: SmileDynamics,
+    /// Keep each side's quoted bid/ask width around the new mid; when
+    /// `false` bid and ask collapse onto the mid
+    pub keep_spreads: bool,
+}
+
+impl ChainRollParameters {
+    /// Creates roll parameters that keep the quoted bid/ask widths.
+    #[must_use]
+    pub fn new(days: Positive, spot_change: Decimal, dynamics: SmileDynamics) -> Self {
+        Self {
+            days,
+            spot_change,
+            dynamics,
+            keep_spreads: true,
+        }
+    }
+
+    /// Sets whether quoted bid/ask widths are kept.
+    #[must_use]
+    pub fn with_keep_spreads(mut self, keep_spreads: bool) -> Self {
+        self.keep_spreads = keep_spreads;
+        self
+    }
+
+    fn rolled_price(&self, underlying_price: Positive) -> Result<Positive, ChainError> {
+        Positive::new_decimal(underlying_price.to_dec() * (Decimal::ONE + self.spot_change))
+            .map_err(|_| {
+                ChainError::invalid_parameters(
+                    "spot_change",
+                    &format!("{} moves the underlying below zero", self.spot_change),
+                )
+            })
+    }
+}
+
+/// Re-centres a quote of width `ask - bid` on `mid`, flooring the bid at
+/// zero.
+fn requote(
+    mid: Option<Positive>,
+    bid: Option<Positive>,
+    ask: Option<Positive>,
+) -> (Option<Positive>, Option<Positive>) {
+    match (mid, bid, ask) {
+        (Some(mid), Some(bid), Some(ask)) if ask >= bid => {
+            let half_width = (ask.to_dec() - bid.to_dec()) / Decimal::TWO;
+            (Some(mid.sub_or_zero(&half_width)), Some(mid + half_width))
+        }
+        _ => (mid, mid),
+    }
+}
+
+impl OptionChain {
+    /// Projects the chain `params.days` into the future with the underlying
+    /// moved by `params.spot_change`, re-reading implied volatilities from
+    /// the current smile under `params.dynamics` and repricing every option.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChainError::ChainBuildError` if the chain has no valid
+    /// expiration, the horizon reaches or passes it, or the spot change
+    /// takes the underlying below zero. Returns `ChainError::Volatility`
+    /// if the chain has no usable smile.
+    pub fn roll_forward(&self, params: &ChainRollParameters) -> Result<OptionChain, ChainError> {
+        let expiration = self.get_expiration().ok_or_else(|| {
+            ChainError::invalid_parameters("expiration_date", "chain has no valid expiration")
+        })?;
+        let days_to_expiration = expiration.get_days()?;
+        if params.days >= days_to_expiration {
+            return Err(ChainError::invalid_parameters(
+                "days",
+                &format!(
+                    "roll of {} days reaches the expiration in {} days",
+                    params.days, days_to_expiration
+                ),
+            ));
+        }
+        let remaining = days_to_expiration - params.days;
+        let rolled_expiration = ExpirationDate::Days(remaining);
+        let years = rolled_expiration.get_years()?;
+        let underlying_price = params.rolled_price(self.underlying_price)?;
+        let smile = StickySmile::from_chain(self, params.dynamics)?;
+
+        let mut rolled = OptionChain::new(
+            &self.symbol,
+            underlying_price,
+            remaining.to_string(),
+            self.risk_free_rate,
+            self.dividend_yield,
+        );
+        let price_params = OptionDataPriceParams::new(
+            Some(Box::new(underlying_price)),
+            Some(rolled_expiration),
+            self.risk_free_rate,
+            self.dividend_yield,
+            Some(self.symbol.clone()),
+        );
+
+        for option in self.get_single_iter() {
+            let mut rolled_option = option.clone();
+            rolled_option.set_extra_params(price_params.clone());
+            rolled_option.implied_volatility =
+                smile.volatility_at(option.strike_price, underlying_price, years)?;
+            if let Err(e) = rolled_option.calculate_prices(None) {
+                debug!(
+                    "roll_forward: dropping strike {}: {}",
+                    option.strike_price, e
+                );
+                continue;
+            }
+            if params.keep_spreads {
+                (rolled_option.call_bid, rolled_option.call_ask) =
+                    requote(rolled_option.call_middle, option.call_bid, option.call_ask);
+                (rolled_option.put_bid, rolled_option.put_ask) =
+                    requote(rolled_option.put_middle, option.put_bid, option.put_ask);
+            }
+            rolled_option.calculate_greeks();
+            rolled.options.insert(rolled_option);
+        }
+
+        Ok(rolled)
+    }
+}
+
+impl OptionSeries {
+    /// Rolls every chain of the series forward with the same parameters.
+    ///
+    /// Chains that expire on or before the horizon are dropped; the
+    /// remaining ones are keyed by their days to expiration at the horizon.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`OptionChain::roll_forward`] for any chain
+    /// that survives the horizon.
+    pub fn roll_forward(&self, params: &ChainRollParameters) -> Result<OptionSeries, ChainError> {
+        let underlying_price = params.rolled_price(self.underlying_price)?;
+        let mut chains = BTreeMap::new();
+        for (expiration, chain) in &self.chains {
+            if expiration.get_days()? <= params.days {
+                debug!("roll_forward: dropping expiration {expiration}");
+                continue;
+            }
+            let rolled = chain.roll_forward(params)?;
+            if let Some(rolled_expiration) = rolled.get_expiration() {
+                chains.insert(rolled_expiration, rolled);
+            }
+        }
+        Ok(OptionSeries {
+            symbol: self.symbol.clone(),
+            underlying_price,
+            chains,
+            risk_free_rate: self.risk_free_rate,
+            dividend_yield: self.dividend_yield,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests_chain_roll {
+    use super::*;
+    use crate::chains::utils::OptionChainBuildParams;
+    use positive::{pos_or_panic, spos};
+    use rust_decimal_macros::dec;
+
+    fn chain(days: f64) -> OptionChain {
+        let params = OptionChainBuildParams::new(
+            "SPY".to_string(),
+            spos!(1000.0),
+            10,
+            spos!(5.0),
+            dec!(-0.2),
+            dec!(0.1),
+            pos_or_panic!(0.02),
+            2,
+            OptionDataPriceParams::new(
+                Some(Box::new(Positive::HUNDRED)),
+                Some(ExpirationDate::Days(pos_or_panic!(days))),
+                Some(dec!(0.05)),
+                spos!(0.0),
+                Some("SPY".to_string()),
+            ),
+            pos_or_panic!(0.2),
+        );
+        OptionChain::build_chain(&params).unwrap()
+    }
+
+    #[test]
+    fn test_roll_without_move_decays_prices() {
+        let chain = chain(30.0);
+        let rolled = chain
+            .roll_forward(&ChainRollParameters::new(
+                pos_or_panic!(10.0),
+                Decimal::ZERO,
+                SmileDynamics::StickyStrike,
+            ))
+            .unwrap();
+        assert_eq!(rolled.underlying_price, Positive::HUNDRED);
+        assert_eq!(
+            rolled.get_single_iter().count(),
+            chain.get_single_iter().count()
+        );
+        for (before, after) in chain.get_single_iter().zip(rolled.get_single_iter()) {
+            assert_eq!(before.strike_price, after.strike_price);
+            assert_eq!(before.implied_volatility, after.implied_volatility);
+            assert!(
+                after.call_middle.unwrap() <= before.call_middle.unwrap() + pos_or_panic!(0.01)
+            );
+            assert!(after.delta_call.is_some());
+        }
+    }
+
+    #[test]
+    fn test_roll_reprices_side_greeks() {
+        let chain = chain(30.0);
+        let rolled = chain
+            .roll_forward(&ChainRollParameters::new(
+                pos_or_panic!(20.0),
+                Decimal::ZERO,
+                SmileDynamics::StickyStrike,
+            ))
+            .unwrap();
+        assert!(
+            rolled
+                .get_single_iter()
+                .all(|o| o.call_greeks.is_some() && o.put_greeks.is_some())
+        );
+        let before = chain.vega_exposure().unwrap();
+        let after = rolled.vega_exposure().unwrap();
+        assert!(after < before);
+
+        let mut refreshed = rolled.clone();
+        refreshed.update_greeks();
+        assert_eq!(refreshed.vega_exposure().unwrap(), after);
+    }
+
+    #[test]
+    fn test_sticky_strike_keeps_smile_and_moneyness_shifts_it() {
+        let chain = chain(30.0);
+        let strike = Positive::HUNDRED;
+        let original = chain
+            .get_single_iter()
+            .find(|o| o.strike_price == strike)
+            .unwrap()
+            .implied_volatility;
+
+        let roll =
+            ChainRollParameters::new(pos_or_panic!(5.0), dec!(-0.05), SmileDynamics::StickyStrike);
+        let sticky_strike = chain.roll_forward(&roll).unwrap();
+        let option = sticky_strike
+            .get_single_iter()
+            .find(|o| o.strike_price == strike)
+            .unwrap();
+        assert_eq!(option.implied_volatility, original);
+
+        let sticky_moneyness = chain
+            .roll_forward(&ChainRollParameters {
+                dynamics: SmileDynamics::StickyMoneyness,
+                ..roll
+            })
+            .unwrap();
+        let option = sticky_moneyness
+            .get_single_iter()
+            .find(|o| o.strike_price == strike)
+            .unwrap();
+        // The 100 strike now sits above spot, where the put skew is lower.
+        assert!(option.implied_volatility < original);
+    }
+
+    #[test]
+    fn test_keep_spreads_preserves_quoted_width() {
+        let chain = chain(30.0);
+        let roll =
+            ChainRollParameters::new(pos_or_panic!(5.0), dec!(0.02), SmileDynamics::StickyStrike);
+        let before = chain
+            .get_single_iter()
+            .find(|o| o.strike_price == Positive::HUNDRED)
+            .unwrap();
+        let after = chain
+            .roll_forward(&roll)
+            .unwrap()
+            .get_single_iter()
+            .find(|o| o.strike_price == Positive::HUNDRED)
+            .cloned()
+            .unwrap();
+        let width = |bid: Option<Positive>, ask: Option<Positive>| {
+            ask.unwrap().to_dec() - bid.unwrap().to_dec()
+        };
+        assert_eq!(
+            width(after.call_bid, after.call_ask),
+            width(before.call_bid, before.call_ask)
+        );
+
+        let collapsed = chain
+            .roll_forward(&roll.with_keep_spreads(false))
+            .unwrap()
+            .get_single_iter()
+            .find(|o| o.strike_price == Positive::HUNDRED)
+            .cloned()
+            .unwrap();
+        assert_eq!(collapsed.call_bid, collapsed.call_ask);
+    }
+
+    #[test]
+    fn test_roll_past_expiration_fails() {
+        let chain = chain(30.0);
+        let roll = ChainRollParameters::new(
+            pos_or_panic!(31.0),
+            Decimal::ZERO,
+            SmileDynamics::StickyStrike,
+        );
+        assert!(chain.roll_forward(&roll).is_err());
+        let roll =
+            ChainRollParameters::new(pos_or_panic!(1.0), dec!(-1.5), SmileDynamics::StickyStrike);
+        assert!(chain.roll_forward(&roll).is_err());
+    }
+
+    #[test]
+    fn test_series_roll_drops_expired_chains() {
+        let mut series = OptionSeries::new("SPY".to_string(), Positive::HUNDRED);
+        for days in [7.0, 30.0, 60.0] {
+            series
+                .chains
+                .insert(ExpirationDate::Days(pos_or_panic!(days)), chain(days));
+        }
+        let roll = ChainRollParameters::new(
+            pos_or_panic!(10.0),
+            dec!(0.1),
+            SmileDynamics::StickyMoneyness,
+        );
+        let rolled = series.roll_forward(&roll).unwrap();
+        assert_eq!(rolled.underlying_price, pos_or_panic!(110.0));
+        let days: Vec<f64> = rolled
+            .chains
+            .keys()
+            .map(|expiration| expiration.get_days().unwrap().to_f64())
+            .collect();
+        let expected: Vec<f64> = series
+            .chains
+            .values()
+            .skip(1)
+            .map(|chain| chain.get_expiration().unwrap().get_days().unwrap().to_f64() - 10.0)
+            .collect();
+        assert_eq!(days.len(), 2);
+        for (rolled, expected) in days.iter().zip(expected) {
+            assert!((rolled - expected).abs() < 1e-6);
+        }
+    }
+}
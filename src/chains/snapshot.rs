@@ -0,0 +1,30 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+use crate::chains::chain::OptionChain;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// An [`OptionChain`] observed at a point in time.
+///
+/// Snapshots are the unit of historical chain data: one chain per symbol,
+/// expiration and observation timestamp.
+#[derive(Debug, Clone, ToSchema, Serialize, Deserialize)]
+pub struct ChainSnapshot {
+    /// Time at which the chain was observed
+    pub timestamp: DateTime<Utc>,
+    /// Chain quotes at `timestamp`
+    pub chain: OptionChain,
+}
+
+impl ChainSnapshot {
+    /// Creates a snapshot of `chain` taken at `timestamp`.
+    #[must_use]
+    pub fn new(timestamp: DateTime<Utc>, chain: OptionChain) -> Self {
+        Self { timestamp, chain }
+    }
+}
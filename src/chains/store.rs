@@ -0,0 +1,626 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Chain Snapshot Store
+//!
+//! A directory-backed store of [`ChainSnapshot`]s indexed by symbol,
+//! snapshot timestamp and expiration. It is the data layer for historical
+//! analysis and backtests, replacing ad-hoc reloading of chain files by
+//! filename convention.
+//!
+//! ## Layout
+//!
+//! ```text
+//! root/
+//! ├── index.jsonl                              # one SnapshotIndexEntry per line
+//! └── SPY/
+//!     ├── 20261018T200000.000000000Z_2026-12-18.json
+//!     └── ...
+//! ```
+//!
+//! Each chain is serialized as JSON in its own file, named after the
+//! nanosecond timestamp and the expiration. Characters outside
+//! `[A-Za-z0-9-]` in the symbol and expiration are escaped as `~XX` bytes,
+//! so distinct keys never share a file. The index is an append-only log:
+//! every insertion appends one line, and a later line for the same key
+//! replaces earlier ones when the store is reopened, so a store can be
+//! reopened without scanning the directory. Chains are only read from disk
+//! when a query returns them.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use chrono::{Duration, Utc};
+//! use optionstratlib::chains::{ChainSnapshot, ChainSnapshotStore, OptionChain};
+//! use positive::{pos_or_panic, Positive};
+//!
+//! let mut store = ChainSnapshotStore::open("data/chains").unwrap();
+//! let chain = OptionChain::new("SPY", Positive::HUNDRED, "2030-01-18".to_string(), None, None);
+//! store.insert(&ChainSnapshot::new(Utc::now(), chain)).unwrap();
+//!
+//! let latest = store.as_of("SPY", "2030-01-18", Utc::now()).unwrap();
+//! let monthly = store.nearest_expiry("SPY", Utc::now(), pos_or_panic!(30.0)).unwrap();
+//! for snapshot in store.range("SPY", None, Utc::now() - Duration::days(7), Utc::now()) {
+//!     let snapshot = snapshot.unwrap();
+//! }
+//! ```
+
+use crate::chains::chain::OptionChain;
+use crate::chains::snapshot::ChainSnapshot;
+use crate::error::ChainError;
+use crate::model::ExpirationDate;
+use chrono::{DateTime, Duration, Utc};
+use positive::Positive;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::ops::Bound;
+use std::path::{Path, PathBuf};
+use utoipa::ToSchema;
+
+const INDEX_FILE: &str = "index.jsonl";
+
+/// Index record of a chain stored in a [`ChainSnapshotStore`].
+#[derive(Debug, Clone, PartialEq, Eq, ToSchema, Serialize, Deserialize)]
+pub struct SnapshotIndexEntry {
+    /// Underlying symbol
+    pub symbol: String,
+    /// Time at which the chain was observed
+    pub timestamp: DateTime<Utc>,
+    /// Expiration string of the chain, as returned by
+    /// [`OptionChain::get_expiration_date`]
+    pub expiration: String,
+    /// Absolute expiration time, resolved relative to `timestamp` for
+    /// chains quoted in days to expiry
+    pub expires_at: DateTime<Utc>,
+    /// Path of the chain file, relative to the store root
+    pub file: String,
+}
+
+impl SnapshotIndexEntry {
+    /// Days from the snapshot to expiration, floored at zero.
+    #[must_use]
+    pub fn days_to_expiry(&self) -> Positive {
+        let seconds = (self.expires_at - self.timestamp).num_seconds().max(0);
+        Positive::new(seconds as f64 / 86_400.0).unwrap_or(Positive::ZERO)
+    }
+}
+
+type IndexKey = (String, DateTime<Utc>, String);
+
+/// Directory-backed store of option chain snapshots.
+#[derive(Debug, Clone)]
+pub struct ChainSnapshotStore {
+    root: PathBuf,
+    index: BTreeMap<IndexKey, SnapshotIndexEntry>,
+}
+
+impl ChainSnapshotStore {
+    /// Opens the store rooted at `root`, creating the directory if needed
+    /// and loading its index.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChainError::FileError`] when the directory cannot be
+    /// created or the index cannot be read or parsed. A truncated last
+    /// line, left by an interrupted insertion, is dropped from the index
+    /// file so later insertions start on a fresh line.
+    pub fn open(root: impl AsRef<Path>) -> Result<Self, ChainError> {
+        let root = root.as_ref().to_path_buf();
+        fs::create_dir_all(&root)?;
+        let index_path = root.join(INDEX_FILE);
+        let content = if index_path.exists() {
+            fs::read_to_string(&index_path)?
+        } else {
+            String::new()
+        };
+        let (complete, tail) = content.split_at(content.rfind('\n').map_or(0, |i| i + 1));
+        let mut entries = complete
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<SnapshotIndexEntry>, _>>()?;
+        if !tail.trim().is_empty() {
+            let mut file = File::options().append(true).open(&index_path)?;
+            match serde_json::from_str(tail) {
+                Ok(entry) => {
+                    file.write_all(b"\n")?;
+                    entries.push(entry);
+                }
+                Err(_) => file.set_len(complete.len() as u64)?,
+            }
+        }
+        let index = entries
+            .into_iter()
+            .map(|entry| {
+                (
+                    key(&entry.symbol, entry.timestamp, &entry.expiration),
+                    entry,
+                )
+            })
+            .collect();
+        Ok(Self { root, index })
+    }
+
+    /// Root directory of the store.
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Number of stored chains.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Returns `true` when the store holds no chain.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// Index entries ordered by symbol, timestamp and expiration.
+    pub fn entries(&self) -> impl Iterator<Item = &SnapshotIndexEntry> {
+        self.index.values()
+    }
+
+    /// Distinct symbols in the store.
+    #[must_use]
+    pub fn symbols(&self) -> Vec<&str> {
+        let mut symbols: Vec<&str> = self.index.keys().map(|(s, _, _)| s.as_str()).collect();
+        symbols.dedup();
+        symbols
+    }
+
+    /// Stores a snapshot, replacing any chain with the same symbol,
+    /// timestamp and expiration, and appends its entry to the index.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChainError::FileError`] when the chain or the index cannot
+    /// be written. File names are unique per key, so a file left at the same
+    /// path by an interrupted insertion is replaced.
+    pub fn insert(&mut self, snapshot: &ChainSnapshot) -> Result<(), ChainError> {
+        let chain = &snapshot.chain;
+        let expiration = chain.get_expiration_date();
+        let expires_at = match chain.get_expiration() {
+            Some(ExpirationDate::Days(days)) => {
+                snapshot.timestamp + Duration::seconds((days.to_f64() * 86_400.0) as i64)
+            }
+            Some(date) => date.get_date()?,
+            None => {
+                return Err(ChainError::invalid_parameters(
+                    "expiration_date",
+                    &format!("invalid chain expiration {expiration}"),
+                ));
+            }
+        };
+        let file = snapshot_file(&chain.symbol, snapshot.timestamp, &expiration);
+        let path = self.root.join(&file);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp = path.with_extension("json.tmp");
+        let mut writer = BufWriter::new(File::create(&tmp)?);
+        serde_json::to_writer(&mut writer, chain)?;
+        writer.flush()?;
+        drop(writer);
+        fs::rename(tmp, &path)?;
+
+        let entry = SnapshotIndexEntry {
+            symbol: chain.symbol.clone(),
+            timestamp: snapshot.timestamp,
+            expiration,
+            expires_at,
+            file,
+        };
+        self.append_index(&entry)?;
+        self.index.insert(
+            key(&entry.symbol, entry.timestamp, &entry.expiration),
+            entry,
+        );
+        Ok(())
+    }
+
+    /// Loads the chain referenced by an index entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChainError::FileError`] when the chain file cannot be read
+    /// or parsed.
+    pub fn load(&self, entry: &SnapshotIndexEntry) -> Result<ChainSnapshot, ChainError> {
+        let chain: OptionChain = serde_json::from_reader(File::open(self.root.join(&entry.file))?)?;
+        Ok(ChainSnapshot::new(entry.timestamp, chain))
+    }
+
+    /// Latest snapshot of `symbol` for `expiration` taken at or before `at`.
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors from [`ChainSnapshotStore::load`].
+    pub fn as_of(
+        &self,
+        symbol: &str,
+        expiration: &str,
+        at: DateTime<Utc>,
+    ) -> Result<Option<ChainSnapshot>, ChainError> {
+        self.entries_until(symbol, at)
+            .rev()
+            .find(|entry| entry.expiration == expiration)
+            .map(|entry| self.load(entry))
+            .transpose()
+    }
+
+    /// Expirations of `symbol` available in its latest snapshot at or
+    /// before `at`, ordered by expiry.
+    #[must_use]
+    pub fn expirations_as_of(&self, symbol: &str, at: DateTime<Utc>) -> Vec<&SnapshotIndexEntry> {
+        let Some(latest) = self
+            .entries_until(symbol, at)
+            .next_back()
+            .map(|e| e.timestamp)
+        else {
+            return Vec::new();
+        };
+        let mut entries: Vec<&SnapshotIndexEntry> = self
+            .entries_until(symbol, at)
+            .rev()
+            .take_while(|entry| entry.timestamp == latest)
+            .collect();
+        entries.sort_by_key(|entry| entry.expires_at);
+        entries
+    }
+
+    /// Snapshot, from the latest observation of `symbol` at or before `at`,
+    /// whose days to expiry are closest to `target_days`.
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors from [`ChainSnapshotStore::load`].
+    pub fn nearest_expiry(
+        &self,
+        symbol: &str,
+        at: DateTime<Utc>,
+        target_days: Positive,
+    ) -> Result<Option<ChainSnapshot>, ChainError> {
+        self.expirations_as_of(symbol, at)
+            .into_iter()
+            .min_by_key(|entry| {
+                let days = entry.days_to_expiry().to_dec();
+                (days - target_days.to_dec()).abs()
+            })
+            .map(|entry| self.load(entry))
+            .transpose()
+    }
+
+    /// Snapshots of `symbol` taken between `start` and `end` inclusive,
+    /// optionally restricted to one expiration, in timestamp order. Chains
+    /// are loaded lazily as the iterator advances.
+    pub fn range<'a>(
+        &'a self,
+        symbol: &'a str,
+        expiration: Option<&'a str>,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> impl Iterator<Item = Result<ChainSnapshot, ChainError>> + 'a {
+        self.index
+            .range((Bound::Included(key(symbol, start, "")), Bound::Unbounded))
+            .map(|(_, entry)| entry)
+            .take_while(move |entry| entry.symbol == symbol && entry.timestamp <= end)
+            .filter(move |entry| expiration.is_none_or(|e| entry.expiration == e))
+            .map(|entry| self.load(entry))
+    }
+
+    fn entries_until<'a>(
+        &'a self,
+        symbol: &str,
+        at: DateTime<Utc>,
+    ) -> impl DoubleEndedIterator<Item = &'a SnapshotIndexEntry> + 'a {
+        self.index
+            .range((
+                Bound::Included(key(symbol, DateTime::<Utc>::MIN_UTC, "")),
+                Bound::Included(key(symbol, at, LAST_EXPIRATION)),
+            ))
+            .map(|(_, entry)| entry)
+    }
+
+    fn append_index(&self, entry: &SnapshotIndexEntry) -> Result<(), ChainError> {
+        let mut line = serde_json::to_string(entry)?;
+        line.push('\n');
+        File::options()
+            .create(true)
+            .append(true)
+            .open(self.root.join(INDEX_FILE))?
+            .write_all(line.as_bytes())?;
+        Ok(())
+    }
+}
+
+/// Sorts after every expiration string of an index key at the same
+/// timestamp, bounding "at or before" range queries.
+const LAST_EXPIRATION: &str = "\u{10FFFF}";
+
+fn key(symbol: &str, timestamp: DateTime<Utc>, expiration: &str) -> IndexKey {
+    (symbol.to_string(), timestamp, expiration.to_string())
+}
+
+/// Path of a chain file relative to the store root.
+fn snapshot_file(symbol: &str, timestamp: DateTime<Utc>, expiration: &str) -> String {
+    format!(
+        "{}/{}_{}.json",
+        escape(symbol),
+        timestamp.format("%Y%m%dT%H%M%S%.9fZ"),
+        escape(expiration)
+    )
+}
+
+/// Escapes every byte outside `[A-Za-z0-9-]` as `~XX`, keeping the
+/// mapping injective.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || byte == b'-' {
+            escaped.push(byte as char);
+        } else {
+            escaped.push_str(&format!("~{byte:02X}"));
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests_store {
+    use super::*;
+    use chrono::TimeZone;
+    use positive::{pos_or_panic, spos};
+    use tempfile::tempdir;
+
+    fn day(d: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, d, 20, 0, 0).unwrap()
+    }
+
+    fn chain(symbol: &str, expiration: &str, price: Positive) -> OptionChain {
+        let mut chain = OptionChain::new(symbol, price, expiration.to_string(), None, None);
+        chain.add_option(
+            price,
+            spos!(1.0),
+            spos!(1.1),
+            spos!(1.0),
+            spos!(1.1),
+            pos_or_panic!(0.2),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        chain
+    }
+
+    fn populated(root: &Path) -> ChainSnapshotStore {
+        let mut store = ChainSnapshotStore::open(root).unwrap();
+        for (d, price) in [(1, 100.0), (2, 101.0), (3, 102.0)] {
+            let price = pos_or_panic!(price);
+            for expiration in ["2026-10-16", "2026-11-20", "2026-12-18"] {
+                let snapshot = ChainSnapshot::new(day(d), chain("SPY", expiration, price));
+                store.insert(&snapshot).unwrap();
+            }
+        }
+        let qqq = ChainSnapshot::new(day(2), chain("QQQ", "2026-11-20", pos_or_panic!(400.0)));
+        store.insert(&qqq).unwrap();
+        store
+    }
+
+    #[test]
+    fn test_insert_and_reopen() {
+        let dir = tempdir().unwrap();
+        let store = populated(dir.path());
+        assert_eq!(store.len(), 10);
+        assert_eq!(store.symbols(), vec!["QQQ", "SPY"]);
+
+        let reopened = ChainSnapshotStore::open(dir.path()).unwrap();
+        assert_eq!(reopened.len(), 10);
+        assert_eq!(
+            reopened.entries().collect::<Vec<_>>(),
+            store.entries().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_as_of() {
+        let dir = tempdir().unwrap();
+        let store = populated(dir.path());
+        let at = day(2) + Duration::hours(5);
+        let snapshot = store.as_of("SPY", "2026-11-20", at).unwrap().unwrap();
+        assert_eq!(snapshot.timestamp, day(2));
+        assert_eq!(snapshot.chain.underlying_price, pos_or_panic!(101.0));
+        assert_eq!(snapshot.chain.options.len(), 1);
+
+        let exact = store.as_of("SPY", "2026-11-20", day(3)).unwrap().unwrap();
+        assert_eq!(exact.timestamp, day(3));
+        assert!(
+            store
+                .as_of("SPY", "2026-11-20", day(1) - Duration::seconds(1))
+                .unwrap()
+                .is_none()
+        );
+        assert!(store.as_of("IWM", "2026-11-20", day(3)).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_range() {
+        let dir = tempdir().unwrap();
+        let store = populated(dir.path());
+        let all: Vec<ChainSnapshot> = store
+            .range("SPY", None, day(2), day(3))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(all.len(), 6);
+        assert!(all.windows(2).all(|w| w[0].timestamp <= w[1].timestamp));
+
+        let december: Vec<ChainSnapshot> = store
+            .range("SPY", Some("2026-12-18"), day(1), day(3))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(december.len(), 3);
+        assert!(
+            december
+                .iter()
+                .all(|s| s.chain.get_expiration_date() == "2026-12-18")
+        );
+        assert_eq!(store.range("QQQ", None, day(3), day(4)).count(), 0);
+    }
+
+    #[test]
+    fn test_nearest_expiry() {
+        let dir = tempdir().unwrap();
+        let store = populated(dir.path());
+        let expirations = store.expirations_as_of("SPY", day(2));
+        assert_eq!(expirations.len(), 3);
+        assert_eq!(expirations[0].expiration, "2026-10-16");
+
+        let snapshot = store
+            .nearest_expiry("SPY", day(2), pos_or_panic!(45.0))
+            .unwrap()
+            .unwrap();
+        assert_eq!(snapshot.timestamp, day(2));
+        assert_eq!(snapshot.chain.get_expiration_date(), "2026-11-20");
+
+        let front = store
+            .nearest_expiry("SPY", day(2), Positive::ZERO)
+            .unwrap()
+            .unwrap();
+        assert_eq!(front.chain.get_expiration_date(), "2026-10-16");
+        assert!(
+            store
+                .nearest_expiry("SPY", day(1) - Duration::days(1), Positive::ONE)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_replace_same_key() {
+        let dir = tempdir().unwrap();
+        let mut store = ChainSnapshotStore::open(dir.path()).unwrap();
+        let first = ChainSnapshot::new(day(1), chain("SPY", "2026-11-20", Positive::HUNDRED));
+        let second = ChainSnapshot::new(day(1), chain("SPY", "2026-11-20", pos_or_panic!(105.0)));
+        store.insert(&first).unwrap();
+        store.insert(&second).unwrap();
+        assert_eq!(store.len(), 1);
+        let loaded = store.as_of("SPY", "2026-11-20", day(1)).unwrap().unwrap();
+        assert_eq!(loaded.chain.underlying_price, pos_or_panic!(105.0));
+    }
+
+    #[test]
+    fn test_distinct_keys_never_share_a_file() {
+        let dir = tempdir().unwrap();
+        let mut store = ChainSnapshotStore::open(dir.path()).unwrap();
+        let early = day(1) + Duration::microseconds(100);
+        let late = day(1) + Duration::microseconds(600);
+        for (at, symbol, price) in [
+            (early, "BRK.B", 100.0),
+            (late, "BRK.B", 101.0),
+            (early, "BRK-B", 102.0),
+        ] {
+            let snapshot =
+                ChainSnapshot::new(at, chain(symbol, "2026-11-20", pos_or_panic!(price)));
+            store.insert(&snapshot).unwrap();
+        }
+        assert_eq!(store.len(), 3);
+
+        let reopened = ChainSnapshotStore::open(dir.path()).unwrap();
+        let first = reopened
+            .as_of("BRK.B", "2026-11-20", early)
+            .unwrap()
+            .unwrap();
+        assert_eq!(first.chain.underlying_price, Positive::HUNDRED);
+        let second = reopened
+            .as_of("BRK.B", "2026-11-20", late)
+            .unwrap()
+            .unwrap();
+        assert_eq!(second.chain.underlying_price, pos_or_panic!(101.0));
+        let other = reopened
+            .as_of("BRK-B", "2026-11-20", late)
+            .unwrap()
+            .unwrap();
+        assert_eq!(other.chain.underlying_price, pos_or_panic!(102.0));
+    }
+
+    #[test]
+    fn test_insert_replaces_orphaned_file() {
+        let dir = tempdir().unwrap();
+        let mut store = ChainSnapshotStore::open(dir.path()).unwrap();
+        let file = dir.path().join(snapshot_file("SPY", day(1), "2026-11-20"));
+        fs::create_dir_all(file.parent().unwrap()).unwrap();
+        fs::write(&file, "orphan").unwrap();
+
+        let snapshot = ChainSnapshot::new(day(1), chain("SPY", "2026-11-20", Positive::HUNDRED));
+        store.insert(&snapshot).unwrap();
+        assert_eq!(store.len(), 1);
+        let loaded = store.as_of("SPY", "2026-11-20", day(1)).unwrap().unwrap();
+        assert_eq!(loaded.chain.underlying_price, Positive::HUNDRED);
+    }
+
+    #[test]
+    fn test_as_of_bounds_at_exact_timestamp() {
+        let dir = tempdir().unwrap();
+        let mut store = ChainSnapshotStore::open(dir.path()).unwrap();
+        let later = day(1) + Duration::microseconds(500);
+        for (at, price) in [(day(1), 100.0), (later, 101.0)] {
+            let snapshot = ChainSnapshot::new(at, chain("SPY", "2026-11-20", pos_or_panic!(price)));
+            store.insert(&snapshot).unwrap();
+        }
+        let at = store.as_of("SPY", "2026-11-20", day(1)).unwrap().unwrap();
+        assert_eq!(at.timestamp, day(1));
+        assert_eq!(store.expirations_as_of("SPY", day(1)).len(), 1);
+
+        let end = store
+            .as_of("SPY", "2026-11-20", DateTime::<Utc>::MAX_UTC)
+            .unwrap()
+            .unwrap();
+        assert_eq!(end.timestamp, later);
+    }
+
+    #[test]
+    fn test_index_appends_and_tolerates_truncated_tail() {
+        let dir = tempdir().unwrap();
+        let store = populated(dir.path());
+        let index = dir.path().join(INDEX_FILE);
+        let lines = fs::read_to_string(&index).unwrap().lines().count();
+        assert_eq!(lines, store.len());
+
+        let mut file = File::options().append(true).open(&index).unwrap();
+        file.write_all(b"{\"symbol\":\"SPY\",\"times").unwrap();
+        let mut reopened = ChainSnapshotStore::open(dir.path()).unwrap();
+        assert_eq!(reopened.len(), store.len());
+
+        let snapshot = ChainSnapshot::new(day(4), chain("SPY", "2026-12-18", Positive::HUNDRED));
+        reopened.insert(&snapshot).unwrap();
+        let again = ChainSnapshotStore::open(dir.path()).unwrap();
+        assert_eq!(again.len(), store.len() + 1);
+        assert!(again.as_of("SPY", "2026-12-18", day(4)).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_index_keeps_complete_tail_without_newline() {
+        let dir = tempdir().unwrap();
+        let store = populated(dir.path());
+        let index = dir.path().join(INDEX_FILE);
+        let content = fs::read_to_string(&index).unwrap();
+        fs::write(&index, content.trim_end()).unwrap();
+
+        let mut reopened = ChainSnapshotStore::open(dir.path()).unwrap();
+        assert_eq!(reopened.len(), store.len());
+        let snapshot = ChainSnapshot::new(day(4), chain("SPY", "2026-12-18", Positive::HUNDRED));
+        reopened.insert(&snapshot).unwrap();
+        let again = ChainSnapshotStore::open(dir.path()).unwrap();
+        assert_eq!(again.len(), store.len() + 1);
+    }
+}
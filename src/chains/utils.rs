@@ -7,8 +7,8 @@ use positive::Positive;
 #[cfg(test)]
 use positive::pos_or_panic;
 
-use crate::chains::OptionData;
 use crate::chains::chain::{SKEW_SLOPE, SKEW_SMILE_CURVE};
+use crate::chains::{OptionData, SideGreeks};
 use crate::error::chains::ChainError;
 use crate::model::ExpirationDate;
 use crate::model::utils::ToRound;
@@ -644,6 +644,34 @@ pub(crate) fn default_empty_string<T: ToString>(input: Option<T>) -> String {
     input.map_or_else(|| "".to_string(), |v| v.to_string())
 }
 
+/// CSV columns of a side's Greeks after delta: gamma, vega, theta, rho,
+/// vanna and charm. Empty strings when the side has no Greeks.
+pub(crate) fn side_greeks_columns(greeks: Option<SideGreeks>) -> [String; 6] {
+    match greeks {
+        Some(g) => [g.gamma, g.vega, g.theta, g.rho, g.vanna, g.charm].map(|v| v.to_string()),
+        None => Default::default(),
+    }
+}
+
+/// Reads the six columns written by [`side_greeks_columns`] starting at
+/// `start`. Returns `None` unless the delta and every column are present.
+pub(crate) fn parse_side_greeks(
+    record: &csv::StringRecord,
+    start: usize,
+    delta: Option<Decimal>,
+) -> Option<SideGreeks> {
+    let column = |offset: usize| parse::<Decimal>(record.get(start + offset)?);
+    Some(SideGreeks {
+        delta: delta?,
+        gamma: column(0)?,
+        vega: column(1)?,
+        theta: column(2)?,
+        rho: column(3)?,
+        vanna: column(4)?,
+        charm: column(5)?,
+    })
+}
+
 pub(crate) fn rounder(reference_price: Positive, strike_interval: Positive) -> Positive {
     if strike_interval == Positive::ZERO {
         return reference_price;
@@ -661,6 +661,26 @@ impl From<csv::Error> for ChainError {
     }
 }
 
+#[cfg(feature = "parquet")]
+impl From<parquet::errors::ParquetError> for ChainError {
+    fn from(err: parquet::errors::ParquetError) -> Self {
+        ChainError::FileError(FileErrorKind::InvalidFormat {
+            format: "Parquet".to_string(),
+            reason: err.to_string(),
+        })
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl From<arrow_schema::ArrowError> for ChainError {
+    fn from(err: arrow_schema::ArrowError) -> Self {
+        ChainError::FileError(FileErrorKind::InvalidFormat {
+            format: "Arrow".to_string(),
+            reason: err.to_string(),
+        })
+    }
+}
+
 impl From<serde_json::Error> for ChainError {
     fn from(err: serde_json::Error) -> Self {
         ChainError::FileError(FileErrorKind::ParseError {
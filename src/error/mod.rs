@@ -18,6 +18,7 @@
 //!
 //! ### Trading and Analysis
 //! * `ChainError` - Option chain operations and data management
+//! * `OsiError` - OCC/OSI option symbol parsing and grouping
 //! * `TradeError` - Trade position opening and status management
 //! * `PositionError` - Position management and trading operations
 //! * `StrategyError` - Trading strategy validation and execution
@@ -224,6 +225,12 @@ mod volatility;
 /// * Date and decimal parsing issues
 mod csv;
 
+/// ### Option Symbol Errors (`OsiError`)
+/// Handles:
+/// * OCC/OSI and vendor symbol parsing failures
+/// * Grouping symbols into chains and series
+mod osi;
+
 /// ### Unified Error Type
 /// Top-level error type that encompasses all errors in the library.
 /// Provides a single error type for unified error handling across modules.
@@ -239,6 +246,7 @@ pub use greeks::GreeksError;
 pub use interpolation::InterpolationError;
 pub use metrics::MetricsError;
 pub use options::{OptionsError, OptionsResult};
+pub use osi::OsiError;
 pub use position::PositionError;
 pub use pricing::{PricingError, PricingResult};
 pub use probability::ProbabilityError;
@@ -0,0 +1,108 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! Error types for OCC/OSI option symbol parsing and formatting.
+
+use thiserror::Error;
+
+/// Error type for OCC/OSI option symbol operations
+///
+/// # Variants
+///
+/// * `InvalidFormat` - The symbol does not match any supported layout
+/// * `InvalidExpiration` - The `YYMMDD` expiration is not a calendar date
+/// * `InvalidStrike` - The strike is missing, non-numeric or not positive
+/// * `InvalidOptionStyle` - The style letter is neither `C` nor `P`
+/// * `MixedSymbols` - Symbols cannot be grouped into a single chain or series
+/// * `Chain` - Building the chain or series from the symbols failed
+///
+/// # Examples
+///
+/// ```
+/// use optionstratlib::model::OsiSymbol;
+///
+/// assert!(OsiSymbol::parse("SPY   261218X00500000").is_err());
+/// ```
+#[derive(Error, Debug)]
+pub enum OsiError {
+    /// The symbol does not match the OCC layout or a supported vendor variant.
+    #[error("invalid option symbol '{symbol}': {reason}")]
+    InvalidFormat {
+        /// Symbol being parsed
+        symbol: String,
+        /// Reason for the error
+        reason: String,
+    },
+
+    /// The expiration field is not a valid calendar date.
+    #[error("invalid expiration in option symbol '{symbol}': {reason}")]
+    InvalidExpiration {
+        /// Symbol being parsed
+        symbol: String,
+        /// Reason for the error
+        reason: String,
+    },
+
+    /// The strike field is not a valid positive price.
+    #[error("invalid strike in option symbol '{symbol}': {reason}")]
+    InvalidStrike {
+        /// Symbol being parsed
+        symbol: String,
+        /// Reason for the error
+        reason: String,
+    },
+
+    /// The option style letter is neither `C` nor `P`.
+    #[error("invalid option style '{style}' in option symbol '{symbol}'")]
+    InvalidOptionStyle {
+        /// Symbol being parsed
+        symbol: String,
+        /// Style letter found
+        style: char,
+    },
+
+    /// Symbols refer to different underlyings or expirations where a single
+    /// one is required.
+    #[error("cannot group option symbols: {reason}")]
+    MixedSymbols {
+        /// Reason for the error
+        reason: String,
+    },
+
+    /// Error raised while building an option chain from symbols.
+    #[error(transparent)]
+    Chain(#[from] Box<crate::error::ChainError>),
+}
+
+impl From<crate::error::ChainError> for OsiError {
+    fn from(error: crate::error::ChainError) -> Self {
+        OsiError::Chain(Box::new(error))
+    }
+}
+
+#[cfg(test)]
+mod tests_osi_error {
+    use super::*;
+    use crate::error::ChainError;
+
+    #[test]
+    fn test_display() {
+        let error = OsiError::InvalidOptionStyle {
+            symbol: "SPY   261218X00500000".to_string(),
+            style: 'X',
+        };
+        assert_eq!(
+            error.to_string(),
+            "invalid option style 'X' in option symbol 'SPY   261218X00500000'"
+        );
+    }
+
+    #[test]
+    fn test_from_chain_error() {
+        let error: OsiError = ChainError::invalid_parameters("strike", "bad").into();
+        assert!(matches!(error, OsiError::Chain(_)));
+    }
+}
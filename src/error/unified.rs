@@ -94,6 +94,10 @@ pub enum Error {
     #[error(transparent)]
     Ohlcv(#[from] crate::error::OhlcvError),
 
+    /// Option symbol errors.
+    #[error(transparent)]
+    Osi(#[from] crate::error::OsiError),
+
     /// Simulation-related errors.
     #[error(transparent)]
     Simulation(#[from] crate::error::SimulationError),
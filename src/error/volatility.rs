@@ -115,6 +115,22 @@ pub enum VolatilityError {
         source: Box<VolatilityError>,
     },
 
+    /// A model calibration could not be carried out, e.g. inconsistent
+    /// parameter bounds or no usable market quotes.
+    #[error("calibration error: {reason}")]
+    CalibrationError {
+        /// Description of why the calibration could not proceed.
+        reason: String,
+    },
+
+    /// A volatility term structure could not be built or queried, e.g.
+    /// duplicate expiries or a negative forward variance between two dates.
+    #[error("term structure error: {reason}")]
+    TermStructureError {
+        /// Description of why the term-structure operation failed.
+        reason: String,
+    },
+
     /// A chain-layer error surfaced while retrieving volatility data
     /// (e.g. empty option chain, ATM lookup failure on a chain).
     ///
@@ -308,6 +324,22 @@ mod tests_volatility_errors {
         );
     }
 
+    #[test]
+    fn test_calibration_error() {
+        let error = VolatilityError::CalibrationError {
+            reason: "no quotes".to_string(),
+        };
+        assert_eq!(error.to_string(), "calibration error: no quotes");
+    }
+
+    #[test]
+    fn test_term_structure_error() {
+        let error = VolatilityError::TermStructureError {
+            reason: "duplicate expiry".to_string(),
+        };
+        assert_eq!(error.to_string(), "term structure error: duplicate expiry");
+    }
+
     #[test]
     fn test_error_is_send() {
         fn assert_send<T: Send>() {}
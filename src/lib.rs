@@ -63,11 +63,14 @@
 //! ### 5. **Trading Strategies (25+ Strategies)**
 //! - **Single Leg**: Long/Short Calls and Puts
 //! - **Spreads**: Bull/Bear Call/Put Spreads
-//! - **Butterflies**: Long/Short Butterfly Spreads, Call Butterfly
-//! - **Complex**: Iron Condor, Iron Butterfly
+//! - **Ratio Spreads**: Call/Put Ratio Spreads and Backspreads
+//! - **Butterflies**: Long/Short Butterfly Spreads, Call Butterfly, Call/Put Broken-Wing Butterflies
+//! - **Complex**: Iron Condor, Iron Butterfly, Call/Put Condors
 //! - **Volatility**: Long/Short Straddles and Strangles
-//! - **Income**: Covered Calls (with spot leg support), Poor Man's Covered Call
-//! - **Protection**: Protective Puts, Collars
+//! - **Time Spreads**: Long/Short Calendar Spreads, Diagonal Spreads (calls or puts)
+//! - **Income**: Covered Calls (with spot leg support), Poor Man's Covered Call, Jade/Big Lizards
+//! - **Protection**: Protective Puts, Collars, Fences, Seagulls, Risk Reversals (zero-cost solvers)
+//! - **Financing**: Box Spreads, Conversions and Reversals with implied rates
 //! - **Custom**: Flexible custom strategy framework
 //! - **Multi-Asset**: Strategies combining options with spot, futures, or perpetuals
 //!
@@ -352,6 +355,7 @@
 //! Profit and loss calculation:
 //! - Real-time P&L tracking
 //! - Historical P&L analysis
+//! - Mark-to-model valuation grids (T+0 / T+n / expiry curves with volatility shifts)
 //! - Performance attribution
 //!
 //! ### **Curves & Surfaces** (`curves/`, `surfaces/`)
@@ -728,16 +732,24 @@
 //! - **Bull Put Spread**: Moderately bullish credit spread
 //! - **Bear Put Spread**: Moderately bearish debit spread
 //!
+//! ### **Ratio Spread Strategies**
+//! Verticals with unequal quantities on each leg:
+//! - **Call/Put Ratio Spread**: Sells more options than it buys, profiting most at the short strike
+//! - **Call/Put Backspread**: Buys more options than it sells, profiting from a large move
+//!
 //! ### **Butterfly Strategies**
 //! Market neutral strategies profiting from low volatility:
 //! - **Long Butterfly Spread**: Profits from price staying near middle strike
 //! - **Short Butterfly Spread**: Profits from price moving away from middle strike
 //! - **Call Butterfly**: Butterfly using only call options
+//! - **Call/Put Broken-Wing Butterfly**: Credit butterfly with one wider wing and no risk beyond the narrow one
 //!
 //! ### **Complex Multi-Leg Strategies**
 //! Advanced strategies for experienced traders:
 //! - **Iron Condor**: Market neutral strategy with wide profit zone
 //! - **Iron Butterfly**: Market neutral strategy with narrow profit zone
+//! - **Long Call/Put Condor**: Iron condor payoff built from a single option style, opened for a debit
+//! - **Short Call/Put Condor**: Credit strategy profiting from a move beyond either wing
 //!
 //! ### **Volatility Strategies**
 //! Strategies that profit from volatility changes:
@@ -746,15 +758,33 @@
 //! - **Long Strangle**: Similar to straddle but with different strikes
 //! - **Short Strangle**: Credit strategy profiting from low volatility
 //!
+//! ### **Time Spread Strategies**
+//! Strategies built from two expirations of an option series, valued at the front expiry:
+//! - **Long Calendar Spread**: Profits from the faster time decay of the front option
+//! - **Short Calendar Spread**: Credit strategy profiting from a large move away from the strike
+//! - **Diagonal Spread**: Calendar spread with a directional bias from different strikes
+//!
 //! ### **Income Generation Strategies**
 //! Strategies focused on generating regular income:
 //! - **Covered Call**: Stock/spot ownership with call selling for income (now with full spot leg support)
 //! - **Poor Man's Covered Call**: LEAPS-based covered call alternative
+//! - **Jade Lizard**: Short put plus short call spread, with a credit covering the call spread width
+//! - **Big Lizard**: Short straddle capped by a long call, with no upside risk
+//!
+//! ### **Financing Strategies**
+//! Synthetic borrowing and lending trades that settle at a fixed amount:
+//! - **Box Spread**: Bull call plus bear put spread on the same strikes, with a chain scanner ranking boxes by implied yield
+//! - **Conversion**: Long underlying with a short call and long put, lending at the implied rate
+//! - **Reversal**: Short underlying with a long call and short put, borrowing at the implied rate
 //!
 //! ### **Protection Strategies**
 //! Risk management and hedging strategies:
 //! - **Protective Put**: Downside protection for stock positions
 //! - **Collar**: Combination of covered call and protective put
+//! - **Fence**: Floor and cap around a long or short exposure, with or without the underlying
+//! - **Seagull**: Call or put spread financed by a sold option on the other side
+//! - **Risk Reversal**: Bought call against a sold put, or the reverse
+//! - Zero-cost quotes solve for the financing strike against an `OptionChain`
 //!
 //! ### **Custom Strategy Framework**
 //! - **Custom Strategy**: Flexible framework for creating any multi-leg strategy
@@ -771,6 +801,9 @@
 //! - **Delta Neutrality**: Delta-neutral position analysis
 //! - **Visualization**: Interactive payoff diagrams and risk profiles
 //! - **Optimization**: Find optimal strikes and expirations
+//! - **Multi-Objective Optimization**: Pareto-ranked candidates under delta, probability, margin, capital and credit constraints
+//! - **Strategy Scanner**: Cross-expiry, multi-underlying screening of strategy templates with table, CSV and JSON reports
+//! - **Roll Engine**: Rule-based rolls out, up, down and for credit with closing/opening trades and Greek and P&L impact
 //!
 //! ### **Strategy Traits System**
 //! All strategies implement a comprehensive trait system:
@@ -821,6 +854,7 @@
 //! - `plotly`: Enables interactive visualization using plotly.rs
 //! - `static_export`: PNG / SVG export via `plotly_static` (pulls in async runtime)
 //! - `async`: Enables asynchronous I/O operations for OptionChain and OHLCV data (tokio + reqwest + futures)
+//! - `parquet`: Columnar Parquet import/export for `OptionChain`, `OptionSeries` and chain history (arrow + parquet)
 //!
 //! ### Building from Source
 //!
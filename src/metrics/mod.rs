@@ -170,7 +170,8 @@ pub use risk::{
     DollarGammaCurve, ImpliedVolatilityCurve, ImpliedVolatilitySurface, RiskReversalCurve,
 };
 pub use stress::{
-    PriceShockCurve, PriceShockSurface, TimeDecayCurve, TimeDecaySurface,
-    VolatilitySensitivityCurve, VolatilitySensitivitySurface,
+    PriceShockCurve, PriceShockSurface, SmileAwarePriceShock, SmileAwareTimeDecay,
+    SmileAwareVolatilitySensitivity, TimeDecayCurve, TimeDecaySurface, VolatilitySensitivityCurve,
+    VolatilitySensitivitySurface,
 };
 pub use temporal::{CharmCurve, CharmSurface, ColorCurve, ColorSurface, ThetaCurve, ThetaSurface};
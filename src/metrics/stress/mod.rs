@@ -40,6 +40,13 @@
 //! - **Curve representation by strike**: P&L for price shock at each strike
 //! - **Surface representation (price vs volatility)**: Combined shock scenarios
 //!
+//! ### Smile-Aware Variants
+//!
+//! [`SmileAwarePriceShock`], [`SmileAwareVolatilitySensitivity`] and
+//! [`SmileAwareTimeDecay`] fully reprice each option with the volatility
+//! re-read from the chain smile under a sticky-strike, sticky-delta,
+//! sticky-moneyness or skew-beta regime, instead of keeping it fixed.
+//!
 //! ## Usage Examples
 //!
 //! ### Volatility Sensitivity
@@ -147,6 +154,8 @@ pub mod price_shock;
 pub mod time_decay;
 pub mod volatility_sensitivity;
 
-pub use price_shock::{PriceShockCurve, PriceShockSurface};
-pub use time_decay::{TimeDecayCurve, TimeDecaySurface};
-pub use volatility_sensitivity::{VolatilitySensitivityCurve, VolatilitySensitivitySurface};
+pub use price_shock::{PriceShockCurve, PriceShockSurface, SmileAwarePriceShock};
+pub use time_decay::{SmileAwareTimeDecay, TimeDecayCurve, TimeDecaySurface};
+pub use volatility_sensitivity::{
+    SmileAwareVolatilitySensitivity, VolatilitySensitivityCurve, VolatilitySensitivitySurface,
+};
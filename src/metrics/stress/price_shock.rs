@@ -51,6 +51,7 @@ use crate::curves::Curve;
 use crate::error::CurveError;
 use crate::error::SurfaceError;
 use crate::surfaces::Surface;
+use crate::volatility::SmileDynamics;
 use positive::Positive;
 use rust_decimal::Decimal;
 
@@ -152,6 +153,73 @@ pub trait PriceShockSurface {
     ) -> Result<Surface, SurfaceError>;
 }
 
+/// A trait for price shock analysis that re-reads implied volatility from
+/// the smile after the underlying moves.
+///
+/// [`PriceShockCurve`] and [`PriceShockSurface`] keep each option's implied
+/// volatility fixed, which ignores skew effects. Implementors of this trait
+/// fully reprice every option with the volatility given by the selected
+/// [`SmileDynamics`] regime at the shocked underlying price.
+///
+/// # Example
+///
+/// ```ignore
+/// use optionstratlib::chains::chain::OptionChain;
+/// use optionstratlib::metrics::SmileAwarePriceShock;
+/// use optionstratlib::volatility::SmileDynamics;
+/// use rust_decimal_macros::dec;
+///
+/// let chain = OptionChain::load_from_json("options.json")?;
+/// let curve = chain.price_shock_curve_with_dynamics(dec!(-0.10), SmileDynamics::StickyMoneyness)?;
+/// ```
+pub trait SmileAwarePriceShock {
+    /// Computes the full-revaluation P&L by strike for a price shock.
+    ///
+    /// # Parameters
+    ///
+    /// - `shock_pct`: Price shock as a decimal (e.g., -0.10 for -10%)
+    /// - `dynamics`: Regime used to re-read each strike's volatility
+    ///
+    /// # Returns
+    ///
+    /// A `Curve` with strike on the x-axis and P&L on the y-axis.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CurveError::ConstructionError` if the smile cannot be built
+    /// or no option can be repriced.
+    fn price_shock_curve_with_dynamics(
+        &self,
+        shock_pct: Decimal,
+        dynamics: SmileDynamics,
+    ) -> Result<Curve, CurveError>;
+
+    /// Computes option values across underlying prices and strikes, with
+    /// volatilities re-read from the smile at each price.
+    ///
+    /// # Parameters
+    ///
+    /// - `price_range`: Tuple of (min_price, max_price) for the underlying
+    /// - `price_steps`: Number of steps along the price axis
+    /// - `dynamics`: Regime used to re-read each strike's volatility
+    ///
+    /// # Returns
+    ///
+    /// A `Surface` with underlying price on the x-axis, strike on the
+    /// y-axis and option value on the z-axis.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SurfaceError::ConstructionError` if the smile cannot be
+    /// built or no option can be repriced.
+    fn price_shock_surface_with_dynamics(
+        &self,
+        price_range: (Positive, Positive),
+        price_steps: usize,
+        dynamics: SmileDynamics,
+    ) -> Result<Surface, SurfaceError>;
+}
+
 #[cfg(test)]
 mod tests_price_shock {
     use super::*;
@@ -49,7 +49,9 @@ use crate::curves::Curve;
 use crate::error::CurveError;
 use crate::error::SurfaceError;
 use crate::surfaces::Surface;
+use crate::volatility::SmileDynamics;
 use positive::Positive;
+use rust_decimal::Decimal;
 
 /// A trait for computing time decay profile curves by strike price.
 ///
@@ -145,6 +147,77 @@ pub trait TimeDecaySurface {
     ) -> Result<Surface, SurfaceError>;
 }
 
+/// A trait for time decay analysis that re-reads implied volatility from
+/// the smile after the underlying moves or time passes.
+///
+/// [`TimeDecayCurve`] and [`TimeDecaySurface`] keep each option's implied
+/// volatility fixed. Implementors of this trait take every volatility from
+/// the selected [`SmileDynamics`] regime at the moved underlying price and
+/// the remaining time to expiry, so sticky-delta smiles also roll with time.
+///
+/// # Example
+///
+/// ```ignore
+/// use optionstratlib::chains::chain::OptionChain;
+/// use optionstratlib::metrics::SmileAwareTimeDecay;
+/// use optionstratlib::volatility::SmileDynamics;
+/// use rust_decimal_macros::dec;
+///
+/// let chain = OptionChain::load_from_json("options.json")?;
+/// let theta = chain.time_decay_curve_with_dynamics(dec!(-0.05), SmileDynamics::StickyMoneyness)?;
+/// ```
+pub trait SmileAwareTimeDecay {
+    /// Computes theta by strike after a price shock, with each strike's
+    /// volatility read from the smile at the shocked price.
+    ///
+    /// # Parameters
+    ///
+    /// - `shock_pct`: Price shock as a decimal (e.g., -0.10 for -10%)
+    /// - `dynamics`: Regime used to re-read each strike's volatility
+    ///
+    /// # Returns
+    ///
+    /// A `Curve` with strike on the x-axis and theta on the y-axis.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CurveError::ConstructionError` if the smile cannot be built
+    /// or no option has a valid theta.
+    fn time_decay_curve_with_dynamics(
+        &self,
+        shock_pct: Decimal,
+        dynamics: SmileDynamics,
+    ) -> Result<Curve, CurveError>;
+
+    /// Computes option values across underlying prices and days to
+    /// expiration, with the volatility read from the smile at each price
+    /// and remaining time.
+    ///
+    /// # Parameters
+    ///
+    /// - `price_range`: Tuple of (min_price, max_price) for the underlying
+    /// - `days_to_expiry`: Vector of days to expiration values
+    /// - `price_steps`: Number of steps along the price axis
+    /// - `dynamics`: Regime used to re-read the volatility at each point
+    ///
+    /// # Returns
+    ///
+    /// A `Surface` with underlying price on the x-axis, days to expiration
+    /// on the y-axis and option value on the z-axis.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SurfaceError::ConstructionError` if the smile cannot be
+    /// built or no point can be priced.
+    fn time_decay_surface_with_dynamics(
+        &self,
+        price_range: (Positive, Positive),
+        days_to_expiry: Vec<Positive>,
+        price_steps: usize,
+        dynamics: SmileDynamics,
+    ) -> Result<Surface, SurfaceError>;
+}
+
 #[cfg(test)]
 mod tests_time_decay {
     use super::*;
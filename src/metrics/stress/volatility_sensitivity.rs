@@ -49,7 +49,9 @@ use crate::curves::Curve;
 use crate::error::CurveError;
 use crate::error::SurfaceError;
 use crate::surfaces::Surface;
+use crate::volatility::SmileDynamics;
 use positive::Positive;
+use rust_decimal::Decimal;
 
 #[cfg(test)]
 use rust_decimal::MathematicalOps;
@@ -150,6 +152,79 @@ pub trait VolatilitySensitivitySurface {
     ) -> Result<Surface, SurfaceError>;
 }
 
+/// A trait for volatility sensitivity analysis that re-reads implied
+/// volatility from the smile after the underlying moves.
+///
+/// [`VolatilitySensitivityCurve`] and [`VolatilitySensitivitySurface`] keep
+/// each option's implied volatility fixed while the underlying moves.
+/// Implementors of this trait take every volatility from the selected
+/// [`SmileDynamics`] regime at the moved underlying price instead.
+///
+/// # Example
+///
+/// ```ignore
+/// use optionstratlib::chains::chain::OptionChain;
+/// use optionstratlib::metrics::SmileAwareVolatilitySensitivity;
+/// use optionstratlib::volatility::SmileDynamics;
+/// use rust_decimal_macros::dec;
+///
+/// let chain = OptionChain::load_from_json("options.json")?;
+/// let vega = chain.volatility_sensitivity_curve_with_dynamics(dec!(-0.05), SmileDynamics::StickyDelta)?;
+/// ```
+pub trait SmileAwareVolatilitySensitivity {
+    /// Computes vega by strike after a price shock, with each strike's
+    /// volatility read from the smile at the shocked price.
+    ///
+    /// # Parameters
+    ///
+    /// - `shock_pct`: Price shock as a decimal (e.g., -0.10 for -10%)
+    /// - `dynamics`: Regime used to re-read each strike's volatility
+    ///
+    /// # Returns
+    ///
+    /// A `Curve` with strike on the x-axis and vega on the y-axis.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CurveError::ConstructionError` if the smile cannot be built
+    /// or no option has a valid vega.
+    fn volatility_sensitivity_curve_with_dynamics(
+        &self,
+        shock_pct: Decimal,
+        dynamics: SmileDynamics,
+    ) -> Result<Curve, CurveError>;
+
+    /// Computes option values across underlying prices and parallel shifts
+    /// of the smile.
+    ///
+    /// # Parameters
+    ///
+    /// - `price_range`: Tuple of (min_price, max_price) for the underlying
+    /// - `vol_shift_range`: Tuple of (min_shift, max_shift) added to the
+    ///   smile volatility, as decimals (e.g., 0.05 for +5 vol points)
+    /// - `price_steps`: Number of steps along the price axis
+    /// - `vol_steps`: Number of steps along the shift axis
+    /// - `dynamics`: Regime used to re-read the volatility at each price
+    ///
+    /// # Returns
+    ///
+    /// A `Surface` with underlying price on the x-axis, volatility shift on
+    /// the y-axis and option value on the z-axis.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SurfaceError::ConstructionError` if the smile cannot be
+    /// built or no point can be priced.
+    fn volatility_sensitivity_surface_with_dynamics(
+        &self,
+        price_range: (Positive, Positive),
+        vol_shift_range: (Decimal, Decimal),
+        price_steps: usize,
+        vol_steps: usize,
+        dynamics: SmileDynamics,
+    ) -> Result<Surface, SurfaceError>;
+}
+
 #[cfg(test)]
 mod tests_volatility_sensitivity {
     use super::*;
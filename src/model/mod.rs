@@ -126,6 +126,7 @@ mod balance;
 mod expiration;
 /// Components for different types of trading legs (spot, futures, perpetuals).
 pub mod leg;
+mod osi;
 mod trade;
 
 pub use axis::BasicAxisTypes;
@@ -133,6 +134,7 @@ pub use balance::*;
 pub use expiration::ExpirationDate;
 pub use expiration::ExpirationDateError;
 pub use option::Options;
+pub use osi::{OsiContractParams, OsiFormat, OsiSymbol};
 pub use position::Position;
 pub use profit_range::ProfitLossRange;
 pub use trade::{Trade, TradeAble, TradeStatus, TradeStatusAble, save_trades};
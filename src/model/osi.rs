@@ -0,0 +1,629 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # OCC/OSI Option Symbols
+//!
+//! Parsing and formatting of the OCC Options Symbology Initiative (OSI)
+//! 21-character contract symbol, e.g. `SPY   261218C00500000`:
+//!
+//! | Field       | Width | Example  |
+//! |-------------|-------|----------|
+//! | Root        | 6     | `SPY   ` |
+//! | Expiration  | 6     | `261218` |
+//! | Style       | 1     | `C`      |
+//! | Strike×1000 | 8     | `00500000` |
+//!
+//! Common vendor variants are accepted as well: the unpadded compact form
+//! (`SPY261218C00500000`), the `O:`-prefixed form (`O:SPY261218C00500000`)
+//! and the dotted form with a decimal strike (`.SPY261218C500`).
+//!
+//! Parsed symbols can be turned into [`Options`] and [`Position`]s, or grouped
+//! into an [`OptionChain`] per expiration and an [`OptionSeries`] per
+//! underlying so broker exports can be ingested directly.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use optionstratlib::model::{OsiFormat, OsiSymbol};
+//! use optionstratlib::OptionStyle;
+//! use positive::pos_or_panic;
+//!
+//! let symbol = OsiSymbol::parse("SPY   261218C00500000").unwrap();
+//! assert_eq!(symbol.underlying, "SPY");
+//! assert_eq!(symbol.style, OptionStyle::Call);
+//! assert_eq!(symbol.strike, pos_or_panic!(500.0));
+//! assert_eq!(symbol.format(OsiFormat::Dotted), ".SPY261218C500");
+//! assert_eq!(OsiSymbol::parse(".SPY261218C500").unwrap(), symbol);
+//! ```
+
+use crate::ExpirationDate;
+use crate::chains::OptionChain;
+use crate::error::OsiError;
+use crate::model::types::{OptionStyle, OptionType, Side};
+use crate::model::{Options, Position};
+use crate::series::OptionSeries;
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use positive::Positive;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use utoipa::ToSchema;
+
+/// Maximum length of an OCC option root.
+const ROOT_WIDTH: usize = 6;
+
+/// Largest strike representable in the 8-digit OCC strike field.
+const MAX_STRIKE: Decimal = dec!(99999.999);
+
+/// Output layout for [`OsiSymbol::format`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ToSchema, Serialize, Deserialize)]
+pub enum OsiFormat {
+    /// OCC 21-character symbol with the root padded to six characters
+    #[default]
+    Occ,
+    /// OCC symbol without root padding, e.g. `SPY261218C00500000`
+    Compact,
+    /// Compact symbol with an `O:` prefix, e.g. `O:SPY261218C00500000`
+    Prefixed,
+    /// Leading dot and decimal strike, e.g. `.SPY261218C500`
+    Dotted,
+}
+
+/// A listed option contract identified by its OCC/OSI symbol.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, ToSchema, Serialize, Deserialize)]
+pub struct OsiSymbol {
+    /// Option root, usually the underlying ticker
+    pub underlying: String,
+    /// Expiration date
+    pub expiration: NaiveDate,
+    /// Call or put
+    pub style: OptionStyle,
+    /// Strike price
+    pub strike: Positive,
+}
+
+/// Contract details not carried by an option symbol, used to build
+/// [`Options`] and [`Position`]s from it.
+#[derive(Debug, Clone, PartialEq, ToSchema, Serialize, Deserialize)]
+pub struct OsiContractParams {
+    /// Long or short
+    pub side: Side,
+    /// Number of contracts
+    pub quantity: Positive,
+    /// Current price of the underlying
+    pub underlying_price: Positive,
+    /// Implied volatility of the contract
+    pub implied_volatility: Positive,
+    /// Risk-free rate
+    pub risk_free_rate: Decimal,
+    /// Dividend yield of the underlying
+    pub dividend_yield: Positive,
+}
+
+impl OsiSymbol {
+    /// Creates a symbol after validating the root and strike.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OsiError::InvalidFormat`] for an empty, non-alphanumeric or
+    /// longer than six character root, and [`OsiError::InvalidStrike`] for a
+    /// strike that does not fit the OCC strike field.
+    pub fn new(
+        underlying: &str,
+        expiration: NaiveDate,
+        style: OptionStyle,
+        strike: Positive,
+    ) -> Result<Self, OsiError> {
+        let underlying = underlying.trim().to_uppercase();
+        if underlying.is_empty()
+            || underlying.len() > ROOT_WIDTH
+            || !underlying.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            return Err(OsiError::InvalidFormat {
+                symbol: underlying,
+                reason: format!("root must be 1 to {ROOT_WIDTH} alphanumeric characters"),
+            });
+        }
+        let strike_dec = strike.to_dec();
+        if strike_dec.is_zero() || strike_dec > MAX_STRIKE || strike_dec.round_dp(3) != strike_dec {
+            return Err(OsiError::InvalidStrike {
+                symbol: underlying,
+                reason: format!("strike {strike} must be in (0, {MAX_STRIKE}] with 3 decimals"),
+            });
+        }
+        Ok(Self {
+            underlying,
+            expiration,
+            style,
+            strike,
+        })
+    }
+
+    /// Parses an OCC symbol or one of the supported vendor variants.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OsiError`] describing the first malformed field.
+    pub fn parse(symbol: &str) -> Result<Self, OsiError> {
+        let invalid = |reason: &str| OsiError::InvalidFormat {
+            symbol: symbol.to_string(),
+            reason: reason.to_string(),
+        };
+        let trimmed = symbol.trim();
+        let body = trimmed
+            .strip_prefix("O:")
+            .or_else(|| trimmed.strip_prefix('.'))
+            .unwrap_or(trimmed);
+        if !body.is_ascii() {
+            return Err(invalid("symbol must be ASCII"));
+        }
+
+        let style_at = body
+            .rfind(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .ok_or_else(|| invalid("missing option style"))?;
+        let (head, tail) = body.split_at(style_at);
+        let mut tail_chars = tail.chars();
+        let style_char = tail_chars
+            .next()
+            .ok_or_else(|| invalid("missing option style"))?;
+        let strike_field = tail_chars.as_str();
+        let style = match style_char.to_ascii_uppercase() {
+            'C' => OptionStyle::Call,
+            'P' => OptionStyle::Put,
+            other => {
+                return Err(OsiError::InvalidOptionStyle {
+                    symbol: symbol.to_string(),
+                    style: other,
+                });
+            }
+        };
+
+        let date_at = head
+            .len()
+            .checked_sub(6)
+            .ok_or_else(|| invalid("missing expiration"))?;
+        let (root, date_field) = head.split_at(date_at);
+        let expiration = parse_expiration(symbol, date_field)?;
+        let strike = parse_strike(symbol, strike_field)?;
+
+        Self::new(root, expiration, style, strike).map_err(|error| match error {
+            OsiError::InvalidFormat { reason, .. } => invalid(&reason),
+            OsiError::InvalidStrike { reason, .. } => OsiError::InvalidStrike {
+                symbol: symbol.to_string(),
+                reason,
+            },
+            other => other,
+        })
+    }
+
+    /// Parses a list of symbols, failing on the first invalid one.
+    ///
+    /// # Errors
+    ///
+    /// Propagates the first error from [`OsiSymbol::parse`].
+    pub fn parse_all<I, S>(symbols: I) -> Result<Vec<Self>, OsiError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        symbols
+            .into_iter()
+            .map(|symbol| Self::parse(symbol.as_ref()))
+            .collect()
+    }
+
+    /// Formats the symbol in the requested layout.
+    #[must_use]
+    pub fn format(&self, format: OsiFormat) -> String {
+        let date = self.expiration.format("%y%m%d");
+        let style = match self.style {
+            OptionStyle::Call => 'C',
+            OptionStyle::Put => 'P',
+        };
+        let strike_field = (self.strike.to_dec() * dec!(1000)).round();
+        match format {
+            OsiFormat::Occ => format!(
+                "{:<width$}{date}{style}{strike_field:0>8}",
+                self.underlying,
+                width = ROOT_WIDTH
+            ),
+            OsiFormat::Compact => format!("{}{date}{style}{strike_field:0>8}", self.underlying),
+            OsiFormat::Prefixed => {
+                format!("O:{}{date}{style}{strike_field:0>8}", self.underlying)
+            }
+            OsiFormat::Dotted => format!(
+                ".{}{date}{style}{}",
+                self.underlying,
+                self.strike.to_dec().normalize()
+            ),
+        }
+    }
+
+    /// Expiration as an [`ExpirationDate`], at the same 18:30 UTC cut-off
+    /// used when parsing date-only expiration strings.
+    pub fn expiration_date(&self) -> ExpirationDate {
+        let naive = self.expiration.and_hms_opt(18, 30, 0).unwrap_or_default();
+        ExpirationDate::DateTime(DateTime::<Utc>::from_naive_utc_and_offset(naive, Utc))
+    }
+
+    /// Builds the symbol of an existing option contract.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OsiError::InvalidExpiration`] when the option's expiration
+    /// cannot be resolved to a date, or propagates [`OsiSymbol::new`] errors.
+    pub fn from_options(option: &Options) -> Result<Self, OsiError> {
+        let date = option
+            .expiration_date
+            .get_date()
+            .map_err(|e| OsiError::InvalidExpiration {
+                symbol: option.underlying_symbol.clone(),
+                reason: e.to_string(),
+            })?;
+        Self::new(
+            &option.underlying_symbol,
+            date.date_naive(),
+            option.option_style,
+            option.strike_price,
+        )
+    }
+
+    /// Builds a European [`Options`] contract for this symbol.
+    #[must_use]
+    pub fn to_options(&self, params: &OsiContractParams) -> Options {
+        Options::new(
+            OptionType::European,
+            params.side,
+            self.underlying.clone(),
+            self.strike,
+            self.expiration_date(),
+            params.implied_volatility,
+            params.quantity,
+            params.underlying_price,
+            params.risk_free_rate,
+            self.style,
+            params.dividend_yield,
+            None,
+        )
+    }
+
+    /// Builds a [`Position`] opened now, using the OCC symbol as its epic.
+    #[must_use]
+    pub fn to_position(
+        &self,
+        params: &OsiContractParams,
+        premium: Positive,
+        open_fee: Positive,
+        close_fee: Positive,
+    ) -> Position {
+        Position::new(
+            self.to_options(params),
+            premium,
+            Utc::now(),
+            open_fee,
+            close_fee,
+            Some(self.to_string()),
+            None,
+        )
+    }
+
+    /// Groups symbols by underlying root.
+    #[must_use]
+    pub fn group_by_underlying(symbols: &[Self]) -> BTreeMap<String, Vec<Self>> {
+        let mut grouped: BTreeMap<String, Vec<Self>> = BTreeMap::new();
+        for symbol in symbols {
+            grouped
+                .entry(symbol.underlying.clone())
+                .or_default()
+                .push(symbol.clone());
+        }
+        grouped
+    }
+
+    /// Builds an unquoted [`OptionChain`] with one strike per distinct
+    /// symbol strike. All symbols must share underlying and expiration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OsiError::MixedSymbols`] for an empty list or symbols with
+    /// different underlyings or expirations.
+    pub fn to_option_chain(
+        symbols: &[Self],
+        underlying_price: Positive,
+        risk_free_rate: Option<Decimal>,
+        dividend_yield: Option<Positive>,
+    ) -> Result<OptionChain, OsiError> {
+        let first = single_underlying(symbols)?;
+        if let Some(other) = symbols.iter().find(|s| s.expiration != first.expiration) {
+            return Err(OsiError::MixedSymbols {
+                reason: format!(
+                    "expirations {} and {} in the same chain",
+                    first.expiration, other.expiration
+                ),
+            });
+        }
+        let mut chain = OptionChain::new(
+            &first.underlying,
+            underlying_price,
+            first.expiration.format("%Y-%m-%d").to_string(),
+            risk_free_rate,
+            dividend_yield,
+        );
+        let mut strikes: Vec<Positive> = symbols.iter().map(|s| s.strike).collect();
+        strikes.sort();
+        strikes.dedup();
+        for strike in strikes {
+            chain.add_option(
+                strike,
+                None,
+                None,
+                None,
+                None,
+                Positive::ZERO,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            );
+        }
+        Ok(chain)
+    }
+
+    /// Builds an [`OptionSeries`] with one chain per expiration. All
+    /// symbols must share the same underlying.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OsiError::MixedSymbols`] for an empty list or symbols with
+    /// different underlyings.
+    pub fn to_option_series(
+        symbols: &[Self],
+        underlying_price: Positive,
+        risk_free_rate: Option<Decimal>,
+        dividend_yield: Option<Positive>,
+    ) -> Result<OptionSeries, OsiError> {
+        let first = single_underlying(symbols)?;
+        let mut by_expiration: BTreeMap<NaiveDate, Vec<Self>> = BTreeMap::new();
+        for symbol in symbols {
+            by_expiration
+                .entry(symbol.expiration)
+                .or_default()
+                .push(symbol.clone());
+        }
+        let mut series = OptionSeries::new(first.underlying.clone(), underlying_price);
+        series.risk_free_rate = risk_free_rate;
+        series.dividend_yield = dividend_yield;
+        for group in by_expiration.values() {
+            let chain =
+                Self::to_option_chain(group, underlying_price, risk_free_rate, dividend_yield)?;
+            if let Some(symbol) = group.first() {
+                series.chains.insert(symbol.expiration_date(), chain);
+            }
+        }
+        Ok(series)
+    }
+}
+
+impl fmt::Display for OsiSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.format(OsiFormat::Occ))
+    }
+}
+
+impl FromStr for OsiSymbol {
+    type Err = OsiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+fn single_underlying(symbols: &[OsiSymbol]) -> Result<&OsiSymbol, OsiError> {
+    let first = symbols.first().ok_or_else(|| OsiError::MixedSymbols {
+        reason: "no symbols".to_string(),
+    })?;
+    if let Some(other) = symbols.iter().find(|s| s.underlying != first.underlying) {
+        return Err(OsiError::MixedSymbols {
+            reason: format!(
+                "underlyings {} and {} in the same group",
+                first.underlying, other.underlying
+            ),
+        });
+    }
+    Ok(first)
+}
+
+fn parse_expiration(symbol: &str, field: &str) -> Result<NaiveDate, OsiError> {
+    let invalid = |reason: String| OsiError::InvalidExpiration {
+        symbol: symbol.to_string(),
+        reason,
+    };
+    if !field.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid(format!("'{field}' is not YYMMDD")));
+    }
+    let number = |range: std::ops::Range<usize>| {
+        field
+            .get(range)
+            .and_then(|part| part.parse::<u32>().ok())
+            .ok_or_else(|| invalid(format!("'{field}' is not YYMMDD")))
+    };
+    let (year, month, day) = (number(0..2)?, number(2..4)?, number(4..6)?);
+    NaiveDate::from_ymd_opt(
+        expand_two_digit_year(year as i32, Utc::now().year()),
+        month,
+        day,
+    )
+    .ok_or_else(|| invalid(format!("'{field}' is not a calendar date")))
+}
+
+/// Expands a two-digit year to the year nearest to `current_year`, within a
+/// window of 49 years before and 50 years after it, so symbols near a century
+/// boundary resolve to the right century.
+fn expand_two_digit_year(two_digit: i32, current_year: i32) -> i32 {
+    let year = current_year - current_year.rem_euclid(100) + two_digit;
+    if year > current_year + 50 {
+        year - 100
+    } else if year <= current_year - 50 {
+        year + 100
+    } else {
+        year
+    }
+}
+
+fn parse_strike(symbol: &str, field: &str) -> Result<Positive, OsiError> {
+    let invalid = |reason: String| OsiError::InvalidStrike {
+        symbol: symbol.to_string(),
+        reason,
+    };
+    let value = Decimal::from_str(field).map_err(|e| invalid(format!("'{field}': {e}")))?;
+    let strike = if field.len() == 8 && !field.contains('.') {
+        value / dec!(1000)
+    } else {
+        value
+    };
+    Positive::new_decimal(strike).map_err(|e| invalid(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests_osi {
+    use super::*;
+    use positive::pos_or_panic;
+
+    fn spy_call() -> OsiSymbol {
+        OsiSymbol::new(
+            "SPY",
+            NaiveDate::from_ymd_opt(2026, 12, 18).unwrap(),
+            OptionStyle::Call,
+            pos_or_panic!(500.0),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_occ() {
+        let symbol = OsiSymbol::parse("SPY   261218C00500000").unwrap();
+        assert_eq!(symbol, spy_call());
+        assert_eq!(symbol.to_string(), "SPY   261218C00500000");
+        assert_eq!(symbol.to_string().len(), 21);
+    }
+
+    #[test]
+    fn test_parse_vendor_variants() {
+        for raw in [
+            "SPY261218C00500000",
+            "O:SPY261218C00500000",
+            ".SPY261218C500",
+            "  spy   261218c00500000 ",
+        ] {
+            assert_eq!(OsiSymbol::parse(raw).unwrap(), spy_call(), "{raw}");
+        }
+    }
+
+    #[test]
+    fn test_fractional_strike_and_numeric_root() {
+        let symbol: OsiSymbol = "BRKB1 270115P00012500".parse().unwrap();
+        assert_eq!(symbol.underlying, "BRKB1");
+        assert_eq!(symbol.style, OptionStyle::Put);
+        assert_eq!(symbol.strike, pos_or_panic!(12.5));
+        assert_eq!(symbol.format(OsiFormat::Dotted), ".BRKB1270115P12.5");
+        assert_eq!(symbol.format(OsiFormat::Compact), "BRKB1270115P00012500");
+        assert_eq!(symbol.format(OsiFormat::Prefixed), "O:BRKB1270115P00012500");
+    }
+
+    #[test]
+    fn test_two_digit_year_window_across_century() {
+        assert_eq!(expand_two_digit_year(26, 2026), 2026);
+        assert_eq!(expand_two_digit_year(76, 2026), 2076);
+        assert_eq!(expand_two_digit_year(77, 2026), 1977);
+        assert_eq!(expand_two_digit_year(99, 2101), 2099);
+        assert_eq!(expand_two_digit_year(1, 2099), 2101);
+        assert_eq!(expand_two_digit_year(0, 2000), 2000);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            OsiSymbol::parse("SPY   261218X00500000"),
+            Err(OsiError::InvalidOptionStyle { style: 'X', .. })
+        ));
+        assert!(matches!(
+            OsiSymbol::parse("SPY   261318C00500000"),
+            Err(OsiError::InvalidExpiration { .. })
+        ));
+        assert!(matches!(
+            OsiSymbol::parse("SPY   261218C00000000"),
+            Err(OsiError::InvalidStrike { .. })
+        ));
+        assert!(matches!(
+            OsiSymbol::parse("TOOLONGROOT261218C00500000"),
+            Err(OsiError::InvalidFormat { .. })
+        ));
+        assert!(OsiSymbol::parse("C500").is_err());
+        assert!(OsiSymbol::parse("").is_err());
+    }
+
+    #[test]
+    fn test_options_round_trip() {
+        let params = OsiContractParams {
+            side: Side::Short,
+            quantity: pos_or_panic!(2.0),
+            underlying_price: pos_or_panic!(480.0),
+            implied_volatility: pos_or_panic!(0.2),
+            risk_free_rate: dec!(0.04),
+            dividend_yield: Positive::ZERO,
+        };
+        let option = spy_call().to_options(&params);
+        assert_eq!(option.side, Side::Short);
+        assert_eq!(option.strike_price, pos_or_panic!(500.0));
+        assert_eq!(OsiSymbol::from_options(&option).unwrap(), spy_call());
+
+        let position = spy_call().to_position(
+            &params,
+            pos_or_panic!(12.0),
+            pos_or_panic!(0.65),
+            pos_or_panic!(0.65),
+        );
+        assert_eq!(position.epic.as_deref(), Some("SPY   261218C00500000"));
+        assert_eq!(position.premium, pos_or_panic!(12.0));
+    }
+
+    #[test]
+    fn test_group_into_chain_and_series() {
+        let symbols = OsiSymbol::parse_all([
+            "SPY   261218C00500000",
+            "SPY   261218P00500000",
+            "SPY   261218C00510000",
+            "SPY   270115C00500000",
+            "QQQ   261218C00400000",
+        ])
+        .unwrap();
+        let grouped = OsiSymbol::group_by_underlying(&symbols);
+        assert_eq!(grouped.len(), 2);
+        let spy = grouped.get("SPY").unwrap();
+
+        assert!(matches!(
+            OsiSymbol::to_option_chain(spy, pos_or_panic!(500.0), None, None),
+            Err(OsiError::MixedSymbols { .. })
+        ));
+        assert!(matches!(
+            OsiSymbol::to_option_series(&symbols, pos_or_panic!(500.0), None, None),
+            Err(OsiError::MixedSymbols { .. })
+        ));
+
+        let series =
+            OsiSymbol::to_option_series(spy, pos_or_panic!(500.0), Some(dec!(0.04)), None).unwrap();
+        assert_eq!(series.chains.len(), 2);
+        let first = series.chains.values().next().unwrap();
+        assert_eq!(first.options.len(), 2);
+        assert_eq!(first.get_expiration_date(), "2026-12-18");
+        assert_eq!(first.risk_free_rate, Some(dec!(0.04)));
+    }
+}
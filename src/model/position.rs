@@ -17,6 +17,7 @@ use crate::model::{Trade, TradeAble, TradeStatus};
 use crate::pnl::utils::PnL;
 use crate::pnl::{PnLCalculator, Transaction, TransactionAble};
 use crate::pricing::payoff::Profit;
+use crate::pricing::{PricingEngine, price_option};
 use crate::strategies::base::BasicAble;
 use crate::visualization::{Graph, GraphConfig, GraphData};
 use crate::{ExpirationDate, OptionType, Options};
@@ -440,6 +441,69 @@ impl Position {
         .map_err(PricingError::from)
     }
 
+    /// Marks the position to model at a valuation date before (or after) expiration.
+    ///
+    /// The option is repriced with `engine` at `underlying_price`, with the time left
+    /// between `elapsed_days` (days from today to the valuation date) and the leg's own
+    /// expiration, and with its implied volatility shifted by `volatility_shift`
+    /// (absolute, e.g. `0.05` for +5 vol points). Premiums and fees are accounted
+    /// exactly as in [`Position::pnl_at_expiration`], which is used once the valuation
+    /// date reaches the leg's expiration.
+    ///
+    /// # Parameters
+    ///
+    /// * `underlying_price` - Spot price of the underlying at the valuation date.
+    /// * `elapsed_days` - Days between today and the valuation date.
+    /// * `volatility_shift` - Absolute shift applied to the implied volatility.
+    /// * `engine` - Pricing engine used to value the remaining time value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PricingError::MethodError` if the shifted volatility is not strictly
+    /// positive, and propagates expiration, pricing and arithmetic failures.
+    pub fn pnl_at_date(
+        &self,
+        underlying_price: &Positive,
+        elapsed_days: Positive,
+        volatility_shift: Decimal,
+        engine: &PricingEngine,
+    ) -> Result<Decimal, PricingError> {
+        let remaining_days = d_sub(
+            self.option.expiration_date.get_days()?.to_dec(),
+            elapsed_days.to_dec(),
+            "position::pnl_at_date::remaining_days",
+        )?;
+        if remaining_days <= Decimal::ZERO {
+            return self.pnl_at_expiration(&Some(underlying_price));
+        }
+        let volatility = self.option.implied_volatility.to_dec() + volatility_shift;
+        if volatility <= Decimal::ZERO {
+            return Err(PricingError::method_error(
+                "pnl_at_date",
+                &format!("shifted implied volatility {volatility} must be positive"),
+            ));
+        }
+
+        let mut option = self.option.clone();
+        option.underlying_price = *underlying_price;
+        option.expiration_date = ExpirationDate::Days(Positive::new_decimal(remaining_days)?);
+        option.implied_volatility = Positive::new_decimal(volatility)?;
+        let value = d_mul(
+            price_option(&option, engine)?.to_dec(),
+            self.option.quantity.to_dec(),
+            "position::pnl_at_date::value",
+        )?;
+        let value = match self.option.side {
+            Side::Long => value,
+            Side::Short => -value,
+        };
+        let cost = self.total_cost()?.to_dec();
+        let premium_recv = self.premium_received()?.to_dec();
+        let net_after_cost = d_sub(value, cost, "position::pnl_at_date::net")?;
+        d_add(net_after_cost, premium_recv, "position::pnl_at_date::total")
+            .map_err(PricingError::from)
+    }
+
     /// Calculates the unrealized profit and loss (PnL) for an options position at a given price.
     ///
     /// This method computes the current theoretical profit or loss of the position if it were
@@ -3384,6 +3448,56 @@ mod tests_pnl_calculator {
         // PnL = -(premium1 - premium2) = -(4.0 - 2.5) = -1.5
         assert_eq!(pnl.realized, Some(dec!(-1.5)));
     }
+
+    #[test]
+    fn test_pnl_at_date_past_expiry_settles_at_intrinsic() {
+        let position = setup_test_position(Side::Long, OptionStyle::Call);
+        let price = pos_or_panic!(107.0);
+        let marked = position
+            .pnl_at_date(
+                &price,
+                pos_or_panic!(45.0),
+                Decimal::ZERO,
+                &PricingEngine::ClosedFormBS,
+            )
+            .unwrap();
+        assert_eq!(marked, position.pnl_at_expiration(&Some(&price)).unwrap());
+    }
+
+    #[test]
+    fn test_pnl_at_date_long_and_short_offset_to_fees() {
+        let long = setup_test_position(Side::Long, OptionStyle::Call);
+        let short = setup_test_position(Side::Short, OptionStyle::Call);
+        let price = pos_or_panic!(103.0);
+        let elapsed = pos_or_panic!(10.0);
+        let engine = PricingEngine::ClosedFormBS;
+        let long_pnl = long
+            .pnl_at_date(&price, elapsed, Decimal::ZERO, &engine)
+            .unwrap();
+        let short_pnl = short
+            .pnl_at_date(&price, elapsed, Decimal::ZERO, &engine)
+            .unwrap();
+        // Option values cancel; only the four fees of both legs remain.
+        assert_decimal_eq!(long_pnl + short_pnl, dec!(-4.0), dec!(1e-9));
+    }
+
+    #[test]
+    fn test_pnl_at_date_volatility_shift() {
+        let position = setup_test_position(Side::Long, OptionStyle::Call);
+        let engine = PricingEngine::ClosedFormBS;
+        let base = position
+            .pnl_at_date(&Positive::HUNDRED, Positive::ZERO, Decimal::ZERO, &engine)
+            .unwrap();
+        let bumped = position
+            .pnl_at_date(&Positive::HUNDRED, Positive::ZERO, dec!(0.05), &engine)
+            .unwrap();
+        assert!(bumped > base);
+        assert!(
+            position
+                .pnl_at_date(&Positive::HUNDRED, Positive::ZERO, dec!(-0.2), &engine)
+                .is_err()
+        );
+    }
 }
 
 #[cfg(test)]
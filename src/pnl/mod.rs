@@ -14,6 +14,7 @@ use positive::pos_or_panic;
 //!
 //! * `PnL` - Structure representing profit and loss information
 //! * `PnLCalculator` - Trait for implementing PnL calculation logic
+//! * `ValuationGrid` - Mark-to-model strategy P&L across spot, valuation date and volatility shift
 //!
 //! ## Key Features
 //!
@@ -112,6 +113,7 @@ mod traits;
 mod transaction;
 /// * [`utils`] - Utility functions for data manipulation and calculations
 pub mod utils;
+mod valuation;
 
 pub use metrics::{
     PnLMetrics, PnLMetricsDocument, PnLMetricsStep, create_pnl_metrics_document, load_pnl_metrics,
@@ -120,3 +122,4 @@ pub use metrics::{
 pub use traits::{PnLCalculator, TransactionAble};
 pub use transaction::Transaction;
 pub use utils::PnL;
+pub use valuation::ValuationGrid;
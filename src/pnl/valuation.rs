@@ -0,0 +1,194 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+use crate::error::PricingError;
+use crate::visualization::{
+    ColorScheme, Graph, GraphConfig, GraphData, LineStyle, Series2D, TraceMode,
+};
+use positive::Positive;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Mark-to-model P&L of a strategy over a grid of spot prices, valuation dates and
+/// implied-volatility shifts.
+///
+/// Each `(date, shift)` pair yields one P&L curve across `prices`, which is the
+/// familiar family of T+0 / T+n / expiry lines traders inspect before entering a
+/// trade. Values are stored as `values[date][shift][price]`.
+#[derive(DebugPretty, DisplaySimple, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct ValuationGrid {
+    /// Title used when the grid is plotted.
+    pub title: String,
+    /// Spot prices of the underlying (x axis of every curve).
+    pub prices: Vec<Positive>,
+    /// Valuation dates, expressed as days from today.
+    pub valuation_days: Vec<Positive>,
+    /// Display label for each valuation date (e.g. `T+0`, `T+7`, `Expiry`).
+    pub date_labels: Vec<String>,
+    /// Absolute implied-volatility shifts applied to every leg.
+    pub volatility_shifts: Vec<Decimal>,
+    /// Mark-to-model P&L indexed as `values[date][shift][price]`.
+    pub values: Vec<Vec<Vec<Decimal>>>,
+}
+
+impl ValuationGrid {
+    /// Creates a new grid, checking that `values` matches the axes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PricingError::MethodError` if `date_labels` or `values` do not have
+    /// one entry per valuation date, or a curve does not have one value per price
+    /// for every volatility shift.
+    pub fn new(
+        title: String,
+        prices: Vec<Positive>,
+        valuation_days: Vec<Positive>,
+        date_labels: Vec<String>,
+        volatility_shifts: Vec<Decimal>,
+        values: Vec<Vec<Vec<Decimal>>>,
+    ) -> Result<Self, PricingError> {
+        let shape_ok = date_labels.len() == valuation_days.len()
+            && values.len() == valuation_days.len()
+            && values.iter().all(|by_shift| {
+                by_shift.len() == volatility_shifts.len()
+                    && by_shift.iter().all(|curve| curve.len() == prices.len())
+            });
+        if !shape_ok {
+            return Err(PricingError::method_error(
+                "ValuationGrid::new",
+                "values must be shaped [valuation_days][volatility_shifts][prices]",
+            ));
+        }
+        Ok(Self {
+            title,
+            prices,
+            valuation_days,
+            date_labels,
+            volatility_shifts,
+            values,
+        })
+    }
+
+    /// Returns the P&L at the given date, volatility shift and price indices.
+    #[must_use]
+    pub fn value_at(&self, date: usize, shift: usize, price: usize) -> Option<Decimal> {
+        self.curve(date, shift)?.get(price).copied()
+    }
+
+    /// Returns the P&L curve across all prices for a date and volatility shift.
+    #[must_use]
+    pub fn curve(&self, date: usize, shift: usize) -> Option<&[Decimal]> {
+        self.values
+            .get(date)?
+            .get(shift)
+            .map(std::vec::Vec::as_slice)
+    }
+
+    fn curve_name(&self, date: usize, shift: usize) -> String {
+        let label = self.date_labels.get(date).cloned().unwrap_or_default();
+        match self.volatility_shifts.get(shift) {
+            Some(dv) if !dv.is_zero() => format!(
+                "{label} (IV {:+}%)",
+                (dv * Decimal::ONE_HUNDRED).normalize()
+            ),
+            _ => label,
+        }
+    }
+}
+
+impl Graph for ValuationGrid {
+    fn graph_data(&self) -> GraphData {
+        let x: Vec<Decimal> = self.prices.iter().map(|p| p.to_dec()).collect();
+        let mut series = Vec::new();
+        for (date, by_shift) in self.values.iter().enumerate() {
+            for (shift, curve) in by_shift.iter().enumerate() {
+                series.push(Series2D {
+                    x: x.clone(),
+                    y: curve.clone(),
+                    name: self.curve_name(date, shift),
+                    mode: TraceMode::Lines,
+                    line_color: None,
+                    line_width: Some(2.0),
+                });
+            }
+        }
+        GraphData::MultiSeries(series)
+    }
+
+    fn graph_config(&self) -> GraphConfig {
+        GraphConfig {
+            title: self.title.clone(),
+            width: 1600,
+            height: 900,
+            x_label: Some("Underlying Price".to_string()),
+            y_label: Some("Profit/Loss".to_string()),
+            z_label: None,
+            line_style: LineStyle::Solid,
+            color_scheme: ColorScheme::Default,
+            legend: None,
+            show_legend: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_valuation_grid {
+    use super::*;
+    use positive::pos_or_panic;
+    use rust_decimal_macros::dec;
+
+    fn sample_grid() -> ValuationGrid {
+        ValuationGrid::new(
+            "Grid".to_string(),
+            vec![pos_or_panic!(90.0), pos_or_panic!(100.0)],
+            vec![Positive::ZERO, pos_or_panic!(30.0)],
+            vec!["T+0".to_string(), "Expiry".to_string()],
+            vec![Decimal::ZERO, dec!(0.05)],
+            vec![
+                vec![vec![dec!(-1), dec!(2)], vec![dec!(-0.5), dec!(2.5)]],
+                vec![vec![dec!(-3), dec!(4)], vec![dec!(-3), dec!(4)]],
+            ],
+        )
+        .expect("valid grid")
+    }
+
+    #[test]
+    fn test_new_rejects_mismatched_shape() {
+        let result = ValuationGrid::new(
+            "Grid".to_string(),
+            vec![pos_or_panic!(90.0), pos_or_panic!(100.0)],
+            vec![Positive::ZERO],
+            vec!["T+0".to_string()],
+            vec![Decimal::ZERO],
+            vec![vec![vec![dec!(1)]]],
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_accessors() {
+        let grid = sample_grid();
+        assert_eq!(grid.value_at(0, 1, 1), Some(dec!(2.5)));
+        assert_eq!(grid.curve(1, 0), Some([dec!(-3), dec!(4)].as_slice()));
+        assert_eq!(grid.value_at(2, 0, 0), None);
+    }
+
+    #[test]
+    fn test_graph_data_one_series_per_curve() {
+        let grid = sample_grid();
+        let GraphData::MultiSeries(series) = grid.graph_data() else {
+            panic!("expected multi series");
+        };
+        let names: Vec<&str> = series.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["T+0", "T+0 (IV +5%)", "Expiry", "Expiry (IV +5%)"]
+        );
+        assert!(series.iter().all(|s| s.x == vec![dec!(90), dec!(100)]));
+    }
+}
@@ -36,28 +36,47 @@ pub use crate::strategies::{
     // Specific strategy implementations (commonly used)
     bear_call_spread::BearCallSpread,
     bear_put_spread::BearPutSpread,
+    box_spread::BoxSpread,
+    broken_wing_butterfly::BrokenWingButterfly,
     bull_call_spread::BullCallSpread,
     bull_put_spread::BullPutSpread,
     call_butterfly::CallButterfly,
     collar::Collar,
+    conversion::Conversion,
     covered_call::CoveredCall,
     custom::CustomStrategy,
     delta_neutral::{
         AdjustmentAction, AdjustmentConfig, AdjustmentError, AdjustmentOptimizer, AdjustmentPlan,
         AdjustmentTarget, DeltaNeutrality, PortfolioGreeks,
     },
+    diagonal_spread::DiagonalSpread,
+    fence::Fence,
     iron_butterfly::IronButterfly,
     iron_condor::IronCondor,
+    lizard::Lizard,
     long_butterfly_spread::LongButterflySpread,
+    long_calendar_spread::LongCalendarSpread,
     long_call::LongCall,
+    long_condor::LongCondor,
     long_put::LongPut,
     long_straddle::LongStraddle,
     long_strangle::LongStrangle,
+    multi_objective::{
+        CandidateMetrics, MultiObjectiveConfig, OptimizationConstraints, OptimizationObjective,
+        ParetoCandidate,
+    },
     poor_mans_covered_call::PoorMansCoveredCall,
     probabilities::ProbabilityAnalysis,
     protective_put::ProtectivePut,
+    ratio_spread::RatioSpread,
+    risk_reversal::RiskReversal,
+    roll::{RollChange, RollProfile, RollProposal, RollRule},
+    scanner::{ScanConfig, ScanFailure, ScanReport, ScanRow, ScanTemplate, StrategyScanner},
+    seagull::Seagull,
     short_butterfly_spread::ShortButterflySpread,
+    short_calendar_spread::ShortCalendarSpread,
     short_call::ShortCall,
+    short_condor::ShortCondor,
     short_put::ShortPut,
     short_straddle::ShortStraddle,
     short_strangle::ShortStrangle,
@@ -8,22 +8,26 @@ use crate::error::strategies::BreakEvenErrorKind;
 use crate::{
     ExpirationDate, Options,
     chains::{StrategyLegs, chain::OptionChain, utils::OptionDataGroup},
-    error::{OperationErrorKind, position::PositionError, strategies::StrategyError},
+    error::{OperationErrorKind, PricingError, position::PositionError, strategies::StrategyError},
     greeks::Greeks,
     model::{
         Trade,
         position::Position,
         types::{Action, OptionBasicType, OptionStyle, OptionType, Side},
     },
-    pnl::PnLCalculator,
-    pricing::payoff::Profit,
+    pnl::{PnL, PnLCalculator, ValuationGrid},
+    pricing::{PricingEngine, payoff::Profit},
+    series::OptionSeries,
     strategies::{
         StrategyConstructor,
         delta_neutral::DeltaNeutrality,
+        multi_objective::{MultiObjectiveConfig, ParetoCandidate, pareto_search},
         probabilities::core::ProbabilityAnalysis,
+        roll::{RollProposal, RollRule, propose_roll},
         utils::{FindOptimalSide, OptimizationCriteria, calculate_price_range},
     },
     visualization::Graph,
+    volatility::StickySmile,
 };
 use positive::Positive;
 use rust_decimal::Decimal;
@@ -31,7 +35,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::str::FromStr;
-use tracing::{error, instrument, warn};
+use tracing::{debug, error, instrument, warn};
 use utoipa::ToSchema;
 
 /// Represents basic information about a trading strategy.
@@ -177,6 +181,48 @@ pub enum StrategyType {
     PoorMansCoveredCall,
     /// Call Butterfly strategy.
     CallButterfly,
+    /// Long Calendar Spread strategy.
+    LongCalendarSpread,
+    /// Short Calendar Spread strategy.
+    ShortCalendarSpread,
+    /// Diagonal Spread strategy.
+    DiagonalSpread,
+    /// Call Ratio Spread strategy.
+    CallRatioSpread,
+    /// Put Ratio Spread strategy.
+    PutRatioSpread,
+    /// Call Backspread strategy.
+    CallBackspread,
+    /// Put Backspread strategy.
+    PutBackspread,
+    /// Long Call Condor strategy.
+    LongCallCondor,
+    /// Long Put Condor strategy.
+    LongPutCondor,
+    /// Short Call Condor strategy.
+    ShortCallCondor,
+    /// Short Put Condor strategy.
+    ShortPutCondor,
+    /// Call Broken-Wing Butterfly strategy.
+    CallBrokenWingButterfly,
+    /// Put Broken-Wing Butterfly strategy.
+    PutBrokenWingButterfly,
+    /// Jade Lizard strategy.
+    JadeLizard,
+    /// Big Lizard strategy.
+    BigLizard,
+    /// Box Spread strategy.
+    BoxSpread,
+    /// Conversion strategy.
+    Conversion,
+    /// Reversal strategy.
+    Reversal,
+    /// Risk Reversal strategy.
+    RiskReversal,
+    /// Seagull strategy.
+    Seagull,
+    /// Fence strategy.
+    Fence,
     /// Custom strategy.
     Custom,
 }
@@ -207,6 +253,27 @@ impl FromStr for StrategyType {
             "ShortPut" => Ok(StrategyType::ShortPut),
             "PoorMansCoveredCall" => Ok(StrategyType::PoorMansCoveredCall),
             "CallButterfly" => Ok(StrategyType::CallButterfly),
+            "LongCalendarSpread" => Ok(StrategyType::LongCalendarSpread),
+            "ShortCalendarSpread" => Ok(StrategyType::ShortCalendarSpread),
+            "DiagonalSpread" => Ok(StrategyType::DiagonalSpread),
+            "CallRatioSpread" => Ok(StrategyType::CallRatioSpread),
+            "PutRatioSpread" => Ok(StrategyType::PutRatioSpread),
+            "CallBackspread" => Ok(StrategyType::CallBackspread),
+            "PutBackspread" => Ok(StrategyType::PutBackspread),
+            "LongCallCondor" => Ok(StrategyType::LongCallCondor),
+            "LongPutCondor" => Ok(StrategyType::LongPutCondor),
+            "ShortCallCondor" => Ok(StrategyType::ShortCallCondor),
+            "ShortPutCondor" => Ok(StrategyType::ShortPutCondor),
+            "CallBrokenWingButterfly" => Ok(StrategyType::CallBrokenWingButterfly),
+            "PutBrokenWingButterfly" => Ok(StrategyType::PutBrokenWingButterfly),
+            "JadeLizard" => Ok(StrategyType::JadeLizard),
+            "BigLizard" => Ok(StrategyType::BigLizard),
+            "BoxSpread" => Ok(StrategyType::BoxSpread),
+            "Conversion" => Ok(StrategyType::Conversion),
+            "Reversal" => Ok(StrategyType::Reversal),
+            "RiskReversal" => Ok(StrategyType::RiskReversal),
+            "Seagull" => Ok(StrategyType::Seagull),
+            "Fence" => Ok(StrategyType::Fence),
             "Custom" => Ok(StrategyType::Custom),
             _ => Err(()),
         }
@@ -991,6 +1058,235 @@ pub trait Strategies: Validable + Positionable + BreakEvenable + BasicAble {
         Ok(fee)
     }
 
+    /// Marks every leg to model at `underlying_price`, re-reading each leg's
+    /// implied volatility from `smile` instead of keeping it fixed.
+    ///
+    /// Each leg keeps its own strike and expiration; its volatility is
+    /// taken from the smile under the snapshot's [`crate::volatility::SmileDynamics`]
+    /// regime, so skew effects of the spot move are reflected in the P&L.
+    /// Any spot leg (see [`Strategies::underlying_pnl_at`]) is added to the
+    /// unrealized P&L.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PricingError::Position` if the positions cannot be
+    /// retrieved, `PricingError::MethodError` if the smile cannot produce a
+    /// volatility for a leg, and propagates any leg repricing failure.
+    fn calculate_pnl_with_smile(
+        &self,
+        underlying_price: &Positive,
+        smile: &StickySmile,
+    ) -> Result<PnL, PricingError> {
+        let positions = self.get_positions()?;
+        let mut pnls = Vec::with_capacity(positions.len());
+        for position in positions {
+            let expiration_date = position.option.expiration_date;
+            let implied_volatility = smile
+                .volatility_at(
+                    position.option.strike_price,
+                    *underlying_price,
+                    expiration_date.get_years()?,
+                )
+                .map_err(|e| {
+                    PricingError::method_error("calculate_pnl_with_smile", &e.to_string())
+                })?;
+            pnls.push(position.calculate_pnl(
+                underlying_price,
+                expiration_date,
+                &implied_volatility,
+            )?);
+        }
+        let mut pnl: PnL = pnls.into_iter().sum();
+        let underlying = self.underlying_pnl_at(underlying_price);
+        if !underlying.is_zero() {
+            pnl.unrealized = Some(pnl.unrealized.unwrap_or(Decimal::ZERO) + underlying);
+        }
+        Ok(pnl)
+    }
+
+    /// P&L contributed by any underlying (spot) holding of the strategy at
+    /// `underlying_price`.
+    ///
+    /// Option-only strategies hold no underlying and use the default of zero;
+    /// strategies that carry a spot leg override it so that
+    /// [`Strategies::pnl_at_date`] and [`Strategies::calculate_pnl_with_smile`]
+    /// value the whole book.
+    fn underlying_pnl_at(&self, _underlying_price: &Positive) -> Decimal {
+        Decimal::ZERO
+    }
+
+    /// Marks the strategy to model at a valuation date before expiration.
+    ///
+    /// `valuation_date` is read as an offset from today: `ExpirationDate::Days(0)`
+    /// is T+0, `ExpirationDate::Days(7)` is T+7, and a `DateTime` is converted to
+    /// the days between now and that date. Every leg is repriced with `engine` at
+    /// `underlying_price` over its own remaining time, with its implied
+    /// volatility shifted by `volatility_shift`; legs already expired at the
+    /// valuation date settle at intrinsic value (see [`Position::pnl_at_date`]).
+    ///
+    /// With a `smile`, each live leg's volatility is first re-read from it at
+    /// `underlying_price` and the leg's remaining time, under the smile's
+    /// [`crate::volatility::SmileDynamics`] regime, as in
+    /// [`Strategies::calculate_pnl_with_smile`]; `volatility_shift` is then
+    /// applied on top. Without one, each leg keeps its own implied volatility.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PricingError::Position` if the positions cannot be retrieved,
+    /// `PricingError::MethodError` if the smile cannot produce a volatility
+    /// for a leg, and propagates date conversion and leg repricing failures.
+    fn pnl_at_date(
+        &self,
+        underlying_price: &Positive,
+        valuation_date: &ExpirationDate,
+        volatility_shift: Decimal,
+        smile: Option<&StickySmile>,
+        engine: &PricingEngine,
+    ) -> Result<Decimal, PricingError> {
+        let elapsed_days = valuation_date.get_days()?;
+        let mut options_pnl = Decimal::ZERO;
+        for position in self.get_positions()? {
+            let remaining_days =
+                position.option.expiration_date.get_days()?.to_dec() - elapsed_days.to_dec();
+            let pnl = match smile {
+                Some(smile) if remaining_days > Decimal::ZERO => {
+                    let years =
+                        ExpirationDate::Days(Positive::new_decimal(remaining_days)?).get_years()?;
+                    let mut marked = position.clone();
+                    marked.option.implied_volatility = smile
+                        .volatility_at(position.option.strike_price, *underlying_price, years)
+                        .map_err(|e| PricingError::method_error("pnl_at_date", &e.to_string()))?;
+                    marked.pnl_at_date(underlying_price, elapsed_days, volatility_shift, engine)?
+                }
+                _ => position.pnl_at_date(
+                    underlying_price,
+                    elapsed_days,
+                    volatility_shift,
+                    engine,
+                )?,
+            };
+            options_pnl += pnl;
+        }
+        Ok(options_pnl + self.underlying_pnl_at(underlying_price))
+    }
+
+    /// Builds the mark-to-model [`ValuationGrid`] of the strategy: one P&L curve
+    /// over `prices` for every valuation date and implied-volatility shift.
+    ///
+    /// Dates follow the convention of [`Strategies::pnl_at_date`], and an
+    /// optional `smile` re-reads every leg's volatility as described there.
+    /// Dates on or past the last leg expiration are labelled `Expiry`, the
+    /// others `T+n`. The
+    /// grid implements [`Graph`], so it can be plotted directly as the family of
+    /// T+0 / T+n / expiry curves.
+    ///
+    /// # Errors
+    ///
+    /// Propagates any failure from [`Strategies::pnl_at_date`], and returns
+    /// `PricingError::Position` if the positions cannot be retrieved.
+    fn valuation_grid(
+        &self,
+        prices: &[Positive],
+        valuation_dates: &[ExpirationDate],
+        volatility_shifts: &[Decimal],
+        smile: Option<&StickySmile>,
+        engine: &PricingEngine,
+    ) -> Result<ValuationGrid, PricingError> {
+        let mut last_expiry = Positive::ZERO;
+        for position in self.get_positions()? {
+            last_expiry = last_expiry.max(position.option.expiration_date.get_days()?);
+        }
+
+        let mut valuation_days = Vec::with_capacity(valuation_dates.len());
+        let mut date_labels = Vec::with_capacity(valuation_dates.len());
+        let mut values = Vec::with_capacity(valuation_dates.len());
+        for valuation_date in valuation_dates {
+            let days = valuation_date.get_days()?;
+            date_labels.push(if days >= last_expiry {
+                "Expiry".to_string()
+            } else {
+                format!("T+{}", days.to_dec().round_dp(1).normalize())
+            });
+            valuation_days.push(days);
+            let mut by_shift = Vec::with_capacity(volatility_shifts.len());
+            for shift in volatility_shifts {
+                by_shift.push(
+                    prices
+                        .iter()
+                        .map(|price| self.pnl_at_date(price, valuation_date, *shift, smile, engine))
+                        .collect::<Result<Vec<Decimal>, PricingError>>()?,
+                );
+            }
+            values.push(by_shift);
+        }
+
+        ValuationGrid::new(
+            format!("{} - Mark-to-Model P&L", self.get_title()),
+            prices.to_vec(),
+            valuation_days,
+            date_labels,
+            volatility_shifts.to_vec(),
+            values,
+        )
+    }
+
+    /// Proposes a roll of this strategy on the expirations of `series` following
+    /// `rule`.
+    ///
+    /// `self` is left unchanged: the proposal carries the rolled strategy, the
+    /// trades closing and opening the rolled legs at the chain quotes with the
+    /// legs' fees, and the Greek and P&L profile before and after the roll.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StrategyError::OperationError`] if the series has no expiration
+    /// or strike the rule needs, if the rule does not apply to this strategy, if
+    /// the rolled legs fail [`Validable::validate`], or if no roll meets the credit
+    /// of [`RollRule::ForCredit`]. Failures computing the
+    /// Greeks, break-even points or maximum profit and loss are propagated.
+    fn roll(
+        &self,
+        series: &OptionSeries,
+        rule: RollRule,
+    ) -> Result<RollProposal<Self>, StrategyError>
+    where
+        Self: Sized + Clone + Greeks,
+    {
+        propose_roll(self, series, rule)
+    }
+
+    /// Proposes one roll per rule in `rules`, skipping the rules that cannot be
+    /// applied to this strategy on `series`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StrategyError::OperationError`] if `rules` is empty.
+    fn propose_rolls(
+        &self,
+        series: &OptionSeries,
+        rules: &[RollRule],
+    ) -> Result<Vec<RollProposal<Self>>, StrategyError>
+    where
+        Self: Sized + Clone + Greeks,
+    {
+        if rules.is_empty() {
+            return Err(StrategyError::invalid_parameters(
+                "propose_rolls",
+                "at least one roll rule is required",
+            ));
+        }
+        Ok(rules
+            .iter()
+            .filter_map(|rule| match self.roll(series, *rule) {
+                Ok(proposal) => Some(proposal),
+                Err(e) => {
+                    debug!("Skipping roll {:?}: {}", rule, e);
+                    None
+                }
+            })
+            .collect())
+    }
+
     /// Calculates the profit area for the strategy. The default implementation returns an error
     /// indicating that the operation is not supported.
     ///
@@ -1459,6 +1755,68 @@ pub trait Optimizable: Validable + Strategies {
             std::any::type_name::<Self>(),
         ))
     }
+
+    /// Maps a combination yielded by [`Optimizable::filter_combinations`] to the
+    /// `StrategyLegs` expected by [`Optimizable::create_strategy`].
+    ///
+    /// The default mapping is positional (`Two` to `TwoLegs`, `Three` to
+    /// `ThreeLegs`, `Four` to `FourLegs`, six options to `SixLegs`); strategies
+    /// that reuse a strike for several legs override it. Returns `None` for
+    /// combinations the strategy cannot build.
+    fn strategy_legs<'a>(&self, group: OptionDataGroup<'a>) -> Option<StrategyLegs<'a>> {
+        match group {
+            OptionDataGroup::Two(first, second) => Some(StrategyLegs::TwoLegs { first, second }),
+            OptionDataGroup::Three(first, second, third) => Some(StrategyLegs::ThreeLegs {
+                first,
+                second,
+                third,
+            }),
+            OptionDataGroup::Four(first, second, third, fourth) => Some(StrategyLegs::FourLegs {
+                first,
+                second,
+                third,
+                fourth,
+            }),
+            OptionDataGroup::Any(options) => match options.as_slice() {
+                [first, second, third, fourth, fifth, sixth] => Some(StrategyLegs::SixLegs {
+                    first,
+                    second,
+                    third,
+                    fourth,
+                    fifth,
+                    sixth,
+                }),
+                _ => None,
+            },
+            OptionDataGroup::One(_) => None,
+        }
+    }
+
+    /// Searches the chain combinations of this strategy for the candidates that
+    /// satisfy `config.constraints`, and ranks them by Pareto front over
+    /// `config.objectives`.
+    ///
+    /// Unlike [`Optimizable::find_optimal`], `self` is only used as a template
+    /// (expiration, quantity, fees) and is left unchanged. Candidates are ordered
+    /// by front and, within a front, by the first objective. Candidates that
+    /// cannot be built or scored are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StrategyError::OperationError`] if `config.objectives` is empty,
+    /// or if [`Optimizable::filter_combinations`] yields no combination, as for
+    /// strategies that do not implement it.
+    fn find_pareto_optimal(
+        &self,
+        option_chain: &OptionChain,
+        side: FindOptimalSide,
+        config: &MultiObjectiveConfig,
+    ) -> Result<Vec<ParetoCandidate<Self::Strategy>>, StrategyError>
+    where
+        Self::Strategy: ProbabilityAnalysis + Greeks,
+    {
+        pareto_search(self, option_chain, side, config)
+    }
 }
 
 /// The `Positionable` trait defines methods for managing positions within a trading strategy.
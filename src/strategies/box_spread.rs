@@ -0,0 +1,931 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Box Spread Strategy
+//!
+//! A box spread combines a bull call spread with a bear put spread on the
+//! same two strikes. Whatever the underlying does, it settles for the
+//! distance between the strikes at expiration, so its price today implies a
+//! financing rate:
+//!
+//! - a long box pays a debit now and receives the settlement, lending at the
+//!   implied rate;
+//! - a short box collects a credit now and pays the settlement, borrowing at
+//!   the implied rate.
+//!
+//! [`BoxSpread::scan`] ranks the long boxes of an option chain by their
+//! annualized implied yield after fees.
+
+use super::base::{
+    BreakEvenable, Optimizable, Positionable, Strategable, StrategyBasics, StrategyType, Validable,
+};
+use super::shared::{
+    EarlyAssignmentRisk, chain_leg, early_assignment_exposure, implied_financing_rate,
+};
+use crate::chains::{StrategyLegs, chain::OptionChain, utils::OptionDataGroup};
+use crate::error::position::{PositionError, PositionValidationErrorKind};
+use crate::error::probability::ProbabilityError;
+use crate::error::strategies::{ProfitLossErrorKind, StrategyError};
+use crate::error::{GreeksError, PricingError};
+use crate::greeks::Greeks;
+use crate::model::ProfitLossRange;
+use crate::model::position::Position;
+use crate::model::types::{OptionBasicType, OptionStyle, OptionType, Side};
+use crate::pnl::{PnLCalculator, utils::PnL};
+use crate::pricing::payoff::Profit;
+use crate::strategies::delta_neutral::DeltaNeutrality;
+use crate::strategies::probabilities::{core::ProbabilityAnalysis, utils::VolatilityAdjustment};
+use crate::strategies::utils::{FindOptimalSide, OptimizationCriteria};
+use crate::strategies::{BasicAble, Strategies, StrategyConstructor};
+use crate::{ExpirationDate, Options, test_strategy_traits};
+use chrono::Utc;
+use positive::Positive;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tracing::debug;
+use utoipa::ToSchema;
+
+/// Default description for the Box Spread strategy.
+pub const BOX_SPREAD_DESCRIPTION: &str = "A box spread combines a bull call spread and a bear put \
+    spread on the same strikes. It settles for the distance between the strikes regardless of the \
+    underlying, so its price implies a financing rate: a long box lends and a short box borrows.";
+
+/// Represents a long or short Box Spread.
+///
+/// A long box buys the lower call and the upper put and sells the upper call
+/// and the lower put; a short box takes the opposite side of every leg.
+#[derive(Clone, DebugPretty, DisplaySimple, Serialize, Deserialize, ToSchema)]
+pub struct BoxSpread {
+    /// Name identifier for this specific strategy instance
+    pub name: String,
+    /// Type of strategy, always `BoxSpread`
+    pub kind: StrategyType,
+    /// Detailed description of this strategy instance
+    pub description: String,
+    /// Always empty: the settlement does not depend on the underlying
+    pub break_even_points: Vec<Positive>,
+    /// The call at the lower strike, bought in a long box
+    pub(super) lower_call: Position,
+    /// The call at the upper strike, sold in a long box
+    pub(super) upper_call: Position,
+    /// The put at the lower strike, sold in a long box
+    pub(super) lower_put: Position,
+    /// The put at the upper strike, bought in a long box
+    pub(super) upper_put: Position,
+}
+
+impl BoxSpread {
+    /// Creates a new Box Spread. `side` is `Long` to lend (buy the box) and
+    /// `Short` to borrow (sell it).
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyError` if `lower_strike` is not below
+    /// `upper_strike` or a leg fails validation.
+    #[allow(clippy::too_many_arguments)]
+    #[inline(never)]
+    pub fn new(
+        underlying_symbol: String,
+        underlying_price: Positive,
+        side: Side,
+        lower_strike: Positive,
+        upper_strike: Positive,
+        expiration: ExpirationDate,
+        implied_volatility: Positive,
+        risk_free_rate: Decimal,
+        dividend_yield: Positive,
+        quantity: Positive,
+        premium_lower_call: Positive,
+        premium_upper_call: Positive,
+        premium_lower_put: Positive,
+        premium_upper_put: Positive,
+        open_fee: Positive,
+        close_fee: Positive,
+    ) -> Result<Self, StrategyError> {
+        let leg = |option_style, side, strike, premium| {
+            Position::new(
+                Options::new(
+                    OptionType::European,
+                    side,
+                    underlying_symbol.clone(),
+                    strike,
+                    expiration,
+                    implied_volatility,
+                    quantity,
+                    underlying_price,
+                    risk_free_rate,
+                    option_style,
+                    dividend_yield,
+                    None,
+                ),
+                premium,
+                Utc::now(),
+                open_fee,
+                close_fee,
+                None,
+                None,
+            )
+        };
+        let other = opposite(side);
+        Self::from_legs(
+            leg(OptionStyle::Call, side, lower_strike, premium_lower_call),
+            leg(OptionStyle::Call, other, upper_strike, premium_upper_call),
+            leg(OptionStyle::Put, other, lower_strike, premium_lower_put),
+            leg(OptionStyle::Put, side, upper_strike, premium_upper_put),
+        )
+    }
+
+    /// Ranks the long boxes of `option_chain` by annualized implied yield
+    /// after fees, highest first.
+    ///
+    /// Every pair of quoted strikes is priced at the chain's ask for the
+    /// bought legs and bid for the sold legs. Pairs with a missing quote or
+    /// a cost that leaves no positive yield are skipped.
+    #[must_use]
+    pub fn scan(
+        option_chain: &OptionChain,
+        quantity: Positive,
+        open_fee: Positive,
+        close_fee: Positive,
+    ) -> Vec<BoxSpread> {
+        let mut boxes: Vec<(Decimal, BoxSpread)> = option_chain
+            .get_double_iter()
+            .filter_map(|(lower, upper)| {
+                let candidate = Self::from_chain(
+                    option_chain,
+                    Side::Long,
+                    lower.strike_price,
+                    upper.strike_price,
+                    quantity,
+                    open_fee,
+                    close_fee,
+                )
+                .ok()?;
+                let rate = candidate.implied_rate().ok()?;
+                (rate > Decimal::ZERO).then_some((rate, candidate))
+            })
+            .collect();
+        boxes.sort_by(|(a, _), (b, _)| b.cmp(a));
+        boxes.into_iter().map(|(_, candidate)| candidate).collect()
+    }
+
+    fn from_chain(
+        chain: &OptionChain,
+        side: Side,
+        lower_strike: Positive,
+        upper_strike: Positive,
+        quantity: Positive,
+        open_fee: Positive,
+        close_fee: Positive,
+    ) -> Result<Self, StrategyError> {
+        let expiration = chain.get_expiration().ok_or_else(|| {
+            StrategyError::invalid_parameters("BoxSpread", "option chain has no expiration")
+        })?;
+        let leg = |strike, option_style, side| {
+            chain_leg(
+                chain,
+                expiration,
+                strike,
+                option_style,
+                side,
+                quantity,
+                open_fee,
+                close_fee,
+            )
+        };
+        let other = opposite(side);
+        Self::from_legs(
+            leg(lower_strike, OptionStyle::Call, side)?,
+            leg(upper_strike, OptionStyle::Call, other)?,
+            leg(lower_strike, OptionStyle::Put, other)?,
+            leg(upper_strike, OptionStyle::Put, side)?,
+        )
+    }
+
+    fn from_legs(
+        lower_call: Position,
+        upper_call: Position,
+        lower_put: Position,
+        upper_put: Position,
+    ) -> Result<Self, StrategyError> {
+        let mut strategy = BoxSpread {
+            name: match lower_call.option.side {
+                Side::Long => "Long Box Spread",
+                Side::Short => "Short Box Spread",
+            }
+            .to_string(),
+            lower_call,
+            upper_call,
+            lower_put,
+            upper_put,
+            ..Default::default()
+        };
+        if !strategy.validate() {
+            return Err(StrategyError::invalid_parameters(
+                "BoxSpread",
+                "requires opposite call and put verticals on the same two strikes, \
+                 with one expiration and quantity",
+            ));
+        }
+        strategy.update_break_even_points()?;
+        Ok(strategy)
+    }
+
+    fn legs(&self) -> [&Position; 4] {
+        [
+            &self.lower_call,
+            &self.upper_call,
+            &self.lower_put,
+            &self.upper_put,
+        ]
+    }
+
+    fn legs_mut(&mut self) -> [&mut Position; 4] {
+        [
+            &mut self.lower_call,
+            &mut self.upper_call,
+            &mut self.lower_put,
+            &mut self.upper_put,
+        ]
+    }
+
+    /// `Long` when the box lends (bought for a debit), `Short` when it
+    /// borrows (sold for a credit).
+    #[must_use]
+    pub fn side(&self) -> Side {
+        self.lower_call.option.side
+    }
+
+    /// Returns the lower and upper strikes.
+    #[must_use]
+    pub fn strikes(&self) -> (Positive, Positive) {
+        (
+            self.lower_call.option.strike_price,
+            self.upper_call.option.strike_price,
+        )
+    }
+
+    /// The amount the box settles for at expiration: the strike distance
+    /// times the quantity.
+    #[must_use]
+    pub fn settlement(&self) -> Positive {
+        let (lower, upper) = self.strikes();
+        (upper - lower) * self.lower_call.option.quantity
+    }
+
+    /// The amount paid to open a long box, or raised by opening a short
+    /// one, net of every fee.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PricingError` if a leg's payoff cannot be computed.
+    pub fn present_value(&self) -> Result<Decimal, PricingError> {
+        let profit = self.calculate_profit_at(self.get_underlying_price())?;
+        Ok(match self.side() {
+            Side::Long => self.settlement().to_dec() - profit,
+            Side::Short => self.settlement().to_dec() + profit,
+        })
+    }
+
+    /// Annualized, continuously compounded rate implied by the box after
+    /// fees: the lending yield of a long box or the borrowing cost of a
+    /// short one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyError` if the expiration cannot be resolved or the
+    /// present value is not positive.
+    pub fn implied_rate(&self) -> Result<Decimal, StrategyError> {
+        let years = self
+            .lower_call
+            .option
+            .expiration_date
+            .get_years()
+            .map_err(PricingError::from)?;
+        implied_financing_rate(self.present_value()?, self.settlement().to_dec(), years)
+    }
+
+    /// How much better the box finances than `risk_free_rate`: the implied
+    /// yield above it for a long box, the implied cost below it for a short
+    /// box. Positive values beat the risk-free rate.
+    ///
+    /// # Errors
+    ///
+    /// Propagates the errors of [`implied_rate`](Self::implied_rate).
+    pub fn financing_edge(&self) -> Result<Decimal, StrategyError> {
+        let rate = self.implied_rate()?;
+        let risk_free_rate = self.lower_call.option.risk_free_rate;
+        Ok(match self.side() {
+            Side::Long => rate - risk_free_rate,
+            Side::Short => risk_free_rate - rate,
+        })
+    }
+
+    /// Early-assignment exposure of the short legs that are American.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PricingError` if a leg's time to expiration cannot be
+    /// resolved.
+    pub fn early_assignment_exposure(&self) -> Result<Vec<EarlyAssignmentRisk>, PricingError> {
+        early_assignment_exposure(&self.legs())
+    }
+
+    fn range(&self) -> Result<ProfitLossRange, ProbabilityError> {
+        let option = &self.lower_call.option;
+        let mut range = ProfitLossRange::new(None, None, Positive::ZERO)?;
+        range.calculate_probability(
+            &option.underlying_price,
+            Some(VolatilityAdjustment {
+                base_volatility: option.implied_volatility,
+                std_dev_adjustment: Positive::ZERO,
+            }),
+            None,
+            &option.expiration_date,
+            Some(option.risk_free_rate),
+        )?;
+        Ok(range)
+    }
+}
+
+fn opposite(side: Side) -> Side {
+    match side {
+        Side::Long => Side::Short,
+        Side::Short => Side::Long,
+    }
+}
+
+impl StrategyConstructor for BoxSpread {
+    fn get_strategy(vec_positions: &[Position]) -> Result<Self, StrategyError> {
+        let mut calls: Vec<&Position> = Vec::new();
+        let mut puts: Vec<&Position> = Vec::new();
+        for position in vec_positions {
+            match position.option.option_style {
+                OptionStyle::Call => calls.push(position),
+                OptionStyle::Put => puts.push(position),
+            }
+        }
+        calls.sort_by_key(|position| position.option.strike_price);
+        puts.sort_by_key(|position| position.option.strike_price);
+        let ([lower_call, upper_call], [lower_put, upper_put]) =
+            (calls.as_slice(), puts.as_slice())
+        else {
+            return Err(StrategyError::invalid_parameters(
+                "Box Spread get_strategy",
+                "Must have exactly 2 calls and 2 puts",
+            ));
+        };
+        Self::from_legs(
+            (*lower_call).clone(),
+            (*upper_call).clone(),
+            (*lower_put).clone(),
+            (*upper_put).clone(),
+        )
+    }
+}
+
+impl BreakEvenable for BoxSpread {
+    fn get_break_even_points(&self) -> Result<&Vec<Positive>, StrategyError> {
+        Ok(&self.break_even_points)
+    }
+
+    /// A box's profit is the same at every price, so it has no break-even.
+    fn update_break_even_points(&mut self) -> Result<(), StrategyError> {
+        self.break_even_points.clear();
+        Ok(())
+    }
+}
+
+impl Validable for BoxSpread {
+    fn validate(&self) -> bool {
+        let legs = self.legs();
+        if !legs.iter().all(|leg| leg.validate()) {
+            debug!("Box spread legs failed validation");
+            return false;
+        }
+        let side = self.side();
+        let other = opposite(side);
+        let shape = [
+            (OptionStyle::Call, side),
+            (OptionStyle::Call, other),
+            (OptionStyle::Put, other),
+            (OptionStyle::Put, side),
+        ];
+        if legs
+            .iter()
+            .zip(shape)
+            .any(|(leg, (style, side))| leg.option.option_style != style || leg.option.side != side)
+        {
+            debug!("Box spread requires a call vertical and the opposite put vertical");
+            return false;
+        }
+        let first = &self.lower_call.option;
+        if legs.iter().any(|leg| {
+            leg.option.expiration_date != first.expiration_date
+                || leg.option.quantity != first.quantity
+        }) {
+            debug!("Box spread legs must share expiration and quantity");
+            return false;
+        }
+        let (lower, upper) = self.strikes();
+        if self.lower_put.option.strike_price != lower
+            || self.upper_put.option.strike_price != upper
+            || lower >= upper
+        {
+            debug!("Box spread verticals must use the same two ascending strikes");
+            return false;
+        }
+        true
+    }
+}
+
+impl Positionable for BoxSpread {
+    /// Replaces the leg with the same style and the nearest strike.
+    fn add_position(&mut self, position: &Position) -> Result<(), PositionError> {
+        let strike = position.option.strike_price.to_dec();
+        let (lower, upper) = self.strikes();
+        let near_lower = (strike - lower.to_dec()).abs() <= (upper.to_dec() - strike).abs();
+        let slot = match (position.option.option_style, near_lower) {
+            (OptionStyle::Call, true) => &mut self.lower_call,
+            (OptionStyle::Call, false) => &mut self.upper_call,
+            (OptionStyle::Put, true) => &mut self.lower_put,
+            (OptionStyle::Put, false) => &mut self.upper_put,
+        };
+        *slot = position.clone();
+        Ok(())
+    }
+
+    fn get_positions(&self) -> Result<Vec<&Position>, PositionError> {
+        Ok(self.legs().to_vec())
+    }
+
+    fn get_position(
+        &mut self,
+        option_style: &OptionStyle,
+        side: &Side,
+        strike: &Positive,
+    ) -> Result<Vec<&mut Position>, PositionError> {
+        let positions: Vec<&mut Position> = self
+            .legs_mut()
+            .into_iter()
+            .filter(|leg| {
+                leg.option.option_style == *option_style
+                    && leg.option.side == *side
+                    && leg.option.strike_price == *strike
+            })
+            .collect();
+        if positions.is_empty() {
+            return Err(PositionError::invalid_position_type(
+                *side,
+                "Strike not found in positions".to_string(),
+            ));
+        }
+        Ok(positions)
+    }
+
+    fn modify_position(&mut self, position: &Position) -> Result<(), PositionError> {
+        if !position.validate() {
+            return Err(PositionError::ValidationError(
+                PositionValidationErrorKind::InvalidPosition {
+                    reason: "Invalid position data".to_string(),
+                },
+            ));
+        }
+        let option = &position.option;
+        let slot = self.legs_mut().into_iter().find(|leg| {
+            leg.option.option_style == option.option_style
+                && leg.option.side == option.side
+                && leg.option.strike_price == option.strike_price
+        });
+        match slot {
+            Some(slot) => {
+                *slot = position.clone();
+                Ok(())
+            }
+            None => Err(PositionError::invalid_position_type(
+                option.side,
+                "Strike not found in positions".to_string(),
+            )),
+        }
+    }
+}
+
+impl Strategable for BoxSpread {
+    fn info(&self) -> Result<StrategyBasics, StrategyError> {
+        Ok(StrategyBasics {
+            name: self.name.clone(),
+            kind: self.kind.clone(),
+            description: self.description.clone(),
+        })
+    }
+}
+
+impl BasicAble for BoxSpread {
+    fn get_title(&self) -> String {
+        let legs: Vec<String> = self.legs().iter().map(|leg| leg.get_title()).collect();
+        format!("{} Strategy: \n\t{}", self.name, legs.join("\n\t"))
+    }
+
+    fn get_option_basic_type(&self) -> HashSet<OptionBasicType<'_>> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                OptionBasicType {
+                    option_style: &option.option_style,
+                    side: &option.side,
+                    strike_price: &option.strike_price,
+                    expiration_date: &option.expiration_date,
+                }
+            })
+            .collect()
+    }
+
+    fn get_implied_volatility(&self) -> HashMap<OptionBasicType<'_>, &Positive> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                (
+                    OptionBasicType {
+                        option_style: &option.option_style,
+                        side: &option.side,
+                        strike_price: &option.strike_price,
+                        expiration_date: &option.expiration_date,
+                    },
+                    &option.implied_volatility,
+                )
+            })
+            .collect()
+    }
+
+    fn get_quantity(&self) -> HashMap<OptionBasicType<'_>, &Positive> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                (
+                    OptionBasicType {
+                        option_style: &option.option_style,
+                        side: &option.side,
+                        strike_price: &option.strike_price,
+                        expiration_date: &option.expiration_date,
+                    },
+                    &option.quantity,
+                )
+            })
+            .collect()
+    }
+
+    fn one_option(&self) -> &Options {
+        self.lower_call.one_option()
+    }
+
+    fn one_option_mut(&mut self) -> &mut Options {
+        self.lower_call.one_option_mut()
+    }
+
+    fn set_expiration_date(
+        &mut self,
+        expiration_date: ExpirationDate,
+    ) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.expiration_date = expiration_date;
+        }
+        Ok(())
+    }
+
+    fn set_underlying_price(&mut self, price: &Positive) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.underlying_price = *price;
+            leg.premium = Positive::new_decimal(leg.option.calculate_price_black_scholes()?.abs())
+                .unwrap_or(Positive::ZERO);
+        }
+        Ok(())
+    }
+
+    fn set_implied_volatility(&mut self, volatility: &Positive) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.implied_volatility = *volatility;
+            leg.premium = Positive::new_decimal(leg.option.calculate_price_black_scholes()?.abs())
+                .unwrap_or(Positive::ZERO);
+        }
+        Ok(())
+    }
+}
+
+impl Strategies for BoxSpread {
+    fn get_max_profit(&self) -> Result<Positive, StrategyError> {
+        let profit = self.calculate_profit_at(self.get_underlying_price())?;
+        if profit <= Decimal::ZERO {
+            return Err(StrategyError::ProfitLossError(
+                ProfitLossErrorKind::MaxProfitError {
+                    reason: "Max profit is negative".to_string(),
+                },
+            ));
+        }
+        Ok(Positive::new_decimal(profit)?)
+    }
+
+    fn get_max_loss(&self) -> Result<Positive, StrategyError> {
+        let profit = self.calculate_profit_at(self.get_underlying_price())?;
+        Ok(Positive::new_decimal(profit.min(Decimal::ZERO).abs())?)
+    }
+}
+
+impl Optimizable for BoxSpread {
+    type Strategy = BoxSpread;
+
+    /// Yields the (lower, upper) strike pairs of `option_chain` that satisfy
+    /// `side`.
+    fn filter_combinations<'a>(
+        &'a self,
+        option_chain: &'a OptionChain,
+        side: FindOptimalSide,
+    ) -> impl Iterator<Item = OptionDataGroup<'a>> {
+        option_chain
+            .get_double_iter()
+            .filter(move |(lower, upper)| {
+                self.is_valid_optimal_option(lower, &side)
+                    && self.is_valid_optimal_option(upper, &side)
+            })
+            .map(|(lower, upper)| OptionDataGroup::Two(lower, upper))
+    }
+
+    /// Builds a box between the strikes of `first` and `second`, keeping
+    /// this box's side, quantity and fees and filling at the chain's quotes.
+    fn create_strategy(
+        &self,
+        chain: &OptionChain,
+        legs: &StrategyLegs,
+    ) -> Result<Self::Strategy, StrategyError> {
+        let StrategyLegs::TwoLegs { first, second } = legs else {
+            return Err(StrategyError::operation_not_supported(
+                "create_strategy",
+                "BoxSpread requires exactly two legs (TwoLegs)",
+            ));
+        };
+        let template = &self.lower_call;
+        Self::from_chain(
+            chain,
+            template.option.side,
+            first.strike_price,
+            second.strike_price,
+            template.option.quantity,
+            template.open_fee,
+            template.close_fee,
+        )
+    }
+
+    /// Selects the strikes with the largest
+    /// [`financing_edge`](BoxSpread::financing_edge), keeping this box's
+    /// side, quantity and fees. Both criteria rank by the implied rate.
+    fn find_optimal(
+        &mut self,
+        option_chain: &OptionChain,
+        side: FindOptimalSide,
+        _criteria: OptimizationCriteria,
+    ) {
+        let mut best_edge = Decimal::MIN;
+        let template = self.lower_call.clone();
+        for (lower, upper) in option_chain.get_double_iter() {
+            if !self.is_valid_optimal_option(lower, &side)
+                || !self.is_valid_optimal_option(upper, &side)
+            {
+                continue;
+            }
+            let candidate = match Self::from_chain(
+                option_chain,
+                template.option.side,
+                lower.strike_price,
+                upper.strike_price,
+                template.option.quantity,
+                template.open_fee,
+                template.close_fee,
+            ) {
+                Ok(candidate) => candidate,
+                Err(e) => {
+                    debug!("Skipping box spread candidate: {}", e);
+                    continue;
+                }
+            };
+            match candidate.financing_edge() {
+                Ok(edge) if edge > best_edge => {
+                    best_edge = edge;
+                    *self = candidate;
+                }
+                Ok(_) => {}
+                Err(e) => debug!("Skipping box spread without an implied rate: {}", e),
+            }
+        }
+    }
+}
+
+impl Profit for BoxSpread {
+    fn calculate_profit_at(&self, price: &Positive) -> Result<Decimal, PricingError> {
+        let price = Some(price);
+        self.legs()
+            .iter()
+            .map(|leg| leg.pnl_at_expiration(&price))
+            .sum()
+    }
+}
+
+impl ProbabilityAnalysis for BoxSpread {
+    fn get_profit_ranges(&self) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        if self.calculate_profit_at(self.get_underlying_price())? > Decimal::ZERO {
+            Ok(vec![self.range()?])
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    fn get_loss_ranges(&self) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        if self.calculate_profit_at(self.get_underlying_price())? > Decimal::ZERO {
+            Ok(Vec::new())
+        } else {
+            Ok(vec![self.range()?])
+        }
+    }
+}
+
+impl Greeks for BoxSpread {
+    fn get_options(&self) -> Result<Vec<&Options>, GreeksError> {
+        Ok(self.legs().map(|leg| &leg.option).to_vec())
+    }
+}
+
+impl DeltaNeutrality for BoxSpread {}
+
+impl PnLCalculator for BoxSpread {
+    fn calculate_pnl(
+        &self,
+        market_price: &Positive,
+        expiration_date: ExpirationDate,
+        implied_volatility: &Positive,
+    ) -> Result<PnL, PricingError> {
+        let mut pnl = PnL::default();
+        for leg in self.legs() {
+            pnl = pnl + leg.calculate_pnl(market_price, expiration_date, implied_volatility)?;
+        }
+        Ok(pnl)
+    }
+
+    fn calculate_pnl_at_expiration(
+        &self,
+        underlying_price: &Positive,
+    ) -> Result<PnL, PricingError> {
+        let mut pnl = PnL::default();
+        for leg in self.legs() {
+            pnl = pnl + leg.calculate_pnl_at_expiration(underlying_price)?;
+        }
+        Ok(pnl)
+    }
+}
+
+test_strategy_traits!(BoxSpread, test_box_spread_implementations);
+
+#[cfg(test)]
+mod tests_box_spread {
+    use super::*;
+    use crate::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
+    use positive::{pos_or_panic, spos};
+    use rust_decimal_macros::dec;
+
+    fn box_spread(side: Side, premiums: [f64; 4], fee: f64) -> BoxSpread {
+        let [lower_call, upper_call, lower_put, upper_put] =
+            premiums.map(|premium| pos_or_panic!(premium));
+        BoxSpread::new(
+            "SPY".to_string(),
+            Positive::HUNDRED,
+            side,
+            pos_or_panic!(90.0),
+            pos_or_panic!(110.0),
+            ExpirationDate::Days(pos_or_panic!(365.0)),
+            pos_or_panic!(0.2),
+            dec!(0.04),
+            Positive::ZERO,
+            Positive::ONE,
+            lower_call,
+            upper_call,
+            lower_put,
+            upper_put,
+            pos_or_panic!(fee),
+            Positive::ZERO,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_long_box_settles_at_strike_distance() {
+        // Debit of 13.0 - 3.0 + 10.0 - 1.0 = 19.0 for a 20-wide box.
+        let strategy = box_spread(Side::Long, [13.0, 3.0, 1.0, 10.0], 0.0);
+        assert_eq!(strategy.name, "Long Box Spread");
+        assert_eq!(strategy.settlement(), pos_or_panic!(20.0));
+        for price in [50.0, 100.0, 150.0] {
+            assert_eq!(
+                strategy.calculate_profit_at(&pos_or_panic!(price)).unwrap(),
+                Decimal::ONE
+            );
+        }
+        assert_eq!(strategy.present_value().unwrap(), dec!(19.0));
+        assert!(strategy.get_break_even_points().unwrap().is_empty());
+        assert_eq!(strategy.get_max_loss().unwrap(), Positive::ZERO);
+    }
+
+    #[test]
+    fn test_implied_rate_and_fees() {
+        let strategy = box_spread(Side::Long, [13.0, 3.0, 1.0, 10.0], 0.0);
+        let rate = strategy.implied_rate().unwrap();
+        // ln(20 / 19) over one year.
+        assert!((rate - dec!(0.0513)).abs() < dec!(0.001));
+        assert!(strategy.financing_edge().unwrap() > Decimal::ZERO);
+
+        // Fees raise the cost of lending and lower the yield.
+        let with_fees = box_spread(Side::Long, [13.0, 3.0, 1.0, 10.0], 0.1);
+        assert_eq!(with_fees.present_value().unwrap(), dec!(19.4));
+        assert!(with_fees.implied_rate().unwrap() < rate);
+
+        // Selling the same box borrows at 5.1%, above the 4% risk-free rate.
+        let short = box_spread(Side::Short, [13.0, 3.0, 1.0, 10.0], 0.0);
+        assert_eq!(short.name, "Short Box Spread");
+        assert_eq!(short.side(), Side::Short);
+        assert!(short.financing_edge().unwrap() < Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_early_assignment_of_american_legs() {
+        let mut strategy = box_spread(Side::Long, [13.0, 3.0, 1.0, 10.0], 0.0);
+        assert!(strategy.early_assignment_exposure().unwrap().is_empty());
+
+        for leg in strategy.legs_mut() {
+            leg.option.option_type = OptionType::American;
+        }
+        let exposure = strategy.early_assignment_exposure().unwrap();
+        assert_eq!(exposure.len(), 2);
+        // The short 110 call is out of the money; the short 90 put too.
+        assert!(exposure.iter().all(|risk| !risk.at_risk));
+
+        // A deep in-the-money short put with no time value is at risk.
+        strategy.lower_put.option.underlying_price = pos_or_panic!(60.0);
+        strategy.lower_put.premium = pos_or_panic!(30.0);
+        let exposure = strategy.early_assignment_exposure().unwrap();
+        let put = exposure
+            .iter()
+            .find(|risk| risk.option_style == OptionStyle::Put)
+            .unwrap();
+        assert_eq!(put.intrinsic_value, dec!(30.0));
+        assert!(put.at_risk);
+    }
+
+    #[test]
+    fn test_scan_ranks_by_implied_yield() {
+        let params = OptionChainBuildParams::new(
+            "SPY".to_string(),
+            spos!(1000.0),
+            5,
+            spos!(5.0),
+            Decimal::ZERO,
+            Decimal::ZERO,
+            pos_or_panic!(0.01),
+            2,
+            OptionDataPriceParams::new(
+                Some(Box::new(Positive::HUNDRED)),
+                Some(ExpirationDate::Days(pos_or_panic!(365.0))),
+                Some(dec!(0.05)),
+                spos!(0.0),
+                Some("SPY".to_string()),
+            ),
+            pos_or_panic!(0.2),
+        );
+        let chain = OptionChain::build_chain(&params).unwrap();
+        let boxes = BoxSpread::scan(&chain, Positive::ONE, Positive::ZERO, Positive::ZERO);
+        assert!(!boxes.is_empty());
+        let rates: Vec<Decimal> = boxes
+            .iter()
+            .map(|candidate| candidate.implied_rate().unwrap())
+            .collect();
+        assert!(rates.iter().zip(rates.iter().skip(1)).all(|(a, b)| a >= b));
+        assert!(boxes.iter().all(|candidate| candidate.side() == Side::Long));
+
+        // Fees lower every yield.
+        let with_fees = BoxSpread::scan(&chain, Positive::ONE, pos_or_panic!(0.05), Positive::ZERO);
+        let best_with_fees = with_fees
+            .first()
+            .map(|candidate| candidate.implied_rate().unwrap())
+            .unwrap_or(Decimal::ZERO);
+        assert!(rates.first().is_some_and(|best| best_with_fees < *best));
+
+        let mut strategy = box_spread(Side::Long, [13.0, 3.0, 1.0, 10.0], 0.0);
+        strategy.get_best_ratio(&chain, FindOptimalSide::All);
+        assert_eq!(
+            Some(strategy.strikes()),
+            boxes.first().map(BoxSpread::strikes)
+        );
+    }
+}
@@ -0,0 +1,928 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Broken-Wing Butterfly Strategy
+//!
+//! A broken-wing butterfly buys one option at each wing and sells two at the
+//! body, like a butterfly, but skips a strike on one side so the wings have
+//! different widths. It is opened for a credit, which leaves no risk beyond
+//! the narrow wing:
+//!
+//! - the call version widens the upper wing and has no downside risk;
+//! - the put version widens the lower wing and has no upside risk.
+//!
+//! The maximum profit is at the body strike and the risk is limited to the
+//! difference between the wing widths less the credit, beyond the broken wing.
+
+use super::base::{
+    BreakEvenable, Optimizable, Positionable, Strategable, StrategyBasics, StrategyType, Validable,
+};
+use super::shared::{ButterflyStrategy, chain_leg, expiration_break_evens};
+use crate::chains::{StrategyLegs, chain::OptionChain, utils::OptionDataGroup};
+use crate::error::position::{PositionError, PositionValidationErrorKind};
+use crate::error::probability::ProbabilityError;
+use crate::error::strategies::{ProfitLossErrorKind, StrategyError};
+use crate::error::{GreeksError, PricingError};
+use crate::greeks::Greeks;
+use crate::model::ProfitLossRange;
+use crate::model::position::Position;
+use crate::model::types::{OptionBasicType, OptionStyle, OptionType, Side};
+use crate::model::utils::mean_and_std;
+use crate::pnl::{PnLCalculator, utils::PnL};
+use crate::pricing::payoff::Profit;
+use crate::strategies::delta_neutral::DeltaNeutrality;
+use crate::strategies::probabilities::{core::ProbabilityAnalysis, utils::VolatilityAdjustment};
+use crate::strategies::utils::{FindOptimalSide, OptimizationCriteria};
+use crate::strategies::{BasicAble, Strategies, StrategyConstructor};
+use crate::{ExpirationDate, Options, test_strategy_traits};
+use chrono::Utc;
+use positive::Positive;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tracing::{debug, info};
+use utoipa::ToSchema;
+
+/// Default description for the Broken-Wing Butterfly strategy.
+pub const BROKEN_WING_BUTTERFLY_DESCRIPTION: &str = "A broken-wing butterfly buys one option at \
+    each wing and sells two at the body, with one wing wider than the other. Opened for a credit, it \
+    has no risk beyond the narrow wing, its best outcome at the body strike and limited risk beyond \
+    the broken wing.";
+
+/// Represents a Call or Put Broken-Wing Butterfly.
+///
+/// The wings share one quantity and the body holds twice as many
+/// contracts. Calls widen the upper wing and puts the lower one.
+#[derive(Clone, DebugPretty, DisplaySimple, Serialize, Deserialize, ToSchema)]
+pub struct BrokenWingButterfly {
+    /// Name identifier for this specific strategy instance
+    pub name: String,
+    /// `CallBrokenWingButterfly` or `PutBrokenWingButterfly`
+    pub kind: StrategyType,
+    /// Detailed description of this strategy instance
+    pub description: String,
+    /// Prices at expiration where the strategy neither makes nor loses money
+    pub break_even_points: Vec<Positive>,
+    /// The bought option at the lowest strike
+    pub(super) lower_wing: Position,
+    /// The two sold options at the body strike
+    pub(super) body: Position,
+    /// The bought option at the highest strike
+    pub(super) upper_wing: Position,
+}
+
+impl BrokenWingButterfly {
+    /// Creates a new Broken-Wing Butterfly with calls or puts.
+    ///
+    /// `quantity` applies to each wing; the body sells twice as many.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyError` if the wider wing is not on the side of the
+    /// option style, the premiums do not leave a credit, a leg fails
+    /// validation or the break-even calculation fails.
+    #[allow(clippy::too_many_arguments)]
+    #[inline(never)]
+    pub fn new(
+        underlying_symbol: String,
+        underlying_price: Positive,
+        option_style: OptionStyle,
+        lower_strike: Positive,
+        body_strike: Positive,
+        upper_strike: Positive,
+        expiration: ExpirationDate,
+        implied_volatility: Positive,
+        risk_free_rate: Decimal,
+        dividend_yield: Positive,
+        quantity: Positive,
+        premium_lower: Positive,
+        premium_body: Positive,
+        premium_upper: Positive,
+        open_fee: Positive,
+        close_fee: Positive,
+    ) -> Result<Self, StrategyError> {
+        let leg = |side, strike, quantity, premium| {
+            Position::new(
+                Options::new(
+                    OptionType::European,
+                    side,
+                    underlying_symbol.clone(),
+                    strike,
+                    expiration,
+                    implied_volatility,
+                    quantity,
+                    underlying_price,
+                    risk_free_rate,
+                    option_style,
+                    dividend_yield,
+                    None,
+                ),
+                premium,
+                Utc::now(),
+                open_fee,
+                close_fee,
+                None,
+                None,
+            )
+        };
+        Self::from_legs(
+            leg(Side::Long, lower_strike, quantity, premium_lower),
+            leg(
+                Side::Short,
+                body_strike,
+                quantity * Positive::TWO,
+                premium_body,
+            ),
+            leg(Side::Long, upper_strike, quantity, premium_upper),
+        )
+    }
+
+    fn from_legs(
+        lower_wing: Position,
+        body: Position,
+        upper_wing: Position,
+    ) -> Result<Self, StrategyError> {
+        let kind = match body.option.option_style {
+            OptionStyle::Call => StrategyType::CallBrokenWingButterfly,
+            OptionStyle::Put => StrategyType::PutBrokenWingButterfly,
+        };
+        let mut strategy = BrokenWingButterfly {
+            name: match kind {
+                StrategyType::PutBrokenWingButterfly => "Put Broken-Wing Butterfly",
+                _ => "Call Broken-Wing Butterfly",
+            }
+            .to_string(),
+            kind,
+            lower_wing,
+            body,
+            upper_wing,
+            ..Default::default()
+        };
+        if !strategy.validate() {
+            return Err(StrategyError::invalid_parameters(
+                "BrokenWingButterfly",
+                "requires a 1x2x1 butterfly of one style and expiration, broken on the \
+                 upper wing for calls or the lower wing for puts, opened for a credit",
+            ));
+        }
+        strategy.update_break_even_points()?;
+        Ok(strategy)
+    }
+
+    fn legs(&self) -> [&Position; 3] {
+        [&self.lower_wing, &self.body, &self.upper_wing]
+    }
+
+    fn legs_mut(&mut self) -> [&mut Position; 3] {
+        [&mut self.lower_wing, &mut self.body, &mut self.upper_wing]
+    }
+
+    /// Widths of the lower and upper wings.
+    pub fn wing_widths(&self) -> (Positive, Positive) {
+        let (lower, upper) = self.wing_strikes();
+        let body = self.body_strike();
+        (body - lower, upper - body)
+    }
+
+    /// The profit at expiration beyond the narrow wing, which the credit
+    /// keeps at or above zero.
+    pub fn credit(&self) -> Result<Decimal, PricingError> {
+        let (_, upper) = self.wing_strikes();
+        match self.body.option.option_style {
+            OptionStyle::Call => self.calculate_profit_at(&Positive::ZERO),
+            OptionStyle::Put => self.calculate_profit_at(&upper),
+        }
+    }
+
+    /// Expiration payoff at zero and at each strike, where its slope changes.
+    fn payoff_nodes(&self) -> Result<Vec<(Positive, Decimal)>, PricingError> {
+        std::iter::once(Positive::ZERO)
+            .chain(self.legs().map(|leg| leg.option.strike_price))
+            .map(|price| Ok((price, self.calculate_profit_at(&price)?)))
+            .collect()
+    }
+
+    fn upper_slope(&self) -> Result<Decimal, PricingError> {
+        let high = self.upper_wing.option.strike_price;
+        Ok(self.calculate_profit_at(&(high + Positive::ONE))? - self.calculate_profit_at(&high)?)
+    }
+
+    fn volatility_adjustment(&self) -> VolatilityAdjustment {
+        let (base_volatility, std_dev_adjustment) = mean_and_std(
+            self.legs()
+                .iter()
+                .map(|leg| leg.option.implied_volatility)
+                .collect(),
+        );
+        VolatilityAdjustment {
+            base_volatility,
+            std_dev_adjustment,
+        }
+    }
+
+    fn ranges(&self, profitable: bool) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        let option = &self.body.option;
+        let mut bounds: Vec<Option<Positive>> = vec![None];
+        bounds.extend(self.break_even_points.iter().copied().map(Some));
+        bounds.push(None);
+        let mut ranges = Vec::new();
+        for window in bounds.windows(2) {
+            let [lower, upper] = window else {
+                continue;
+            };
+            let probe = match (lower, upper) {
+                (Some(l), Some(u)) => (*l + *u) / Positive::TWO,
+                (None, Some(u)) => *u / Positive::TWO,
+                (Some(l), None) => *l * Positive::TWO,
+                (None, None) => option.underlying_price,
+            };
+            if (self.calculate_profit_at(&probe)? > Decimal::ZERO) != profitable {
+                continue;
+            }
+            let mut range = ProfitLossRange::new(*lower, *upper, Positive::ZERO)?;
+            range.calculate_probability(
+                &option.underlying_price,
+                Some(self.volatility_adjustment()),
+                None,
+                &option.expiration_date,
+                Some(option.risk_free_rate),
+            )?;
+            ranges.push(range);
+        }
+        Ok(ranges)
+    }
+}
+
+impl StrategyConstructor for BrokenWingButterfly {
+    fn get_strategy(vec_positions: &[Position]) -> Result<Self, StrategyError> {
+        let mut sorted = vec_positions.to_vec();
+        sorted.sort_by_key(|position| position.option.strike_price);
+        let [lower_wing, body, upper_wing] = sorted.as_slice() else {
+            return Err(StrategyError::invalid_parameters(
+                "Broken-Wing Butterfly get_strategy",
+                "Must have exactly 3 options",
+            ));
+        };
+        Self::from_legs(lower_wing.clone(), body.clone(), upper_wing.clone())
+    }
+}
+
+impl BreakEvenable for BrokenWingButterfly {
+    fn get_break_even_points(&self) -> Result<&Vec<Positive>, StrategyError> {
+        Ok(&self.break_even_points)
+    }
+
+    fn update_break_even_points(&mut self) -> Result<(), StrategyError> {
+        self.break_even_points =
+            expiration_break_evens(&self.payoff_nodes()?, self.upper_slope()?)?;
+        Ok(())
+    }
+}
+
+impl Validable for BrokenWingButterfly {
+    fn validate(&self) -> bool {
+        let legs = self.legs();
+        if !legs.iter().all(|leg| leg.validate()) {
+            debug!("Broken-wing butterfly legs failed validation");
+            return false;
+        }
+        let sides = [Side::Long, Side::Short, Side::Long];
+        if legs
+            .iter()
+            .zip(sides)
+            .any(|(leg, side)| leg.option.side != side)
+        {
+            debug!("Broken-wing butterfly requires long, short, long legs");
+            return false;
+        }
+        let body = &self.body.option;
+        if legs.iter().any(|leg| {
+            leg.option.option_style != body.option_style
+                || leg.option.expiration_date != body.expiration_date
+        }) {
+            debug!("Broken-wing butterfly legs must share style and expiration");
+            return false;
+        }
+        let wing_quantity = self.lower_wing.option.quantity;
+        if self.upper_wing.option.quantity != wing_quantity
+            || body.quantity != wing_quantity * Positive::TWO
+        {
+            debug!("Broken-wing butterfly must sell two body options per wing");
+            return false;
+        }
+        let (lower, upper) = self.wing_strikes();
+        if lower >= body.strike_price || body.strike_price >= upper {
+            debug!("Broken-wing butterfly strikes must be strictly ascending");
+            return false;
+        }
+        let (lower_width, upper_width) = self.wing_widths();
+        let broken = match body.option_style {
+            OptionStyle::Call => upper_width > lower_width,
+            OptionStyle::Put => lower_width > upper_width,
+        };
+        if !broken {
+            debug!("Broken-wing butterfly must widen the upper wing for calls, the lower for puts");
+            return false;
+        }
+        match self.credit() {
+            Ok(credit) if credit >= Decimal::ZERO => true,
+            _ => {
+                debug!("Broken-wing butterfly must be opened for a credit");
+                false
+            }
+        }
+    }
+}
+
+impl Positionable for BrokenWingButterfly {
+    /// Replaces the body for a short position, otherwise the wing whose
+    /// strike is closest to the new position's strike.
+    fn add_position(&mut self, position: &Position) -> Result<(), PositionError> {
+        let strike = position.option.strike_price.to_dec();
+        let slot = match position.option.side {
+            Side::Short => &mut self.body,
+            Side::Long => {
+                if (strike - self.lower_wing.option.strike_price.to_dec()).abs()
+                    <= (self.upper_wing.option.strike_price.to_dec() - strike).abs()
+                {
+                    &mut self.lower_wing
+                } else {
+                    &mut self.upper_wing
+                }
+            }
+        };
+        *slot = position.clone();
+        Ok(())
+    }
+
+    fn get_positions(&self) -> Result<Vec<&Position>, PositionError> {
+        Ok(self.legs().to_vec())
+    }
+
+    fn get_position(
+        &mut self,
+        option_style: &OptionStyle,
+        side: &Side,
+        strike: &Positive,
+    ) -> Result<Vec<&mut Position>, PositionError> {
+        let positions: Vec<&mut Position> = self
+            .legs_mut()
+            .into_iter()
+            .filter(|leg| {
+                leg.option.option_style == *option_style
+                    && leg.option.side == *side
+                    && leg.option.strike_price == *strike
+            })
+            .collect();
+        if positions.is_empty() {
+            return Err(PositionError::invalid_position_type(
+                *side,
+                "Strike not found in positions".to_string(),
+            ));
+        }
+        Ok(positions)
+    }
+
+    fn modify_position(&mut self, position: &Position) -> Result<(), PositionError> {
+        if !position.validate() {
+            return Err(PositionError::ValidationError(
+                PositionValidationErrorKind::InvalidPosition {
+                    reason: "Invalid position data".to_string(),
+                },
+            ));
+        }
+        let option = &position.option;
+        let slot = self.legs_mut().into_iter().find(|leg| {
+            leg.option.option_style == option.option_style
+                && leg.option.side == option.side
+                && leg.option.strike_price == option.strike_price
+        });
+        match slot {
+            Some(slot) => {
+                *slot = position.clone();
+                Ok(())
+            }
+            None => Err(PositionError::invalid_position_type(
+                option.side,
+                "Strike not found in positions".to_string(),
+            )),
+        }
+    }
+}
+
+impl Strategable for BrokenWingButterfly {
+    fn info(&self) -> Result<StrategyBasics, StrategyError> {
+        Ok(StrategyBasics {
+            name: self.name.clone(),
+            kind: self.kind.clone(),
+            description: self.description.clone(),
+        })
+    }
+}
+
+impl BasicAble for BrokenWingButterfly {
+    fn get_title(&self) -> String {
+        let legs: Vec<String> = self.legs().iter().map(|leg| leg.get_title()).collect();
+        format!("{:?} Strategy: \n\t{}", self.kind, legs.join("\n\t"))
+    }
+
+    fn get_option_basic_type(&self) -> HashSet<OptionBasicType<'_>> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                OptionBasicType {
+                    option_style: &option.option_style,
+                    side: &option.side,
+                    strike_price: &option.strike_price,
+                    expiration_date: &option.expiration_date,
+                }
+            })
+            .collect()
+    }
+
+    fn get_implied_volatility(&self) -> HashMap<OptionBasicType<'_>, &Positive> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                (
+                    OptionBasicType {
+                        option_style: &option.option_style,
+                        side: &option.side,
+                        strike_price: &option.strike_price,
+                        expiration_date: &option.expiration_date,
+                    },
+                    &option.implied_volatility,
+                )
+            })
+            .collect()
+    }
+
+    fn get_quantity(&self) -> HashMap<OptionBasicType<'_>, &Positive> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                (
+                    OptionBasicType {
+                        option_style: &option.option_style,
+                        side: &option.side,
+                        strike_price: &option.strike_price,
+                        expiration_date: &option.expiration_date,
+                    },
+                    &option.quantity,
+                )
+            })
+            .collect()
+    }
+
+    fn one_option(&self) -> &Options {
+        self.body.one_option()
+    }
+
+    fn one_option_mut(&mut self) -> &mut Options {
+        self.body.one_option_mut()
+    }
+
+    fn set_expiration_date(
+        &mut self,
+        expiration_date: ExpirationDate,
+    ) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.expiration_date = expiration_date;
+        }
+        Ok(())
+    }
+
+    fn set_underlying_price(&mut self, price: &Positive) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.underlying_price = *price;
+            leg.premium = Positive::new_decimal(leg.option.calculate_price_black_scholes()?.abs())
+                .unwrap_or(Positive::ZERO);
+        }
+        Ok(())
+    }
+
+    fn set_implied_volatility(&mut self, volatility: &Positive) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.implied_volatility = *volatility;
+            leg.premium = Positive::new_decimal(leg.option.calculate_price_black_scholes()?.abs())
+                .unwrap_or(Positive::ZERO);
+        }
+        Ok(())
+    }
+}
+
+impl Strategies for BrokenWingButterfly {
+    fn get_max_profit(&self) -> Result<Positive, StrategyError> {
+        let max = self
+            .payoff_nodes()?
+            .into_iter()
+            .map(|(_, profit)| profit)
+            .fold(Decimal::MIN, Decimal::max);
+        if max <= Decimal::ZERO {
+            return Err(StrategyError::ProfitLossError(
+                ProfitLossErrorKind::MaxProfitError {
+                    reason: "Max profit is negative".to_string(),
+                },
+            ));
+        }
+        Ok(Positive::new_decimal(max)?)
+    }
+
+    fn get_max_loss(&self) -> Result<Positive, StrategyError> {
+        let min = self
+            .payoff_nodes()?
+            .into_iter()
+            .map(|(_, profit)| profit)
+            .fold(Decimal::MAX, Decimal::min);
+        Ok(Positive::new_decimal(min.min(Decimal::ZERO).abs())?)
+    }
+
+    /// Area of the profitable region at expiration, from one wing width
+    /// below the lowest strike to one wing width above the highest, divided
+    /// by the underlying price.
+    fn get_profit_area(&self) -> Result<Decimal, StrategyError> {
+        let (low, high) = self.wing_strikes();
+        let (lower_width, upper_width) = self.wing_widths();
+        let floor =
+            Positive::new_decimal((low.to_dec() - lower_width.to_dec()).max(Decimal::ZERO))?;
+        let mut prices: Vec<Positive> = self
+            .legs()
+            .iter()
+            .map(|leg| leg.option.strike_price)
+            .chain(self.break_even_points.iter().copied())
+            .chain([floor, high + upper_width])
+            .collect();
+        prices.sort();
+        let mut area = Decimal::ZERO;
+        for pair in prices.windows(2) {
+            let [x0, x1] = pair else {
+                continue;
+            };
+            let mean =
+                (self.calculate_profit_at(x0)? + self.calculate_profit_at(x1)?) / Decimal::TWO;
+            if mean > Decimal::ZERO {
+                area += mean * (x1.to_dec() - x0.to_dec());
+            }
+        }
+        let underlying = self.get_underlying_price().to_dec();
+        if underlying.is_zero() {
+            return Ok(Decimal::ZERO);
+        }
+        Ok(area / underlying)
+    }
+
+    /// Max profit over max loss as a percentage; `Decimal::MAX` when the
+    /// credit covers the broken wing and nothing can be lost.
+    fn get_profit_ratio(&self) -> Result<Decimal, StrategyError> {
+        let Ok(profit) = self.get_max_profit() else {
+            return Ok(Decimal::ZERO);
+        };
+        let loss = self.get_max_loss()?;
+        if loss == Positive::ZERO {
+            return Ok(Decimal::MAX);
+        }
+        Ok(profit.to_dec() / loss.to_dec() * Decimal::ONE_HUNDRED)
+    }
+}
+
+impl Optimizable for BrokenWingButterfly {
+    type Strategy = BrokenWingButterfly;
+
+    /// Yields the strike triples that build a credit butterfly broken on the
+    /// side of this strategy's option style, across every pair of wing widths.
+    fn filter_combinations<'a>(
+        &'a self,
+        option_chain: &'a OptionChain,
+        side: FindOptimalSide,
+    ) -> impl Iterator<Item = OptionDataGroup<'a>> {
+        let underlying_price = self.get_underlying_price();
+        let strategy = self.clone();
+        option_chain
+            .get_triple_iter()
+            .filter(move |(lower, body, upper)| {
+                if side == FindOptimalSide::Center {
+                    lower.is_valid_optimal_side(underlying_price, &FindOptimalSide::Lower)
+                        && upper.is_valid_optimal_side(underlying_price, &FindOptimalSide::Upper)
+                } else {
+                    [lower, body, upper]
+                        .iter()
+                        .all(|option| option.is_valid_optimal_side(underlying_price, &side))
+                }
+            })
+            .filter(move |(lower, body, upper)| {
+                let legs = StrategyLegs::ThreeLegs {
+                    first: lower,
+                    second: body,
+                    third: upper,
+                };
+                match strategy.create_strategy(option_chain, &legs) {
+                    Ok(s) => s.get_max_profit().is_ok(),
+                    Err(_) => false,
+                }
+            })
+            .map(|(lower, body, upper)| OptionDataGroup::Three(lower, body, upper))
+    }
+
+    fn find_optimal(
+        &mut self,
+        option_chain: &OptionChain,
+        side: FindOptimalSide,
+        criteria: OptimizationCriteria,
+    ) {
+        let mut best_value = Decimal::MIN;
+        let strategy_clone = self.clone();
+        for option_data_group in strategy_clone.filter_combinations(option_chain, side) {
+            let OptionDataGroup::Three(first, second, third) = option_data_group else {
+                continue;
+            };
+            let legs = StrategyLegs::ThreeLegs {
+                first,
+                second,
+                third,
+            };
+            let strategy = match self.create_strategy(option_chain, &legs) {
+                Ok(s) => s,
+                Err(e) => {
+                    debug!("Skipping broken-wing butterfly candidate: {}", e);
+                    continue;
+                }
+            };
+            let metric = match criteria {
+                OptimizationCriteria::Ratio => strategy.get_profit_ratio(),
+                OptimizationCriteria::Area => strategy.get_profit_area(),
+            };
+            match metric {
+                Ok(value) if value > best_value => {
+                    info!("Found better value: {}", value);
+                    best_value = value;
+                    *self = strategy;
+                }
+                Ok(_) => {}
+                Err(e) => debug!("Skipping candidate with unscorable metric: {}", e),
+            }
+        }
+    }
+
+    /// Builds a broken-wing butterfly on the three strikes, in ascending
+    /// order, filling the wings at the ask and the body at the bid.
+    fn create_strategy(
+        &self,
+        chain: &OptionChain,
+        legs: &StrategyLegs,
+    ) -> Result<Self::Strategy, StrategyError> {
+        let StrategyLegs::ThreeLegs {
+            first,
+            second,
+            third,
+        } = legs
+        else {
+            return Err(StrategyError::operation_not_supported(
+                "create_strategy",
+                "BrokenWingButterfly requires exactly three legs (ThreeLegs)",
+            ));
+        };
+        let template = &self.lower_wing;
+        let expiration = chain
+            .get_expiration()
+            .unwrap_or(template.option.expiration_date);
+        let leg = |strike, side, quantity| {
+            chain_leg(
+                chain,
+                expiration,
+                strike,
+                self.body.option.option_style,
+                side,
+                quantity,
+                template.open_fee,
+                template.close_fee,
+            )
+        };
+        let quantity = template.option.quantity;
+        Self::from_legs(
+            leg(first.strike_price, Side::Long, quantity)?,
+            leg(second.strike_price, Side::Short, quantity * Positive::TWO)?,
+            leg(third.strike_price, Side::Long, quantity)?,
+        )
+    }
+}
+
+impl Profit for BrokenWingButterfly {
+    fn calculate_profit_at(&self, price: &Positive) -> Result<Decimal, PricingError> {
+        let price = Some(price);
+        self.legs()
+            .iter()
+            .map(|leg| leg.pnl_at_expiration(&price))
+            .sum()
+    }
+}
+
+impl ProbabilityAnalysis for BrokenWingButterfly {
+    fn get_profit_ranges(&self) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        self.ranges(true)
+    }
+
+    fn get_loss_ranges(&self) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        self.ranges(false)
+    }
+}
+
+impl Greeks for BrokenWingButterfly {
+    fn get_options(&self) -> Result<Vec<&Options>, GreeksError> {
+        Ok(self.legs().map(|leg| &leg.option).to_vec())
+    }
+}
+
+impl DeltaNeutrality for BrokenWingButterfly {}
+
+impl ButterflyStrategy for BrokenWingButterfly {
+    fn wing_strikes(&self) -> (Positive, Positive) {
+        (
+            self.lower_wing.option.strike_price,
+            self.upper_wing.option.strike_price,
+        )
+    }
+
+    fn body_strike(&self) -> Positive {
+        self.body.option.strike_price
+    }
+
+    fn get_butterfly_positions(&self) -> Vec<&Position> {
+        self.legs().to_vec()
+    }
+}
+
+impl PnLCalculator for BrokenWingButterfly {
+    fn calculate_pnl(
+        &self,
+        market_price: &Positive,
+        expiration_date: ExpirationDate,
+        implied_volatility: &Positive,
+    ) -> Result<PnL, PricingError> {
+        let mut pnl = PnL::default();
+        for leg in self.legs() {
+            pnl = pnl + leg.calculate_pnl(market_price, expiration_date, implied_volatility)?;
+        }
+        Ok(pnl)
+    }
+
+    fn calculate_pnl_at_expiration(
+        &self,
+        underlying_price: &Positive,
+    ) -> Result<PnL, PricingError> {
+        let mut pnl = PnL::default();
+        for leg in self.legs() {
+            pnl = pnl + leg.calculate_pnl_at_expiration(underlying_price)?;
+        }
+        Ok(pnl)
+    }
+}
+
+test_strategy_traits!(
+    BrokenWingButterfly,
+    test_broken_wing_butterfly_implementations
+);
+
+#[cfg(test)]
+mod tests_broken_wing_butterfly {
+    use super::*;
+    use crate::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
+    use positive::{pos_or_panic, spos};
+    use rust_decimal_macros::dec;
+
+    fn butterfly(
+        option_style: OptionStyle,
+        strikes: [f64; 3],
+        premiums: [f64; 3],
+    ) -> Result<BrokenWingButterfly, StrategyError> {
+        let [lower, body, upper] = strikes.map(|strike| pos_or_panic!(strike));
+        let [premium_lower, premium_body, premium_upper] =
+            premiums.map(|premium| pos_or_panic!(premium));
+        BrokenWingButterfly::new(
+            "SPY".to_string(),
+            Positive::HUNDRED,
+            option_style,
+            lower,
+            body,
+            upper,
+            ExpirationDate::Days(pos_or_panic!(30.0)),
+            pos_or_panic!(0.2),
+            dec!(0.03),
+            Positive::ZERO,
+            Positive::ONE,
+            premium_lower,
+            premium_body,
+            premium_upper,
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+    }
+
+    #[test]
+    fn test_call_version_has_no_downside_risk() {
+        // Credit of 2 * 3.6 - 6.5 - 0.6 = 0.1.
+        let strategy = butterfly(OptionStyle::Call, [95.0, 100.0, 110.0], [6.5, 3.6, 0.6]).unwrap();
+        assert_eq!(strategy.kind, StrategyType::CallBrokenWingButterfly);
+        assert_eq!(strategy.credit().unwrap(), dec!(0.1));
+        assert_eq!(
+            strategy.wing_widths(),
+            (pos_or_panic!(5.0), pos_or_panic!(10.0))
+        );
+        assert_eq!(strategy.get_max_profit().unwrap(), pos_or_panic!(5.1));
+        assert_eq!(strategy.get_max_loss().unwrap(), pos_or_panic!(4.9));
+        assert_eq!(
+            strategy.get_break_even_points().unwrap(),
+            &vec![pos_or_panic!(105.1)]
+        );
+        assert!(
+            strategy
+                .calculate_profit_at(&pos_or_panic!(50.0))
+                .unwrap()
+                .is_sign_positive()
+        );
+    }
+
+    #[test]
+    fn test_put_version_has_no_upside_risk() {
+        let strategy = butterfly(OptionStyle::Put, [90.0, 100.0, 105.0], [0.6, 3.6, 6.5]).unwrap();
+        assert_eq!(strategy.kind, StrategyType::PutBrokenWingButterfly);
+        assert_eq!(
+            strategy.calculate_profit_at(&pos_or_panic!(150.0)).unwrap(),
+            dec!(0.1)
+        );
+        assert_eq!(strategy.get_max_loss().unwrap(), pos_or_panic!(4.9));
+        assert_eq!(
+            strategy.get_break_even_points().unwrap(),
+            &vec![pos_or_panic!(94.9)]
+        );
+    }
+
+    #[test]
+    fn test_rejects_debits_and_unbroken_wings() {
+        // Opened for a debit.
+        assert!(butterfly(OptionStyle::Call, [95.0, 100.0, 110.0], [6.5, 3.0, 0.6]).is_err());
+        // Symmetric wings.
+        assert!(butterfly(OptionStyle::Call, [95.0, 100.0, 105.0], [6.5, 3.6, 0.6]).is_err());
+        // Broken on the wrong side for the style.
+        assert!(butterfly(OptionStyle::Put, [95.0, 100.0, 110.0], [0.6, 3.6, 6.5]).is_err());
+    }
+
+    #[test]
+    fn test_optimizer_searches_asymmetric_wings() {
+        let params = OptionChainBuildParams::new(
+            "SPY".to_string(),
+            spos!(1000.0),
+            15,
+            spos!(5.0),
+            dec!(-0.3),
+            dec!(0.1),
+            pos_or_panic!(0.02),
+            2,
+            OptionDataPriceParams::new(
+                Some(Box::new(Positive::HUNDRED)),
+                Some(ExpirationDate::Days(pos_or_panic!(30.0))),
+                Some(dec!(0.03)),
+                spos!(0.0),
+                Some("SPY".to_string()),
+            ),
+            pos_or_panic!(0.2),
+        );
+        let chain = OptionChain::build_chain(&params).unwrap();
+        for (option_style, premiums) in [
+            (OptionStyle::Call, [6.5, 3.6, 0.6]),
+            (OptionStyle::Put, [0.6, 3.6, 6.5]),
+        ] {
+            let strikes = match option_style {
+                OptionStyle::Call => [95.0, 100.0, 110.0],
+                OptionStyle::Put => [90.0, 100.0, 105.0],
+            };
+            let original = butterfly(option_style, strikes, premiums).unwrap();
+            let mut strategy = original.clone();
+            strategy.get_best_area(&chain, FindOptimalSide::All);
+            assert!(strategy.validate());
+            assert_ne!(strategy.wing_strikes(), original.wing_strikes());
+            let (lower_width, upper_width) = strategy.wing_widths();
+            assert_ne!(lower_width, upper_width);
+            assert!(strategy.credit().unwrap() >= Decimal::ZERO);
+        }
+    }
+
+    #[test]
+    fn test_probabilities_sum_to_one() {
+        let strategy = butterfly(OptionStyle::Call, [95.0, 100.0, 110.0], [6.5, 3.6, 0.6]).unwrap();
+        let total: Positive = strategy
+            .get_profit_ranges()
+            .unwrap()
+            .iter()
+            .chain(strategy.get_loss_ranges().unwrap().iter())
+            .map(|range| range.probability)
+            .sum();
+        assert!((total.to_dec() - Decimal::ONE).abs() < dec!(0.01));
+    }
+}
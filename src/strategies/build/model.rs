@@ -9,9 +9,11 @@ use crate::model::Position;
 use crate::strategies::base::StrategyType;
 use crate::strategies::custom::CustomStrategy;
 use crate::strategies::{
-    BearCallSpread, BearPutSpread, BullCallSpread, BullPutSpread, CallButterfly, IronButterfly,
-    IronCondor, LongButterflySpread, LongStraddle, LongStrangle, PoorMansCoveredCall,
-    ShortButterflySpread, ShortStraddle, ShortStrangle, Strategable, StrategyConstructor,
+    BearCallSpread, BearPutSpread, BoxSpread, BrokenWingButterfly, BullCallSpread, BullPutSpread,
+    CallButterfly, DiagonalSpread, Fence, IronButterfly, IronCondor, Lizard, LongButterflySpread,
+    LongCalendarSpread, LongCondor, LongStraddle, LongStrangle, PoorMansCoveredCall, RatioSpread,
+    RiskReversal, Seagull, ShortButterflySpread, ShortCalendarSpread, ShortCondor, ShortStraddle,
+    ShortStrangle, Strategable, StrategyConstructor,
 };
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -129,6 +131,40 @@ impl StrategyRequest {
             StrategyType::CallButterfly => {
                 Ok(Box::new(CallButterfly::get_strategy(&self.positions)?))
             }
+            StrategyType::LongCalendarSpread => {
+                Ok(Box::new(LongCalendarSpread::get_strategy(&self.positions)?))
+            }
+            StrategyType::ShortCalendarSpread => Ok(Box::new(ShortCalendarSpread::get_strategy(
+                &self.positions,
+            )?)),
+            StrategyType::DiagonalSpread => {
+                Ok(Box::new(DiagonalSpread::get_strategy(&self.positions)?))
+            }
+            StrategyType::CallRatioSpread
+            | StrategyType::PutRatioSpread
+            | StrategyType::CallBackspread
+            | StrategyType::PutBackspread => {
+                Ok(Box::new(RatioSpread::get_strategy(&self.positions)?))
+            }
+            StrategyType::LongCallCondor | StrategyType::LongPutCondor => {
+                Ok(Box::new(LongCondor::get_strategy(&self.positions)?))
+            }
+            StrategyType::ShortCallCondor | StrategyType::ShortPutCondor => {
+                Ok(Box::new(ShortCondor::get_strategy(&self.positions)?))
+            }
+            StrategyType::CallBrokenWingButterfly | StrategyType::PutBrokenWingButterfly => Ok(
+                Box::new(BrokenWingButterfly::get_strategy(&self.positions)?),
+            ),
+            StrategyType::JadeLizard | StrategyType::BigLizard => {
+                Ok(Box::new(Lizard::get_strategy(&self.positions)?))
+            }
+            StrategyType::BoxSpread => Ok(Box::new(BoxSpread::get_strategy(&self.positions)?)),
+            StrategyType::Conversion | StrategyType::Reversal => Err(StrategyError::NotImplemented),
+            StrategyType::RiskReversal => {
+                Ok(Box::new(RiskReversal::get_strategy(&self.positions)?))
+            }
+            StrategyType::Seagull => Ok(Box::new(Seagull::get_strategy(&self.positions)?)),
+            StrategyType::Fence => Ok(Box::new(Fence::get_strategy(&self.positions)?)),
             StrategyType::Custom => Ok(Box::new(CustomStrategy::get_strategy(&self.positions)?)),
         }
     }
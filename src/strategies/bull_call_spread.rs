@@ -2986,6 +2986,129 @@ mod tests_bull_call_spread_pnl {
         assert!(pnl.unrealized.unwrap() < dec!(3.0)); // But not too much gain
     }
 
+    #[test]
+    fn test_calculate_pnl_with_smile() {
+        use crate::strategies::Strategies;
+        use crate::volatility::{SmileDynamics, StickySmile};
+
+        let spread = create_test_bull_call_spread().unwrap();
+        let market_price = pos_or_panic!(98.0);
+        let expiration_date = spread.long_call.option.expiration_date;
+        let skew = vec![
+            (pos_or_panic!(90.0), pos_or_panic!(0.25)),
+            (Positive::HUNDRED, pos_or_panic!(0.2)),
+            (pos_or_panic!(110.0), pos_or_panic!(0.15)),
+        ];
+        let smile = StickySmile::new(
+            Positive::HUNDRED,
+            expiration_date.get_years().unwrap(),
+            dec!(0.05),
+            Positive::ZERO,
+            skew,
+            SmileDynamics::StickyStrike,
+        )
+        .unwrap();
+
+        // Sticky strike on a flat smile reduces to the fixed-volatility P&L.
+        let flat = StickySmile::new(
+            Positive::HUNDRED,
+            expiration_date.get_years().unwrap(),
+            dec!(0.05),
+            Positive::ZERO,
+            vec![(Positive::HUNDRED, pos_or_panic!(0.2))],
+            SmileDynamics::StickyStrike,
+        )
+        .unwrap();
+        let flat_pnl = spread
+            .calculate_pnl_with_smile(&market_price, &flat)
+            .unwrap();
+        let fixed_pnl = spread
+            .calculate_pnl(&market_price, expiration_date, &pos_or_panic!(0.2))
+            .unwrap();
+        assert_decimal_eq!(
+            flat_pnl.unrealized.unwrap(),
+            fixed_pnl.unrealized.unwrap(),
+            dec!(1e-9)
+        );
+
+        // After a sell-off, sticky moneyness reads lower volatilities off a
+        // put skew than sticky strike does, so the long-vega spread is worth less.
+        let sticky_strike = spread
+            .calculate_pnl_with_smile(&market_price, &smile)
+            .unwrap();
+        let sticky_moneyness = spread
+            .calculate_pnl_with_smile(
+                &market_price,
+                &smile.with_dynamics(SmileDynamics::StickyMoneyness),
+            )
+            .unwrap();
+        assert!(sticky_moneyness.unrealized.unwrap() < sticky_strike.unrealized.unwrap());
+    }
+
+    #[test]
+    fn test_valuation_grid_with_smile() {
+        use crate::pricing::PricingEngine;
+        use crate::strategies::Strategies;
+        use crate::volatility::{SmileDynamics, StickySmile};
+
+        let spread = create_test_bull_call_spread().unwrap();
+        let years = spread.long_call.option.expiration_date.get_years().unwrap();
+        let engine = PricingEngine::ClosedFormBS;
+        let price = pos_or_panic!(98.0);
+        let today = ExpirationDate::Days(Positive::ZERO);
+        let flat = StickySmile::new(
+            Positive::HUNDRED,
+            years,
+            dec!(0.05),
+            Positive::ZERO,
+            vec![(Positive::HUNDRED, pos_or_panic!(0.2))],
+            SmileDynamics::StickyStrike,
+        )
+        .unwrap();
+        let skewed = StickySmile::new(
+            Positive::HUNDRED,
+            years,
+            dec!(0.05),
+            Positive::ZERO,
+            vec![
+                (pos_or_panic!(90.0), pos_or_panic!(0.25)),
+                (Positive::HUNDRED, pos_or_panic!(0.2)),
+                (pos_or_panic!(110.0), pos_or_panic!(0.15)),
+            ],
+            SmileDynamics::StickyMoneyness,
+        )
+        .unwrap();
+
+        // A flat smile at the legs' own volatility leaves the valuation unchanged.
+        let fixed = spread
+            .pnl_at_date(&price, &today, Decimal::ZERO, None, &engine)
+            .unwrap();
+        let on_flat = spread
+            .pnl_at_date(&price, &today, Decimal::ZERO, Some(&flat), &engine)
+            .unwrap();
+        assert_decimal_eq!(fixed, on_flat, dec!(1e-9));
+
+        let on_skew = spread
+            .pnl_at_date(&price, &today, Decimal::ZERO, Some(&skewed), &engine)
+            .unwrap();
+        assert_ne!(on_skew, fixed);
+
+        let grid = spread
+            .valuation_grid(
+                &[price],
+                &[today],
+                &[Decimal::ZERO, dec!(0.05)],
+                Some(&skewed),
+                &engine,
+            )
+            .unwrap();
+        assert_eq!(grid.value_at(0, 0, 0), Some(on_skew));
+        let shifted = spread
+            .pnl_at_date(&price, &today, dec!(0.05), Some(&skewed), &engine)
+            .unwrap();
+        assert_eq!(grid.value_at(0, 1, 0), Some(shifted));
+    }
+
     #[test]
     fn test_calculate_pnl_at_expiration_max_profit() {
         let spread = create_test_bull_call_spread().unwrap();
@@ -702,6 +702,10 @@ impl BasicAble for Collar {
 }
 
 impl Strategies for Collar {
+    fn underlying_pnl_at(&self, underlying_price: &Positive) -> Decimal {
+        self.spot_leg.pnl_at_price(*underlying_price)
+    }
+
     fn get_max_profit(&self) -> Result<Positive, StrategyError> {
         self.max_profit_potential().map_err(StrategyError::from)
     }
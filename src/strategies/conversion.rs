@@ -0,0 +1,688 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Conversion and Reversal Strategies
+//!
+//! A conversion holds the underlying and sells it forward synthetically with
+//! a short call and a long put on the same strike. A reversal (or reverse
+//! conversion) is the mirror image: short the underlying, long the call and
+//! short the put.
+//!
+//! Either way the underlying changes hands at the strike at expiration, so
+//! the position's net cost today implies a financing rate: a conversion
+//! lends cash against the underlying and a reversal borrows it.
+//!
+//! The option legs hold one option per unit of the underlying so the
+//! synthetic forward exactly offsets the spot leg.
+
+use super::base::{
+    BreakEvenable, Optimizable, Positionable, Strategable, StrategyBasics, StrategyType, Validable,
+};
+use super::shared::{EarlyAssignmentRisk, early_assignment_exposure, implied_financing_rate};
+use crate::error::position::{PositionError, PositionValidationErrorKind};
+use crate::error::probability::ProbabilityError;
+use crate::error::strategies::{ProfitLossErrorKind, StrategyError};
+use crate::error::{GreeksError, PricingError};
+use crate::greeks::Greeks;
+use crate::model::ProfitLossRange;
+use crate::model::leg::traits::LegAble;
+use crate::model::leg::{Leg, SpotPosition};
+use crate::model::position::Position;
+use crate::model::types::{OptionBasicType, OptionStyle, OptionType, Side};
+use crate::pnl::{PnLCalculator, utils::PnL};
+use crate::pricing::payoff::Profit;
+use crate::strategies::delta_neutral::DeltaNeutrality;
+use crate::strategies::probabilities::{core::ProbabilityAnalysis, utils::VolatilityAdjustment};
+use crate::strategies::{BasicAble, Strategies, StrategyConstructor};
+use crate::{ExpirationDate, Options, test_strategy_traits};
+use chrono::Utc;
+use positive::Positive;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tracing::debug;
+use utoipa::ToSchema;
+
+/// Default description for the Conversion and Reversal strategies.
+pub const CONVERSION_DESCRIPTION: &str = "A conversion buys the underlying and sells it forward \
+    with a short call and a long put on the same strike; a reversal shorts the underlying and buys \
+    it forward with a long call and a short put. The underlying changes hands at the strike at \
+    expiration, so the net cost implies a financing rate.";
+
+/// Represents a Conversion or a Reversal.
+///
+/// The kind follows the spot leg: long for a conversion, short for a
+/// reversal. The call and put take the opposite and the same side as the
+/// spot leg respectively.
+#[derive(Clone, DebugPretty, DisplaySimple, Serialize, Deserialize, ToSchema)]
+pub struct Conversion {
+    /// Name identifier for this specific strategy instance
+    pub name: String,
+    /// `Conversion` or `Reversal`
+    pub kind: StrategyType,
+    /// Detailed description of this strategy instance
+    pub description: String,
+    /// Always empty: the position settles at the strike regardless of price
+    pub break_even_points: Vec<Positive>,
+    /// The position in the underlying
+    pub spot_leg: SpotPosition,
+    /// The call, short in a conversion
+    pub(super) call: Position,
+    /// The put, long in a conversion
+    pub(super) put: Position,
+}
+
+impl Conversion {
+    /// Creates a Conversion when `spot_side` is `Long` or a Reversal when it
+    /// is `Short`. `quantity` is in units of the underlying and applies to
+    /// every leg.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyError` if a leg fails validation.
+    #[allow(clippy::too_many_arguments)]
+    #[inline(never)]
+    pub fn new(
+        underlying_symbol: String,
+        underlying_price: Positive,
+        spot_side: Side,
+        strike: Positive,
+        expiration: ExpirationDate,
+        implied_volatility: Positive,
+        risk_free_rate: Decimal,
+        dividend_yield: Positive,
+        quantity: Positive,
+        premium_call: Positive,
+        premium_put: Positive,
+        spot_open_fee: Positive,
+        spot_close_fee: Positive,
+        option_open_fee: Positive,
+        option_close_fee: Positive,
+    ) -> Result<Self, StrategyError> {
+        let spot_leg = SpotPosition::new(
+            underlying_symbol.clone(),
+            quantity,
+            underlying_price,
+            spot_side,
+            Utc::now(),
+            spot_open_fee,
+            spot_close_fee,
+        );
+        let leg = |option_style, side, premium| {
+            Position::new(
+                Options::new(
+                    OptionType::European,
+                    side,
+                    underlying_symbol.clone(),
+                    strike,
+                    expiration,
+                    implied_volatility,
+                    quantity,
+                    underlying_price,
+                    risk_free_rate,
+                    option_style,
+                    dividend_yield,
+                    None,
+                ),
+                premium,
+                Utc::now(),
+                option_open_fee,
+                option_close_fee,
+                None,
+                None,
+            )
+        };
+        let call_side = match spot_side {
+            Side::Long => Side::Short,
+            Side::Short => Side::Long,
+        };
+        Self::from_legs(
+            spot_leg,
+            leg(OptionStyle::Call, call_side, premium_call),
+            leg(OptionStyle::Put, spot_side, premium_put),
+        )
+    }
+
+    fn from_legs(
+        spot_leg: SpotPosition,
+        call: Position,
+        put: Position,
+    ) -> Result<Self, StrategyError> {
+        let (kind, name) = match spot_leg.side {
+            Side::Long => (StrategyType::Conversion, "Conversion"),
+            Side::Short => (StrategyType::Reversal, "Reversal"),
+        };
+        let mut strategy = Conversion {
+            name: name.to_string(),
+            kind,
+            spot_leg,
+            call,
+            put,
+            ..Default::default()
+        };
+        if !strategy.validate() {
+            return Err(StrategyError::invalid_parameters(
+                "Conversion",
+                "requires a spot leg with a call on the opposite side and a put on the same \
+                 side, sharing strike, expiration and quantity",
+            ));
+        }
+        strategy.update_break_even_points()?;
+        Ok(strategy)
+    }
+
+    fn options(&self) -> [&Position; 2] {
+        [&self.call, &self.put]
+    }
+
+    fn options_mut(&mut self) -> [&mut Position; 2] {
+        [&mut self.call, &mut self.put]
+    }
+
+    /// Returns the spot and option legs of the strategy.
+    #[must_use]
+    pub fn get_legs(&self) -> Vec<Leg> {
+        vec![
+            Leg::Spot(self.spot_leg.clone()),
+            Leg::option(self.call.clone()),
+            Leg::option(self.put.clone()),
+        ]
+    }
+
+    /// Returns the strike shared by the call and the put.
+    #[must_use]
+    pub fn strike(&self) -> Positive {
+        self.call.option.strike_price
+    }
+
+    /// The amount exchanged for the underlying at expiration: the strike
+    /// times the quantity.
+    #[must_use]
+    pub fn settlement(&self) -> Positive {
+        self.strike() * self.spot_leg.quantity
+    }
+
+    /// The cash paid to open a conversion, or raised by opening a reversal,
+    /// net of every fee.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PricingError` if an option leg's payoff cannot be computed.
+    pub fn present_value(&self) -> Result<Decimal, PricingError> {
+        let profit = self.calculate_profit_at(&self.spot_leg.cost_basis)?;
+        Ok(match self.spot_leg.side {
+            Side::Long => self.settlement().to_dec() - profit,
+            Side::Short => self.settlement().to_dec() + profit,
+        })
+    }
+
+    /// Annualized, continuously compounded rate implied by the position
+    /// after fees: the lending yield of a conversion or the borrowing cost
+    /// of a reversal.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyError` if the expiration cannot be resolved or the
+    /// present value is not positive.
+    pub fn implied_rate(&self) -> Result<Decimal, StrategyError> {
+        let years = self
+            .call
+            .option
+            .expiration_date
+            .get_years()
+            .map_err(PricingError::from)?;
+        implied_financing_rate(self.present_value()?, self.settlement().to_dec(), years)
+    }
+
+    /// How much better the position finances than `risk_free_rate`: the
+    /// implied yield above it for a conversion, the implied cost below it
+    /// for a reversal. Positive values beat the risk-free rate.
+    ///
+    /// # Errors
+    ///
+    /// Propagates the errors of [`implied_rate`](Self::implied_rate).
+    pub fn financing_edge(&self) -> Result<Decimal, StrategyError> {
+        let rate = self.implied_rate()?;
+        let risk_free_rate = self.call.option.risk_free_rate;
+        Ok(match self.spot_leg.side {
+            Side::Long => rate - risk_free_rate,
+            Side::Short => risk_free_rate - rate,
+        })
+    }
+
+    /// Early-assignment exposure of the short option if it is American.
+    /// Assignment would unwind the spot leg before expiration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PricingError` if the option's time to expiration cannot be
+    /// resolved.
+    pub fn early_assignment_exposure(&self) -> Result<Vec<EarlyAssignmentRisk>, PricingError> {
+        early_assignment_exposure(&self.options())
+    }
+
+    fn range(&self) -> Result<ProfitLossRange, ProbabilityError> {
+        let option = &self.call.option;
+        let mut range = ProfitLossRange::new(None, None, Positive::ZERO)?;
+        range.calculate_probability(
+            &self.spot_leg.cost_basis,
+            Some(VolatilityAdjustment {
+                base_volatility: option.implied_volatility,
+                std_dev_adjustment: Positive::ZERO,
+            }),
+            None,
+            &option.expiration_date,
+            Some(option.risk_free_rate),
+        )?;
+        Ok(range)
+    }
+}
+
+impl StrategyConstructor for Conversion {}
+
+impl BreakEvenable for Conversion {
+    fn get_break_even_points(&self) -> Result<&Vec<Positive>, StrategyError> {
+        Ok(&self.break_even_points)
+    }
+
+    /// The profit is the same at every price, so there is no break-even.
+    fn update_break_even_points(&mut self) -> Result<(), StrategyError> {
+        self.break_even_points.clear();
+        Ok(())
+    }
+}
+
+impl Validable for Conversion {
+    fn validate(&self) -> bool {
+        if self.spot_leg.quantity == Positive::ZERO {
+            debug!("Invalid: Spot quantity is zero");
+            return false;
+        }
+        if !self.call.validate() || !self.put.validate() {
+            debug!("Conversion option legs failed validation");
+            return false;
+        }
+        if self.call.option.option_style != OptionStyle::Call
+            || self.put.option.option_style != OptionStyle::Put
+        {
+            debug!("Conversion requires one call and one put");
+            return false;
+        }
+        if self.put.option.side != self.spot_leg.side || self.call.option.side == self.spot_leg.side
+        {
+            debug!("Conversion put must share the spot side and the call must oppose it");
+            return false;
+        }
+        let (call, put) = (&self.call.option, &self.put.option);
+        if call.strike_price != put.strike_price
+            || call.expiration_date != put.expiration_date
+            || call.quantity != self.spot_leg.quantity
+            || put.quantity != self.spot_leg.quantity
+        {
+            debug!("Conversion legs must share strike, expiration and quantity");
+            return false;
+        }
+        true
+    }
+}
+
+impl Positionable for Conversion {
+    /// Replaces the option leg with the same style.
+    fn add_position(&mut self, position: &Position) -> Result<(), PositionError> {
+        match position.option.option_style {
+            OptionStyle::Call => self.call = position.clone(),
+            OptionStyle::Put => self.put = position.clone(),
+        }
+        Ok(())
+    }
+
+    fn get_positions(&self) -> Result<Vec<&Position>, PositionError> {
+        Ok(self.options().to_vec())
+    }
+
+    fn get_position(
+        &mut self,
+        option_style: &OptionStyle,
+        side: &Side,
+        strike: &Positive,
+    ) -> Result<Vec<&mut Position>, PositionError> {
+        let positions: Vec<&mut Position> = self
+            .options_mut()
+            .into_iter()
+            .filter(|leg| {
+                leg.option.option_style == *option_style
+                    && leg.option.side == *side
+                    && leg.option.strike_price == *strike
+            })
+            .collect();
+        if positions.is_empty() {
+            return Err(PositionError::invalid_position(
+                "Position not found in Conversion",
+            ));
+        }
+        Ok(positions)
+    }
+
+    fn modify_position(&mut self, position: &Position) -> Result<(), PositionError> {
+        if !position.validate() {
+            return Err(PositionError::ValidationError(
+                PositionValidationErrorKind::InvalidPosition {
+                    reason: "Invalid position data".to_string(),
+                },
+            ));
+        }
+        let option = &position.option;
+        let slot = self.options_mut().into_iter().find(|leg| {
+            leg.option.option_style == option.option_style
+                && leg.option.side == option.side
+                && leg.option.strike_price == option.strike_price
+        });
+        match slot {
+            Some(slot) => {
+                *slot = position.clone();
+                Ok(())
+            }
+            None => Err(PositionError::invalid_position(
+                "Position does not match an existing option leg",
+            )),
+        }
+    }
+}
+
+impl Strategable for Conversion {
+    fn info(&self) -> Result<StrategyBasics, StrategyError> {
+        Ok(StrategyBasics {
+            name: self.name.clone(),
+            kind: self.kind.clone(),
+            description: self.description.clone(),
+        })
+    }
+}
+
+impl BasicAble for Conversion {
+    fn get_title(&self) -> String {
+        format!(
+            "{} Strategy:\n\t{} {} {} @ {}\n\t{}\n\t{}",
+            self.name,
+            self.spot_leg.side,
+            self.spot_leg.quantity,
+            self.spot_leg.symbol,
+            self.spot_leg.cost_basis,
+            self.call.get_title(),
+            self.put.get_title()
+        )
+    }
+
+    fn get_option_basic_type(&self) -> HashSet<OptionBasicType<'_>> {
+        self.options()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                OptionBasicType {
+                    option_style: &option.option_style,
+                    side: &option.side,
+                    strike_price: &option.strike_price,
+                    expiration_date: &option.expiration_date,
+                }
+            })
+            .collect()
+    }
+
+    fn get_implied_volatility(&self) -> HashMap<OptionBasicType<'_>, &Positive> {
+        self.options()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                (
+                    OptionBasicType {
+                        option_style: &option.option_style,
+                        side: &option.side,
+                        strike_price: &option.strike_price,
+                        expiration_date: &option.expiration_date,
+                    },
+                    &option.implied_volatility,
+                )
+            })
+            .collect()
+    }
+
+    fn get_quantity(&self) -> HashMap<OptionBasicType<'_>, &Positive> {
+        self.options()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                (
+                    OptionBasicType {
+                        option_style: &option.option_style,
+                        side: &option.side,
+                        strike_price: &option.strike_price,
+                        expiration_date: &option.expiration_date,
+                    },
+                    &option.quantity,
+                )
+            })
+            .collect()
+    }
+
+    fn one_option(&self) -> &Options {
+        self.call.one_option()
+    }
+
+    fn one_option_mut(&mut self) -> &mut Options {
+        self.call.one_option_mut()
+    }
+
+    fn set_expiration_date(
+        &mut self,
+        expiration_date: ExpirationDate,
+    ) -> Result<(), StrategyError> {
+        for leg in self.options_mut() {
+            leg.option.expiration_date = expiration_date;
+        }
+        Ok(())
+    }
+
+    /// Reprices the option legs; the spot leg keeps its cost basis.
+    fn set_underlying_price(&mut self, price: &Positive) -> Result<(), StrategyError> {
+        for leg in self.options_mut() {
+            leg.option.underlying_price = *price;
+            leg.premium = Positive::new_decimal(leg.option.calculate_price_black_scholes()?.abs())
+                .unwrap_or(Positive::ZERO);
+        }
+        Ok(())
+    }
+
+    fn set_implied_volatility(&mut self, volatility: &Positive) -> Result<(), StrategyError> {
+        for leg in self.options_mut() {
+            leg.option.implied_volatility = *volatility;
+            leg.premium = Positive::new_decimal(leg.option.calculate_price_black_scholes()?.abs())
+                .unwrap_or(Positive::ZERO);
+        }
+        Ok(())
+    }
+}
+
+impl Strategies for Conversion {
+    fn underlying_pnl_at(&self, underlying_price: &Positive) -> Decimal {
+        self.spot_leg.pnl_at_price(*underlying_price)
+    }
+
+    fn get_max_profit(&self) -> Result<Positive, StrategyError> {
+        let profit = self.calculate_profit_at(&self.spot_leg.cost_basis)?;
+        if profit <= Decimal::ZERO {
+            return Err(StrategyError::ProfitLossError(
+                ProfitLossErrorKind::MaxProfitError {
+                    reason: "Max profit is negative".to_string(),
+                },
+            ));
+        }
+        Ok(Positive::new_decimal(profit)?)
+    }
+
+    fn get_max_loss(&self) -> Result<Positive, StrategyError> {
+        let profit = self.calculate_profit_at(&self.spot_leg.cost_basis)?;
+        Ok(Positive::new_decimal(profit.min(Decimal::ZERO).abs())?)
+    }
+}
+
+impl Profit for Conversion {
+    fn calculate_profit_at(&self, price: &Positive) -> Result<Decimal, PricingError> {
+        let spot_pnl = self.spot_leg.pnl_at_price(*price);
+        let option_pnl: Decimal = self
+            .options()
+            .iter()
+            .map(|leg| leg.pnl_at_expiration(&Some(price)))
+            .sum::<Result<Decimal, PricingError>>()?;
+        Ok(spot_pnl + option_pnl)
+    }
+}
+
+impl Greeks for Conversion {
+    fn get_options(&self) -> Result<Vec<&Options>, GreeksError> {
+        Ok(self.options().map(|leg| &leg.option).to_vec())
+    }
+
+    /// Includes the spot leg, so a conversion is close to delta neutral.
+    fn delta(&self) -> Result<Decimal, GreeksError> {
+        Ok(self.spot_leg.delta()? + self.call.delta()? + self.put.delta()?)
+    }
+}
+
+impl PnLCalculator for Conversion {
+    fn calculate_pnl(
+        &self,
+        underlying_price: &Positive,
+        _expiration_date: ExpirationDate,
+        _implied_volatility: &Positive,
+    ) -> Result<PnL, PricingError> {
+        self.calculate_pnl_at_expiration(underlying_price)
+    }
+
+    fn calculate_pnl_at_expiration(
+        &self,
+        underlying_price: &Positive,
+    ) -> Result<PnL, PricingError> {
+        let profit = self.calculate_profit_at(underlying_price)?;
+        let option_costs = self.call.total_cost()? + self.put.total_cost()?;
+        let option_income = self.call.premium_received()? + self.put.premium_received()?;
+        Ok(PnL {
+            realized: None,
+            unrealized: Some(profit),
+            initial_costs: self.spot_leg.total_cost() + option_costs,
+            initial_income: option_income,
+            date_time: Utc::now(),
+        })
+    }
+}
+
+impl DeltaNeutrality for Conversion {}
+
+impl Optimizable for Conversion {
+    type Strategy = Conversion;
+}
+
+impl ProbabilityAnalysis for Conversion {
+    fn get_profit_ranges(&self) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        if self.calculate_profit_at(&self.spot_leg.cost_basis)? > Decimal::ZERO {
+            Ok(vec![self.range()?])
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    fn get_loss_ranges(&self) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        if self.calculate_profit_at(&self.spot_leg.cost_basis)? > Decimal::ZERO {
+            Ok(Vec::new())
+        } else {
+            Ok(vec![self.range()?])
+        }
+    }
+}
+
+test_strategy_traits!(Conversion, test_conversion_implementations);
+
+#[cfg(test)]
+mod tests_conversion {
+    use super::*;
+    use positive::pos_or_panic;
+    use rust_decimal_macros::dec;
+
+    fn conversion(spot_side: Side, premium_call: f64, premium_put: f64) -> Conversion {
+        Conversion::new(
+            "AAPL".to_string(),
+            Positive::HUNDRED,
+            spot_side,
+            pos_or_panic!(105.0),
+            ExpirationDate::Days(pos_or_panic!(365.0)),
+            pos_or_panic!(0.2),
+            dec!(0.04),
+            Positive::ZERO,
+            Positive::TEN,
+            pos_or_panic!(premium_call),
+            pos_or_panic!(premium_put),
+            Positive::ZERO,
+            Positive::ZERO,
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_conversion_locks_in_the_strike() {
+        // 100 + 6.0 - 4.0 = 102 per unit, delivered at 105 in a year.
+        let strategy = conversion(Side::Long, 4.0, 6.0);
+        assert_eq!(strategy.kind, StrategyType::Conversion);
+        for price in [60.0, 105.0, 160.0] {
+            assert_eq!(
+                strategy.calculate_profit_at(&pos_or_panic!(price)).unwrap(),
+                dec!(30.0)
+            );
+        }
+        assert_eq!(strategy.settlement(), pos_or_panic!(1050.0));
+        assert_eq!(strategy.present_value().unwrap(), dec!(1020.0));
+        let rate = strategy.implied_rate().unwrap();
+        assert!((rate - dec!(0.0290)).abs() < dec!(0.001));
+        // Lending at 2.9% loses to the 4% risk-free rate.
+        assert!(strategy.financing_edge().unwrap() < Decimal::ZERO);
+        assert!(strategy.delta().unwrap().abs() < dec!(0.5));
+    }
+
+    #[test]
+    fn test_reversal_borrows_against_short_spot() {
+        let strategy = conversion(Side::Short, 4.0, 6.0);
+        assert_eq!(strategy.kind, StrategyType::Reversal);
+        assert_eq!(strategy.name, "Reversal");
+        // Raises 1020 now and buys back at 1050: borrowing at 2.9%, which
+        // beats the 4% risk-free rate.
+        assert_eq!(strategy.present_value().unwrap(), dec!(1020.0));
+        assert!(strategy.financing_edge().unwrap() > Decimal::ZERO);
+        assert_eq!(strategy.get_max_loss().unwrap(), pos_or_panic!(30.0));
+        assert_eq!(strategy.get_legs().len(), 3);
+        assert_eq!(strategy.get_underlying_price(), &Positive::HUNDRED);
+    }
+
+    #[test]
+    fn test_early_assignment_of_short_american_option() {
+        let mut strategy = conversion(Side::Long, 4.0, 6.0);
+        assert!(strategy.early_assignment_exposure().unwrap().is_empty());
+
+        // A deep in-the-money short call with a dividend due.
+        strategy.call.option.option_type = OptionType::American;
+        strategy.call.option.underlying_price = pos_or_panic!(150.0);
+        strategy.call.option.dividend_yield = pos_or_panic!(0.03);
+        strategy.call.premium = pos_or_panic!(45.5);
+        let exposure = strategy.early_assignment_exposure().unwrap();
+        let [call] = exposure.as_slice() else {
+            panic!("expected the short call only");
+        };
+        assert_eq!(call.option_style, OptionStyle::Call);
+        assert_eq!(call.intrinsic_value, dec!(45.0));
+        assert!(call.carry > call.extrinsic_value);
+        assert!(call.at_risk);
+    }
+}
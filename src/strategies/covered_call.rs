@@ -550,6 +550,10 @@ impl BasicAble for CoveredCall {
 }
 
 impl Strategies for CoveredCall {
+    fn underlying_pnl_at(&self, underlying_price: &Positive) -> Decimal {
+        self.spot_leg.pnl_at_price(*underlying_price)
+    }
+
     fn get_max_profit(&self) -> Result<Positive, StrategyError> {
         self.max_profit_potential().map_err(StrategyError::from)
     }
@@ -863,4 +867,94 @@ mod tests {
         let cc = create_test_covered_call();
         assert_eq!(cc.quantity(), Positive::HUNDRED);
     }
+
+    #[test]
+    fn test_valuation_grid_includes_spot_leg() {
+        use crate::assert_decimal_eq;
+        use crate::pricing::PricingEngine;
+
+        let cc = create_test_covered_call();
+        let prices = [
+            pos_or_panic!(140.0),
+            pos_or_panic!(150.0),
+            pos_or_panic!(160.0),
+        ];
+        let dates = [
+            ExpirationDate::Days(Positive::ZERO),
+            ExpirationDate::Days(pos_or_panic!(30.0)),
+        ];
+        let grid = cc
+            .valuation_grid(
+                &prices,
+                &dates,
+                &[Decimal::ZERO],
+                None,
+                &PricingEngine::ClosedFormBS,
+            )
+            .unwrap();
+
+        assert_eq!(grid.date_labels, vec!["T+0", "Expiry"]);
+        for (i, price) in prices.iter().enumerate() {
+            assert_eq!(
+                grid.value_at(1, 0, i),
+                Some(cc.calculate_profit_at(price).unwrap())
+            );
+        }
+        // Spot moves dominate the short call's time value at T+0.
+        let t0 = grid.curve(0, 0).unwrap();
+        assert!(t0.windows(2).all(|w| matches!(w, [a, b] if a < b)));
+        assert_decimal_eq!(
+            grid.value_at(0, 0, 2).unwrap() - cc.underlying_pnl_at(&pos_or_panic!(160.0)),
+            cc.short_call
+                .pnl_at_date(
+                    &pos_or_panic!(160.0),
+                    Positive::ZERO,
+                    Decimal::ZERO,
+                    &PricingEngine::ClosedFormBS
+                )
+                .unwrap(),
+            dec!(1e-20)
+        );
+    }
+
+    #[test]
+    fn test_calculate_pnl_with_smile_includes_spot_leg() {
+        use crate::assert_decimal_eq;
+        use crate::volatility::{SmileDynamics, StickySmile};
+
+        let cc = create_test_covered_call();
+        let price = pos_or_panic!(160.0);
+        let expiration_date = cc.short_call.option.expiration_date;
+        let smile = StickySmile::new(
+            pos_or_panic!(150.0),
+            expiration_date.get_years().unwrap(),
+            dec!(0.05),
+            pos_or_panic!(0.01),
+            vec![
+                (pos_or_panic!(140.0), pos_or_panic!(0.3)),
+                (pos_or_panic!(150.0), pos_or_panic!(0.25)),
+                (pos_or_panic!(160.0), pos_or_panic!(0.22)),
+            ],
+            SmileDynamics::StickyStrike,
+        )
+        .unwrap();
+        let call_volatility = smile
+            .volatility_at(
+                cc.short_call.option.strike_price,
+                price,
+                expiration_date.get_years().unwrap(),
+            )
+            .unwrap();
+        let call_pnl = cc
+            .short_call
+            .calculate_pnl(&price, expiration_date, &call_volatility)
+            .unwrap();
+
+        let pnl = cc.calculate_pnl_with_smile(&price, &smile).unwrap();
+        assert_decimal_eq!(
+            pnl.unrealized.unwrap(),
+            call_pnl.unrealized.unwrap() + cc.underlying_pnl_at(&price),
+            dec!(1e-12)
+        );
+    }
 }
@@ -1,14 +1,30 @@
 use crate::model::Position;
+use crate::model::leg::SpotPosition;
 use crate::strategies::base::StrategyType;
+use crate::strategies::box_spread::BOX_SPREAD_DESCRIPTION;
+use crate::strategies::broken_wing_butterfly::BROKEN_WING_BUTTERFLY_DESCRIPTION;
+use crate::strategies::conversion::CONVERSION_DESCRIPTION;
+use crate::strategies::diagonal_spread::DIAGONAL_SPREAD_DESCRIPTION;
+use crate::strategies::fence::FENCE_DESCRIPTION;
+use crate::strategies::lizard::LIZARD_DESCRIPTION;
+use crate::strategies::long_calendar_spread::LONG_CALENDAR_SPREAD_DESCRIPTION;
 use crate::strategies::long_call::LONG_CALL_DESCRIPTION;
+use crate::strategies::long_condor::LONG_CONDOR_DESCRIPTION;
 use crate::strategies::long_put::LONG_PUT_DESCRIPTION;
 use crate::strategies::poor_mans_covered_call::PMCC_DESCRIPTION;
+use crate::strategies::ratio_spread::RATIO_SPREAD_DESCRIPTION;
+use crate::strategies::risk_reversal::RISK_REVERSAL_DESCRIPTION;
+use crate::strategies::seagull::SEAGULL_DESCRIPTION;
+use crate::strategies::short_calendar_spread::SHORT_CALENDAR_SPREAD_DESCRIPTION;
 use crate::strategies::short_call::SHORT_CALL_DESCRIPTION;
+use crate::strategies::short_condor::SHORT_CONDOR_DESCRIPTION;
 use crate::strategies::short_put::SHORT_PUT_DESCRIPTION;
 use crate::strategies::{
-    BearCallSpread, BearPutSpread, BullCallSpread, BullPutSpread, CallButterfly, IronButterfly,
-    IronCondor, LongButterflySpread, LongCall, LongPut, LongStraddle, LongStrangle,
-    PoorMansCoveredCall, ShortButterflySpread, ShortCall, ShortPut, ShortStraddle, ShortStrangle,
+    BearCallSpread, BearPutSpread, BoxSpread, BrokenWingButterfly, BullCallSpread, BullPutSpread,
+    CallButterfly, Conversion, DiagonalSpread, Fence, IronButterfly, IronCondor, Lizard,
+    LongButterflySpread, LongCalendarSpread, LongCall, LongCondor, LongPut, LongStraddle,
+    LongStrangle, PoorMansCoveredCall, RatioSpread, RiskReversal, Seagull, ShortButterflySpread,
+    ShortCalendarSpread, ShortCall, ShortCondor, ShortPut, ShortStraddle, ShortStrangle,
 };
 
 impl Default for BullCallSpread {
@@ -192,6 +208,174 @@ impl Default for CallButterfly {
         }
     }
 }
+impl Default for LongCalendarSpread {
+    fn default() -> Self {
+        LongCalendarSpread {
+            name: "Long Calendar Spread".to_string(),
+            kind: StrategyType::LongCalendarSpread,
+            description: LONG_CALENDAR_SPREAD_DESCRIPTION.to_string(),
+            break_even_points: Vec::new(),
+            short_front: Position::default(),
+            long_back: Position::default(),
+        }
+    }
+}
+impl Default for ShortCalendarSpread {
+    fn default() -> Self {
+        ShortCalendarSpread {
+            name: "Short Calendar Spread".to_string(),
+            kind: StrategyType::ShortCalendarSpread,
+            description: SHORT_CALENDAR_SPREAD_DESCRIPTION.to_string(),
+            break_even_points: Vec::new(),
+            long_front: Position::default(),
+            short_back: Position::default(),
+        }
+    }
+}
+impl Default for DiagonalSpread {
+    fn default() -> Self {
+        DiagonalSpread {
+            name: "Diagonal Spread".to_string(),
+            kind: StrategyType::DiagonalSpread,
+            description: DIAGONAL_SPREAD_DESCRIPTION.to_string(),
+            break_even_points: Vec::new(),
+            short_front: Position::default(),
+            long_back: Position::default(),
+        }
+    }
+}
+impl Default for RatioSpread {
+    fn default() -> Self {
+        RatioSpread {
+            name: "Call Ratio Spread".to_string(),
+            kind: StrategyType::CallRatioSpread,
+            description: RATIO_SPREAD_DESCRIPTION.to_string(),
+            break_even_points: Vec::new(),
+            long_leg: Position::default(),
+            short_leg: Position::default(),
+        }
+    }
+}
+impl Default for LongCondor {
+    fn default() -> Self {
+        LongCondor {
+            name: "Long Call Condor".to_string(),
+            kind: StrategyType::LongCallCondor,
+            description: LONG_CONDOR_DESCRIPTION.to_string(),
+            break_even_points: Vec::new(),
+            long_low: Position::default(),
+            short_lower: Position::default(),
+            short_upper: Position::default(),
+            long_high: Position::default(),
+        }
+    }
+}
+impl Default for ShortCondor {
+    fn default() -> Self {
+        ShortCondor {
+            name: "Short Call Condor".to_string(),
+            kind: StrategyType::ShortCallCondor,
+            description: SHORT_CONDOR_DESCRIPTION.to_string(),
+            break_even_points: Vec::new(),
+            short_low: Position::default(),
+            long_lower: Position::default(),
+            long_upper: Position::default(),
+            short_high: Position::default(),
+        }
+    }
+}
+impl Default for BrokenWingButterfly {
+    fn default() -> Self {
+        BrokenWingButterfly {
+            name: "Call Broken-Wing Butterfly".to_string(),
+            kind: StrategyType::CallBrokenWingButterfly,
+            description: BROKEN_WING_BUTTERFLY_DESCRIPTION.to_string(),
+            break_even_points: Vec::new(),
+            lower_wing: Position::default(),
+            body: Position::default(),
+            upper_wing: Position::default(),
+        }
+    }
+}
+impl Default for Lizard {
+    fn default() -> Self {
+        Lizard {
+            name: "Jade Lizard".to_string(),
+            kind: StrategyType::JadeLizard,
+            description: LIZARD_DESCRIPTION.to_string(),
+            break_even_points: Vec::new(),
+            short_put: Position::default(),
+            short_call: Position::default(),
+            long_call: Position::default(),
+        }
+    }
+}
+impl Default for BoxSpread {
+    fn default() -> Self {
+        BoxSpread {
+            name: "Long Box Spread".to_string(),
+            kind: StrategyType::BoxSpread,
+            description: BOX_SPREAD_DESCRIPTION.to_string(),
+            break_even_points: Vec::new(),
+            lower_call: Position::default(),
+            upper_call: Position::default(),
+            lower_put: Position::default(),
+            upper_put: Position::default(),
+        }
+    }
+}
+impl Default for Conversion {
+    fn default() -> Self {
+        Conversion {
+            name: "Conversion".to_string(),
+            kind: StrategyType::Conversion,
+            description: CONVERSION_DESCRIPTION.to_string(),
+            break_even_points: Vec::new(),
+            spot_leg: SpotPosition::default(),
+            call: Position::default(),
+            put: Position::default(),
+        }
+    }
+}
+impl Default for RiskReversal {
+    fn default() -> Self {
+        RiskReversal {
+            name: "Bullish Risk Reversal".to_string(),
+            kind: StrategyType::RiskReversal,
+            description: RISK_REVERSAL_DESCRIPTION.to_string(),
+            break_even_points: Vec::new(),
+            put: Position::default(),
+            call: Position::default(),
+        }
+    }
+}
+impl Default for Seagull {
+    fn default() -> Self {
+        Seagull {
+            name: "Bullish Seagull".to_string(),
+            kind: StrategyType::Seagull,
+            description: SEAGULL_DESCRIPTION.to_string(),
+            break_even_points: Vec::new(),
+            underlying: None,
+            short_put: Position::default(),
+            long_option: Position::default(),
+            short_call: Position::default(),
+        }
+    }
+}
+impl Default for Fence {
+    fn default() -> Self {
+        Fence {
+            name: "Fence".to_string(),
+            kind: StrategyType::Fence,
+            description: FENCE_DESCRIPTION.to_string(),
+            break_even_points: Vec::new(),
+            underlying: None,
+            put: Position::default(),
+            call: Position::default(),
+        }
+    }
+}
 impl Default for LongCall {
     fn default() -> Self {
         LongCall {
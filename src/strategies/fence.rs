@@ -0,0 +1,913 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Fence Strategy
+//!
+//! A fence hedges an exposure to the underlying between two strikes:
+//!
+//! - a long exposure (a producer) buys a put as a floor and sells a call as
+//!   a cap;
+//! - a short exposure (a consumer) buys a call as a cap and sells a put as a
+//!   floor.
+//!
+//! The sold option pays for the bought one, and corporate hedges are usually
+//! quoted zero-cost: [`Fence::zero_cost`] fixes the protection strike and
+//! solves for the financing strike against an `OptionChain`.
+//!
+//! The underlying is optional. With it, the fence is the full hedged
+//! position and, for a long exposure, generalizes `Collar` to any strikes and
+//! quantities. Without it, the fence is the option overlay alone, which has
+//! the payoff of a risk reversal against the exposure.
+
+use super::base::{
+    BreakEvenable, Optimizable, Positionable, Strategable, StrategyBasics, StrategyType, Validable,
+};
+use super::shared::{chain_leg, expiration_break_evens, net_premium, premium_neutral};
+use crate::chains::{StrategyLegs, chain::OptionChain, utils::OptionDataGroup};
+use crate::error::position::{PositionError, PositionValidationErrorKind};
+use crate::error::probability::ProbabilityError;
+use crate::error::strategies::{ProfitLossErrorKind, StrategyError};
+use crate::error::{GreeksError, PricingError};
+use crate::greeks::Greeks;
+use crate::model::ProfitLossRange;
+use crate::model::leg::traits::LegAble;
+use crate::model::leg::{Leg, SpotPosition};
+use crate::model::position::Position;
+use crate::model::types::{OptionBasicType, OptionStyle, OptionType, Side};
+use crate::model::utils::mean_and_std;
+use crate::pnl::{PnLCalculator, utils::PnL};
+use crate::pricing::payoff::Profit;
+use crate::strategies::delta_neutral::DeltaNeutrality;
+use crate::strategies::probabilities::{core::ProbabilityAnalysis, utils::VolatilityAdjustment};
+use crate::strategies::utils::FindOptimalSide;
+use crate::strategies::{BasicAble, Strategies, StrategyConstructor};
+use crate::{ExpirationDate, Options, test_strategy_traits};
+use chrono::Utc;
+use positive::Positive;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tracing::debug;
+use utoipa::ToSchema;
+
+/// Default description for the Fence strategy.
+pub const FENCE_DESCRIPTION: &str = "A fence hedges an exposure to the underlying between a floor \
+    and a cap: a long exposure buys a put and sells a call, a short exposure buys a call and sells \
+    a put. The sold option pays for the bought one, with or without the underlying in the \
+    position.";
+
+/// Represents a Fence over a long or short exposure.
+///
+/// The exposure follows the bought option: a long put hedges a long
+/// exposure and a long call a short one. The put strike is at or below the
+/// call strike, and the underlying, when held, is on the exposure's side
+/// with one unit per option.
+#[derive(Clone, DebugPretty, DisplaySimple, Serialize, Deserialize, ToSchema)]
+pub struct Fence {
+    /// Name identifier for this specific strategy instance
+    pub name: String,
+    /// Type of strategy, always `Fence`
+    pub kind: StrategyType,
+    /// Detailed description of this strategy instance
+    pub description: String,
+    /// Prices at expiration where the strategy neither makes nor loses money
+    pub break_even_points: Vec<Positive>,
+    /// The hedged position in the underlying, if held
+    pub underlying: Option<SpotPosition>,
+    /// The put, the floor bought by a long exposure
+    pub(super) put: Position,
+    /// The call, the cap bought by a short exposure
+    pub(super) call: Position,
+}
+
+impl Fence {
+    /// Creates a Fence over a long exposure when `exposure` is `Long`
+    /// (long put, short call) or a short one when it is `Short` (short put,
+    /// long call), without the underlying.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyError` if the put strike is above the call strike,
+    /// a leg fails validation or the break-even calculation fails.
+    #[allow(clippy::too_many_arguments)]
+    #[inline(never)]
+    pub fn new(
+        underlying_symbol: String,
+        underlying_price: Positive,
+        exposure: Side,
+        put_strike: Positive,
+        call_strike: Positive,
+        expiration: ExpirationDate,
+        implied_volatility: Positive,
+        risk_free_rate: Decimal,
+        dividend_yield: Positive,
+        quantity: Positive,
+        premium_put: Positive,
+        premium_call: Positive,
+        open_fee: Positive,
+        close_fee: Positive,
+    ) -> Result<Self, StrategyError> {
+        let leg = |option_style, side, strike, premium| {
+            Position::new(
+                Options::new(
+                    OptionType::European,
+                    side,
+                    underlying_symbol.clone(),
+                    strike,
+                    expiration,
+                    implied_volatility,
+                    quantity,
+                    underlying_price,
+                    risk_free_rate,
+                    option_style,
+                    dividend_yield,
+                    None,
+                ),
+                premium,
+                Utc::now(),
+                open_fee,
+                close_fee,
+                None,
+                None,
+            )
+        };
+        Self::from_legs(
+            None,
+            leg(OptionStyle::Put, exposure, put_strike, premium_put),
+            leg(
+                OptionStyle::Call,
+                opposite(exposure),
+                call_strike,
+                premium_call,
+            ),
+        )
+    }
+
+    /// Builds a zero-cost Fence over `exposure` from the quotes of `chain`,
+    /// without the underlying.
+    ///
+    /// The bought option sits at `protection_strike`: a put for a long
+    /// exposure, a call for a short one. The sold option is the one on the
+    /// other side of `protection_strike` whose premium best offsets it, with
+    /// long legs filled at the ask and short legs at the bid.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyError` if the chain has no expiration,
+    /// `protection_strike` is not quoted or no strike on the other side
+    /// produces a valid fence.
+    pub fn zero_cost(
+        chain: &OptionChain,
+        exposure: Side,
+        protection_strike: Positive,
+        quantity: Positive,
+        open_fee: Positive,
+        close_fee: Positive,
+    ) -> Result<Self, StrategyError> {
+        let expiration = chain.get_expiration().ok_or_else(|| {
+            StrategyError::invalid_parameters("Fence::zero_cost", "chain has no expiration")
+        })?;
+        let leg = |strike, option_style, side| {
+            chain_leg(
+                chain,
+                expiration,
+                strike,
+                option_style,
+                side,
+                quantity,
+                open_fee,
+                close_fee,
+            )
+        };
+        let candidates = chain
+            .options
+            .iter()
+            .map(|option| option.strike_price)
+            .filter(|strike| match exposure {
+                Side::Long => *strike >= protection_strike,
+                Side::Short => *strike <= protection_strike,
+            });
+        premium_neutral(
+            candidates,
+            |strike| match exposure {
+                Side::Long => Self::from_legs(
+                    None,
+                    leg(protection_strike, OptionStyle::Put, Side::Long)?,
+                    leg(strike, OptionStyle::Call, Side::Short)?,
+                ),
+                Side::Short => Self::from_legs(
+                    None,
+                    leg(strike, OptionStyle::Put, Side::Short)?,
+                    leg(protection_strike, OptionStyle::Call, Side::Long)?,
+                ),
+            },
+            |strategy| strategy.net_premium(),
+        )
+    }
+
+    /// Adds the hedged position in the underlying.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyError` if `spot_leg` is not on the exposure's side
+    /// or its quantity differs from the options'.
+    pub fn with_underlying(self, spot_leg: SpotPosition) -> Result<Self, StrategyError> {
+        Self::from_legs(Some(spot_leg), self.put, self.call)
+    }
+
+    fn from_legs(
+        underlying: Option<SpotPosition>,
+        put: Position,
+        call: Position,
+    ) -> Result<Self, StrategyError> {
+        let mut strategy = Fence {
+            underlying,
+            put,
+            call,
+            ..Default::default()
+        };
+        if !strategy.validate() {
+            return Err(StrategyError::invalid_parameters(
+                "Fence",
+                "requires a put and a call on opposite sides with the put strike at or below \
+                 the call strike, sharing expiration and quantity, and any underlying on the \
+                 put's side",
+            ));
+        }
+        strategy.update_break_even_points()?;
+        Ok(strategy)
+    }
+
+    fn legs(&self) -> [&Position; 2] {
+        [&self.put, &self.call]
+    }
+
+    fn legs_mut(&mut self) -> [&mut Position; 2] {
+        [&mut self.put, &mut self.call]
+    }
+
+    /// Returns the option legs and, if held, the underlying.
+    #[must_use]
+    pub fn get_legs(&self) -> Vec<Leg> {
+        let mut legs: Vec<Leg> = self.underlying.iter().cloned().map(Leg::Spot).collect();
+        legs.push(Leg::option(self.put.clone()));
+        legs.push(Leg::option(self.call.clone()));
+        legs
+    }
+
+    /// Returns the hedged exposure: `Long` when the put is bought.
+    #[must_use]
+    pub fn exposure(&self) -> Side {
+        self.put.option.side
+    }
+
+    /// Returns the floor and cap strikes.
+    #[must_use]
+    pub fn strikes(&self) -> (Positive, Positive) {
+        (self.put.option.strike_price, self.call.option.strike_price)
+    }
+
+    /// Premium collected minus premium paid on the options, before fees.
+    /// Zero for a zero-cost quote.
+    #[must_use]
+    pub fn net_premium(&self) -> Decimal {
+        net_premium(&self.legs())
+    }
+
+    /// Expiration payoff at zero and at each strike, where its slope changes.
+    fn payoff_nodes(&self) -> Result<Vec<(Positive, Decimal)>, PricingError> {
+        let (put, call) = self.strikes();
+        let mut prices = vec![Positive::ZERO, put, call];
+        prices.dedup();
+        prices
+            .into_iter()
+            .map(|price| Ok((price, self.calculate_profit_at(&price)?)))
+            .collect()
+    }
+
+    /// Slope of the expiration payoff above the call strike, including the
+    /// underlying.
+    fn upper_slope(&self) -> Decimal {
+        let signed = |side, quantity: Positive| match side {
+            Side::Long => quantity.to_dec(),
+            Side::Short => -quantity.to_dec(),
+        };
+        let call = signed(self.call.option.side, self.call.option.quantity);
+        let spot = self
+            .underlying
+            .as_ref()
+            .map_or(Decimal::ZERO, |spot| signed(spot.side, spot.quantity));
+        call + spot
+    }
+
+    fn volatility_adjustment(&self) -> VolatilityAdjustment {
+        let (base_volatility, std_dev_adjustment) = mean_and_std(
+            self.legs()
+                .iter()
+                .map(|leg| leg.option.implied_volatility)
+                .collect(),
+        );
+        VolatilityAdjustment {
+            base_volatility,
+            std_dev_adjustment,
+        }
+    }
+
+    fn ranges(&self, profitable: bool) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        let option = &self.put.option;
+        let mut bounds: Vec<Option<Positive>> = vec![None];
+        bounds.extend(self.break_even_points.iter().copied().map(Some));
+        bounds.push(None);
+        let mut ranges = Vec::new();
+        for window in bounds.windows(2) {
+            let [lower, upper] = window else {
+                continue;
+            };
+            let probe = match (lower, upper) {
+                (Some(l), Some(u)) => (*l + *u) / Positive::TWO,
+                (None, Some(u)) => *u / Positive::TWO,
+                (Some(l), None) => *l * Positive::TWO,
+                (None, None) => option.underlying_price,
+            };
+            if (self.calculate_profit_at(&probe)? > Decimal::ZERO) != profitable {
+                continue;
+            }
+            let mut range = ProfitLossRange::new(*lower, *upper, Positive::ZERO)?;
+            range.calculate_probability(
+                &option.underlying_price,
+                Some(self.volatility_adjustment()),
+                None,
+                &option.expiration_date,
+                Some(option.risk_free_rate),
+            )?;
+            ranges.push(range);
+        }
+        Ok(ranges)
+    }
+
+    /// Profit and loss of the underlying at `price`, zero when not held.
+    fn spot_pnl(&self, price: &Positive) -> PnL {
+        match &self.underlying {
+            Some(spot) => PnL {
+                realized: None,
+                unrealized: Some(spot.pnl_at_price(*price)),
+                initial_costs: spot.total_cost(),
+                initial_income: Positive::ZERO,
+                date_time: Utc::now(),
+            },
+            None => PnL::default(),
+        }
+    }
+}
+
+fn opposite(side: Side) -> Side {
+    match side {
+        Side::Long => Side::Short,
+        Side::Short => Side::Long,
+    }
+}
+
+impl StrategyConstructor for Fence {
+    /// Builds the option overlay; add the underlying with
+    /// [`Fence::with_underlying`].
+    fn get_strategy(vec_positions: &[Position]) -> Result<Self, StrategyError> {
+        let (puts, calls): (Vec<&Position>, Vec<&Position>) = vec_positions
+            .iter()
+            .partition(|position| position.option.option_style == OptionStyle::Put);
+        let ([put], [call]) = (puts.as_slice(), calls.as_slice()) else {
+            return Err(StrategyError::invalid_parameters(
+                "Fence get_strategy",
+                "Must have exactly 1 put and 1 call",
+            ));
+        };
+        Self::from_legs(None, (*put).clone(), (*call).clone())
+    }
+}
+
+impl BreakEvenable for Fence {
+    fn get_break_even_points(&self) -> Result<&Vec<Positive>, StrategyError> {
+        Ok(&self.break_even_points)
+    }
+
+    fn update_break_even_points(&mut self) -> Result<(), StrategyError> {
+        self.break_even_points = expiration_break_evens(&self.payoff_nodes()?, self.upper_slope())?;
+        Ok(())
+    }
+}
+
+impl Validable for Fence {
+    fn validate(&self) -> bool {
+        if !self.put.validate() || !self.call.validate() {
+            debug!("Fence legs failed validation");
+            return false;
+        }
+        if self.put.option.option_style != OptionStyle::Put
+            || self.call.option.option_style != OptionStyle::Call
+        {
+            debug!("Fence requires one put and one call");
+            return false;
+        }
+        let (put, call) = (&self.put.option, &self.call.option);
+        if put.side == call.side {
+            debug!("Fence put and call must be on opposite sides");
+            return false;
+        }
+        if put.expiration_date != call.expiration_date || put.quantity != call.quantity {
+            debug!("Fence legs must share expiration and quantity");
+            return false;
+        }
+        if put.strike_price > call.strike_price {
+            debug!("Fence put strike must be at or below the call strike");
+            return false;
+        }
+        if let Some(spot) = &self.underlying
+            && (spot.side != put.side || spot.quantity != put.quantity)
+        {
+            debug!("Fence underlying must be on the exposure's side with the options' quantity");
+            return false;
+        }
+        true
+    }
+}
+
+impl Positionable for Fence {
+    /// Replaces the option leg with the same style.
+    fn add_position(&mut self, position: &Position) -> Result<(), PositionError> {
+        match position.option.option_style {
+            OptionStyle::Put => self.put = position.clone(),
+            OptionStyle::Call => self.call = position.clone(),
+        }
+        Ok(())
+    }
+
+    fn get_positions(&self) -> Result<Vec<&Position>, PositionError> {
+        Ok(self.legs().to_vec())
+    }
+
+    fn get_position(
+        &mut self,
+        option_style: &OptionStyle,
+        side: &Side,
+        strike: &Positive,
+    ) -> Result<Vec<&mut Position>, PositionError> {
+        let positions: Vec<&mut Position> = self
+            .legs_mut()
+            .into_iter()
+            .filter(|leg| {
+                leg.option.option_style == *option_style
+                    && leg.option.side == *side
+                    && leg.option.strike_price == *strike
+            })
+            .collect();
+        if positions.is_empty() {
+            return Err(PositionError::invalid_position_type(
+                *side,
+                "Strike not found in positions".to_string(),
+            ));
+        }
+        Ok(positions)
+    }
+
+    fn modify_position(&mut self, position: &Position) -> Result<(), PositionError> {
+        if !position.validate() {
+            return Err(PositionError::ValidationError(
+                PositionValidationErrorKind::InvalidPosition {
+                    reason: "Invalid position data".to_string(),
+                },
+            ));
+        }
+        let option = &position.option;
+        let slot = self.legs_mut().into_iter().find(|leg| {
+            leg.option.option_style == option.option_style
+                && leg.option.side == option.side
+                && leg.option.strike_price == option.strike_price
+        });
+        match slot {
+            Some(slot) => {
+                *slot = position.clone();
+                Ok(())
+            }
+            None => Err(PositionError::invalid_position_type(
+                option.side,
+                "Strike not found in positions".to_string(),
+            )),
+        }
+    }
+}
+
+impl Strategable for Fence {
+    fn info(&self) -> Result<StrategyBasics, StrategyError> {
+        Ok(StrategyBasics {
+            name: self.name.clone(),
+            kind: self.kind.clone(),
+            description: self.description.clone(),
+        })
+    }
+}
+
+impl BasicAble for Fence {
+    fn get_title(&self) -> String {
+        let mut legs: Vec<String> = self
+            .underlying
+            .iter()
+            .map(|spot| {
+                format!(
+                    "{} {} {} @ {}",
+                    spot.side, spot.quantity, spot.symbol, spot.cost_basis
+                )
+            })
+            .collect();
+        legs.extend(self.legs().iter().map(|leg| leg.get_title()));
+        format!("{} Strategy: \n\t{}", self.name, legs.join("\n\t"))
+    }
+
+    fn get_option_basic_type(&self) -> HashSet<OptionBasicType<'_>> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                OptionBasicType {
+                    option_style: &option.option_style,
+                    side: &option.side,
+                    strike_price: &option.strike_price,
+                    expiration_date: &option.expiration_date,
+                }
+            })
+            .collect()
+    }
+
+    fn get_implied_volatility(&self) -> HashMap<OptionBasicType<'_>, &Positive> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                (
+                    OptionBasicType {
+                        option_style: &option.option_style,
+                        side: &option.side,
+                        strike_price: &option.strike_price,
+                        expiration_date: &option.expiration_date,
+                    },
+                    &option.implied_volatility,
+                )
+            })
+            .collect()
+    }
+
+    fn get_quantity(&self) -> HashMap<OptionBasicType<'_>, &Positive> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                (
+                    OptionBasicType {
+                        option_style: &option.option_style,
+                        side: &option.side,
+                        strike_price: &option.strike_price,
+                        expiration_date: &option.expiration_date,
+                    },
+                    &option.quantity,
+                )
+            })
+            .collect()
+    }
+
+    fn one_option(&self) -> &Options {
+        self.put.one_option()
+    }
+
+    fn one_option_mut(&mut self) -> &mut Options {
+        self.put.one_option_mut()
+    }
+
+    fn set_expiration_date(
+        &mut self,
+        expiration_date: ExpirationDate,
+    ) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.expiration_date = expiration_date;
+        }
+        Ok(())
+    }
+
+    /// Reprices the option legs; the underlying keeps its cost basis.
+    fn set_underlying_price(&mut self, price: &Positive) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.underlying_price = *price;
+            leg.premium = Positive::new_decimal(leg.option.calculate_price_black_scholes()?.abs())
+                .unwrap_or(Positive::ZERO);
+        }
+        Ok(())
+    }
+
+    fn set_implied_volatility(&mut self, volatility: &Positive) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.implied_volatility = *volatility;
+            leg.premium = Positive::new_decimal(leg.option.calculate_price_black_scholes()?.abs())
+                .unwrap_or(Positive::ZERO);
+        }
+        Ok(())
+    }
+}
+
+impl Strategies for Fence {
+    fn underlying_pnl_at(&self, underlying_price: &Positive) -> Decimal {
+        self.underlying
+            .as_ref()
+            .map_or(Decimal::ZERO, |spot| spot.pnl_at_price(*underlying_price))
+    }
+
+    /// Unbounded when the payoff keeps rising above the call strike.
+    fn get_max_profit(&self) -> Result<Positive, StrategyError> {
+        if self.upper_slope() > Decimal::ZERO {
+            return Ok(Positive::INFINITY);
+        }
+        let max = self
+            .payoff_nodes()?
+            .into_iter()
+            .map(|(_, profit)| profit)
+            .fold(Decimal::MIN, Decimal::max);
+        if max <= Decimal::ZERO {
+            return Err(StrategyError::ProfitLossError(
+                ProfitLossErrorKind::MaxProfitError {
+                    reason: "Max profit is negative".to_string(),
+                },
+            ));
+        }
+        Ok(Positive::new_decimal(max)?)
+    }
+
+    /// Unbounded when the payoff keeps falling above the call strike.
+    fn get_max_loss(&self) -> Result<Positive, StrategyError> {
+        if self.upper_slope() < Decimal::ZERO {
+            return Ok(Positive::INFINITY);
+        }
+        let min = self
+            .payoff_nodes()?
+            .into_iter()
+            .map(|(_, profit)| profit)
+            .fold(Decimal::MAX, Decimal::min);
+        Ok(Positive::new_decimal(min.min(Decimal::ZERO).abs())?)
+    }
+}
+
+impl Optimizable for Fence {
+    type Strategy = Fence;
+
+    /// Yields the (put, call) strike pairs of `option_chain` that satisfy
+    /// `side`, with the put strike below the call strike.
+    fn filter_combinations<'a>(
+        &'a self,
+        option_chain: &'a OptionChain,
+        side: FindOptimalSide,
+    ) -> impl Iterator<Item = OptionDataGroup<'a>> {
+        option_chain
+            .get_double_iter()
+            .filter(move |(put, call)| {
+                self.is_valid_optimal_option(put, &side)
+                    && self.is_valid_optimal_option(call, &side)
+            })
+            .map(|(put, call)| OptionDataGroup::Two(put, call))
+    }
+
+    /// Builds a fence with the put at `first` and the call at `second`,
+    /// keeping this one's exposure, underlying, quantity and fees and
+    /// filling at the chain's quotes.
+    fn create_strategy(
+        &self,
+        chain: &OptionChain,
+        legs: &StrategyLegs,
+    ) -> Result<Self::Strategy, StrategyError> {
+        let StrategyLegs::TwoLegs { first, second } = legs else {
+            return Err(StrategyError::operation_not_supported(
+                "create_strategy",
+                "Fence requires exactly two legs (TwoLegs)",
+            ));
+        };
+        let expiration = chain
+            .get_expiration()
+            .unwrap_or(self.call.option.expiration_date);
+        let leg = |template: &Position, strike| {
+            chain_leg(
+                chain,
+                expiration,
+                strike,
+                template.option.option_style,
+                template.option.side,
+                template.option.quantity,
+                template.open_fee,
+                template.close_fee,
+            )
+        };
+        Self::from_legs(
+            self.underlying.clone(),
+            leg(&self.put, first.strike_price)?,
+            leg(&self.call, second.strike_price)?,
+        )
+    }
+}
+
+impl Profit for Fence {
+    fn calculate_profit_at(&self, price: &Positive) -> Result<Decimal, PricingError> {
+        let spot_pnl = self
+            .underlying
+            .as_ref()
+            .map_or(Decimal::ZERO, |spot| spot.pnl_at_price(*price));
+        let option_pnl: Decimal = self
+            .legs()
+            .iter()
+            .map(|leg| leg.pnl_at_expiration(&Some(price)))
+            .sum::<Result<Decimal, PricingError>>()?;
+        Ok(spot_pnl + option_pnl)
+    }
+}
+
+impl ProbabilityAnalysis for Fence {
+    fn get_profit_ranges(&self) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        self.ranges(true)
+    }
+
+    fn get_loss_ranges(&self) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        self.ranges(false)
+    }
+}
+
+impl Greeks for Fence {
+    fn get_options(&self) -> Result<Vec<&Options>, GreeksError> {
+        Ok(self.legs().map(|leg| &leg.option).to_vec())
+    }
+
+    /// Includes the underlying when held.
+    fn delta(&self) -> Result<Decimal, GreeksError> {
+        let spot_delta = match &self.underlying {
+            Some(spot) => spot.delta()?,
+            None => Decimal::ZERO,
+        };
+        Ok(spot_delta + self.put.delta()? + self.call.delta()?)
+    }
+}
+
+impl DeltaNeutrality for Fence {}
+
+impl PnLCalculator for Fence {
+    fn calculate_pnl(
+        &self,
+        market_price: &Positive,
+        expiration_date: ExpirationDate,
+        implied_volatility: &Positive,
+    ) -> Result<PnL, PricingError> {
+        let mut pnl = self.spot_pnl(market_price);
+        for leg in self.legs() {
+            pnl = pnl + leg.calculate_pnl(market_price, expiration_date, implied_volatility)?;
+        }
+        Ok(pnl)
+    }
+
+    fn calculate_pnl_at_expiration(
+        &self,
+        underlying_price: &Positive,
+    ) -> Result<PnL, PricingError> {
+        let mut pnl = self.spot_pnl(underlying_price);
+        for leg in self.legs() {
+            pnl = pnl + leg.calculate_pnl_at_expiration(underlying_price)?;
+        }
+        Ok(pnl)
+    }
+}
+
+test_strategy_traits!(Fence, test_fence_implementations);
+
+#[cfg(test)]
+mod tests_fence {
+    use super::*;
+    use crate::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
+    use positive::{pos_or_panic, spos};
+    use rust_decimal_macros::dec;
+
+    fn fence(exposure: Side, premium_put: f64, premium_call: f64) -> Fence {
+        Fence::new(
+            "WTI".to_string(),
+            Positive::HUNDRED,
+            exposure,
+            pos_or_panic!(90.0),
+            pos_or_panic!(110.0),
+            ExpirationDate::Days(pos_or_panic!(180.0)),
+            pos_or_panic!(0.3),
+            dec!(0.03),
+            Positive::ZERO,
+            Positive::TEN,
+            pos_or_panic!(premium_put),
+            pos_or_panic!(premium_call),
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+        .unwrap()
+    }
+
+    fn spot(side: Side) -> SpotPosition {
+        SpotPosition::new(
+            "WTI".to_string(),
+            Positive::TEN,
+            Positive::HUNDRED,
+            side,
+            Utc::now(),
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+    }
+
+    fn chain() -> OptionChain {
+        let params = OptionChainBuildParams::new(
+            "WTI".to_string(),
+            spos!(1000.0),
+            10,
+            spos!(2.5),
+            dec!(-0.2),
+            dec!(0.1),
+            pos_or_panic!(0.02),
+            2,
+            OptionDataPriceParams::new(
+                Some(Box::new(Positive::HUNDRED)),
+                Some(ExpirationDate::Days(pos_or_panic!(180.0))),
+                Some(dec!(0.03)),
+                spos!(0.0),
+                Some("WTI".to_string()),
+            ),
+            pos_or_panic!(0.3),
+        );
+        OptionChain::build_chain(&params).unwrap()
+    }
+
+    #[test]
+    fn test_producer_fence_bounds_the_hedged_position() {
+        // Buys the 90 put for 4.0 and sells the 110 call for 4.5 on 10 units.
+        let strategy = fence(Side::Long, 4.0, 4.5)
+            .with_underlying(spot(Side::Long))
+            .unwrap();
+        assert_eq!(strategy.exposure(), Side::Long);
+        assert_eq!(strategy.net_premium(), dec!(5.0));
+        assert_eq!(strategy.get_legs().len(), 3);
+        // Floor of 90 and cap of 110, shifted by the 0.5 credit per unit.
+        assert_eq!(strategy.get_max_loss().unwrap(), pos_or_panic!(95.0));
+        assert_eq!(strategy.get_max_profit().unwrap(), pos_or_panic!(105.0));
+        assert_eq!(
+            strategy.get_break_even_points().unwrap(),
+            &vec![pos_or_panic!(99.5)]
+        );
+        assert!(strategy.delta().unwrap() > Decimal::ZERO);
+        assert!(strategy.delta().unwrap() < Positive::TEN.to_dec());
+    }
+
+    #[test]
+    fn test_overlay_without_the_underlying() {
+        let strategy = fence(Side::Long, 4.0, 4.5);
+        assert!(strategy.underlying.is_none());
+        assert_eq!(strategy.get_max_loss().unwrap(), Positive::INFINITY);
+        assert_eq!(strategy.get_legs().len(), 2);
+
+        let consumer = fence(Side::Short, 4.0, 4.5)
+            .with_underlying(spot(Side::Short))
+            .unwrap();
+        assert_eq!(consumer.exposure(), Side::Short);
+        assert_eq!(consumer.net_premium(), dec!(-5.0));
+        assert_eq!(consumer.get_max_loss().unwrap(), pos_or_panic!(105.0));
+    }
+
+    #[test]
+    fn test_rejects_underlying_against_the_exposure() {
+        assert!(
+            fence(Side::Long, 4.0, 4.5)
+                .with_underlying(spot(Side::Short))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_zero_cost_fence() {
+        let chain = chain();
+        let protection = pos_or_panic!(95.0);
+        let strategy = Fence::zero_cost(
+            &chain,
+            Side::Long,
+            protection,
+            Positive::TEN,
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+        .unwrap();
+        let (put, call) = strategy.strikes();
+        assert_eq!(put, protection);
+        assert!(call > Positive::HUNDRED);
+        // The quote is within one strike step of zero cost.
+        let step_value = Positive::TEN.to_dec() * dec!(2.5);
+        assert!(strategy.net_premium().abs() < step_value);
+
+        let hedged = strategy.with_underlying(spot(Side::Long)).unwrap();
+        assert!(hedged.get_max_loss().unwrap() < Positive::INFINITY);
+        assert!(hedged.get_max_profit().unwrap() < Positive::INFINITY);
+    }
+}
@@ -7,9 +7,11 @@
 use crate::pricing::Profit;
 use crate::strategies::base::BreakEvenable;
 use crate::strategies::{
-    BasicAble, BearCallSpread, BearPutSpread, BullCallSpread, BullPutSpread, CallButterfly,
-    IronButterfly, IronCondor, LongButterflySpread, LongCall, LongPut, LongStraddle, LongStrangle,
-    PoorMansCoveredCall, ShortButterflySpread, ShortCall, ShortPut, ShortStraddle, ShortStrangle,
+    BasicAble, BearCallSpread, BearPutSpread, BoxSpread, BrokenWingButterfly, BullCallSpread,
+    BullPutSpread, CallButterfly, Conversion, DiagonalSpread, Fence, IronButterfly, IronCondor,
+    Lizard, LongButterflySpread, LongCalendarSpread, LongCall, LongCondor, LongPut, LongStraddle,
+    LongStrangle, PoorMansCoveredCall, RatioSpread, RiskReversal, Seagull, ShortButterflySpread,
+    ShortCalendarSpread, ShortCall, ShortCondor, ShortPut, ShortStraddle, ShortStrangle,
     Strategies,
 };
 use crate::visualization::{
@@ -394,6 +396,19 @@ impl_graph_for_payoff_strategy!(
     ShortPut,
     PoorMansCoveredCall,
     CallButterfly,
+    LongCalendarSpread,
+    ShortCalendarSpread,
+    DiagonalSpread,
+    RatioSpread,
+    LongCondor,
+    ShortCondor,
+    BrokenWingButterfly,
+    Lizard,
+    BoxSpread,
+    Conversion,
+    RiskReversal,
+    Seagull,
+    Fence,
     crate::strategies::custom::CustomStrategy,
     crate::strategies::covered_call::CoveredCall,
     crate::strategies::collar::Collar,
@@ -897,6 +897,18 @@ impl Optimizable for IronButterfly {
             .map(move |(low, mid, high)| OptionDataGroup::Three(low, mid, high))
     }
 
+    fn strategy_legs<'a>(&self, group: OptionDataGroup<'a>) -> Option<StrategyLegs<'a>> {
+        match group {
+            OptionDataGroup::Three(low, mid, high) => Some(StrategyLegs::FourLegs {
+                first: low,
+                second: mid,
+                third: mid,
+                fourth: high,
+            }),
+            _ => None,
+        }
+    }
+
     fn find_optimal(
         &mut self,
         option_chain: &OptionChain,
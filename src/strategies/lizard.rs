@@ -0,0 +1,880 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Jade Lizard and Big Lizard Strategies
+//!
+//! Both lizards sell a put and a call spread with the same expiration:
+//!
+//! - a jade lizard sells an out-of-the-money put below a short call spread;
+//! - a big lizard sells the put at the short call's strike, a short straddle
+//!   covered on the upside by the long call.
+//!
+//! The total credit must be at least the width of the call spread, so the
+//! position has no upside risk. The remaining risk is to the downside, below
+//! the short put.
+
+use super::base::{
+    BreakEvenable, Optimizable, Positionable, Strategable, StrategyBasics, StrategyType, Validable,
+};
+use super::shared::{chain_leg, expiration_break_evens};
+use crate::chains::{StrategyLegs, chain::OptionChain, utils::OptionDataGroup};
+use crate::error::position::{PositionError, PositionValidationErrorKind};
+use crate::error::probability::ProbabilityError;
+use crate::error::strategies::{ProfitLossErrorKind, StrategyError};
+use crate::error::{GreeksError, PricingError};
+use crate::greeks::Greeks;
+use crate::model::ProfitLossRange;
+use crate::model::position::Position;
+use crate::model::types::{OptionBasicType, OptionStyle, OptionType, Side};
+use crate::model::utils::mean_and_std;
+use crate::pnl::{PnLCalculator, utils::PnL};
+use crate::pricing::payoff::Profit;
+use crate::strategies::delta_neutral::DeltaNeutrality;
+use crate::strategies::probabilities::{core::ProbabilityAnalysis, utils::VolatilityAdjustment};
+use crate::strategies::utils::{FindOptimalSide, OptimizationCriteria};
+use crate::strategies::{BasicAble, Strategies, StrategyConstructor};
+use crate::{ExpirationDate, Options, test_strategy_traits};
+use chrono::Utc;
+use positive::Positive;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tracing::{debug, info};
+use utoipa::ToSchema;
+
+/// Default description for the Jade Lizard and Big Lizard strategies.
+pub const LIZARD_DESCRIPTION: &str = "A lizard sells a put and a call spread with the same \
+    expiration, collecting at least the width of the call spread so there is no upside risk. The \
+    jade lizard sells the put below the call spread; the big lizard sells it at the short call \
+    strike.";
+
+/// Represents a Jade Lizard or a Big Lizard.
+///
+/// The kind follows the strikes: a put below the short call is a jade
+/// lizard and a put at the short call's strike a big lizard.
+#[derive(Clone, DebugPretty, DisplaySimple, Serialize, Deserialize, ToSchema)]
+pub struct Lizard {
+    /// Name identifier for this specific strategy instance
+    pub name: String,
+    /// `JadeLizard` or `BigLizard`
+    pub kind: StrategyType,
+    /// Detailed description of this strategy instance
+    pub description: String,
+    /// Prices at expiration where the strategy neither makes nor loses money
+    pub break_even_points: Vec<Positive>,
+    /// The sold put
+    pub(super) short_put: Position,
+    /// The sold call of the call spread
+    pub(super) short_call: Position,
+    /// The bought call capping the upside
+    pub(super) long_call: Position,
+}
+
+impl Lizard {
+    /// Creates a new Jade Lizard, or a Big Lizard when `put_strike` equals
+    /// `short_call_strike`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyError` if the strikes are out of order, the credit
+    /// is less than the call spread width, a leg fails validation or the
+    /// break-even calculation fails.
+    #[allow(clippy::too_many_arguments)]
+    #[inline(never)]
+    pub fn new(
+        underlying_symbol: String,
+        underlying_price: Positive,
+        put_strike: Positive,
+        short_call_strike: Positive,
+        long_call_strike: Positive,
+        expiration: ExpirationDate,
+        implied_volatility: Positive,
+        risk_free_rate: Decimal,
+        dividend_yield: Positive,
+        quantity: Positive,
+        premium_put: Positive,
+        premium_short_call: Positive,
+        premium_long_call: Positive,
+        open_fee: Positive,
+        close_fee: Positive,
+    ) -> Result<Self, StrategyError> {
+        let leg = |option_style, side, strike, premium| {
+            Position::new(
+                Options::new(
+                    OptionType::European,
+                    side,
+                    underlying_symbol.clone(),
+                    strike,
+                    expiration,
+                    implied_volatility,
+                    quantity,
+                    underlying_price,
+                    risk_free_rate,
+                    option_style,
+                    dividend_yield,
+                    None,
+                ),
+                premium,
+                Utc::now(),
+                open_fee,
+                close_fee,
+                None,
+                None,
+            )
+        };
+        Self::from_legs(
+            leg(OptionStyle::Put, Side::Short, put_strike, premium_put),
+            leg(
+                OptionStyle::Call,
+                Side::Short,
+                short_call_strike,
+                premium_short_call,
+            ),
+            leg(
+                OptionStyle::Call,
+                Side::Long,
+                long_call_strike,
+                premium_long_call,
+            ),
+        )
+    }
+
+    fn from_legs(
+        short_put: Position,
+        short_call: Position,
+        long_call: Position,
+    ) -> Result<Self, StrategyError> {
+        let kind = if short_put.option.strike_price == short_call.option.strike_price {
+            StrategyType::BigLizard
+        } else {
+            StrategyType::JadeLizard
+        };
+        let mut strategy = Lizard {
+            name: match kind {
+                StrategyType::BigLizard => "Big Lizard",
+                _ => "Jade Lizard",
+            }
+            .to_string(),
+            kind,
+            short_put,
+            short_call,
+            long_call,
+            ..Default::default()
+        };
+        if !strategy.validate() {
+            return Err(StrategyError::invalid_parameters(
+                "Lizard",
+                "requires a short put at or below a short call spread with one expiration, \
+                 opened for at least the call spread width",
+            ));
+        }
+        strategy.update_break_even_points()?;
+        Ok(strategy)
+    }
+
+    fn legs(&self) -> [&Position; 3] {
+        [&self.short_put, &self.short_call, &self.long_call]
+    }
+
+    fn legs_mut(&mut self) -> [&mut Position; 3] {
+        [
+            &mut self.short_put,
+            &mut self.short_call,
+            &mut self.long_call,
+        ]
+    }
+
+    /// Returns the put, short call and long call strikes.
+    pub fn strikes(&self) -> (Positive, Positive, Positive) {
+        (
+            self.short_put.option.strike_price,
+            self.short_call.option.strike_price,
+            self.long_call.option.strike_price,
+        )
+    }
+
+    /// The profit at expiration above the long call, which must not be
+    /// negative for the position to have no upside risk.
+    pub fn upside_profit(&self) -> Result<Decimal, PricingError> {
+        self.calculate_profit_at(&self.long_call.option.strike_price)
+    }
+
+    /// Expiration payoff at zero and at each strike, where its slope changes.
+    fn payoff_nodes(&self) -> Result<Vec<(Positive, Decimal)>, PricingError> {
+        let (put, short_call, long_call) = self.strikes();
+        let mut prices = vec![Positive::ZERO, put, short_call, long_call];
+        prices.dedup();
+        prices
+            .into_iter()
+            .map(|price| Ok((price, self.calculate_profit_at(&price)?)))
+            .collect()
+    }
+
+    fn volatility_adjustment(&self) -> VolatilityAdjustment {
+        let (base_volatility, std_dev_adjustment) = mean_and_std(
+            self.legs()
+                .iter()
+                .map(|leg| leg.option.implied_volatility)
+                .collect(),
+        );
+        VolatilityAdjustment {
+            base_volatility,
+            std_dev_adjustment,
+        }
+    }
+
+    fn ranges(&self, profitable: bool) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        let option = &self.short_put.option;
+        let mut bounds: Vec<Option<Positive>> = vec![None];
+        bounds.extend(self.break_even_points.iter().copied().map(Some));
+        bounds.push(None);
+        let mut ranges = Vec::new();
+        for window in bounds.windows(2) {
+            let [lower, upper] = window else {
+                continue;
+            };
+            let probe = match (lower, upper) {
+                (Some(l), Some(u)) => (*l + *u) / Positive::TWO,
+                (None, Some(u)) => *u / Positive::TWO,
+                (Some(l), None) => *l * Positive::TWO,
+                (None, None) => option.underlying_price,
+            };
+            if (self.calculate_profit_at(&probe)? > Decimal::ZERO) != profitable {
+                continue;
+            }
+            let mut range = ProfitLossRange::new(*lower, *upper, Positive::ZERO)?;
+            range.calculate_probability(
+                &option.underlying_price,
+                Some(self.volatility_adjustment()),
+                None,
+                &option.expiration_date,
+                Some(option.risk_free_rate),
+            )?;
+            ranges.push(range);
+        }
+        Ok(ranges)
+    }
+}
+
+impl StrategyConstructor for Lizard {
+    fn get_strategy(vec_positions: &[Position]) -> Result<Self, StrategyError> {
+        let mut puts = vec_positions
+            .iter()
+            .filter(|position| position.option.option_style == OptionStyle::Put);
+        let mut calls: Vec<&Position> = vec_positions
+            .iter()
+            .filter(|position| position.option.option_style == OptionStyle::Call)
+            .collect();
+        calls.sort_by_key(|position| position.option.strike_price);
+        let (Some(short_put), None, [short_call, long_call]) =
+            (puts.next(), puts.next(), calls.as_slice())
+        else {
+            return Err(StrategyError::invalid_parameters(
+                "Lizard get_strategy",
+                "Must have exactly 1 put and 2 calls",
+            ));
+        };
+        Self::from_legs(
+            short_put.clone(),
+            (*short_call).clone(),
+            (*long_call).clone(),
+        )
+    }
+}
+
+impl BreakEvenable for Lizard {
+    fn get_break_even_points(&self) -> Result<&Vec<Positive>, StrategyError> {
+        Ok(&self.break_even_points)
+    }
+
+    fn update_break_even_points(&mut self) -> Result<(), StrategyError> {
+        self.break_even_points = expiration_break_evens(&self.payoff_nodes()?, Decimal::ZERO)?;
+        Ok(())
+    }
+}
+
+impl Validable for Lizard {
+    fn validate(&self) -> bool {
+        let legs = self.legs();
+        if !legs.iter().all(|leg| leg.validate()) {
+            debug!("Lizard legs failed validation");
+            return false;
+        }
+        let shape = [
+            (OptionStyle::Put, Side::Short),
+            (OptionStyle::Call, Side::Short),
+            (OptionStyle::Call, Side::Long),
+        ];
+        if legs
+            .iter()
+            .zip(shape)
+            .any(|(leg, (style, side))| leg.option.option_style != style || leg.option.side != side)
+        {
+            debug!("Lizard requires a short put, a short call and a long call");
+            return false;
+        }
+        let put = &self.short_put.option;
+        if legs.iter().any(|leg| {
+            leg.option.expiration_date != put.expiration_date || leg.option.quantity != put.quantity
+        }) {
+            debug!("Lizard legs must share expiration and quantity");
+            return false;
+        }
+        let (put_strike, short_call_strike, long_call_strike) = self.strikes();
+        if put_strike > short_call_strike || short_call_strike >= long_call_strike {
+            debug!("Lizard put must be at or below a short call below the long call");
+            return false;
+        }
+        match self.upside_profit() {
+            Ok(profit) if profit >= Decimal::ZERO => true,
+            _ => {
+                debug!("Lizard credit must cover the call spread width");
+                false
+            }
+        }
+    }
+}
+
+impl Positionable for Lizard {
+    /// Replaces the put for a put position, otherwise the call with the
+    /// same side.
+    fn add_position(&mut self, position: &Position) -> Result<(), PositionError> {
+        let slot = match (position.option.option_style, position.option.side) {
+            (OptionStyle::Put, _) => &mut self.short_put,
+            (OptionStyle::Call, Side::Short) => &mut self.short_call,
+            (OptionStyle::Call, Side::Long) => &mut self.long_call,
+        };
+        *slot = position.clone();
+        Ok(())
+    }
+
+    fn get_positions(&self) -> Result<Vec<&Position>, PositionError> {
+        Ok(self.legs().to_vec())
+    }
+
+    fn get_position(
+        &mut self,
+        option_style: &OptionStyle,
+        side: &Side,
+        strike: &Positive,
+    ) -> Result<Vec<&mut Position>, PositionError> {
+        let positions: Vec<&mut Position> = self
+            .legs_mut()
+            .into_iter()
+            .filter(|leg| {
+                leg.option.option_style == *option_style
+                    && leg.option.side == *side
+                    && leg.option.strike_price == *strike
+            })
+            .collect();
+        if positions.is_empty() {
+            return Err(PositionError::invalid_position_type(
+                *side,
+                "Strike not found in positions".to_string(),
+            ));
+        }
+        Ok(positions)
+    }
+
+    fn modify_position(&mut self, position: &Position) -> Result<(), PositionError> {
+        if !position.validate() {
+            return Err(PositionError::ValidationError(
+                PositionValidationErrorKind::InvalidPosition {
+                    reason: "Invalid position data".to_string(),
+                },
+            ));
+        }
+        let option = &position.option;
+        let slot = self.legs_mut().into_iter().find(|leg| {
+            leg.option.option_style == option.option_style
+                && leg.option.side == option.side
+                && leg.option.strike_price == option.strike_price
+        });
+        match slot {
+            Some(slot) => {
+                *slot = position.clone();
+                Ok(())
+            }
+            None => Err(PositionError::invalid_position_type(
+                option.side,
+                "Strike not found in positions".to_string(),
+            )),
+        }
+    }
+}
+
+impl Strategable for Lizard {
+    fn info(&self) -> Result<StrategyBasics, StrategyError> {
+        Ok(StrategyBasics {
+            name: self.name.clone(),
+            kind: self.kind.clone(),
+            description: self.description.clone(),
+        })
+    }
+}
+
+impl BasicAble for Lizard {
+    fn get_title(&self) -> String {
+        let legs: Vec<String> = self.legs().iter().map(|leg| leg.get_title()).collect();
+        format!("{:?} Strategy: \n\t{}", self.kind, legs.join("\n\t"))
+    }
+
+    fn get_option_basic_type(&self) -> HashSet<OptionBasicType<'_>> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                OptionBasicType {
+                    option_style: &option.option_style,
+                    side: &option.side,
+                    strike_price: &option.strike_price,
+                    expiration_date: &option.expiration_date,
+                }
+            })
+            .collect()
+    }
+
+    fn get_implied_volatility(&self) -> HashMap<OptionBasicType<'_>, &Positive> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                (
+                    OptionBasicType {
+                        option_style: &option.option_style,
+                        side: &option.side,
+                        strike_price: &option.strike_price,
+                        expiration_date: &option.expiration_date,
+                    },
+                    &option.implied_volatility,
+                )
+            })
+            .collect()
+    }
+
+    fn get_quantity(&self) -> HashMap<OptionBasicType<'_>, &Positive> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                (
+                    OptionBasicType {
+                        option_style: &option.option_style,
+                        side: &option.side,
+                        strike_price: &option.strike_price,
+                        expiration_date: &option.expiration_date,
+                    },
+                    &option.quantity,
+                )
+            })
+            .collect()
+    }
+
+    fn one_option(&self) -> &Options {
+        self.short_put.one_option()
+    }
+
+    fn one_option_mut(&mut self) -> &mut Options {
+        self.short_put.one_option_mut()
+    }
+
+    fn set_expiration_date(
+        &mut self,
+        expiration_date: ExpirationDate,
+    ) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.expiration_date = expiration_date;
+        }
+        Ok(())
+    }
+
+    fn set_underlying_price(&mut self, price: &Positive) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.underlying_price = *price;
+            leg.premium = Positive::new_decimal(leg.option.calculate_price_black_scholes()?.abs())
+                .unwrap_or(Positive::ZERO);
+        }
+        Ok(())
+    }
+
+    fn set_implied_volatility(&mut self, volatility: &Positive) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.implied_volatility = *volatility;
+            leg.premium = Positive::new_decimal(leg.option.calculate_price_black_scholes()?.abs())
+                .unwrap_or(Positive::ZERO);
+        }
+        Ok(())
+    }
+}
+
+impl Strategies for Lizard {
+    fn get_max_profit(&self) -> Result<Positive, StrategyError> {
+        let max = self
+            .payoff_nodes()?
+            .into_iter()
+            .map(|(_, profit)| profit)
+            .fold(Decimal::MIN, Decimal::max);
+        if max <= Decimal::ZERO {
+            return Err(StrategyError::ProfitLossError(
+                ProfitLossErrorKind::MaxProfitError {
+                    reason: "Max profit is negative".to_string(),
+                },
+            ));
+        }
+        Ok(Positive::new_decimal(max)?)
+    }
+
+    /// The loss with the underlying at zero, below the short put.
+    fn get_max_loss(&self) -> Result<Positive, StrategyError> {
+        let min = self
+            .payoff_nodes()?
+            .into_iter()
+            .map(|(_, profit)| profit)
+            .fold(Decimal::MAX, Decimal::min);
+        Ok(Positive::new_decimal(min.min(Decimal::ZERO).abs())?)
+    }
+
+    /// Area of the profitable region at expiration from the lower break-even
+    /// to one call spread width above the long call, divided by the
+    /// underlying price.
+    fn get_profit_area(&self) -> Result<Decimal, StrategyError> {
+        let (_, short_call, long_call) = self.strikes();
+        let mut prices: Vec<Positive> = self
+            .legs()
+            .iter()
+            .map(|leg| leg.option.strike_price)
+            .chain(self.break_even_points.iter().copied())
+            .chain([long_call + (long_call - short_call)])
+            .collect();
+        prices.sort();
+        let mut area = Decimal::ZERO;
+        for pair in prices.windows(2) {
+            let [x0, x1] = pair else {
+                continue;
+            };
+            let mean =
+                (self.calculate_profit_at(x0)? + self.calculate_profit_at(x1)?) / Decimal::TWO;
+            if mean > Decimal::ZERO {
+                area += mean * (x1.to_dec() - x0.to_dec());
+            }
+        }
+        let underlying = self.get_underlying_price().to_dec();
+        if underlying.is_zero() {
+            return Ok(Decimal::ZERO);
+        }
+        Ok(area / underlying)
+    }
+
+    fn get_profit_ratio(&self) -> Result<Decimal, StrategyError> {
+        match (self.get_max_profit(), self.get_max_loss()) {
+            (Ok(profit), Ok(loss)) if loss > Positive::ZERO => {
+                Ok(profit.to_dec() / loss.to_dec() * Decimal::ONE_HUNDRED)
+            }
+            _ => Ok(Decimal::ZERO),
+        }
+    }
+}
+
+impl Optimizable for Lizard {
+    type Strategy = Lizard;
+
+    /// Yields the put, short call and long call strikes that keep this
+    /// lizard's kind and cover the call spread width with the credit.
+    fn filter_combinations<'a>(
+        &'a self,
+        option_chain: &'a OptionChain,
+        side: FindOptimalSide,
+    ) -> impl Iterator<Item = OptionDataGroup<'a>> {
+        let underlying_price = self.get_underlying_price();
+        let big = self.kind == StrategyType::BigLizard;
+        let strategy = self.clone();
+        option_chain
+            .get_triple_inclusive_iter()
+            .filter(move |(put, short_call, long_call)| {
+                let straddled = put.strike_price == short_call.strike_price;
+                straddled == big && short_call.strike_price < long_call.strike_price
+            })
+            .filter(move |(put, short_call, long_call)| {
+                if side == FindOptimalSide::Center {
+                    put.is_valid_optimal_side(underlying_price, &FindOptimalSide::Lower)
+                        && long_call
+                            .is_valid_optimal_side(underlying_price, &FindOptimalSide::Upper)
+                } else {
+                    [put, short_call, long_call]
+                        .iter()
+                        .all(|option| option.is_valid_optimal_side(underlying_price, &side))
+                }
+            })
+            .filter(move |(put, short_call, long_call)| {
+                let legs = StrategyLegs::ThreeLegs {
+                    first: put,
+                    second: short_call,
+                    third: long_call,
+                };
+                match strategy.create_strategy(option_chain, &legs) {
+                    Ok(s) => s.get_max_profit().is_ok(),
+                    Err(_) => false,
+                }
+            })
+            .map(|(put, short_call, long_call)| OptionDataGroup::Three(put, short_call, long_call))
+    }
+
+    fn find_optimal(
+        &mut self,
+        option_chain: &OptionChain,
+        side: FindOptimalSide,
+        criteria: OptimizationCriteria,
+    ) {
+        let mut best_value = Decimal::MIN;
+        let strategy_clone = self.clone();
+        for option_data_group in strategy_clone.filter_combinations(option_chain, side) {
+            let OptionDataGroup::Three(first, second, third) = option_data_group else {
+                continue;
+            };
+            let legs = StrategyLegs::ThreeLegs {
+                first,
+                second,
+                third,
+            };
+            let strategy = match self.create_strategy(option_chain, &legs) {
+                Ok(s) => s,
+                Err(e) => {
+                    debug!("Skipping lizard candidate: {}", e);
+                    continue;
+                }
+            };
+            let metric = match criteria {
+                OptimizationCriteria::Ratio => strategy.get_profit_ratio(),
+                OptimizationCriteria::Area => strategy.get_profit_area(),
+            };
+            match metric {
+                Ok(value) if value > best_value => {
+                    info!("Found better value: {}", value);
+                    best_value = value;
+                    *self = strategy;
+                }
+                Ok(_) => {}
+                Err(e) => debug!("Skipping candidate with unscorable metric: {}", e),
+            }
+        }
+    }
+
+    /// Builds a lizard selling the put at `first` and the call at `second`
+    /// and buying the call at `third`, filling at the chain's bid and ask.
+    fn create_strategy(
+        &self,
+        chain: &OptionChain,
+        legs: &StrategyLegs,
+    ) -> Result<Self::Strategy, StrategyError> {
+        let StrategyLegs::ThreeLegs {
+            first,
+            second,
+            third,
+        } = legs
+        else {
+            return Err(StrategyError::operation_not_supported(
+                "create_strategy",
+                "Lizard requires exactly three legs (ThreeLegs)",
+            ));
+        };
+        let template = &self.short_put;
+        let expiration = chain
+            .get_expiration()
+            .unwrap_or(template.option.expiration_date);
+        let leg = |strike, option_style, side| {
+            chain_leg(
+                chain,
+                expiration,
+                strike,
+                option_style,
+                side,
+                template.option.quantity,
+                template.open_fee,
+                template.close_fee,
+            )
+        };
+        Self::from_legs(
+            leg(first.strike_price, OptionStyle::Put, Side::Short)?,
+            leg(second.strike_price, OptionStyle::Call, Side::Short)?,
+            leg(third.strike_price, OptionStyle::Call, Side::Long)?,
+        )
+    }
+}
+
+impl Profit for Lizard {
+    fn calculate_profit_at(&self, price: &Positive) -> Result<Decimal, PricingError> {
+        let price = Some(price);
+        self.legs()
+            .iter()
+            .map(|leg| leg.pnl_at_expiration(&price))
+            .sum()
+    }
+}
+
+impl ProbabilityAnalysis for Lizard {
+    fn get_profit_ranges(&self) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        self.ranges(true)
+    }
+
+    fn get_loss_ranges(&self) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        self.ranges(false)
+    }
+}
+
+impl Greeks for Lizard {
+    fn get_options(&self) -> Result<Vec<&Options>, GreeksError> {
+        Ok(self.legs().map(|leg| &leg.option).to_vec())
+    }
+}
+
+impl DeltaNeutrality for Lizard {}
+
+impl PnLCalculator for Lizard {
+    fn calculate_pnl(
+        &self,
+        market_price: &Positive,
+        expiration_date: ExpirationDate,
+        implied_volatility: &Positive,
+    ) -> Result<PnL, PricingError> {
+        let mut pnl = PnL::default();
+        for leg in self.legs() {
+            pnl = pnl + leg.calculate_pnl(market_price, expiration_date, implied_volatility)?;
+        }
+        Ok(pnl)
+    }
+
+    fn calculate_pnl_at_expiration(
+        &self,
+        underlying_price: &Positive,
+    ) -> Result<PnL, PricingError> {
+        let mut pnl = PnL::default();
+        for leg in self.legs() {
+            pnl = pnl + leg.calculate_pnl_at_expiration(underlying_price)?;
+        }
+        Ok(pnl)
+    }
+}
+
+test_strategy_traits!(Lizard, test_lizard_implementations);
+
+#[cfg(test)]
+mod tests_lizard {
+    use super::*;
+    use crate::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
+    use positive::{pos_or_panic, spos};
+    use rust_decimal_macros::dec;
+
+    fn lizard(strikes: [f64; 3], premiums: [f64; 3]) -> Result<Lizard, StrategyError> {
+        let [put, short_call, long_call] = strikes.map(|strike| pos_or_panic!(strike));
+        let [premium_put, premium_short_call, premium_long_call] =
+            premiums.map(|premium| pos_or_panic!(premium));
+        Lizard::new(
+            "SPY".to_string(),
+            Positive::HUNDRED,
+            put,
+            short_call,
+            long_call,
+            ExpirationDate::Days(pos_or_panic!(30.0)),
+            pos_or_panic!(0.2),
+            dec!(0.03),
+            Positive::ZERO,
+            Positive::ONE,
+            premium_put,
+            premium_short_call,
+            premium_long_call,
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+    }
+
+    fn chain() -> OptionChain {
+        let params = OptionChainBuildParams::new(
+            "SPY".to_string(),
+            spos!(1000.0),
+            10,
+            spos!(5.0),
+            dec!(-0.3),
+            dec!(0.1),
+            pos_or_panic!(0.02),
+            2,
+            OptionDataPriceParams::new(
+                Some(Box::new(Positive::HUNDRED)),
+                Some(ExpirationDate::Days(pos_or_panic!(30.0))),
+                Some(dec!(0.03)),
+                spos!(0.0),
+                Some("SPY".to_string()),
+            ),
+            pos_or_panic!(0.2),
+        );
+        OptionChain::build_chain(&params).unwrap()
+    }
+
+    #[test]
+    fn test_jade_lizard_has_no_upside_risk() {
+        // Credit of 1.5 + 2.4 - 0.9 = 3.0 against a 2.5-wide call spread.
+        let strategy = lizard([95.0, 102.5, 105.0], [1.5, 2.4, 0.9]).unwrap();
+        assert_eq!(strategy.kind, StrategyType::JadeLizard);
+        assert_eq!(strategy.upside_profit().unwrap(), dec!(0.5));
+        assert_eq!(strategy.get_max_profit().unwrap(), pos_or_panic!(3.0));
+        assert_eq!(strategy.get_max_loss().unwrap(), pos_or_panic!(92.0));
+        assert_eq!(
+            strategy.get_break_even_points().unwrap(),
+            &vec![pos_or_panic!(92.0)]
+        );
+        assert!(strategy.get_profit_area().unwrap() > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_big_lizard_sells_a_straddle() {
+        // Credit of 4.2 + 4.5 - 1.0 = 7.7 against a 7.5-wide call spread.
+        let strategy = lizard([100.0, 100.0, 107.5], [4.2, 4.5, 1.0]).unwrap();
+        assert_eq!(strategy.kind, StrategyType::BigLizard);
+        assert_eq!(strategy.name, "Big Lizard");
+        assert_eq!(strategy.get_max_profit().unwrap(), pos_or_panic!(7.7));
+        assert_eq!(strategy.upside_profit().unwrap(), dec!(0.2));
+        assert_eq!(
+            strategy.get_break_even_points().unwrap(),
+            &vec![pos_or_panic!(92.3)]
+        );
+    }
+
+    #[test]
+    fn test_rejects_upside_risk() {
+        // Credit of 2.0 does not cover the 5-wide call spread.
+        assert!(lizard([95.0, 100.0, 105.0], [1.0, 1.5, 0.5]).is_err());
+        // Put above the short call.
+        assert!(lizard([105.0, 100.0, 110.0], [6.0, 4.0, 1.0]).is_err());
+    }
+
+    #[test]
+    fn test_optimizer_keeps_the_kind() {
+        let chain = chain();
+        let mut jade = lizard([95.0, 102.5, 105.0], [1.5, 2.4, 0.9]).unwrap();
+        jade.get_best_ratio(&chain, FindOptimalSide::All);
+        assert!(jade.validate());
+        assert_eq!(jade.kind, StrategyType::JadeLizard);
+
+        let mut big = lizard([100.0, 100.0, 107.5], [4.2, 4.5, 1.0]).unwrap();
+        big.get_best_area(&chain, FindOptimalSide::All);
+        assert!(big.validate());
+        assert_eq!(big.kind, StrategyType::BigLizard);
+        assert!(big.upside_profit().unwrap() >= Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_probabilities_sum_to_one() {
+        let strategy = lizard([95.0, 102.5, 105.0], [1.5, 2.4, 0.9]).unwrap();
+        let total: Positive = strategy
+            .get_profit_ranges()
+            .unwrap()
+            .iter()
+            .chain(strategy.get_loss_ranges().unwrap().iter())
+            .map(|range| range.probability)
+            .sum();
+        assert!((total.to_dec() - Decimal::ONE).abs() < dec!(0.01));
+    }
+}
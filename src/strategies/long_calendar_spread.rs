@@ -0,0 +1,962 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Long Calendar Spread Strategy
+//!
+//! A long calendar spread (or time spread) sells a front-month option and buys
+//! a back-month option of the same style at the same strike. The position is
+//! opened for a debit and profits from the faster time decay of the front leg:
+//! the best outcome is the underlying pinned at the strike when the front leg
+//! expires, while the back leg still holds most of its time value.
+//!
+//! Unlike single-expiration strategies, the payoff is measured at the front
+//! expiry with the back leg marked to model for its remaining time (see
+//! [`TimeSpreadStrategy`]), so break-even points and extremes are found
+//! numerically. The spread can be built with calls or puts.
+
+use super::base::{
+    BreakEvenable, Optimizable, Positionable, Strategable, StrategyBasics, StrategyType, Validable,
+};
+use crate::chains::{OptionData, StrategyLegs, chain::OptionChain, utils::OptionDataGroup};
+use crate::error::position::{PositionError, PositionValidationErrorKind};
+use crate::error::probability::ProbabilityError;
+use crate::error::strategies::{ProfitLossErrorKind, StrategyError};
+use crate::error::{GreeksError, PricingError};
+use crate::greeks::Greeks;
+use crate::model::ProfitLossRange;
+use crate::model::position::Position;
+use crate::model::types::{OptionBasicType, OptionStyle, OptionType, Side};
+use crate::model::utils::mean_and_std;
+use crate::pnl::{PnLCalculator, utils::PnL};
+use crate::pricing::payoff::Profit;
+use crate::series::OptionSeries;
+use crate::strategies::delta_neutral::DeltaNeutrality;
+use crate::strategies::probabilities::{core::ProbabilityAnalysis, utils::VolatilityAdjustment};
+use crate::strategies::shared::{TimeSpreadStrategy, chain_leg};
+use crate::strategies::utils::{FindOptimalSide, OptimizationCriteria};
+use crate::strategies::{BasicAble, Strategies, StrategyConstructor};
+use crate::{ExpirationDate, Options, test_strategy_traits};
+use chrono::Utc;
+use positive::Positive;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tracing::debug;
+use utoipa::ToSchema;
+
+/// Default description for the Long Calendar Spread strategy.
+pub const LONG_CALENDAR_SPREAD_DESCRIPTION: &str = "A long calendar spread sells a near-term option \
+    and buys a longer-term option of the same type and strike. It is opened for a debit and profits \
+    from the faster time decay of the near-term option, peaking when the underlying sits at the \
+    strike at the front expiration.";
+
+/// Represents a Long Calendar Spread options trading strategy.
+///
+/// The spread is short the front-month option and long the back-month option,
+/// both at the same strike and of the same style (calls or puts).
+#[derive(Clone, DebugPretty, DisplaySimple, Serialize, Deserialize, ToSchema)]
+pub struct LongCalendarSpread {
+    /// Name identifier for this specific strategy instance
+    pub name: String,
+    /// Identifies this as a LongCalendarSpread strategy type
+    pub kind: StrategyType,
+    /// Detailed description of this strategy instance
+    pub description: String,
+    /// Prices at the front expiry where the strategy neither makes nor loses money
+    pub break_even_points: Vec<Positive>,
+    /// The near-term option that is sold
+    pub(super) short_front: Position,
+    /// The longer-term option that is bought
+    pub(super) long_back: Position,
+}
+
+impl LongCalendarSpread {
+    /// Creates a new Long Calendar Spread.
+    ///
+    /// Each leg keeps its own implied volatility so the term structure is
+    /// reflected when the back leg is valued at the front expiry.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyError` if the back leg does not expire after the
+    /// front leg or the break-even calculation fails.
+    #[allow(clippy::too_many_arguments)]
+    #[inline(never)]
+    pub fn new(
+        underlying_symbol: String,
+        underlying_price: Positive,
+        strike: Positive,
+        option_style: OptionStyle,
+        front_expiration: ExpirationDate,
+        back_expiration: ExpirationDate,
+        front_implied_volatility: Positive,
+        back_implied_volatility: Positive,
+        risk_free_rate: Decimal,
+        dividend_yield: Positive,
+        quantity: Positive,
+        premium_front: Positive,
+        premium_back: Positive,
+        open_fee_front: Positive,
+        close_fee_front: Positive,
+        open_fee_back: Positive,
+        close_fee_back: Positive,
+    ) -> Result<Self, StrategyError> {
+        let leg = |side, expiration, implied_volatility, premium, open_fee, close_fee| {
+            Position::new(
+                Options::new(
+                    OptionType::European,
+                    side,
+                    underlying_symbol.clone(),
+                    strike,
+                    expiration,
+                    implied_volatility,
+                    quantity,
+                    underlying_price,
+                    risk_free_rate,
+                    option_style,
+                    dividend_yield,
+                    None,
+                ),
+                premium,
+                Utc::now(),
+                open_fee,
+                close_fee,
+                None,
+                None,
+            )
+        };
+        let short_front = leg(
+            Side::Short,
+            front_expiration,
+            front_implied_volatility,
+            premium_front,
+            open_fee_front,
+            close_fee_front,
+        );
+        let long_back = leg(
+            Side::Long,
+            back_expiration,
+            back_implied_volatility,
+            premium_back,
+            open_fee_back,
+            close_fee_back,
+        );
+        Self::from_legs(short_front, long_back)
+    }
+
+    /// Creates a Long Calendar Spread from two chains of an option series,
+    /// selling `strike` at `front_expiration` (filled at the bid) and buying
+    /// it at `back_expiration` (filled at the ask).
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyError` if either expiration is not in the series, the
+    /// strike is not quoted in both chains or the legs are inconsistent.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_series(
+        series: &OptionSeries,
+        front_expiration: &ExpirationDate,
+        back_expiration: &ExpirationDate,
+        strike: Positive,
+        option_style: OptionStyle,
+        quantity: Positive,
+        open_fee: Positive,
+        close_fee: Positive,
+    ) -> Result<Self, StrategyError> {
+        let chain = |expiration: &ExpirationDate| {
+            series.chains.get(expiration).ok_or_else(|| {
+                StrategyError::invalid_parameters(
+                    "LongCalendarSpread::from_series",
+                    &format!("expiration {expiration} is not in the series"),
+                )
+            })
+        };
+        let short_front = chain_leg(
+            chain(front_expiration)?,
+            *front_expiration,
+            strike,
+            option_style,
+            Side::Short,
+            quantity,
+            open_fee,
+            close_fee,
+        )?;
+        let long_back = chain_leg(
+            chain(back_expiration)?,
+            *back_expiration,
+            strike,
+            option_style,
+            Side::Long,
+            quantity,
+            open_fee,
+            close_fee,
+        )?;
+        Self::from_legs(short_front, long_back)
+    }
+
+    /// Searches every pair of expirations of `series` and every common strike
+    /// for the best Long Calendar Spread of this spread's option style,
+    /// replacing `self` with it.
+    ///
+    /// Candidates keep this spread's quantity and fees and must satisfy
+    /// `side` on their strike.
+    pub fn find_optimal_in_series(
+        &mut self,
+        series: &OptionSeries,
+        side: FindOptimalSide,
+        criteria: OptimizationCriteria,
+    ) {
+        let option_style = self.short_front.option.option_style;
+        let expirations: Vec<&ExpirationDate> = series.chains.keys().collect();
+        let mut best_value = Decimal::MIN;
+        for (i, front) in expirations.iter().enumerate() {
+            for back in expirations.iter().skip(i + 1) {
+                let Some(front_chain) = series.chains.get(*front) else {
+                    continue;
+                };
+                for option in &front_chain.options {
+                    if !self.is_valid_optimal_option(option, &side) {
+                        continue;
+                    }
+                    let candidate = match Self::from_series(
+                        series,
+                        front,
+                        back,
+                        option.strike_price,
+                        option_style,
+                        self.short_front.option.quantity,
+                        self.short_front.open_fee,
+                        self.short_front.close_fee,
+                    ) {
+                        Ok(candidate) => candidate,
+                        Err(e) => {
+                            debug!("Skipping calendar candidate: {}", e);
+                            continue;
+                        }
+                    };
+                    if let Some(value) = candidate.score(&criteria)
+                        && value > best_value
+                    {
+                        best_value = value;
+                        *self = candidate;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the strike shared by both legs.
+    #[must_use]
+    pub fn strike(&self) -> Positive {
+        self.short_front.option.strike_price
+    }
+
+    fn from_legs(short_front: Position, long_back: Position) -> Result<Self, StrategyError> {
+        let mut strategy = LongCalendarSpread {
+            short_front,
+            long_back,
+            ..Default::default()
+        };
+        if !strategy.validate() {
+            return Err(StrategyError::invalid_parameters(
+                "LongCalendarSpread",
+                "requires a short front and a long back option of the same style and strike, \
+                 with the back leg expiring later",
+            ));
+        }
+        strategy.update_break_even_points()?;
+        Ok(strategy)
+    }
+
+    fn score(&self, criteria: &OptimizationCriteria) -> Option<Decimal> {
+        if !self.validate() {
+            return None;
+        }
+        let metric = match criteria {
+            OptimizationCriteria::Ratio => self.get_profit_ratio(),
+            OptimizationCriteria::Area => self.get_profit_area(),
+        };
+        metric
+            .inspect_err(|e| debug!("Skipping candidate with unscorable metric: {}", e))
+            .ok()
+    }
+}
+
+impl TimeSpreadStrategy for LongCalendarSpread {
+    fn front_leg(&self) -> &Position {
+        &self.short_front
+    }
+
+    fn back_leg(&self) -> &Position {
+        &self.long_back
+    }
+}
+
+impl StrategyConstructor for LongCalendarSpread {
+    fn get_strategy(vec_positions: &[Position]) -> Result<Self, StrategyError> {
+        let [first, second] = vec_positions else {
+            return Err(StrategyError::invalid_parameters(
+                "Long Calendar Spread get_strategy",
+                "Must have exactly 2 options",
+            ));
+        };
+        let (short_front, long_back) = match (first.option.side, second.option.side) {
+            (Side::Short, Side::Long) => (first, second),
+            (Side::Long, Side::Short) => (second, first),
+            _ => {
+                return Err(StrategyError::invalid_parameters(
+                    "Long Calendar Spread get_strategy",
+                    "Long Calendar Spread requires a short front option and a long back option",
+                ));
+            }
+        };
+        Self::from_legs(short_front.clone(), long_back.clone())
+    }
+}
+
+impl BreakEvenable for LongCalendarSpread {
+    fn get_break_even_points(&self) -> Result<&Vec<Positive>, StrategyError> {
+        Ok(&self.break_even_points)
+    }
+
+    fn update_break_even_points(&mut self) -> Result<(), StrategyError> {
+        self.break_even_points = self.front_expiry_break_evens()?;
+        Ok(())
+    }
+}
+
+impl Validable for LongCalendarSpread {
+    fn validate(&self) -> bool {
+        let (front, back) = (&self.short_front.option, &self.long_back.option);
+        if !self.short_front.validate() || !self.long_back.validate() {
+            debug!("Calendar legs failed validation");
+            return false;
+        }
+        if front.side != Side::Short || back.side != Side::Long {
+            debug!("Long calendar requires a short front and a long back leg");
+            return false;
+        }
+        if front.option_style != back.option_style || front.strike_price != back.strike_price {
+            debug!("Calendar legs must share style and strike");
+            return false;
+        }
+        self.remaining_days().is_ok()
+    }
+}
+
+impl Positionable for LongCalendarSpread {
+    fn add_position(&mut self, position: &Position) -> Result<(), PositionError> {
+        match position.option.side {
+            Side::Short => self.short_front = position.clone(),
+            Side::Long => self.long_back = position.clone(),
+        }
+        Ok(())
+    }
+
+    fn get_positions(&self) -> Result<Vec<&Position>, PositionError> {
+        Ok(vec![&self.short_front, &self.long_back])
+    }
+
+    fn get_position(
+        &mut self,
+        option_style: &OptionStyle,
+        side: &Side,
+        strike: &Positive,
+    ) -> Result<Vec<&mut Position>, PositionError> {
+        let position = match side {
+            Side::Short => &mut self.short_front,
+            Side::Long => &mut self.long_back,
+        };
+        if position.option.option_style == *option_style && position.option.strike_price == *strike
+        {
+            Ok(vec![position])
+        } else {
+            Err(PositionError::invalid_position_type(
+                *side,
+                "Strike not found in positions".to_string(),
+            ))
+        }
+    }
+
+    fn modify_position(&mut self, position: &Position) -> Result<(), PositionError> {
+        if !position.validate() {
+            return Err(PositionError::ValidationError(
+                PositionValidationErrorKind::InvalidPosition {
+                    reason: "Invalid position data".to_string(),
+                },
+            ));
+        }
+        let option = &position.option;
+        let current = match option.side {
+            Side::Short => &mut self.short_front,
+            Side::Long => &mut self.long_back,
+        };
+        if current.option.option_style != option.option_style
+            || current.option.strike_price != option.strike_price
+        {
+            return Err(PositionError::invalid_position_type(
+                option.side,
+                "Strike not found in positions".to_string(),
+            ));
+        }
+        *current = position.clone();
+        Ok(())
+    }
+}
+
+impl Strategable for LongCalendarSpread {
+    fn info(&self) -> Result<StrategyBasics, StrategyError> {
+        Ok(StrategyBasics {
+            name: self.name.clone(),
+            kind: self.kind.clone(),
+            description: self.description.clone(),
+        })
+    }
+}
+
+impl BasicAble for LongCalendarSpread {
+    fn get_title(&self) -> String {
+        format!(
+            "{:?} Strategy: \n\t{}\n\t{}",
+            self.kind,
+            self.short_front.get_title(),
+            self.long_back.get_title()
+        )
+    }
+
+    fn get_option_basic_type(&self) -> HashSet<OptionBasicType<'_>> {
+        [&self.short_front.option, &self.long_back.option]
+            .into_iter()
+            .map(|option| OptionBasicType {
+                option_style: &option.option_style,
+                side: &option.side,
+                strike_price: &option.strike_price,
+                expiration_date: &option.expiration_date,
+            })
+            .collect()
+    }
+
+    fn get_implied_volatility(&self) -> HashMap<OptionBasicType<'_>, &Positive> {
+        [&self.short_front.option, &self.long_back.option]
+            .into_iter()
+            .map(|option| {
+                (
+                    OptionBasicType {
+                        option_style: &option.option_style,
+                        side: &option.side,
+                        strike_price: &option.strike_price,
+                        expiration_date: &option.expiration_date,
+                    },
+                    &option.implied_volatility,
+                )
+            })
+            .collect()
+    }
+
+    fn get_quantity(&self) -> HashMap<OptionBasicType<'_>, &Positive> {
+        [&self.short_front.option, &self.long_back.option]
+            .into_iter()
+            .map(|option| {
+                (
+                    OptionBasicType {
+                        option_style: &option.option_style,
+                        side: &option.side,
+                        strike_price: &option.strike_price,
+                        expiration_date: &option.expiration_date,
+                    },
+                    &option.quantity,
+                )
+            })
+            .collect()
+    }
+
+    fn one_option(&self) -> &Options {
+        self.short_front.one_option()
+    }
+
+    fn one_option_mut(&mut self) -> &mut Options {
+        self.short_front.one_option_mut()
+    }
+
+    /// Moves the front expiration to `expiration_date`, keeping the gap
+    /// between the front and back legs.
+    fn set_expiration_date(
+        &mut self,
+        expiration_date: ExpirationDate,
+    ) -> Result<(), StrategyError> {
+        let remaining = self.remaining_days()?;
+        self.short_front.option.expiration_date = expiration_date;
+        self.long_back.option.expiration_date = ExpirationDate::Days(
+            expiration_date.get_days().map_err(PricingError::from)? + remaining,
+        );
+        Ok(())
+    }
+
+    fn set_underlying_price(&mut self, price: &Positive) -> Result<(), StrategyError> {
+        for position in [&mut self.short_front, &mut self.long_back] {
+            position.option.underlying_price = *price;
+            position.premium =
+                Positive::new_decimal(position.option.calculate_price_black_scholes()?.abs())
+                    .unwrap_or(Positive::ZERO);
+        }
+        Ok(())
+    }
+
+    fn set_implied_volatility(&mut self, volatility: &Positive) -> Result<(), StrategyError> {
+        for position in [&mut self.short_front, &mut self.long_back] {
+            position.option.implied_volatility = *volatility;
+            position.premium =
+                Positive::new_decimal(position.option.calculate_price_black_scholes()?.abs())
+                    .unwrap_or(Positive::ZERO);
+        }
+        Ok(())
+    }
+}
+
+impl Strategies for LongCalendarSpread {
+    fn get_max_profit(&self) -> Result<Positive, StrategyError> {
+        let (max, _) = self.front_expiry_extremes()?;
+        if max <= Decimal::ZERO {
+            return Err(StrategyError::ProfitLossError(
+                ProfitLossErrorKind::MaxProfitError {
+                    reason: "Max profit is negative".to_string(),
+                },
+            ));
+        }
+        Ok(Positive::new_decimal(max)?)
+    }
+
+    fn get_max_loss(&self) -> Result<Positive, StrategyError> {
+        let (_, min) = self.front_expiry_extremes()?;
+        if min >= Decimal::ZERO {
+            return Err(StrategyError::ProfitLossError(
+                ProfitLossErrorKind::MaxLossError {
+                    reason: "Max loss must be negative".to_string(),
+                },
+            ));
+        }
+        Ok(Positive::new_decimal(min.abs())?)
+    }
+
+    fn get_profit_area(&self) -> Result<Decimal, StrategyError> {
+        Ok(self.front_expiry_profit_area()?)
+    }
+
+    fn get_profit_ratio(&self) -> Result<Decimal, StrategyError> {
+        match (self.get_max_profit(), self.get_max_loss()) {
+            (Ok(profit), Ok(loss)) => Ok(profit.to_dec() / loss.to_dec() * Decimal::ONE_HUNDRED),
+            _ => Ok(Decimal::ZERO),
+        }
+    }
+}
+
+impl Optimizable for LongCalendarSpread {
+    type Strategy = LongCalendarSpread;
+
+    /// Yields every strike of `option_chain` that satisfies `side`, paired
+    /// with itself: the front leg and the volatility of the back leg both
+    /// come from that strike.
+    fn filter_combinations<'a>(
+        &'a self,
+        option_chain: &'a OptionChain,
+        side: FindOptimalSide,
+    ) -> impl Iterator<Item = OptionDataGroup<'a>> {
+        option_chain
+            .options
+            .iter()
+            .filter(move |option| self.is_valid_optimal_option(option, &side))
+            .map(|option| OptionDataGroup::Two(option, option))
+    }
+
+    /// Finds the best strike using `option_chain` as the front expiration.
+    ///
+    /// A single chain only quotes one expiration, so the back leg is priced
+    /// with Black-Scholes at this spread's back expiration using the strike's
+    /// implied volatility. Use [`LongCalendarSpread::find_optimal_in_series`]
+    /// to price both legs from market quotes.
+    fn find_optimal(
+        &mut self,
+        option_chain: &OptionChain,
+        side: FindOptimalSide,
+        criteria: OptimizationCriteria,
+    ) {
+        let mut best_value = Decimal::MIN;
+        for option in &option_chain.options {
+            if !self.is_valid_optimal_option(option, &side) {
+                continue;
+            }
+            let legs = StrategyLegs::TwoLegs {
+                first: option,
+                second: option,
+            };
+            let candidate = match self.create_strategy(option_chain, &legs) {
+                Ok(candidate) => candidate,
+                Err(e) => {
+                    debug!("Skipping calendar candidate: {}", e);
+                    continue;
+                }
+            };
+            if let Some(value) = candidate.score(&criteria)
+                && value > best_value
+            {
+                best_value = value;
+                *self = candidate;
+            }
+        }
+    }
+
+    /// Builds a calendar whose front leg is `first` from `chain` and whose
+    /// back leg is the same strike at this spread's back expiration, priced
+    /// with the implied volatility of `second`.
+    fn create_strategy(
+        &self,
+        chain: &OptionChain,
+        legs: &StrategyLegs,
+    ) -> Result<Self::Strategy, StrategyError> {
+        let StrategyLegs::TwoLegs { first, second } = legs else {
+            return Err(StrategyError::operation_not_supported(
+                "create_strategy",
+                "LongCalendarSpread requires exactly two legs (TwoLegs)",
+            ));
+        };
+        let option_style = self.short_front.option.option_style;
+        let front_expiration = chain
+            .get_expiration()
+            .unwrap_or(self.short_front.option.expiration_date);
+        let short_front = chain_leg(
+            chain,
+            front_expiration,
+            first.strike_price,
+            option_style,
+            Side::Short,
+            self.short_front.option.quantity,
+            self.short_front.open_fee,
+            self.short_front.close_fee,
+        )?;
+        let back_option = Options::new(
+            OptionType::European,
+            Side::Long,
+            chain.symbol.clone(),
+            first.strike_price,
+            self.long_back.option.expiration_date,
+            second.implied_volatility,
+            self.long_back.option.quantity,
+            chain.underlying_price,
+            chain.risk_free_rate.unwrap_or(Decimal::ZERO),
+            option_style,
+            chain.dividend_yield.unwrap_or(Positive::ZERO),
+            None,
+        );
+        let premium = Positive::new_decimal(back_option.calculate_price_black_scholes()?.abs())?;
+        let long_back = Position::new(
+            back_option,
+            premium,
+            Utc::now(),
+            self.long_back.open_fee,
+            self.long_back.close_fee,
+            None,
+            None,
+        );
+        Self::from_legs(short_front, long_back)
+    }
+
+    fn is_valid_optimal_option(&self, option: &OptionData, side: &FindOptimalSide) -> bool {
+        match side {
+            FindOptimalSide::Center => {
+                let underlying = *self.get_underlying_price();
+                option.strike_price >= underlying * Positive::new(0.95).unwrap_or(Positive::ONE)
+                    && option.strike_price
+                        <= underlying * Positive::new(1.05).unwrap_or(Positive::ONE)
+            }
+            FindOptimalSide::Upper => option.strike_price >= *self.get_underlying_price(),
+            FindOptimalSide::Lower => option.strike_price <= *self.get_underlying_price(),
+            FindOptimalSide::All => true,
+            FindOptimalSide::Range(start, end) => {
+                option.strike_price >= *start && option.strike_price <= *end
+            }
+            FindOptimalSide::Deltable(_) | FindOptimalSide::DeltaRange(..) => true,
+        }
+    }
+}
+
+impl Profit for LongCalendarSpread {
+    fn calculate_profit_at(&self, price: &Positive) -> Result<Decimal, PricingError> {
+        self.profit_at_front_expiry(price)
+    }
+}
+
+impl ProbabilityAnalysis for LongCalendarSpread {
+    fn get_profit_ranges(&self) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        self.front_expiry_ranges(true, Some(self.volatility_adjustment()))
+    }
+
+    fn get_loss_ranges(&self) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        self.front_expiry_ranges(false, Some(self.volatility_adjustment()))
+    }
+}
+
+impl LongCalendarSpread {
+    fn volatility_adjustment(&self) -> VolatilityAdjustment {
+        let (base_volatility, std_dev_adjustment) = mean_and_std(vec![
+            self.short_front.option.implied_volatility,
+            self.long_back.option.implied_volatility,
+        ]);
+        VolatilityAdjustment {
+            base_volatility,
+            std_dev_adjustment,
+        }
+    }
+}
+
+impl Greeks for LongCalendarSpread {
+    fn get_options(&self) -> Result<Vec<&Options>, GreeksError> {
+        Ok(vec![&self.short_front.option, &self.long_back.option])
+    }
+}
+
+impl DeltaNeutrality for LongCalendarSpread {}
+
+impl PnLCalculator for LongCalendarSpread {
+    fn calculate_pnl(
+        &self,
+        market_price: &Positive,
+        expiration_date: ExpirationDate,
+        implied_volatility: &Positive,
+    ) -> Result<PnL, PricingError> {
+        Ok(self
+            .short_front
+            .calculate_pnl(market_price, expiration_date, implied_volatility)?
+            + self
+                .long_back
+                .calculate_pnl(market_price, expiration_date, implied_volatility)?)
+    }
+
+    /// P&L at the front expiration: the front leg settles at intrinsic value
+    /// and the back leg is marked to model with its remaining time.
+    fn calculate_pnl_at_expiration(
+        &self,
+        underlying_price: &Positive,
+    ) -> Result<PnL, PricingError> {
+        Ok(self
+            .short_front
+            .calculate_pnl_at_expiration(underlying_price)?
+            + self.long_back.calculate_pnl(
+                underlying_price,
+                ExpirationDate::Days(self.remaining_days()?),
+                &self.long_back.option.implied_volatility,
+            )?)
+    }
+}
+
+test_strategy_traits!(
+    LongCalendarSpread,
+    test_long_calendar_spread_implementations
+);
+
+#[cfg(test)]
+mod tests_long_calendar_spread {
+    use super::*;
+    use crate::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
+    use positive::{pos_or_panic, spos};
+    use rust_decimal_macros::dec;
+
+    fn create_test_strategy(option_style: OptionStyle) -> LongCalendarSpread {
+        let (premium_front, premium_back) = premiums(option_style);
+        LongCalendarSpread::new(
+            "SPY".to_string(),
+            Positive::HUNDRED,
+            Positive::HUNDRED,
+            option_style,
+            ExpirationDate::Days(pos_or_panic!(30.0)),
+            ExpirationDate::Days(pos_or_panic!(90.0)),
+            pos_or_panic!(0.2),
+            pos_or_panic!(0.2),
+            dec!(0.03),
+            Positive::ZERO,
+            Positive::ONE,
+            premium_front,
+            premium_back,
+            Positive::ZERO,
+            Positive::ZERO,
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+        .unwrap()
+    }
+
+    fn premiums(option_style: OptionStyle) -> (Positive, Positive) {
+        match option_style {
+            OptionStyle::Call => (pos_or_panic!(2.41), pos_or_panic!(4.35)),
+            OptionStyle::Put => (pos_or_panic!(2.16), pos_or_panic!(3.61)),
+        }
+    }
+
+    fn chain(days: f64) -> OptionChain {
+        let params = OptionChainBuildParams::new(
+            "SPY".to_string(),
+            spos!(1000.0),
+            10,
+            spos!(5.0),
+            dec!(-0.2),
+            dec!(0.1),
+            pos_or_panic!(0.02),
+            2,
+            OptionDataPriceParams::new(
+                Some(Box::new(Positive::HUNDRED)),
+                Some(ExpirationDate::Days(pos_or_panic!(days))),
+                Some(dec!(0.03)),
+                spos!(0.0),
+                Some("SPY".to_string()),
+            ),
+            pos_or_panic!(0.2),
+        );
+        OptionChain::build_chain(&params).unwrap()
+    }
+
+    fn series() -> OptionSeries {
+        let mut series = OptionSeries::new("SPY".to_string(), Positive::HUNDRED);
+        for days in [30.0, 90.0] {
+            series
+                .chains
+                .insert(ExpirationDate::Days(pos_or_panic!(days)), chain(days));
+        }
+        series
+    }
+
+    #[test]
+    fn test_profit_peaks_at_strike_with_two_break_evens() {
+        for option_style in [OptionStyle::Call, OptionStyle::Put] {
+            let strategy = create_test_strategy(option_style);
+            assert!(strategy.validate());
+            let at_strike = strategy.calculate_profit_at(&Positive::HUNDRED).unwrap();
+            let far_below = strategy.calculate_profit_at(&pos_or_panic!(70.0)).unwrap();
+            let far_above = strategy.calculate_profit_at(&pos_or_panic!(130.0)).unwrap();
+            assert!(at_strike > Decimal::ZERO);
+            assert!(far_below < Decimal::ZERO && far_above < Decimal::ZERO);
+
+            let break_evens = strategy.get_break_even_points().unwrap();
+            assert_eq!(break_evens.len(), 2);
+            assert!(break_evens[0] < Positive::HUNDRED && break_evens[1] > Positive::HUNDRED);
+            // The loss is roughly the debit paid; deep in the money a European
+            // put trades below intrinsic, so allow for the discounting.
+            let (premium_front, premium_back) = premiums(option_style);
+            let max_loss = strategy.get_max_loss().unwrap();
+            assert!(max_loss <= premium_back - premium_front + pos_or_panic!(0.5));
+            assert!(strategy.get_max_profit().unwrap() > Positive::ZERO);
+        }
+    }
+
+    #[test]
+    fn test_back_leg_keeps_time_value_at_front_expiry() {
+        let strategy = create_test_strategy(OptionStyle::Call);
+        assert_eq!(strategy.remaining_days().unwrap(), pos_or_panic!(60.0));
+        // With intrinsic values only the spread would lose the full debit at the strike.
+        let profit = strategy.calculate_profit_at(&Positive::HUNDRED).unwrap();
+        assert!(profit > Decimal::ZERO);
+        let pnl = strategy
+            .calculate_pnl_at_expiration(&Positive::HUNDRED)
+            .unwrap();
+        assert_ne!(pnl.unrealized.unwrap(), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_invalid_legs_rejected() {
+        let strategy = create_test_strategy(OptionStyle::Call);
+        let mut back = strategy.long_back.clone();
+        back.option.expiration_date = ExpirationDate::Days(pos_or_panic!(10.0));
+        assert!(LongCalendarSpread::get_strategy(&[strategy.short_front.clone(), back]).is_err());
+
+        let mut back = strategy.long_back.clone();
+        back.option.strike_price = pos_or_panic!(105.0);
+        assert!(LongCalendarSpread::get_strategy(&[strategy.short_front.clone(), back]).is_err());
+
+        let rebuilt = LongCalendarSpread::get_strategy(&[
+            strategy.long_back.clone(),
+            strategy.short_front.clone(),
+        ])
+        .unwrap();
+        assert_eq!(rebuilt.strike(), Positive::HUNDRED);
+    }
+
+    #[test]
+    fn test_from_series_prices_legs_from_each_chain() {
+        let series = series();
+        let strategy = LongCalendarSpread::from_series(
+            &series,
+            &ExpirationDate::Days(pos_or_panic!(30.0)),
+            &ExpirationDate::Days(pos_or_panic!(90.0)),
+            Positive::HUNDRED,
+            OptionStyle::Call,
+            Positive::ONE,
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+        .unwrap();
+        let front_bid = series
+            .chains
+            .values()
+            .next()
+            .unwrap()
+            .options
+            .iter()
+            .find(|o| o.strike_price == Positive::HUNDRED)
+            .unwrap()
+            .call_bid
+            .unwrap();
+        assert_eq!(strategy.short_front.premium, front_bid);
+        assert!(strategy.long_back.premium > strategy.short_front.premium);
+        assert!(
+            LongCalendarSpread::from_series(
+                &series,
+                &ExpirationDate::Days(pos_or_panic!(30.0)),
+                &ExpirationDate::Days(pos_or_panic!(45.0)),
+                Positive::HUNDRED,
+                OptionStyle::Call,
+                Positive::ONE,
+                Positive::ZERO,
+                Positive::ZERO,
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_find_optimal_in_series_and_chain() {
+        let series = series();
+        let mut strategy = create_test_strategy(OptionStyle::Put);
+        strategy.find_optimal_in_series(&series, FindOptimalSide::All, OptimizationCriteria::Ratio);
+        assert!(strategy.validate());
+        assert_eq!(strategy.short_front.option.option_style, OptionStyle::Put);
+        assert!(strategy.get_profit_ratio().unwrap() > Decimal::ZERO);
+
+        let mut strategy = create_test_strategy(OptionStyle::Call);
+        strategy.get_best_area(&chain(30.0), FindOptimalSide::Center);
+        assert!(strategy.validate());
+        assert_eq!(
+            strategy.long_back.option.expiration_date,
+            ExpirationDate::Days(pos_or_panic!(90.0))
+        );
+    }
+
+    #[test]
+    fn test_probabilities_sum_to_one() {
+        let strategy = create_test_strategy(OptionStyle::Call);
+        let profit: Positive = strategy
+            .get_profit_ranges()
+            .unwrap()
+            .iter()
+            .map(|r| r.probability)
+            .sum();
+        let loss: Positive = strategy
+            .get_loss_ranges()
+            .unwrap()
+            .iter()
+            .map(|r| r.probability)
+            .sum();
+        assert!((profit + loss).to_dec() - Decimal::ONE < dec!(0.01));
+    }
+}
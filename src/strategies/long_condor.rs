@@ -0,0 +1,927 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Long Condor Strategy
+//!
+//! A long condor is built from four options of the same style and expiration:
+//! buy the lowest strike, sell the two middle strikes and buy the highest
+//! strike. It is opened for a debit and reaches its maximum profit when the
+//! underlying settles between the two short strikes, with the loss limited to
+//! the debit outside the wings.
+//!
+//! The call and put versions have the same payoff shape as an iron condor on
+//! the same strikes, so the three can be compared directly on one chain.
+
+use super::base::{
+    BreakEvenable, Optimizable, Positionable, Strategable, StrategyBasics, StrategyType, Validable,
+};
+use super::shared::{CondorStrategy, chain_leg, expiration_break_evens};
+use crate::chains::{StrategyLegs, chain::OptionChain, utils::OptionDataGroup};
+use crate::error::position::{PositionError, PositionValidationErrorKind};
+use crate::error::probability::ProbabilityError;
+use crate::error::strategies::{ProfitLossErrorKind, StrategyError};
+use crate::error::{GreeksError, PricingError};
+use crate::greeks::Greeks;
+use crate::model::ProfitLossRange;
+use crate::model::position::Position;
+use crate::model::types::{OptionBasicType, OptionStyle, OptionType, Side};
+use crate::model::utils::mean_and_std;
+use crate::pnl::{PnLCalculator, utils::PnL};
+use crate::pricing::payoff::Profit;
+use crate::strategies::delta_neutral::DeltaNeutrality;
+use crate::strategies::probabilities::{core::ProbabilityAnalysis, utils::VolatilityAdjustment};
+use crate::strategies::utils::{FindOptimalSide, OptimizationCriteria};
+use crate::strategies::{BasicAble, Strategies, StrategyConstructor};
+use crate::{ExpirationDate, Options, test_strategy_traits};
+use chrono::Utc;
+use positive::Positive;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tracing::{debug, info};
+use utoipa::ToSchema;
+
+/// Default description for the Long Condor strategy.
+pub const LONG_CONDOR_DESCRIPTION: &str = "A long condor buys the lowest and highest strikes and \
+    sells the two middle strikes, all calls or all puts with the same expiration. It is opened for a \
+    debit and profits when the underlying stays between the short strikes, with risk limited to the \
+    debit paid.";
+
+/// Represents a Long Call Condor or Long Put Condor.
+///
+/// All four legs share the option style, expiration and quantity, with
+/// strikes in ascending order from `long_low` to `long_high`.
+#[derive(Clone, DebugPretty, DisplaySimple, Serialize, Deserialize, ToSchema)]
+pub struct LongCondor {
+    /// Name identifier for this specific strategy instance
+    pub name: String,
+    /// `LongCallCondor` or `LongPutCondor`
+    pub kind: StrategyType,
+    /// Detailed description of this strategy instance
+    pub description: String,
+    /// Prices at expiration where the strategy neither makes nor loses money
+    pub break_even_points: Vec<Positive>,
+    /// The bought option at the lowest strike
+    pub(super) long_low: Position,
+    /// The sold option at the lower middle strike
+    pub(super) short_lower: Position,
+    /// The sold option at the upper middle strike
+    pub(super) short_upper: Position,
+    /// The bought option at the highest strike
+    pub(super) long_high: Position,
+}
+
+impl LongCondor {
+    /// Creates a new Long Condor with calls or puts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyError` if the strikes are not strictly ascending, a
+    /// leg fails validation or the break-even calculation fails.
+    #[allow(clippy::too_many_arguments)]
+    #[inline(never)]
+    pub fn new(
+        underlying_symbol: String,
+        underlying_price: Positive,
+        option_style: OptionStyle,
+        low_strike: Positive,
+        lower_strike: Positive,
+        upper_strike: Positive,
+        high_strike: Positive,
+        expiration: ExpirationDate,
+        implied_volatility: Positive,
+        risk_free_rate: Decimal,
+        dividend_yield: Positive,
+        quantity: Positive,
+        premium_low: Positive,
+        premium_lower: Positive,
+        premium_upper: Positive,
+        premium_high: Positive,
+        open_fee: Positive,
+        close_fee: Positive,
+    ) -> Result<Self, StrategyError> {
+        let leg = |side, strike, premium| {
+            Position::new(
+                Options::new(
+                    OptionType::European,
+                    side,
+                    underlying_symbol.clone(),
+                    strike,
+                    expiration,
+                    implied_volatility,
+                    quantity,
+                    underlying_price,
+                    risk_free_rate,
+                    option_style,
+                    dividend_yield,
+                    None,
+                ),
+                premium,
+                Utc::now(),
+                open_fee,
+                close_fee,
+                None,
+                None,
+            )
+        };
+        Self::from_legs(
+            leg(Side::Long, low_strike, premium_low),
+            leg(Side::Short, lower_strike, premium_lower),
+            leg(Side::Short, upper_strike, premium_upper),
+            leg(Side::Long, high_strike, premium_high),
+        )
+    }
+
+    fn from_legs(
+        long_low: Position,
+        short_lower: Position,
+        short_upper: Position,
+        long_high: Position,
+    ) -> Result<Self, StrategyError> {
+        let kind = match long_low.option.option_style {
+            OptionStyle::Call => StrategyType::LongCallCondor,
+            OptionStyle::Put => StrategyType::LongPutCondor,
+        };
+        let mut strategy = LongCondor {
+            name: match kind {
+                StrategyType::LongPutCondor => "Long Put Condor",
+                _ => "Long Call Condor",
+            }
+            .to_string(),
+            kind,
+            long_low,
+            short_lower,
+            short_upper,
+            long_high,
+            ..Default::default()
+        };
+        if !strategy.validate() {
+            return Err(StrategyError::invalid_parameters(
+                "LongCondor",
+                "requires long, short, short, long options of one style and expiration \
+                 with strictly ascending strikes",
+            ));
+        }
+        strategy.update_break_even_points()?;
+        Ok(strategy)
+    }
+
+    fn legs(&self) -> [&Position; 4] {
+        [
+            &self.long_low,
+            &self.short_lower,
+            &self.short_upper,
+            &self.long_high,
+        ]
+    }
+
+    fn legs_mut(&mut self) -> [&mut Position; 4] {
+        [
+            &mut self.long_low,
+            &mut self.short_lower,
+            &mut self.short_upper,
+            &mut self.long_high,
+        ]
+    }
+
+    /// Expiration payoff at zero and at each strike, where its slope changes.
+    fn payoff_nodes(&self) -> Result<Vec<(Positive, Decimal)>, PricingError> {
+        std::iter::once(Positive::ZERO)
+            .chain(self.legs().map(|leg| leg.option.strike_price))
+            .map(|price| Ok((price, self.calculate_profit_at(&price)?)))
+            .collect()
+    }
+
+    fn upper_slope(&self) -> Result<Decimal, PricingError> {
+        let high = self.long_high.option.strike_price;
+        Ok(self.calculate_profit_at(&(high + Positive::ONE))? - self.calculate_profit_at(&high)?)
+    }
+
+    fn volatility_adjustment(&self) -> VolatilityAdjustment {
+        let (base_volatility, std_dev_adjustment) = mean_and_std(
+            self.legs()
+                .iter()
+                .map(|leg| leg.option.implied_volatility)
+                .collect(),
+        );
+        VolatilityAdjustment {
+            base_volatility,
+            std_dev_adjustment,
+        }
+    }
+
+    fn range(
+        &self,
+        lower: Option<Positive>,
+        upper: Option<Positive>,
+    ) -> Result<ProfitLossRange, ProbabilityError> {
+        let option = &self.short_lower.option;
+        let mut range = ProfitLossRange::new(lower, upper, Positive::ZERO)?;
+        range.calculate_probability(
+            self.get_underlying_price(),
+            Some(self.volatility_adjustment()),
+            None,
+            &option.expiration_date,
+            Some(option.risk_free_rate),
+        )?;
+        Ok(range)
+    }
+}
+
+impl StrategyConstructor for LongCondor {
+    fn get_strategy(vec_positions: &[Position]) -> Result<Self, StrategyError> {
+        let mut sorted = vec_positions.to_vec();
+        sorted.sort_by_key(|position| position.option.strike_price);
+        let [long_low, short_lower, short_upper, long_high] = sorted.as_slice() else {
+            return Err(StrategyError::invalid_parameters(
+                "Long Condor get_strategy",
+                "Must have exactly 4 options",
+            ));
+        };
+        Self::from_legs(
+            long_low.clone(),
+            short_lower.clone(),
+            short_upper.clone(),
+            long_high.clone(),
+        )
+    }
+}
+
+impl BreakEvenable for LongCondor {
+    fn get_break_even_points(&self) -> Result<&Vec<Positive>, StrategyError> {
+        Ok(&self.break_even_points)
+    }
+
+    fn update_break_even_points(&mut self) -> Result<(), StrategyError> {
+        self.break_even_points =
+            expiration_break_evens(&self.payoff_nodes()?, self.upper_slope()?)?;
+        Ok(())
+    }
+}
+
+impl Validable for LongCondor {
+    fn validate(&self) -> bool {
+        let legs = self.legs();
+        if !legs.iter().all(|leg| leg.validate()) {
+            debug!("Long condor legs failed validation");
+            return false;
+        }
+        let sides = [Side::Long, Side::Short, Side::Short, Side::Long];
+        if legs
+            .iter()
+            .zip(sides)
+            .any(|(leg, side)| leg.option.side != side)
+        {
+            debug!("Long condor requires long, short, short, long legs");
+            return false;
+        }
+        let first = &self.long_low.option;
+        if legs.iter().any(|leg| {
+            leg.option.option_style != first.option_style
+                || leg.option.expiration_date != first.expiration_date
+                || leg.option.quantity != first.quantity
+        }) {
+            debug!("Long condor legs must share style, expiration and quantity");
+            return false;
+        }
+        let ascending = legs
+            .iter()
+            .zip(legs.iter().skip(1))
+            .all(|(lower, upper)| lower.option.strike_price < upper.option.strike_price);
+        if !ascending {
+            debug!("Long condor strikes must be strictly ascending");
+        }
+        ascending
+    }
+}
+
+impl Positionable for LongCondor {
+    /// Replaces the leg with the same side whose strike is closest to the
+    /// new position's strike.
+    fn add_position(&mut self, position: &Position) -> Result<(), PositionError> {
+        let strike = position.option.strike_price;
+        let slot = match position.option.side {
+            Side::Long => {
+                if strike.to_dec() - self.long_low.option.strike_price.to_dec()
+                    <= self.long_high.option.strike_price.to_dec() - strike.to_dec()
+                {
+                    &mut self.long_low
+                } else {
+                    &mut self.long_high
+                }
+            }
+            Side::Short => {
+                if strike.to_dec() - self.short_lower.option.strike_price.to_dec()
+                    <= self.short_upper.option.strike_price.to_dec() - strike.to_dec()
+                {
+                    &mut self.short_lower
+                } else {
+                    &mut self.short_upper
+                }
+            }
+        };
+        *slot = position.clone();
+        Ok(())
+    }
+
+    fn get_positions(&self) -> Result<Vec<&Position>, PositionError> {
+        Ok(self.legs().to_vec())
+    }
+
+    fn get_position(
+        &mut self,
+        option_style: &OptionStyle,
+        side: &Side,
+        strike: &Positive,
+    ) -> Result<Vec<&mut Position>, PositionError> {
+        let positions: Vec<&mut Position> = self
+            .legs_mut()
+            .into_iter()
+            .filter(|leg| {
+                leg.option.option_style == *option_style
+                    && leg.option.side == *side
+                    && leg.option.strike_price == *strike
+            })
+            .collect();
+        if positions.is_empty() {
+            return Err(PositionError::invalid_position_type(
+                *side,
+                "Strike not found in positions".to_string(),
+            ));
+        }
+        Ok(positions)
+    }
+
+    fn modify_position(&mut self, position: &Position) -> Result<(), PositionError> {
+        if !position.validate() {
+            return Err(PositionError::ValidationError(
+                PositionValidationErrorKind::InvalidPosition {
+                    reason: "Invalid position data".to_string(),
+                },
+            ));
+        }
+        let option = &position.option;
+        let slot = self.legs_mut().into_iter().find(|leg| {
+            leg.option.option_style == option.option_style
+                && leg.option.side == option.side
+                && leg.option.strike_price == option.strike_price
+        });
+        match slot {
+            Some(slot) => {
+                *slot = position.clone();
+                Ok(())
+            }
+            None => Err(PositionError::invalid_position_type(
+                option.side,
+                "Strike not found in positions".to_string(),
+            )),
+        }
+    }
+}
+
+impl Strategable for LongCondor {
+    fn info(&self) -> Result<StrategyBasics, StrategyError> {
+        Ok(StrategyBasics {
+            name: self.name.clone(),
+            kind: self.kind.clone(),
+            description: self.description.clone(),
+        })
+    }
+}
+
+impl BasicAble for LongCondor {
+    fn get_title(&self) -> String {
+        let legs: Vec<String> = self.legs().iter().map(|leg| leg.get_title()).collect();
+        format!("{:?} Strategy: \n\t{}", self.kind, legs.join("\n\t"))
+    }
+
+    fn get_option_basic_type(&self) -> HashSet<OptionBasicType<'_>> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                OptionBasicType {
+                    option_style: &option.option_style,
+                    side: &option.side,
+                    strike_price: &option.strike_price,
+                    expiration_date: &option.expiration_date,
+                }
+            })
+            .collect()
+    }
+
+    fn get_implied_volatility(&self) -> HashMap<OptionBasicType<'_>, &Positive> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                (
+                    OptionBasicType {
+                        option_style: &option.option_style,
+                        side: &option.side,
+                        strike_price: &option.strike_price,
+                        expiration_date: &option.expiration_date,
+                    },
+                    &option.implied_volatility,
+                )
+            })
+            .collect()
+    }
+
+    fn get_quantity(&self) -> HashMap<OptionBasicType<'_>, &Positive> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                (
+                    OptionBasicType {
+                        option_style: &option.option_style,
+                        side: &option.side,
+                        strike_price: &option.strike_price,
+                        expiration_date: &option.expiration_date,
+                    },
+                    &option.quantity,
+                )
+            })
+            .collect()
+    }
+
+    fn one_option(&self) -> &Options {
+        self.short_lower.one_option()
+    }
+
+    fn one_option_mut(&mut self) -> &mut Options {
+        self.short_lower.one_option_mut()
+    }
+
+    fn set_expiration_date(
+        &mut self,
+        expiration_date: ExpirationDate,
+    ) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.expiration_date = expiration_date;
+        }
+        Ok(())
+    }
+
+    fn set_underlying_price(&mut self, price: &Positive) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.underlying_price = *price;
+            leg.premium = Positive::new_decimal(leg.option.calculate_price_black_scholes()?.abs())
+                .unwrap_or(Positive::ZERO);
+        }
+        Ok(())
+    }
+
+    fn set_implied_volatility(&mut self, volatility: &Positive) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.implied_volatility = *volatility;
+            leg.premium = Positive::new_decimal(leg.option.calculate_price_black_scholes()?.abs())
+                .unwrap_or(Positive::ZERO);
+        }
+        Ok(())
+    }
+}
+
+impl Strategies for LongCondor {
+    fn get_max_profit(&self) -> Result<Positive, StrategyError> {
+        let max = self
+            .payoff_nodes()?
+            .into_iter()
+            .map(|(_, profit)| profit)
+            .fold(Decimal::MIN, Decimal::max);
+        if max <= Decimal::ZERO {
+            return Err(StrategyError::ProfitLossError(
+                ProfitLossErrorKind::MaxProfitError {
+                    reason: "Max profit is negative".to_string(),
+                },
+            ));
+        }
+        Ok(Positive::new_decimal(max)?)
+    }
+
+    fn get_max_loss(&self) -> Result<Positive, StrategyError> {
+        let min = self
+            .payoff_nodes()?
+            .into_iter()
+            .map(|(_, profit)| profit)
+            .fold(Decimal::MAX, Decimal::min);
+        if min >= Decimal::ZERO {
+            return Err(StrategyError::ProfitLossError(
+                ProfitLossErrorKind::MaxLossError {
+                    reason: "Max loss must be negative".to_string(),
+                },
+            ));
+        }
+        Ok(Positive::new_decimal(min.abs())?)
+    }
+
+    /// Area of the profitable region at expiration, from one wing width
+    /// below the lowest strike to one wing width above the highest, divided
+    /// by the underlying price.
+    fn get_profit_area(&self) -> Result<Decimal, StrategyError> {
+        let (low, _, _, high) = self.strikes();
+        let floor = Positive::new_decimal(
+            (low.to_dec() - self.put_spread_width().to_dec()).max(Decimal::ZERO),
+        )?;
+        let mut prices: Vec<Positive> = self
+            .legs()
+            .iter()
+            .map(|leg| leg.option.strike_price)
+            .chain(self.break_even_points.iter().copied())
+            .chain([floor, high + self.call_spread_width()])
+            .collect();
+        prices.sort();
+        let mut area = Decimal::ZERO;
+        for pair in prices.windows(2) {
+            let [x0, x1] = pair else {
+                continue;
+            };
+            let mean =
+                (self.calculate_profit_at(x0)? + self.calculate_profit_at(x1)?) / Decimal::TWO;
+            if mean > Decimal::ZERO {
+                area += mean * (x1.to_dec() - x0.to_dec());
+            }
+        }
+        let underlying = self.get_underlying_price().to_dec();
+        if underlying.is_zero() {
+            return Ok(Decimal::ZERO);
+        }
+        Ok(area / underlying)
+    }
+
+    fn get_profit_ratio(&self) -> Result<Decimal, StrategyError> {
+        match (self.get_max_profit(), self.get_max_loss()) {
+            (Ok(profit), Ok(loss)) => Ok(profit.to_dec() / loss.to_dec() * Decimal::ONE_HUNDRED),
+            _ => Ok(Decimal::ZERO),
+        }
+    }
+}
+
+impl Optimizable for LongCondor {
+    type Strategy = LongCondor;
+
+    fn filter_combinations<'a>(
+        &'a self,
+        option_chain: &'a OptionChain,
+        side: FindOptimalSide,
+    ) -> impl Iterator<Item = OptionDataGroup<'a>> {
+        let underlying_price = self.get_underlying_price();
+        let strategy = self.clone();
+        option_chain
+            .get_quad_iter()
+            .filter(move |(low, lower, upper, high)| {
+                if side == FindOptimalSide::Center {
+                    low.is_valid_optimal_side(underlying_price, &FindOptimalSide::Lower)
+                        && lower.is_valid_optimal_side(underlying_price, &FindOptimalSide::Lower)
+                        && upper.is_valid_optimal_side(underlying_price, &FindOptimalSide::Upper)
+                        && high.is_valid_optimal_side(underlying_price, &FindOptimalSide::Upper)
+                } else {
+                    [low, lower, upper, high]
+                        .iter()
+                        .all(|option| option.is_valid_optimal_side(underlying_price, &side))
+                }
+            })
+            .filter(move |(low, lower, upper, high)| {
+                let legs = StrategyLegs::FourLegs {
+                    first: low,
+                    second: lower,
+                    third: upper,
+                    fourth: high,
+                };
+                match strategy.create_strategy(option_chain, &legs) {
+                    Ok(s) => s.get_max_profit().is_ok() && s.get_max_loss().is_ok(),
+                    Err(_) => false,
+                }
+            })
+            .map(|(low, lower, upper, high)| OptionDataGroup::Four(low, lower, upper, high))
+    }
+
+    fn find_optimal(
+        &mut self,
+        option_chain: &OptionChain,
+        side: FindOptimalSide,
+        criteria: OptimizationCriteria,
+    ) {
+        let mut best_value = Decimal::MIN;
+        let strategy_clone = self.clone();
+        for option_data_group in strategy_clone.filter_combinations(option_chain, side) {
+            let OptionDataGroup::Four(first, second, third, fourth) = option_data_group else {
+                continue;
+            };
+            let legs = StrategyLegs::FourLegs {
+                first,
+                second,
+                third,
+                fourth,
+            };
+            let strategy = match self.create_strategy(option_chain, &legs) {
+                Ok(s) => s,
+                Err(e) => {
+                    debug!("Skipping condor candidate: {}", e);
+                    continue;
+                }
+            };
+            let metric = match criteria {
+                OptimizationCriteria::Ratio => strategy.get_profit_ratio(),
+                OptimizationCriteria::Area => strategy.get_profit_area(),
+            };
+            match metric {
+                Ok(value) if value > best_value => {
+                    info!("Found better value: {}", value);
+                    best_value = value;
+                    *self = strategy;
+                }
+                Ok(_) => {}
+                Err(e) => debug!("Skipping candidate with unscorable metric: {}", e),
+            }
+        }
+    }
+
+    /// Builds a condor on the four strikes, in ascending order, filling long
+    /// legs at the ask and short legs at the bid.
+    fn create_strategy(
+        &self,
+        chain: &OptionChain,
+        legs: &StrategyLegs,
+    ) -> Result<Self::Strategy, StrategyError> {
+        let StrategyLegs::FourLegs {
+            first,
+            second,
+            third,
+            fourth,
+        } = legs
+        else {
+            return Err(StrategyError::operation_not_supported(
+                "create_strategy",
+                "LongCondor requires exactly four legs (FourLegs)",
+            ));
+        };
+        let template = &self.short_lower;
+        let expiration = chain
+            .get_expiration()
+            .unwrap_or(template.option.expiration_date);
+        let leg = |strike, side| {
+            chain_leg(
+                chain,
+                expiration,
+                strike,
+                template.option.option_style,
+                side,
+                template.option.quantity,
+                template.open_fee,
+                template.close_fee,
+            )
+        };
+        Self::from_legs(
+            leg(first.strike_price, Side::Long)?,
+            leg(second.strike_price, Side::Short)?,
+            leg(third.strike_price, Side::Short)?,
+            leg(fourth.strike_price, Side::Long)?,
+        )
+    }
+}
+
+impl Profit for LongCondor {
+    fn calculate_profit_at(&self, price: &Positive) -> Result<Decimal, PricingError> {
+        let price = Some(price);
+        self.legs()
+            .iter()
+            .map(|leg| leg.pnl_at_expiration(&price))
+            .sum()
+    }
+}
+
+impl ProbabilityAnalysis for LongCondor {
+    fn get_profit_ranges(&self) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        let [lower, upper] = self.get_break_even_points()?.as_slice() else {
+            return Ok(Vec::new());
+        };
+        Ok(vec![self.range(Some(*lower), Some(*upper))?])
+    }
+
+    fn get_loss_ranges(&self) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        let [lower, upper] = self.get_break_even_points()?.as_slice() else {
+            return Ok(Vec::new());
+        };
+        Ok(vec![
+            self.range(None, Some(*lower))?,
+            self.range(Some(*upper), None)?,
+        ])
+    }
+}
+
+impl Greeks for LongCondor {
+    fn get_options(&self) -> Result<Vec<&Options>, GreeksError> {
+        Ok(self.legs().map(|leg| &leg.option).to_vec())
+    }
+}
+
+impl DeltaNeutrality for LongCondor {}
+
+impl CondorStrategy for LongCondor {
+    fn strikes(&self) -> (Positive, Positive, Positive, Positive) {
+        (
+            self.long_low.option.strike_price,
+            self.short_lower.option.strike_price,
+            self.short_upper.option.strike_price,
+            self.long_high.option.strike_price,
+        )
+    }
+
+    fn get_condor_positions(&self) -> Vec<&Position> {
+        self.legs().to_vec()
+    }
+}
+
+impl PnLCalculator for LongCondor {
+    fn calculate_pnl(
+        &self,
+        market_price: &Positive,
+        expiration_date: ExpirationDate,
+        implied_volatility: &Positive,
+    ) -> Result<PnL, PricingError> {
+        let mut pnl = PnL::default();
+        for leg in self.legs() {
+            pnl = pnl + leg.calculate_pnl(market_price, expiration_date, implied_volatility)?;
+        }
+        Ok(pnl)
+    }
+
+    fn calculate_pnl_at_expiration(
+        &self,
+        underlying_price: &Positive,
+    ) -> Result<PnL, PricingError> {
+        let mut pnl = PnL::default();
+        for leg in self.legs() {
+            pnl = pnl + leg.calculate_pnl_at_expiration(underlying_price)?;
+        }
+        Ok(pnl)
+    }
+}
+
+test_strategy_traits!(LongCondor, test_long_condor_implementations);
+
+#[cfg(test)]
+mod tests_long_condor {
+    use super::*;
+    use crate::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
+    use crate::strategies::IronCondor;
+    use positive::{pos_or_panic, spos};
+    use rust_decimal_macros::dec;
+
+    fn create_test_strategy(option_style: OptionStyle) -> LongCondor {
+        LongCondor::new(
+            "SPY".to_string(),
+            Positive::HUNDRED,
+            option_style,
+            pos_or_panic!(90.0),
+            pos_or_panic!(95.0),
+            pos_or_panic!(105.0),
+            pos_or_panic!(110.0),
+            ExpirationDate::Days(pos_or_panic!(30.0)),
+            pos_or_panic!(0.2),
+            dec!(0.03),
+            Positive::ZERO,
+            Positive::ONE,
+            pos_or_panic!(10.5),
+            pos_or_panic!(6.3),
+            pos_or_panic!(1.2),
+            pos_or_panic!(0.4),
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+        .unwrap()
+    }
+
+    fn chain() -> OptionChain {
+        let params = OptionChainBuildParams::new(
+            "SPY".to_string(),
+            spos!(1000.0),
+            10,
+            spos!(5.0),
+            dec!(-0.2),
+            dec!(0.1),
+            pos_or_panic!(0.02),
+            2,
+            OptionDataPriceParams::new(
+                Some(Box::new(Positive::HUNDRED)),
+                Some(ExpirationDate::Days(pos_or_panic!(30.0))),
+                Some(dec!(0.03)),
+                spos!(0.0),
+                Some("SPY".to_string()),
+            ),
+            pos_or_panic!(0.2),
+        );
+        OptionChain::build_chain(&params).unwrap()
+    }
+
+    #[test]
+    fn test_call_condor_profile() {
+        let strategy = create_test_strategy(OptionStyle::Call);
+        assert_eq!(strategy.kind, StrategyType::LongCallCondor);
+        assert_eq!(strategy.name, "Long Call Condor");
+        // Debit of 10.5 + 0.4 - 6.3 - 1.2 = 3.4 on 5-wide wings.
+        assert_eq!(strategy.get_max_loss().unwrap(), pos_or_panic!(3.4));
+        assert_eq!(strategy.get_max_profit().unwrap(), pos_or_panic!(1.6));
+        assert_eq!(
+            strategy.get_break_even_points().unwrap(),
+            &vec![pos_or_panic!(93.4), pos_or_panic!(106.6)]
+        );
+        assert_eq!(strategy.inner_width(), pos_or_panic!(10.0));
+        assert_eq!(strategy.outer_width(), pos_or_panic!(20.0));
+        assert!(strategy.get_profit_area().unwrap() > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_put_condor_kind_and_validation() {
+        let strategy = create_test_strategy(OptionStyle::Put);
+        assert_eq!(strategy.kind, StrategyType::LongPutCondor);
+        assert_eq!(strategy.get_break_even_points().unwrap().len(), 2);
+
+        let mut positions: Vec<Position> = strategy
+            .get_positions()
+            .unwrap()
+            .into_iter()
+            .cloned()
+            .collect();
+        positions.reverse();
+        let rebuilt = LongCondor::get_strategy(&positions).unwrap();
+        assert_eq!(rebuilt.strikes(), strategy.strikes());
+
+        positions[0].option.option_style = OptionStyle::Call;
+        assert!(LongCondor::get_strategy(&positions).is_err());
+        assert!(LongCondor::get_strategy(&positions[..3]).is_err());
+    }
+
+    #[test]
+    fn test_matches_iron_condor_shape_on_same_strikes() {
+        let condor = create_test_strategy(OptionStyle::Call);
+        let iron = IronCondor::new(
+            "SPY".to_string(),
+            Positive::HUNDRED,
+            pos_or_panic!(105.0),
+            pos_or_panic!(95.0),
+            pos_or_panic!(110.0),
+            pos_or_panic!(90.0),
+            ExpirationDate::Days(pos_or_panic!(30.0)),
+            pos_or_panic!(0.2),
+            dec!(0.03),
+            Positive::ZERO,
+            Positive::ONE,
+            pos_or_panic!(1.2),
+            pos_or_panic!(1.1),
+            pos_or_panic!(0.4),
+            pos_or_panic!(0.3),
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+        .unwrap();
+        // Both payoffs have the same shape; only the premium offset differs.
+        let offset = iron.calculate_profit_at(&Positive::HUNDRED).unwrap()
+            - condor.calculate_profit_at(&Positive::HUNDRED).unwrap();
+        for price in [80.0, 92.5, 100.0, 107.5, 120.0] {
+            let price = pos_or_panic!(price);
+            assert_eq!(
+                iron.calculate_profit_at(&price).unwrap()
+                    - condor.calculate_profit_at(&price).unwrap(),
+                offset
+            );
+        }
+    }
+
+    #[test]
+    fn test_best_ratio_and_area_on_chain() {
+        let chain = chain();
+        for option_style in [OptionStyle::Call, OptionStyle::Put] {
+            let mut strategy = create_test_strategy(option_style);
+            strategy.get_best_ratio(&chain, FindOptimalSide::Center);
+            assert!(strategy.validate());
+            assert!(strategy.get_profit_ratio().unwrap() > Decimal::ZERO);
+            let (_, lower, upper, _) = strategy.strikes();
+            assert!(lower <= Positive::HUNDRED && upper >= Positive::HUNDRED);
+
+            let mut strategy = create_test_strategy(option_style);
+            strategy.get_best_area(&chain, FindOptimalSide::All);
+            assert!(strategy.validate());
+            assert_eq!(strategy.long_low.option.option_style, option_style);
+        }
+    }
+
+    #[test]
+    fn test_probabilities_sum_to_one() {
+        let strategy = create_test_strategy(OptionStyle::Call);
+        let total: Positive = strategy
+            .get_profit_ranges()
+            .unwrap()
+            .iter()
+            .chain(strategy.get_loss_ranges().unwrap().iter())
+            .map(|range| range.probability)
+            .sum();
+        assert!((total.to_dec() - Decimal::ONE).abs() < dec!(0.01));
+    }
+}
@@ -690,6 +690,16 @@ impl Optimizable for LongStraddle {
             .map(OptionDataGroup::One)
     }
 
+    fn strategy_legs<'a>(&self, group: OptionDataGroup<'a>) -> Option<StrategyLegs<'a>> {
+        match group {
+            OptionDataGroup::One(both) => Some(StrategyLegs::TwoLegs {
+                first: both,
+                second: both,
+            }),
+            _ => None,
+        }
+    }
+
     fn find_optimal(
         &mut self,
         option_chain: &OptionChain,
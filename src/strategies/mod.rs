@@ -16,18 +16,34 @@
 //! - `base`: Provides the base traits and structures for the strategies.
 //! - `bear_call_spread`: Implements the Bear Call Spread strategy.
 //! - `bear_put_spread`: Implements the Bear Put Spread strategy.
+//! - `box_spread`: Implements the long and short Box Spread strategies.
 //! - `bull_call_spread`: Implements the Bull Call Spread strategy.
+//! - `broken_wing_butterfly`: Implements the Call and Put Broken-Wing Butterfly strategies.
 //! - `bull_put_spread`: Implements the Bull Put Spread strategy.
 //! - `butterfly_spread`: Implements the Butterfly Spread strategy.
 //! - `call_butterfly`: Implements the Call Butterfly strategy.
 //! - `collar`: Implements the Collar strategy.
+//! - `conversion`: Implements the Conversion and Reversal strategies.
 //! - `covered_call`: Implements the Covered Call strategy.
 //! - `custom`: Provides utilities for creating custom strategies.
+//! - `diagonal_spread`: Implements the Diagonal Spread strategy.
+//! - `fence`: Implements the Fence strategy, with or without the underlying.
 //! - `iron_butterfly`: Implements the Iron Butterfly strategy.
 //! - `iron_condor`: Implements the Iron Condor strategy.
+//! - `lizard`: Implements the Jade Lizard and Big Lizard strategies.
+//! - `long_calendar_spread`: Implements the Long Calendar Spread strategy.
+//! - `long_condor`: Implements the Long Call and Long Put Condor strategies.
+//! - `multi_objective`: Provides the constrained, multi-objective Pareto optimizer.
 //! - `poor_mans_covered_call`: Implements the Poor Man's Covered Call strategy.
 //! - `probabilities`: Provides probability calculations for the strategies.
 //! - `protective_put`: Implements the Protective Put strategy.
+//! - `ratio_spread`: Implements the call/put Ratio Spread and Backspread strategies.
+//! - `risk_reversal`: Implements the bullish and bearish Risk Reversal strategies.
+//! - `roll`: Proposes rolls of open strategies across the expirations of an option series.
+//! - `scanner`: Runs strategy templates across the expirations of option series.
+//! - `seagull`: Implements the bullish and bearish Seagull strategies.
+//! - `short_calendar_spread`: Implements the Short Calendar Spread strategy.
+//! - `short_condor`: Implements the Short Call and Short Put Condor strategies.
 //! - `straddle`: Implements the Straddle strategy.
 //! - `strangle`: Implements the Strangle strategy.
 //! - `utils`: Provides utility functions for the strategies.
@@ -109,6 +125,8 @@
 //! The `Optimizable` trait extends the `Validable` and `Strategies` traits and adds methods for optimizing
 //! a trading strategy. It includes methods for finding the optimal strategy based on different criteria,
 //! such as best ratio or best area.
+//! `find_pareto_optimal` searches the same combinations under Greek, margin and probability
+//! constraints and returns a ranked Pareto set instead of mutating the strategy in place.
 //!
 //! ## Usage
 //!
@@ -206,6 +224,10 @@ pub mod base;
 pub mod bear_call_spread;
 /// Bear Put Spread strategy implementation  
 pub mod bear_put_spread;
+/// Box Spread strategy implementation
+pub mod box_spread;
+/// Broken-Wing Butterfly strategy implementation
+pub mod broken_wing_butterfly;
 /// Internal module for strategy building utilities
 mod build;
 /// Bull Call Spread strategy implementation
@@ -216,6 +238,8 @@ pub mod bull_put_spread;
 pub mod call_butterfly;
 /// Collar strategy implementation
 pub mod collar;
+/// Conversion and Reversal strategy implementation
+pub mod conversion;
 /// Covered Call strategy implementation
 pub mod covered_call;
 /// Custom strategy implementation and utilities
@@ -224,6 +248,10 @@ pub mod custom;
 pub mod default;
 /// Delta-neutral strategy implementation and utilities
 pub mod delta_neutral;
+/// Diagonal Spread strategy implementation
+pub mod diagonal_spread;
+/// Fence strategy implementation
+pub mod fence;
 
 /// The `graph` module provides functionality for creating, managing, and
 /// manipulating graph data structures. Common use cases include representing
@@ -248,10 +276,16 @@ pub mod graph;
 pub mod iron_butterfly;
 /// Iron Condor strategy implementation
 pub mod iron_condor;
+/// Jade Lizard and Big Lizard strategy implementation
+pub mod lizard;
 /// Butterfly Spread strategy implementation
 pub mod long_butterfly_spread;
+/// Long Calendar Spread strategy implementation
+pub mod long_calendar_spread;
 /// Long Call strategy implementation
 pub mod long_call;
+/// Long Call and Long Put Condor strategy implementation
+pub mod long_condor;
 /// Long Put strategy implementation
 pub mod long_put;
 /// Long Straddle strategy implementation
@@ -260,18 +294,34 @@ pub mod long_straddle;
 pub mod long_strangle;
 /// Macros for options strategies
 pub mod macros;
+/// Multi-objective strategy optimizer with Greek, margin and probability constraints
+pub mod multi_objective;
 /// Poor Man's Covered Call strategy implementation
 pub mod poor_mans_covered_call;
 /// Probability calculations for options strategies
 pub mod probabilities;
 /// Protective Put strategy implementation
 pub mod protective_put;
+/// Ratio Spread and Backspread strategy implementation
+pub mod ratio_spread;
+/// Risk Reversal strategy implementation
+pub mod risk_reversal;
+/// Roll engine proposing rolls out, up, down and for credit
+pub mod roll;
+/// Cross-expiry strategy scanner over option series
+pub mod scanner;
+/// Seagull strategy implementation
+pub mod seagull;
 /// Shared traits for strategy categories
 pub mod shared;
 /// Short Call strategy implementation
 pub mod short_butterfly_spread;
+/// Short Calendar Spread strategy implementation
+pub mod short_calendar_spread;
 /// Short Call strategy implementation
 pub mod short_call;
+/// Short Call and Short Put Condor strategy implementation
+pub mod short_condor;
 /// Short Put strategy implementation
 pub mod short_put;
 /// Short Straddle strategy implementation
@@ -284,34 +334,55 @@ pub mod utils;
 pub use base::{BasicAble, Strategable, Strategies, StrategyBasics, Validable};
 pub use bear_call_spread::BearCallSpread;
 pub use bear_put_spread::BearPutSpread;
+pub use box_spread::BoxSpread;
+pub use broken_wing_butterfly::BrokenWingButterfly;
 pub use build::model::StrategyRequest;
 pub use build::traits::StrategyConstructor;
 pub use bull_call_spread::BullCallSpread;
 pub use bull_put_spread::BullPutSpread;
 pub use call_butterfly::CallButterfly;
 pub use collar::Collar;
+pub use conversion::Conversion;
 pub use covered_call::CoveredCall;
 pub use delta_neutral::{
     AdjustmentAction, AdjustmentConfig, AdjustmentError, AdjustmentOptimizer, AdjustmentPlan,
     AdjustmentTarget, DELTA_THRESHOLD, DeltaAdjustment, DeltaInfo, DeltaNeutrality,
     PortfolioGreeks,
 };
+pub use diagonal_spread::DiagonalSpread;
+pub use fence::Fence;
 pub use iron_butterfly::IronButterfly;
 pub use iron_condor::IronCondor;
+pub use lizard::Lizard;
 pub use long_butterfly_spread::LongButterflySpread;
+pub use long_calendar_spread::LongCalendarSpread;
 pub use long_call::LongCall;
+pub use long_condor::LongCondor;
 pub use long_put::LongPut;
 pub use long_straddle::LongStraddle;
 pub use long_strangle::LongStrangle;
+pub use multi_objective::{
+    CandidateMetrics, MultiObjectiveConfig, OptimizationConstraints, OptimizationObjective,
+    ParetoCandidate,
+};
 pub use poor_mans_covered_call::PoorMansCoveredCall;
 pub use protective_put::ProtectivePut;
+pub use ratio_spread::RatioSpread;
+pub use risk_reversal::RiskReversal;
+pub use roll::{RollChange, RollProfile, RollProposal, RollRule};
+pub use scanner::{ScanConfig, ScanFailure, ScanReport, ScanRow, ScanTemplate, StrategyScanner};
+pub use seagull::Seagull;
 pub use shared::{
-    ButterflyStrategy, CondorStrategy, SpreadStrategy, StraddleStrategy, StrangleStrategy,
-    aggregate_fees, aggregate_premiums, calculate_profit_ratio, credit_spread_break_even,
-    debit_spread_break_even,
+    ButterflyStrategy, CondorStrategy, EarlyAssignmentRisk, SpreadStrategy, StraddleStrategy,
+    StrangleStrategy, TimeSpreadStrategy, aggregate_fees, aggregate_premiums,
+    calculate_profit_ratio, chain_leg, credit_spread_break_even, debit_spread_break_even,
+    early_assignment_exposure, expiration_break_evens, implied_financing_rate, net_premium,
+    premium_neutral,
 };
 pub use short_butterfly_spread::ShortButterflySpread;
+pub use short_calendar_spread::ShortCalendarSpread;
 pub use short_call::ShortCall;
+pub use short_condor::ShortCondor;
 pub use short_put::ShortPut;
 pub use short_straddle::ShortStraddle;
 pub use short_strangle::ShortStrangle;
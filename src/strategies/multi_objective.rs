@@ -0,0 +1,573 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! Multi-objective strategy optimization.
+//!
+//! [`Optimizable::find_optimal`](crate::strategies::base::Optimizable::find_optimal)
+//! keeps the single best candidate for one [`OptimizationCriteria`](crate::strategies::utils::OptimizationCriteria)
+//! and overwrites the strategy in place. The search in this module walks the same
+//! chain combinations, discards the candidates that break the user's
+//! [`OptimizationConstraints`], scores the rest on several
+//! [`OptimizationObjective`]s and returns them ranked by Pareto front, leaving the
+//! template strategy untouched.
+
+use crate::chains::chain::OptionChain;
+use crate::error::probability::ProbabilityError;
+use crate::error::strategies::StrategyError;
+use crate::greeks::Greeks;
+use crate::strategies::base::Optimizable;
+use crate::strategies::probabilities::{
+    PriceTrend, ProbabilityAnalysis, VolatilityAdjustment, calculate_single_point_probability,
+};
+use crate::strategies::utils::FindOptimalSide;
+use positive::Positive;
+use rust_decimal::{Decimal, MathematicalOps};
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use utoipa::ToSchema;
+
+/// Objective maximized by the multi-objective search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum OptimizationObjective {
+    /// Probability-weighted P&L at expiration.
+    ExpectedValue,
+    /// Expected value per unit of margin.
+    ReturnOnMargin,
+    /// Expected value over the standard deviation of the P&L at expiration.
+    SharpeLike,
+}
+
+/// Hard limits a candidate must satisfy to be ranked. Unset limits are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct OptimizationConstraints {
+    /// Maximum absolute net delta of the strategy.
+    pub max_net_delta: Option<Decimal>,
+    /// Minimum probability of profit at expiration.
+    pub min_probability_of_profit: Option<Positive>,
+    /// Maximum margin, estimated as the maximum loss at expiration.
+    pub max_margin: Option<Positive>,
+    /// Maximum capital paid upfront (net debit including fees).
+    pub max_capital: Option<Positive>,
+    /// Minimum net credit received after fees.
+    pub min_credit: Option<Positive>,
+}
+
+impl OptimizationConstraints {
+    /// Returns `true` when `metrics` respects every configured limit.
+    #[must_use]
+    pub fn is_satisfied_by(&self, metrics: &CandidateMetrics) -> bool {
+        self.max_net_delta
+            .is_none_or(|limit| metrics.net_delta.abs() <= limit)
+            && self
+                .min_probability_of_profit
+                .is_none_or(|limit| metrics.probability_of_profit >= limit)
+            && self.max_margin.is_none_or(|limit| metrics.margin <= limit)
+            && self
+                .max_capital
+                .is_none_or(|limit| metrics.capital <= limit)
+            && self
+                .min_credit
+                .is_none_or(|limit| metrics.net_credit >= limit.to_dec())
+    }
+}
+
+/// Configuration of a multi-objective search.
+#[derive(Debug, Clone)]
+pub struct MultiObjectiveConfig {
+    /// Constraints every ranked candidate satisfies.
+    pub constraints: OptimizationConstraints,
+    /// Objectives to maximize. The first one orders candidates within a front.
+    pub objectives: Vec<OptimizationObjective>,
+    /// Volatility used by the probability model.
+    pub volatility_adj: Option<VolatilityAdjustment>,
+    /// Drift used by the probability model.
+    pub trend: Option<PriceTrend>,
+    /// Maximum number of candidates returned.
+    pub max_results: Option<usize>,
+}
+
+impl Default for MultiObjectiveConfig {
+    fn default() -> Self {
+        Self {
+            constraints: OptimizationConstraints::default(),
+            objectives: vec![
+                OptimizationObjective::ExpectedValue,
+                OptimizationObjective::ReturnOnMargin,
+                OptimizationObjective::SharpeLike,
+            ],
+            volatility_adj: None,
+            trend: None,
+            max_results: None,
+        }
+    }
+}
+
+/// Risk and reward figures of a single candidate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CandidateMetrics {
+    /// Net delta of the strategy.
+    pub net_delta: Decimal,
+    /// Probability of profit at expiration.
+    pub probability_of_profit: Positive,
+    /// Margin estimate: the maximum loss at expiration.
+    pub margin: Positive,
+    /// Net debit paid upfront, including fees. Zero for credit strategies.
+    pub capital: Positive,
+    /// Net credit received after fees. Negative for debit strategies.
+    pub net_credit: Decimal,
+    /// Probability-weighted P&L at expiration.
+    pub expected_value: Decimal,
+    /// Standard deviation of the P&L at expiration.
+    pub pnl_std_dev: Decimal,
+}
+
+impl CandidateMetrics {
+    /// Computes the metrics of `strategy` under the probability model of `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProbabilityError`] if the delta, the probability of profit, the
+    /// maximum loss, the net cost or the P&L distribution cannot be computed.
+    pub fn evaluate<S>(
+        strategy: &S,
+        config: &MultiObjectiveConfig,
+    ) -> Result<Self, ProbabilityError>
+    where
+        S: ProbabilityAnalysis + Greeks,
+    {
+        let net_cost = strategy.get_net_cost().map_err(StrategyError::from)?;
+        let (expected_value, pnl_std_dev) = pnl_moments(strategy, config)?;
+        Ok(Self {
+            net_delta: strategy.delta()?,
+            probability_of_profit: strategy
+                .probability_of_profit(config.volatility_adj.clone(), config.trend.clone())?,
+            margin: strategy.get_max_loss()?,
+            capital: Positive::new_decimal(net_cost.max(Decimal::ZERO))?,
+            net_credit: -net_cost,
+            expected_value,
+            pnl_std_dev,
+        })
+    }
+
+    /// Value of `objective` for this candidate; higher is better.
+    ///
+    /// Ratios with a zero or unbounded denominator score zero.
+    #[must_use]
+    pub fn score(&self, objective: OptimizationObjective) -> Decimal {
+        match objective {
+            OptimizationObjective::ExpectedValue => self.expected_value,
+            OptimizationObjective::ReturnOnMargin => {
+                if self.margin == Positive::ZERO || self.margin == Positive::INFINITY {
+                    Decimal::ZERO
+                } else {
+                    self.expected_value / self.margin.to_dec()
+                }
+            }
+            OptimizationObjective::SharpeLike => {
+                if self.pnl_std_dev.is_zero() {
+                    Decimal::ZERO
+                } else {
+                    self.expected_value / self.pnl_std_dev
+                }
+            }
+        }
+    }
+}
+
+/// A feasible candidate returned by the multi-objective search.
+#[derive(Debug, Clone)]
+pub struct ParetoCandidate<S> {
+    /// The candidate strategy.
+    pub strategy: S,
+    /// Its risk and reward figures.
+    pub metrics: CandidateMetrics,
+    /// Pareto front index: `0` for non-dominated candidates, `1` for the
+    /// candidates only dominated by front `0`, and so on.
+    pub rank: usize,
+}
+
+/// Runs the multi-objective search behind
+/// [`Optimizable::find_pareto_optimal`](crate::strategies::base::Optimizable::find_pareto_optimal).
+pub(crate) fn pareto_search<O>(
+    template: &O,
+    option_chain: &OptionChain,
+    side: FindOptimalSide,
+    config: &MultiObjectiveConfig,
+) -> Result<Vec<ParetoCandidate<O::Strategy>>, StrategyError>
+where
+    O: Optimizable + ?Sized,
+    O::Strategy: ProbabilityAnalysis + Greeks,
+{
+    let Some(&primary) = config.objectives.first() else {
+        return Err(StrategyError::invalid_parameters(
+            "find_pareto_optimal",
+            "at least one objective is required",
+        ));
+    };
+
+    let mut feasible = Vec::new();
+    let mut combinations = 0usize;
+    for group in template.filter_combinations(option_chain, side) {
+        combinations += 1;
+        let Some(legs) = template.strategy_legs(group) else {
+            continue;
+        };
+        let strategy = match template.create_strategy(option_chain, &legs) {
+            Ok(strategy) => strategy,
+            Err(e) => {
+                debug!("Skipping candidate: {}", e);
+                continue;
+            }
+        };
+        match CandidateMetrics::evaluate(&strategy, config) {
+            Ok(metrics) if config.constraints.is_satisfied_by(&metrics) => {
+                feasible.push((strategy, metrics));
+            }
+            Ok(_) => {}
+            Err(e) => debug!("Skipping candidate with unscorable metrics: {}", e),
+        }
+    }
+    if combinations == 0 {
+        return Err(StrategyError::invalid_parameters(
+            "find_pareto_optimal",
+            &format!(
+                "{} exposes no leg combinations for this chain",
+                std::any::type_name::<O>()
+            ),
+        ));
+    }
+
+    let scores: Vec<Vec<Decimal>> = feasible
+        .iter()
+        .map(|(_, metrics)| {
+            config
+                .objectives
+                .iter()
+                .map(|objective| metrics.score(*objective))
+                .collect()
+        })
+        .collect();
+    let mut candidates: Vec<ParetoCandidate<O::Strategy>> = feasible
+        .into_iter()
+        .zip(pareto_ranks(&scores))
+        .map(|((strategy, metrics), rank)| ParetoCandidate {
+            strategy,
+            metrics,
+            rank,
+        })
+        .collect();
+    candidates.sort_by(|a, b| {
+        a.rank
+            .cmp(&b.rank)
+            .then_with(|| b.metrics.score(primary).cmp(&a.metrics.score(primary)))
+    });
+    if let Some(limit) = config.max_results {
+        candidates.truncate(limit);
+    }
+    Ok(candidates)
+}
+
+/// `true` if `a` is at least as good as `b` on every objective and strictly
+/// better on one.
+fn dominates(a: &[Decimal], b: &[Decimal]) -> bool {
+    a.iter().zip(b).all(|(x, y)| x >= y) && a.iter().zip(b).any(|(x, y)| x > y)
+}
+
+/// Assigns every score vector its Pareto front index by repeatedly peeling off
+/// the non-dominated set.
+fn pareto_ranks(scores: &[Vec<Decimal>]) -> Vec<usize> {
+    let mut ranks: Vec<Option<usize>> = vec![None; scores.len()];
+    let mut rank = 0;
+    while ranks.iter().any(Option::is_none) {
+        let front: Vec<bool> = scores
+            .iter()
+            .zip(&ranks)
+            .map(|(score, assigned)| {
+                assigned.is_none()
+                    && !scores
+                        .iter()
+                        .zip(&ranks)
+                        .any(|(other, other_rank)| other_rank.is_none() && dominates(other, score))
+            })
+            .collect();
+        for (assigned, in_front) in ranks.iter_mut().zip(front) {
+            if in_front {
+                *assigned = Some(rank);
+            }
+        }
+        rank += 1;
+    }
+    ranks.into_iter().map(|r| r.unwrap_or(rank)).collect()
+}
+
+/// Mean and standard deviation of the P&L at expiration, using the same
+/// discretised log-normal distribution as
+/// [`ProbabilityAnalysis::expected_value`] but keeping the sign of the mean.
+fn pnl_moments<S>(
+    strategy: &S,
+    config: &MultiObjectiveConfig,
+) -> Result<(Decimal, Decimal), ProbabilityError>
+where
+    S: ProbabilityAnalysis,
+{
+    let underlying_price = strategy.get_underlying_price();
+    let range = strategy.get_best_range_to_show(underlying_price / 100.0)?;
+    let expiration = strategy.one_option().expiration_date;
+
+    let mut last_cdf = Decimal::ZERO;
+    let mut weighted = Vec::with_capacity(range.len());
+    for price in &range {
+        let (cdf, _) = calculate_single_point_probability(
+            underlying_price,
+            price,
+            config.volatility_adj.clone(),
+            config.trend.clone(),
+            &expiration,
+            None,
+        )?;
+        let probability = cdf.to_dec() - last_cdf;
+        last_cdf = cdf.to_dec();
+        weighted.push((probability, strategy.calculate_profit_at(price)?));
+    }
+
+    let total: Decimal = weighted.iter().map(|(p, _)| *p).sum();
+    if total <= Decimal::ZERO {
+        return Ok((Decimal::ZERO, Decimal::ZERO));
+    }
+    let mean = weighted.iter().map(|(p, pnl)| p * pnl).sum::<Decimal>() / total;
+    let variance = weighted
+        .iter()
+        .map(|(p, pnl)| p * (pnl - mean) * (pnl - mean))
+        .sum::<Decimal>()
+        / total;
+    Ok((mean, variance.sqrt().unwrap_or(Decimal::ZERO)))
+}
+
+#[cfg(test)]
+mod tests_multi_objective {
+    use super::*;
+    use crate::chains::{OptionChainBuildParams, utils::OptionDataPriceParams};
+    use crate::model::types::Side;
+    use crate::strategies::Strategies;
+    use crate::strategies::bull_put_spread::BullPutSpread;
+    use crate::strategies::risk_reversal::RiskReversal;
+    use crate::{ExpirationDate, assert_decimal_eq};
+    use positive::{pos_or_panic, spos};
+    use rust_decimal_macros::dec;
+
+    fn metrics(net_delta: Decimal, margin: Positive, net_credit: Decimal) -> CandidateMetrics {
+        CandidateMetrics {
+            net_delta,
+            probability_of_profit: pos_or_panic!(0.6),
+            margin,
+            capital: Positive::ZERO,
+            net_credit,
+            expected_value: dec!(10),
+            pnl_std_dev: dec!(40),
+        }
+    }
+
+    fn chain() -> OptionChain {
+        let params = OptionChainBuildParams::new(
+            "SPY".to_string(),
+            spos!(1000.0),
+            6,
+            spos!(5.0),
+            dec!(-0.2),
+            dec!(0.1),
+            pos_or_panic!(0.02),
+            2,
+            OptionDataPriceParams::new(
+                Some(Box::new(Positive::HUNDRED)),
+                Some(ExpirationDate::Days(pos_or_panic!(30.0))),
+                Some(dec!(0.05)),
+                spos!(0.0),
+                Some("SPY".to_string()),
+            ),
+            pos_or_panic!(0.2),
+        );
+        OptionChain::build_chain(&params).unwrap()
+    }
+
+    fn template() -> BullPutSpread {
+        BullPutSpread::new(
+            "SPY".to_string(),
+            Positive::HUNDRED,
+            pos_or_panic!(95.0),
+            Positive::HUNDRED,
+            ExpirationDate::Days(pos_or_panic!(30.0)),
+            pos_or_panic!(0.2),
+            dec!(0.05),
+            Positive::ZERO,
+            Positive::ONE,
+            pos_or_panic!(1.0),
+            pos_or_panic!(3.0),
+            Positive::ZERO,
+            Positive::ZERO,
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_constraints() {
+        let candidate = metrics(dec!(-0.3), pos_or_panic!(4.0), dec!(1.0));
+        assert!(OptimizationConstraints::default().is_satisfied_by(&candidate));
+
+        let delta = OptimizationConstraints {
+            max_net_delta: Some(dec!(0.2)),
+            ..Default::default()
+        };
+        assert!(!delta.is_satisfied_by(&candidate));
+
+        let credit = OptimizationConstraints {
+            min_credit: Some(pos_or_panic!(0.5)),
+            max_margin: Some(pos_or_panic!(5.0)),
+            ..Default::default()
+        };
+        assert!(credit.is_satisfied_by(&candidate));
+        assert!(!credit.is_satisfied_by(&metrics(dec!(0), pos_or_panic!(4.0), dec!(-1.0))));
+    }
+
+    #[test]
+    fn test_scores() {
+        let candidate = metrics(Decimal::ZERO, pos_or_panic!(4.0), dec!(1.0));
+        assert_eq!(
+            candidate.score(OptimizationObjective::ExpectedValue),
+            dec!(10)
+        );
+        assert_eq!(
+            candidate.score(OptimizationObjective::ReturnOnMargin),
+            dec!(2.5)
+        );
+        assert_eq!(
+            candidate.score(OptimizationObjective::SharpeLike),
+            dec!(0.25)
+        );
+
+        let unbounded = metrics(Decimal::ZERO, Positive::INFINITY, dec!(1.0));
+        assert_eq!(
+            unbounded.score(OptimizationObjective::ReturnOnMargin),
+            Decimal::ZERO
+        );
+    }
+
+    #[test]
+    fn test_pareto_ranks() {
+        let scores = vec![
+            vec![dec!(3), dec!(1)],
+            vec![dec!(1), dec!(3)],
+            vec![dec!(2), dec!(2)],
+            vec![dec!(1), dec!(1)],
+            vec![dec!(0), dec!(0)],
+        ];
+        assert_eq!(pareto_ranks(&scores), vec![0, 0, 0, 1, 2]);
+        assert!(pareto_ranks(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_find_pareto_optimal_respects_constraints_and_ranks() {
+        let spread = template();
+        let original = spread.clone();
+        let config = MultiObjectiveConfig {
+            constraints: OptimizationConstraints {
+                min_credit: Some(pos_or_panic!(0.5)),
+                max_margin: Some(pos_or_panic!(8.0)),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let candidates = spread
+            .find_pareto_optimal(&chain(), FindOptimalSide::Lower, &config)
+            .unwrap();
+
+        assert!(!candidates.is_empty());
+        assert_eq!(
+            spread.short_put.option.strike_price,
+            original.short_put.option.strike_price
+        );
+        assert_eq!(
+            spread.long_put.option.strike_price,
+            original.long_put.option.strike_price
+        );
+        assert!(candidates.iter().all(|candidate| {
+            config.constraints.is_satisfied_by(&candidate.metrics)
+                && candidate.metrics.net_credit >= dec!(0.5)
+                && candidate.metrics.margin <= pos_or_panic!(8.0)
+        }));
+        assert!(candidates.windows(2).all(|pair| match pair {
+            [a, b] => a.rank <= b.rank,
+            _ => true,
+        }));
+        assert_eq!(candidates.first().map(|c| c.rank), Some(0));
+        for candidate in &candidates {
+            assert_decimal_eq!(
+                candidate.metrics.net_credit,
+                candidate
+                    .strategy
+                    .get_net_premium_received()
+                    .unwrap()
+                    .to_dec(),
+                dec!(1e-9)
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_pareto_optimal_requires_objective() {
+        let config = MultiObjectiveConfig {
+            objectives: vec![],
+            ..Default::default()
+        };
+        assert!(
+            template()
+                .find_pareto_optimal(&chain(), FindOptimalSide::All, &config)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_find_pareto_optimal_without_combinations_is_an_error() {
+        let risk_reversal = RiskReversal::new(
+            "SPY".to_string(),
+            Positive::HUNDRED,
+            Side::Long,
+            pos_or_panic!(95.0),
+            pos_or_panic!(105.0),
+            ExpirationDate::Days(pos_or_panic!(30.0)),
+            pos_or_panic!(0.2),
+            dec!(0.05),
+            Positive::ZERO,
+            Positive::ONE,
+            pos_or_panic!(1.0),
+            pos_or_panic!(1.0),
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+        .unwrap();
+        let error = risk_reversal
+            .find_pareto_optimal(
+                &chain(),
+                FindOptimalSide::Range(pos_or_panic!(1000.0), pos_or_panic!(2000.0)),
+                &MultiObjectiveConfig::default(),
+            )
+            .unwrap_err();
+        assert!(error.to_string().contains("no leg combinations"));
+    }
+
+    #[test]
+    fn test_find_pareto_optimal_max_results() {
+        let config = MultiObjectiveConfig {
+            max_results: Some(3),
+            ..Default::default()
+        };
+        let candidates = template()
+            .find_pareto_optimal(&chain(), FindOptimalSide::All, &config)
+            .unwrap();
+        assert!(candidates.len() <= 3);
+    }
+}
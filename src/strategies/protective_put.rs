@@ -403,6 +403,10 @@ impl BasicAble for ProtectivePut {
 }
 
 impl Strategies for ProtectivePut {
+    fn underlying_pnl_at(&self, underlying_price: &Positive) -> Decimal {
+        self.spot_leg.pnl_at_price(*underlying_price)
+    }
+
     fn get_max_profit(&self) -> Result<Positive, StrategyError> {
         Ok(Positive::new_decimal(Decimal::MAX).unwrap_or(Positive::ZERO))
     }
@@ -0,0 +1,1168 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Ratio Spread and Backspread Strategies
+//!
+//! Ratio spreads are verticals with unequal quantities on each leg:
+//!
+//! * **Call ratio spread**: buy calls at a lower strike and sell more calls at
+//!   a higher strike (1x2, 1x3). Profits most at the short strike and carries
+//!   unlimited risk above it.
+//! * **Put ratio spread**: buy puts at a higher strike and sell more puts at a
+//!   lower strike. Profits most at the short strike, with large risk below it.
+//! * **Call backspread**: sell calls at a lower strike and buy more calls at a
+//!   higher strike. Unlimited profit on a rally, worst loss at the long strike.
+//! * **Put backspread**: sell puts at a higher strike and buy more puts at a
+//!   lower strike. Large profit on a sell-off, worst loss at the long strike.
+//!
+//! All four are represented by [`RatioSpread`]; the [`StrategyType`](crate::strategies::base::StrategyType) is derived
+//! from the option style, the strikes and which leg carries more contracts.
+//! Because the risk of the short-heavy variants is unbounded (or close to it),
+//! the profit ratio and the optimizer measure risk with
+//! [`RatioSpread::margin_requirement`], the worst expiration loss under a
+//! stressed move of the underlying.
+
+use super::base::{
+    BreakEvenable, Optimizable, Positionable, Strategable, StrategyBasics, StrategyType, Validable,
+};
+use crate::chains::{OptionData, StrategyLegs, chain::OptionChain, utils::OptionDataGroup};
+use crate::error::position::{PositionError, PositionValidationErrorKind};
+use crate::error::probability::ProbabilityError;
+use crate::error::strategies::{ProfitLossErrorKind, StrategyError};
+use crate::error::{GreeksError, PricingError};
+use crate::greeks::{Greeks, delta};
+use crate::model::ProfitLossRange;
+use crate::model::position::Position;
+use crate::model::types::{OptionBasicType, OptionStyle, OptionType, Side};
+use crate::model::utils::mean_and_std;
+use crate::pnl::{PnLCalculator, utils::PnL};
+use crate::pricing::payoff::Profit;
+use crate::strategies::delta_neutral::DeltaNeutrality;
+use crate::strategies::probabilities::{core::ProbabilityAnalysis, utils::VolatilityAdjustment};
+use crate::strategies::shared::{chain_leg, expiration_break_evens};
+use crate::strategies::utils::{FindOptimalSide, OptimizationCriteria};
+use crate::strategies::{BasicAble, Strategies, StrategyConstructor};
+use crate::{ExpirationDate, Options, test_strategy_traits};
+use chrono::Utc;
+use positive::Positive;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tracing::debug;
+use utoipa::ToSchema;
+
+/// Default description for the Ratio Spread strategies.
+pub const RATIO_SPREAD_DESCRIPTION: &str = "A ratio spread buys and sells options of the same type \
+    and expiration in unequal quantities. Ratio spreads sell more contracts than they buy and profit \
+    most at the short strike; backspreads buy more contracts than they sell and profit from a large \
+    move through the long strike.";
+
+/// Relative move of the underlying, up and down, used to stress the
+/// position when estimating its margin requirement.
+pub const RATIO_SPREAD_MARGIN_SCAN: Decimal = dec!(0.5);
+
+/// Contract ratios tried by the optimizer for the leg with more contracts.
+const RATIO_SPREAD_RATIOS: [Decimal; 2] = [dec!(2), dec!(3)];
+
+/// Represents a call/put ratio spread or backspread.
+///
+/// Both legs share the option style and expiration. The leg with more
+/// contracts is the short leg for ratio spreads and the long leg for
+/// backspreads.
+#[derive(Clone, DebugPretty, DisplaySimple, Serialize, Deserialize, ToSchema)]
+pub struct RatioSpread {
+    /// Name identifier for this specific strategy instance
+    pub name: String,
+    /// One of the ratio spread or backspread strategy types
+    pub kind: StrategyType,
+    /// Detailed description of this strategy instance
+    pub description: String,
+    /// Prices at expiration where the strategy neither makes nor loses money
+    pub break_even_points: Vec<Positive>,
+    /// The bought options
+    pub(super) long_leg: Position,
+    /// The sold options
+    pub(super) short_leg: Position,
+}
+
+impl RatioSpread {
+    /// Creates a new ratio spread or backspread.
+    ///
+    /// The strategy type follows from the inputs: selling more contracts than
+    /// are bought gives a ratio spread, buying more gives a backspread. The
+    /// single-quantity leg must be the one closer to the money (the lower
+    /// strike for calls, the higher strike for puts).
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyError` if the quantities are equal, the strikes are
+    /// in the wrong order for the ratio, or the legs fail validation.
+    #[allow(clippy::too_many_arguments)]
+    #[inline(never)]
+    pub fn new(
+        underlying_symbol: String,
+        underlying_price: Positive,
+        option_style: OptionStyle,
+        long_strike: Positive,
+        short_strike: Positive,
+        expiration: ExpirationDate,
+        implied_volatility: Positive,
+        risk_free_rate: Decimal,
+        dividend_yield: Positive,
+        long_quantity: Positive,
+        short_quantity: Positive,
+        premium_long: Positive,
+        premium_short: Positive,
+        open_fee_long: Positive,
+        close_fee_long: Positive,
+        open_fee_short: Positive,
+        close_fee_short: Positive,
+    ) -> Result<Self, StrategyError> {
+        let leg = |side, strike, quantity, premium, open_fee, close_fee| {
+            Position::new(
+                Options::new(
+                    OptionType::European,
+                    side,
+                    underlying_symbol.clone(),
+                    strike,
+                    expiration,
+                    implied_volatility,
+                    quantity,
+                    underlying_price,
+                    risk_free_rate,
+                    option_style,
+                    dividend_yield,
+                    None,
+                ),
+                premium,
+                Utc::now(),
+                open_fee,
+                close_fee,
+                None,
+                None,
+            )
+        };
+        Self::from_legs(
+            leg(
+                Side::Long,
+                long_strike,
+                long_quantity,
+                premium_long,
+                open_fee_long,
+                close_fee_long,
+            ),
+            leg(
+                Side::Short,
+                short_strike,
+                short_quantity,
+                premium_short,
+                open_fee_short,
+                close_fee_short,
+            ),
+        )
+    }
+
+    /// Builds a delta-neutral ratio spread or backspread from `chain`.
+    ///
+    /// The leg closer to the money gets `base_quantity` contracts and the
+    /// quantity of the other leg is chosen so the net delta is zero, which
+    /// [`DeltaNeutrality::is_delta_neutral`] confirms on the result. Whether
+    /// a ratio spread or a backspread is built follows from the strike order:
+    /// for calls a long strike below the short strike gives a ratio spread and
+    /// above it a backspread, and the reverse for puts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyError` if the chain has no expiration, a strike is not
+    /// quoted, the far leg has no delta or the neutral quantity would not
+    /// exceed `base_quantity`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn delta_neutral(
+        chain: &OptionChain,
+        option_style: OptionStyle,
+        long_strike: Positive,
+        short_strike: Positive,
+        base_quantity: Positive,
+        open_fee: Positive,
+        close_fee: Positive,
+    ) -> Result<Self, StrategyError> {
+        let expiration = chain.get_expiration().ok_or_else(|| {
+            StrategyError::invalid_parameters(
+                "RatioSpread::delta_neutral",
+                "option chain has no expiration",
+            )
+        })?;
+        let backspread = match option_style {
+            OptionStyle::Call => long_strike > short_strike,
+            OptionStyle::Put => long_strike < short_strike,
+        };
+        let leg = |strike, side, quantity| {
+            chain_leg(
+                chain,
+                expiration,
+                strike,
+                option_style,
+                side,
+                quantity,
+                open_fee,
+                close_fee,
+            )
+        };
+        let long_leg = leg(long_strike, Side::Long, Positive::ONE)?;
+        let short_leg = leg(short_strike, Side::Short, Positive::ONE)?;
+        let (single, multi) = if backspread {
+            (&short_leg, &long_leg)
+        } else {
+            (&long_leg, &short_leg)
+        };
+        let single_delta = delta(&single.option).map_err(PricingError::from)?.abs();
+        let multi_delta = delta(&multi.option).map_err(PricingError::from)?.abs();
+        if multi_delta.is_zero() {
+            return Err(StrategyError::invalid_parameters(
+                "RatioSpread::delta_neutral",
+                "the far leg has no delta",
+            ));
+        }
+        let multi_quantity =
+            Positive::new_decimal(base_quantity.to_dec() * single_delta / multi_delta)?;
+        if multi_quantity <= base_quantity {
+            return Err(StrategyError::invalid_parameters(
+                "RatioSpread::delta_neutral",
+                &format!(
+                    "neutral quantity {multi_quantity} does not exceed the base quantity {base_quantity}"
+                ),
+            ));
+        }
+        let (long_quantity, short_quantity) = if backspread {
+            (multi_quantity, base_quantity)
+        } else {
+            (base_quantity, multi_quantity)
+        };
+        Self::from_legs(
+            leg(long_strike, Side::Long, long_quantity)?,
+            leg(short_strike, Side::Short, short_quantity)?,
+        )
+    }
+
+    /// Returns the number of contracts on the larger leg per contract on the
+    /// smaller leg.
+    #[must_use]
+    pub fn ratio(&self) -> Decimal {
+        let (long, short) = (
+            self.long_leg.option.quantity.to_dec(),
+            self.short_leg.option.quantity.to_dec(),
+        );
+        let (small, large) = if long < short {
+            (long, short)
+        } else {
+            (short, long)
+        };
+        if small.is_zero() {
+            return Decimal::ZERO;
+        }
+        large / small
+    }
+
+    /// Returns `true` for call and put backspreads.
+    #[must_use]
+    pub fn is_backspread(&self) -> bool {
+        matches!(
+            self.kind,
+            StrategyType::CallBackspread | StrategyType::PutBackspread
+        )
+    }
+
+    /// Estimates the margin requirement as the worst expiration loss with
+    /// the underlying anywhere within [`RATIO_SPREAD_MARGIN_SCAN`] of its
+    /// current price.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyError` if the payoff cannot be evaluated.
+    pub fn margin_requirement(&self) -> Result<Positive, StrategyError> {
+        let worst = self
+            .stress_profile()?
+            .into_iter()
+            .map(|(_, profit)| profit)
+            .fold(Decimal::ZERO, Decimal::min);
+        Ok(Positive::new_decimal(worst.abs())?)
+    }
+
+    /// Runs the optimizer over the strike pairs of `option_chain` and the
+    /// 1x2 and 1x3 ratios, skipping candidates whose
+    /// [`margin_requirement`](Self::margin_requirement) exceeds `max_margin`.
+    ///
+    /// The search keeps this spread's option style, family (ratio spread or
+    /// backspread), expiration, fees and the quantity of its smaller leg.
+    pub fn find_optimal_with_margin(
+        &mut self,
+        option_chain: &OptionChain,
+        side: FindOptimalSide,
+        criteria: OptimizationCriteria,
+        max_margin: Option<Positive>,
+    ) {
+        let option_style = self.long_leg.option.option_style;
+        let backspread = self.is_backspread();
+        let base_quantity = self
+            .long_leg
+            .option
+            .quantity
+            .min(self.short_leg.option.quantity);
+        let mut best_value = Decimal::MIN;
+        for (low, high) in option_chain.get_double_iter() {
+            if !self.is_valid_optimal_option(low, &side)
+                || !self.is_valid_optimal_option(high, &side)
+            {
+                continue;
+            }
+            // The single leg sits closer to the money: the lower strike for
+            // calls, the higher strike for puts.
+            let (near, far) = match option_style {
+                OptionStyle::Call => (low, high),
+                OptionStyle::Put => (high, low),
+            };
+            let (long, short) = if backspread { (far, near) } else { (near, far) };
+            for ratio in RATIO_SPREAD_RATIOS {
+                let multi_quantity = base_quantity * ratio;
+                let (long_quantity, short_quantity) = if backspread {
+                    (multi_quantity, base_quantity)
+                } else {
+                    (base_quantity, multi_quantity)
+                };
+                let candidate = match self.candidate(
+                    option_chain,
+                    long,
+                    short,
+                    long_quantity,
+                    short_quantity,
+                ) {
+                    Ok(candidate) => candidate,
+                    Err(e) => {
+                        debug!("Skipping ratio spread candidate: {}", e);
+                        continue;
+                    }
+                };
+                if let Some(limit) = max_margin {
+                    match candidate.margin_requirement() {
+                        Ok(margin) if margin <= limit => {}
+                        _ => continue,
+                    }
+                }
+                let metric = match criteria {
+                    OptimizationCriteria::Ratio => candidate.get_profit_ratio(),
+                    OptimizationCriteria::Area => candidate.get_profit_area(),
+                };
+                match metric {
+                    Ok(value) if value > best_value => {
+                        best_value = value;
+                        *self = candidate;
+                    }
+                    Ok(_) => {}
+                    Err(e) => debug!("Skipping candidate with unscorable metric: {}", e),
+                }
+            }
+        }
+    }
+
+    fn candidate(
+        &self,
+        chain: &OptionChain,
+        long: &OptionData,
+        short: &OptionData,
+        long_quantity: Positive,
+        short_quantity: Positive,
+    ) -> Result<Self, StrategyError> {
+        let option_style = self.long_leg.option.option_style;
+        let expiration = chain
+            .get_expiration()
+            .unwrap_or(self.long_leg.option.expiration_date);
+        let long_leg = chain_leg(
+            chain,
+            expiration,
+            long.strike_price,
+            option_style,
+            Side::Long,
+            long_quantity,
+            self.long_leg.open_fee,
+            self.long_leg.close_fee,
+        )?;
+        let short_leg = chain_leg(
+            chain,
+            expiration,
+            short.strike_price,
+            option_style,
+            Side::Short,
+            short_quantity,
+            self.short_leg.open_fee,
+            self.short_leg.close_fee,
+        )?;
+        Self::from_legs(long_leg, short_leg)
+    }
+
+    fn from_legs(long_leg: Position, short_leg: Position) -> Result<Self, StrategyError> {
+        let kind = Self::classify(&long_leg, &short_leg).ok_or_else(|| {
+            StrategyError::invalid_parameters(
+                "RatioSpread",
+                "requires unequal quantities with the single leg closer to the money",
+            )
+        })?;
+        let mut strategy = RatioSpread {
+            name: Self::name_for(&kind).to_string(),
+            kind,
+            long_leg,
+            short_leg,
+            ..Default::default()
+        };
+        if !strategy.validate() {
+            return Err(StrategyError::invalid_parameters(
+                "RatioSpread",
+                "legs must be valid options of the same style and expiration",
+            ));
+        }
+        strategy.update_break_even_points()?;
+        Ok(strategy)
+    }
+
+    fn classify(long_leg: &Position, short_leg: &Position) -> Option<StrategyType> {
+        let (long, short) = (&long_leg.option, &short_leg.option);
+        if long.option_style != short.option_style {
+            return None;
+        }
+        let ratio = short.quantity > long.quantity;
+        let backspread = long.quantity > short.quantity;
+        match long.option_style {
+            OptionStyle::Call if ratio && long.strike_price < short.strike_price => {
+                Some(StrategyType::CallRatioSpread)
+            }
+            OptionStyle::Call if backspread && short.strike_price < long.strike_price => {
+                Some(StrategyType::CallBackspread)
+            }
+            OptionStyle::Put if ratio && long.strike_price > short.strike_price => {
+                Some(StrategyType::PutRatioSpread)
+            }
+            OptionStyle::Put if backspread && short.strike_price > long.strike_price => {
+                Some(StrategyType::PutBackspread)
+            }
+            _ => None,
+        }
+    }
+
+    fn name_for(kind: &StrategyType) -> &'static str {
+        match kind {
+            StrategyType::PutRatioSpread => "Put Ratio Spread",
+            StrategyType::CallBackspread => "Call Backspread",
+            StrategyType::PutBackspread => "Put Backspread",
+            _ => "Call Ratio Spread",
+        }
+    }
+
+    fn strikes(&self) -> (Positive, Positive) {
+        let (a, b) = (
+            self.long_leg.option.strike_price,
+            self.short_leg.option.strike_price,
+        );
+        if a < b { (a, b) } else { (b, a) }
+    }
+
+    /// Expiration payoff at zero and at both strikes, where its slope changes.
+    fn payoff_nodes(&self) -> Result<Vec<(Positive, Decimal)>, PricingError> {
+        let (low, high) = self.strikes();
+        [Positive::ZERO, low, high]
+            .into_iter()
+            .map(|price| Ok((price, self.calculate_profit_at(&price)?)))
+            .collect()
+    }
+
+    /// Slope of the expiration payoff above the highest strike.
+    fn upper_slope(&self) -> Result<Decimal, PricingError> {
+        let (_, high) = self.strikes();
+        Ok(self.calculate_profit_at(&(high + Positive::ONE))? - self.calculate_profit_at(&high)?)
+    }
+
+    /// Expiration payoff at the edges of the stress range, at the strikes and
+    /// break-even points inside it, sorted by price. The payoff is linear
+    /// and keeps its sign between consecutive entries.
+    fn stress_profile(&self) -> Result<Vec<(Positive, Decimal)>, PricingError> {
+        let underlying = self.long_leg.option.underlying_price;
+        let lower = underlying * (Decimal::ONE - RATIO_SPREAD_MARGIN_SCAN);
+        let upper = underlying * (Decimal::ONE + RATIO_SPREAD_MARGIN_SCAN);
+        let (low, high) = self.strikes();
+        let mut prices: Vec<Positive> = [lower, upper, low, high]
+            .into_iter()
+            .chain(self.break_even_points.iter().copied())
+            .filter(|price| *price >= lower && *price <= upper)
+            .collect();
+        prices.sort();
+        prices.dedup();
+        prices
+            .into_iter()
+            .map(|price| Ok((price, self.calculate_profit_at(&price)?)))
+            .collect()
+    }
+
+    fn volatility_adjustment(&self) -> VolatilityAdjustment {
+        let (base_volatility, std_dev_adjustment) = mean_and_std(vec![
+            self.long_leg.option.implied_volatility,
+            self.short_leg.option.implied_volatility,
+        ]);
+        VolatilityAdjustment {
+            base_volatility,
+            std_dev_adjustment,
+        }
+    }
+
+    fn ranges(&self, profitable: bool) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        let option = &self.long_leg.option;
+        let mut bounds: Vec<Option<Positive>> = vec![None];
+        bounds.extend(self.break_even_points.iter().copied().map(Some));
+        bounds.push(None);
+        let mut ranges = Vec::new();
+        for window in bounds.windows(2) {
+            let [lower, upper] = window else {
+                continue;
+            };
+            let probe = match (lower, upper) {
+                (Some(l), Some(u)) => (*l + *u) / Positive::TWO,
+                (None, Some(u)) => *u / Positive::TWO,
+                (Some(l), None) => *l * Positive::TWO,
+                (None, None) => option.underlying_price,
+            };
+            if (self.calculate_profit_at(&probe)? > Decimal::ZERO) != profitable {
+                continue;
+            }
+            let mut range = ProfitLossRange::new(*lower, *upper, Positive::ZERO)?;
+            range.calculate_probability(
+                &option.underlying_price,
+                Some(self.volatility_adjustment()),
+                None,
+                &option.expiration_date,
+                Some(option.risk_free_rate),
+            )?;
+            ranges.push(range);
+        }
+        Ok(ranges)
+    }
+}
+
+impl StrategyConstructor for RatioSpread {
+    fn get_strategy(vec_positions: &[Position]) -> Result<Self, StrategyError> {
+        let [first, second] = vec_positions else {
+            return Err(StrategyError::invalid_parameters(
+                "Ratio Spread get_strategy",
+                "Must have exactly 2 options",
+            ));
+        };
+        let (long_leg, short_leg) = match (first.option.side, second.option.side) {
+            (Side::Long, Side::Short) => (first, second),
+            (Side::Short, Side::Long) => (second, first),
+            _ => {
+                return Err(StrategyError::invalid_parameters(
+                    "Ratio Spread get_strategy",
+                    "Ratio Spread requires one long and one short option",
+                ));
+            }
+        };
+        Self::from_legs(long_leg.clone(), short_leg.clone())
+    }
+}
+
+impl BreakEvenable for RatioSpread {
+    fn get_break_even_points(&self) -> Result<&Vec<Positive>, StrategyError> {
+        Ok(&self.break_even_points)
+    }
+
+    fn update_break_even_points(&mut self) -> Result<(), StrategyError> {
+        self.break_even_points =
+            expiration_break_evens(&self.payoff_nodes()?, self.upper_slope()?)?;
+        Ok(())
+    }
+}
+
+impl Validable for RatioSpread {
+    fn validate(&self) -> bool {
+        let (long, short) = (&self.long_leg.option, &self.short_leg.option);
+        if !self.long_leg.validate() || !self.short_leg.validate() {
+            debug!("Ratio spread legs failed validation");
+            return false;
+        }
+        if long.side != Side::Long || short.side != Side::Short {
+            debug!("Ratio spread requires one long and one short leg");
+            return false;
+        }
+        if long.expiration_date != short.expiration_date {
+            debug!("Ratio spread legs must share the expiration");
+            return false;
+        }
+        Self::classify(&self.long_leg, &self.short_leg).is_some()
+    }
+}
+
+impl Positionable for RatioSpread {
+    fn add_position(&mut self, position: &Position) -> Result<(), PositionError> {
+        match position.option.side {
+            Side::Long => self.long_leg = position.clone(),
+            Side::Short => self.short_leg = position.clone(),
+        }
+        Ok(())
+    }
+
+    fn get_positions(&self) -> Result<Vec<&Position>, PositionError> {
+        Ok(vec![&self.long_leg, &self.short_leg])
+    }
+
+    fn get_position(
+        &mut self,
+        option_style: &OptionStyle,
+        side: &Side,
+        strike: &Positive,
+    ) -> Result<Vec<&mut Position>, PositionError> {
+        let position = match side {
+            Side::Long => &mut self.long_leg,
+            Side::Short => &mut self.short_leg,
+        };
+        if position.option.option_style == *option_style && position.option.strike_price == *strike
+        {
+            Ok(vec![position])
+        } else {
+            Err(PositionError::invalid_position_type(
+                *side,
+                "Strike not found in positions".to_string(),
+            ))
+        }
+    }
+
+    fn modify_position(&mut self, position: &Position) -> Result<(), PositionError> {
+        if !position.validate() {
+            return Err(PositionError::ValidationError(
+                PositionValidationErrorKind::InvalidPosition {
+                    reason: "Invalid position data".to_string(),
+                },
+            ));
+        }
+        let option = &position.option;
+        let current = match option.side {
+            Side::Long => &mut self.long_leg,
+            Side::Short => &mut self.short_leg,
+        };
+        if current.option.option_style != option.option_style
+            || current.option.strike_price != option.strike_price
+        {
+            return Err(PositionError::invalid_position_type(
+                option.side,
+                "Strike not found in positions".to_string(),
+            ));
+        }
+        *current = position.clone();
+        Ok(())
+    }
+}
+
+impl Strategable for RatioSpread {
+    fn info(&self) -> Result<StrategyBasics, StrategyError> {
+        Ok(StrategyBasics {
+            name: self.name.clone(),
+            kind: self.kind.clone(),
+            description: self.description.clone(),
+        })
+    }
+}
+
+impl BasicAble for RatioSpread {
+    fn get_title(&self) -> String {
+        format!(
+            "{:?} Strategy: \n\t{}\n\t{}",
+            self.kind,
+            self.long_leg.get_title(),
+            self.short_leg.get_title()
+        )
+    }
+
+    fn get_option_basic_type(&self) -> HashSet<OptionBasicType<'_>> {
+        [&self.long_leg.option, &self.short_leg.option]
+            .into_iter()
+            .map(|option| OptionBasicType {
+                option_style: &option.option_style,
+                side: &option.side,
+                strike_price: &option.strike_price,
+                expiration_date: &option.expiration_date,
+            })
+            .collect()
+    }
+
+    fn get_implied_volatility(&self) -> HashMap<OptionBasicType<'_>, &Positive> {
+        [&self.long_leg.option, &self.short_leg.option]
+            .into_iter()
+            .map(|option| {
+                (
+                    OptionBasicType {
+                        option_style: &option.option_style,
+                        side: &option.side,
+                        strike_price: &option.strike_price,
+                        expiration_date: &option.expiration_date,
+                    },
+                    &option.implied_volatility,
+                )
+            })
+            .collect()
+    }
+
+    fn get_quantity(&self) -> HashMap<OptionBasicType<'_>, &Positive> {
+        [&self.long_leg.option, &self.short_leg.option]
+            .into_iter()
+            .map(|option| {
+                (
+                    OptionBasicType {
+                        option_style: &option.option_style,
+                        side: &option.side,
+                        strike_price: &option.strike_price,
+                        expiration_date: &option.expiration_date,
+                    },
+                    &option.quantity,
+                )
+            })
+            .collect()
+    }
+
+    fn one_option(&self) -> &Options {
+        self.long_leg.one_option()
+    }
+
+    fn one_option_mut(&mut self) -> &mut Options {
+        self.long_leg.one_option_mut()
+    }
+
+    fn set_expiration_date(
+        &mut self,
+        expiration_date: ExpirationDate,
+    ) -> Result<(), StrategyError> {
+        self.long_leg.option.expiration_date = expiration_date;
+        self.short_leg.option.expiration_date = expiration_date;
+        Ok(())
+    }
+
+    fn set_underlying_price(&mut self, price: &Positive) -> Result<(), StrategyError> {
+        for position in [&mut self.long_leg, &mut self.short_leg] {
+            position.option.underlying_price = *price;
+            position.premium =
+                Positive::new_decimal(position.option.calculate_price_black_scholes()?.abs())
+                    .unwrap_or(Positive::ZERO);
+        }
+        Ok(())
+    }
+
+    fn set_implied_volatility(&mut self, volatility: &Positive) -> Result<(), StrategyError> {
+        for position in [&mut self.long_leg, &mut self.short_leg] {
+            position.option.implied_volatility = *volatility;
+            position.premium =
+                Positive::new_decimal(position.option.calculate_price_black_scholes()?.abs())
+                    .unwrap_or(Positive::ZERO);
+        }
+        Ok(())
+    }
+}
+
+impl Strategies for RatioSpread {
+    /// Unlimited (`Positive::INFINITY`) for call backspreads, whose long
+    /// contracts outnumber the short ones above the strikes.
+    fn get_max_profit(&self) -> Result<Positive, StrategyError> {
+        if self.upper_slope()? > Decimal::ZERO {
+            return Ok(Positive::INFINITY);
+        }
+        let max = self
+            .payoff_nodes()?
+            .into_iter()
+            .map(|(_, profit)| profit)
+            .fold(Decimal::MIN, Decimal::max);
+        if max <= Decimal::ZERO {
+            return Err(StrategyError::ProfitLossError(
+                ProfitLossErrorKind::MaxProfitError {
+                    reason: "Max profit is negative".to_string(),
+                },
+            ));
+        }
+        Ok(Positive::new_decimal(max)?)
+    }
+
+    /// Unlimited (`Positive::INFINITY`) for call ratio spreads, whose short
+    /// contracts outnumber the long ones above the strikes.
+    fn get_max_loss(&self) -> Result<Positive, StrategyError> {
+        if self.upper_slope()? < Decimal::ZERO {
+            return Ok(Positive::INFINITY);
+        }
+        let min = self
+            .payoff_nodes()?
+            .into_iter()
+            .map(|(_, profit)| profit)
+            .fold(Decimal::MAX, Decimal::min);
+        Ok(Positive::new_decimal(min.min(Decimal::ZERO).abs())?)
+    }
+
+    /// Positive area under the expiration payoff within the margin stress
+    /// range, as a percentage of the underlying price.
+    fn get_profit_area(&self) -> Result<Decimal, StrategyError> {
+        let area: Decimal = self
+            .stress_profile()?
+            .windows(2)
+            .filter_map(|pair| match pair {
+                [(x0, p0), (x1, p1)] => {
+                    let mean = (*p0 + *p1) / Decimal::TWO;
+                    (mean > Decimal::ZERO).then(|| mean * (x1.to_dec() - x0.to_dec()))
+                }
+                _ => None,
+            })
+            .sum();
+        let underlying = self.long_leg.option.underlying_price.to_dec();
+        if underlying.is_zero() {
+            return Ok(Decimal::ZERO);
+        }
+        Ok(area / underlying * Decimal::ONE_HUNDRED)
+    }
+
+    /// Max profit over [`margin_requirement`](RatioSpread::margin_requirement)
+    /// as a percentage. Unlimited profit is measured within the stress range.
+    fn get_profit_ratio(&self) -> Result<Decimal, StrategyError> {
+        let profit = match self.get_max_profit() {
+            Ok(profit) if profit == Positive::INFINITY => self
+                .stress_profile()?
+                .into_iter()
+                .map(|(_, profit)| profit)
+                .fold(Decimal::ZERO, Decimal::max),
+            Ok(profit) => profit.to_dec(),
+            Err(_) => return Ok(Decimal::ZERO),
+        };
+        let margin = self.margin_requirement()?;
+        if margin == Positive::ZERO {
+            return Ok(Decimal::MAX);
+        }
+        Ok(profit / margin.to_dec() * Decimal::ONE_HUNDRED)
+    }
+}
+
+impl Optimizable for RatioSpread {
+    type Strategy = RatioSpread;
+
+    /// Yields the (long, short) strike pairs of `option_chain` that satisfy
+    /// `side`, oriented like this spread: the single leg sits closer to the
+    /// money and is bought in a ratio spread or sold in a backspread.
+    fn filter_combinations<'a>(
+        &'a self,
+        option_chain: &'a OptionChain,
+        side: FindOptimalSide,
+    ) -> impl Iterator<Item = OptionDataGroup<'a>> {
+        let option_style = self.long_leg.option.option_style;
+        let backspread = self.is_backspread();
+        option_chain
+            .get_double_iter()
+            .filter(move |(low, high)| {
+                self.is_valid_optimal_option(low, &side)
+                    && self.is_valid_optimal_option(high, &side)
+            })
+            .map(move |(low, high)| {
+                let (near, far) = match option_style {
+                    OptionStyle::Call => (low, high),
+                    OptionStyle::Put => (high, low),
+                };
+                if backspread {
+                    OptionDataGroup::Two(far, near)
+                } else {
+                    OptionDataGroup::Two(near, far)
+                }
+            })
+    }
+
+    /// Searches strikes and the 1x2 and 1x3 ratios without a margin limit;
+    /// see [`RatioSpread::find_optimal_with_margin`].
+    fn find_optimal(
+        &mut self,
+        option_chain: &OptionChain,
+        side: FindOptimalSide,
+        criteria: OptimizationCriteria,
+    ) {
+        self.find_optimal_with_margin(option_chain, side, criteria, None);
+    }
+
+    /// Builds a spread buying `first` and selling `second` with this spread's
+    /// quantities, filling at the chain's ask and bid.
+    fn create_strategy(
+        &self,
+        chain: &OptionChain,
+        legs: &StrategyLegs,
+    ) -> Result<Self::Strategy, StrategyError> {
+        let StrategyLegs::TwoLegs { first, second } = legs else {
+            return Err(StrategyError::operation_not_supported(
+                "create_strategy",
+                "RatioSpread requires exactly two legs (TwoLegs)",
+            ));
+        };
+        self.candidate(
+            chain,
+            first,
+            second,
+            self.long_leg.option.quantity,
+            self.short_leg.option.quantity,
+        )
+    }
+
+    fn is_valid_optimal_option(&self, option: &OptionData, side: &FindOptimalSide) -> bool {
+        match side {
+            FindOptimalSide::Center => {
+                let underlying = *self.get_underlying_price();
+                option.strike_price >= underlying * Positive::new(0.9).unwrap_or(Positive::ONE)
+                    && option.strike_price
+                        <= underlying * Positive::new(1.1).unwrap_or(Positive::ONE)
+            }
+            FindOptimalSide::Upper => option.strike_price >= *self.get_underlying_price(),
+            FindOptimalSide::Lower => option.strike_price <= *self.get_underlying_price(),
+            FindOptimalSide::All => true,
+            FindOptimalSide::Range(start, end) => {
+                option.strike_price >= *start && option.strike_price <= *end
+            }
+            FindOptimalSide::Deltable(_) | FindOptimalSide::DeltaRange(..) => true,
+        }
+    }
+}
+
+impl Profit for RatioSpread {
+    fn calculate_profit_at(&self, price: &Positive) -> Result<Decimal, PricingError> {
+        let price = Some(price);
+        Ok(self.long_leg.pnl_at_expiration(&price)? + self.short_leg.pnl_at_expiration(&price)?)
+    }
+}
+
+impl ProbabilityAnalysis for RatioSpread {
+    fn get_profit_ranges(&self) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        self.ranges(true)
+    }
+
+    fn get_loss_ranges(&self) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        self.ranges(false)
+    }
+}
+
+impl Greeks for RatioSpread {
+    fn get_options(&self) -> Result<Vec<&Options>, GreeksError> {
+        Ok(vec![&self.long_leg.option, &self.short_leg.option])
+    }
+}
+
+impl DeltaNeutrality for RatioSpread {}
+
+impl PnLCalculator for RatioSpread {
+    fn calculate_pnl(
+        &self,
+        market_price: &Positive,
+        expiration_date: ExpirationDate,
+        implied_volatility: &Positive,
+    ) -> Result<PnL, PricingError> {
+        Ok(self
+            .long_leg
+            .calculate_pnl(market_price, expiration_date, implied_volatility)?
+            + self
+                .short_leg
+                .calculate_pnl(market_price, expiration_date, implied_volatility)?)
+    }
+
+    fn calculate_pnl_at_expiration(
+        &self,
+        underlying_price: &Positive,
+    ) -> Result<PnL, PricingError> {
+        Ok(self
+            .long_leg
+            .calculate_pnl_at_expiration(underlying_price)?
+            + self
+                .short_leg
+                .calculate_pnl_at_expiration(underlying_price)?)
+    }
+}
+
+test_strategy_traits!(RatioSpread, test_ratio_spread_implementations);
+
+#[cfg(test)]
+mod tests_ratio_spread {
+    use super::*;
+    use crate::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
+    use positive::{pos_or_panic, spos};
+
+    #[allow(clippy::too_many_arguments)]
+    fn spread(
+        option_style: OptionStyle,
+        long_strike: f64,
+        short_strike: f64,
+        long_quantity: f64,
+        short_quantity: f64,
+        premium_long: f64,
+        premium_short: f64,
+    ) -> Result<RatioSpread, StrategyError> {
+        RatioSpread::new(
+            "SPY".to_string(),
+            Positive::HUNDRED,
+            option_style,
+            pos_or_panic!(long_strike),
+            pos_or_panic!(short_strike),
+            ExpirationDate::Days(pos_or_panic!(30.0)),
+            pos_or_panic!(0.2),
+            dec!(0.03),
+            Positive::ZERO,
+            pos_or_panic!(long_quantity),
+            pos_or_panic!(short_quantity),
+            pos_or_panic!(premium_long),
+            pos_or_panic!(premium_short),
+            Positive::ZERO,
+            Positive::ZERO,
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+    }
+
+    fn chain() -> OptionChain {
+        let params = OptionChainBuildParams::new(
+            "SPY".to_string(),
+            spos!(1000.0),
+            10,
+            spos!(5.0),
+            dec!(-0.2),
+            dec!(0.1),
+            pos_or_panic!(0.02),
+            2,
+            OptionDataPriceParams::new(
+                Some(Box::new(Positive::HUNDRED)),
+                Some(ExpirationDate::Days(pos_or_panic!(30.0))),
+                Some(dec!(0.03)),
+                spos!(0.0),
+                Some("SPY".to_string()),
+            ),
+            pos_or_panic!(0.2),
+        );
+        OptionChain::build_chain(&params).unwrap()
+    }
+
+    #[test]
+    fn test_call_ratio_spread_has_unbounded_risk() {
+        let strategy = spread(OptionStyle::Call, 100.0, 105.0, 1.0, 2.0, 2.5, 0.9).unwrap();
+        assert_eq!(strategy.kind, StrategyType::CallRatioSpread);
+        assert_eq!(strategy.ratio(), dec!(2));
+        // Debit of 0.70, peak of 4.30 at the short strike, slope -1 above it.
+        assert_eq!(
+            strategy.get_break_even_points().unwrap(),
+            &vec![pos_or_panic!(100.7), pos_or_panic!(109.3)]
+        );
+        assert_eq!(strategy.get_max_profit().unwrap(), pos_or_panic!(4.3));
+        assert_eq!(strategy.get_max_loss().unwrap(), Positive::INFINITY);
+        // Worst loss within a 50% move: 4.30 - 45 at 150.
+        assert_eq!(strategy.margin_requirement().unwrap(), pos_or_panic!(40.7));
+        assert!(strategy.get_profit_ratio().unwrap() > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_put_backspread_profit_is_bounded_at_zero() {
+        let strategy = spread(OptionStyle::Put, 95.0, 100.0, 2.0, 1.0, 0.8, 2.3).unwrap();
+        assert_eq!(strategy.kind, StrategyType::PutBackspread);
+        assert!(strategy.is_backspread());
+        assert_eq!(
+            strategy.get_break_even_points().unwrap(),
+            &vec![pos_or_panic!(90.7), pos_or_panic!(99.3)]
+        );
+        assert_eq!(strategy.get_max_profit().unwrap(), pos_or_panic!(90.7));
+        assert_eq!(strategy.get_max_loss().unwrap(), pos_or_panic!(4.3));
+    }
+
+    #[test]
+    fn test_remaining_kinds_and_invalid_shapes() {
+        let call_back = spread(OptionStyle::Call, 105.0, 100.0, 2.0, 1.0, 0.9, 2.5).unwrap();
+        assert_eq!(call_back.kind, StrategyType::CallBackspread);
+        assert_eq!(call_back.get_max_profit().unwrap(), Positive::INFINITY);
+        assert!(call_back.get_max_loss().unwrap() > Positive::ZERO);
+
+        let put_ratio = spread(OptionStyle::Put, 100.0, 95.0, 1.0, 3.0, 2.3, 0.8).unwrap();
+        assert_eq!(put_ratio.kind, StrategyType::PutRatioSpread);
+        assert_eq!(put_ratio.name, "Put Ratio Spread");
+        assert!(put_ratio.get_max_loss().unwrap() < Positive::INFINITY);
+
+        // Equal quantities are a plain vertical.
+        assert!(spread(OptionStyle::Call, 100.0, 105.0, 1.0, 1.0, 2.5, 0.9).is_err());
+        // Selling more calls below the long strike is not a ratio spread.
+        assert!(spread(OptionStyle::Call, 105.0, 100.0, 1.0, 2.0, 0.9, 2.5).is_err());
+
+        let rebuilt =
+            RatioSpread::get_strategy(&[put_ratio.short_leg.clone(), put_ratio.long_leg.clone()])
+                .unwrap();
+        assert_eq!(rebuilt.kind, StrategyType::PutRatioSpread);
+    }
+
+    #[test]
+    fn test_delta_neutral_construction() {
+        let chain = chain();
+        let strategy = RatioSpread::delta_neutral(
+            &chain,
+            OptionStyle::Call,
+            Positive::HUNDRED,
+            pos_or_panic!(110.0),
+            Positive::ONE,
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+        .unwrap();
+        assert_eq!(strategy.kind, StrategyType::CallRatioSpread);
+        assert!(strategy.ratio() > Decimal::ONE);
+        assert!(strategy.is_delta_neutral());
+
+        let backspread = RatioSpread::delta_neutral(
+            &chain,
+            OptionStyle::Put,
+            pos_or_panic!(90.0),
+            Positive::HUNDRED,
+            Positive::ONE,
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+        .unwrap();
+        assert_eq!(backspread.kind, StrategyType::PutBackspread);
+        assert!(backspread.is_delta_neutral());
+    }
+
+    #[test]
+    fn test_optimizer_respects_margin_limit() {
+        let chain = chain();
+        let mut unlimited = spread(OptionStyle::Call, 100.0, 105.0, 1.0, 2.0, 2.5, 0.9).unwrap();
+        unlimited.find_optimal(&chain, FindOptimalSide::Center, OptimizationCriteria::Area);
+        assert!(unlimited.validate());
+        assert_eq!(unlimited.kind, StrategyType::CallRatioSpread);
+
+        let limit = pos_or_panic!(35.0);
+        assert!(unlimited.margin_requirement().unwrap() > limit);
+        let mut limited = spread(OptionStyle::Call, 100.0, 105.0, 1.0, 2.0, 2.5, 0.9).unwrap();
+        limited.find_optimal_with_margin(
+            &chain,
+            FindOptimalSide::Center,
+            OptimizationCriteria::Area,
+            Some(limit),
+        );
+        assert!(limited.validate());
+        assert!(limited.margin_requirement().unwrap() <= limit);
+        assert!([dec!(2), dec!(3)].contains(&limited.ratio()));
+
+        let mut backspread = spread(OptionStyle::Put, 95.0, 100.0, 2.0, 1.0, 0.8, 2.3).unwrap();
+        backspread.get_best_ratio(&chain, FindOptimalSide::Center);
+        assert_eq!(backspread.kind, StrategyType::PutBackspread);
+    }
+
+    #[test]
+    fn test_probabilities_sum_to_one() {
+        let strategy = spread(OptionStyle::Call, 100.0, 105.0, 1.0, 2.0, 2.5, 0.9).unwrap();
+        let profit = strategy.get_profit_ranges().unwrap();
+        let loss = strategy.get_loss_ranges().unwrap();
+        assert_eq!(profit.len(), 1);
+        assert_eq!(loss.len(), 2);
+        let total: Positive = profit
+            .iter()
+            .chain(loss.iter())
+            .map(|r| r.probability)
+            .sum();
+        assert!((total.to_dec() - Decimal::ONE).abs() < dec!(0.01));
+    }
+}
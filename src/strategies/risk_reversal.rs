@@ -0,0 +1,868 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Risk Reversal Strategy
+//!
+//! A risk reversal buys an option on one side of the underlying and sells
+//! one on the other side to pay for it:
+//!
+//! - a bullish risk reversal buys a call and sells a put below it;
+//! - a bearish risk reversal buys a put and sells a call above it.
+//!
+//! Quoted zero-cost, the premium of the sold option matches the bought one
+//! and the position is a directional bet with unbounded exposure on both
+//! sides of the strikes. [`RiskReversal::zero_cost`] solves for the strike of
+//! the sold option against an `OptionChain`.
+
+use super::base::{
+    BreakEvenable, Optimizable, Positionable, Strategable, StrategyBasics, StrategyType, Validable,
+};
+use super::shared::{chain_leg, expiration_break_evens, net_premium, premium_neutral};
+use crate::chains::{StrategyLegs, chain::OptionChain, utils::OptionDataGroup};
+use crate::error::position::{PositionError, PositionValidationErrorKind};
+use crate::error::probability::ProbabilityError;
+use crate::error::strategies::{ProfitLossErrorKind, StrategyError};
+use crate::error::{GreeksError, PricingError};
+use crate::greeks::Greeks;
+use crate::model::ProfitLossRange;
+use crate::model::position::Position;
+use crate::model::types::{OptionBasicType, OptionStyle, OptionType, Side};
+use crate::model::utils::mean_and_std;
+use crate::pnl::{PnLCalculator, utils::PnL};
+use crate::pricing::payoff::Profit;
+use crate::strategies::delta_neutral::DeltaNeutrality;
+use crate::strategies::probabilities::{core::ProbabilityAnalysis, utils::VolatilityAdjustment};
+use crate::strategies::utils::FindOptimalSide;
+use crate::strategies::{BasicAble, Strategies, StrategyConstructor};
+use crate::{ExpirationDate, Options, test_strategy_traits};
+use chrono::Utc;
+use positive::Positive;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tracing::debug;
+use utoipa::ToSchema;
+
+/// Default description for the Risk Reversal strategy.
+pub const RISK_REVERSAL_DESCRIPTION: &str = "A risk reversal buys a call and sells a put below \
+    it, or buys a put and sells a call above it, using the sold option to pay for the bought one. \
+    It is a directional position with unbounded exposure beyond the strikes.";
+
+/// Represents a bullish or bearish Risk Reversal.
+///
+/// The direction follows the call: a long call with a short put is bullish
+/// and a short call with a long put is bearish. The put strike is at or
+/// below the call strike.
+#[derive(Clone, DebugPretty, DisplaySimple, Serialize, Deserialize, ToSchema)]
+pub struct RiskReversal {
+    /// Name identifier for this specific strategy instance
+    pub name: String,
+    /// Type of strategy, always `RiskReversal`
+    pub kind: StrategyType,
+    /// Detailed description of this strategy instance
+    pub description: String,
+    /// Prices at expiration where the strategy neither makes nor loses money
+    pub break_even_points: Vec<Positive>,
+    /// The put, sold in a bullish risk reversal
+    pub(super) put: Position,
+    /// The call, bought in a bullish risk reversal
+    pub(super) call: Position,
+}
+
+impl RiskReversal {
+    /// Creates a bullish Risk Reversal when `call_side` is `Long` or a
+    /// bearish one when it is `Short`. The put takes the other side.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyError` if the put strike is above the call strike,
+    /// a leg fails validation or the break-even calculation fails.
+    #[allow(clippy::too_many_arguments)]
+    #[inline(never)]
+    pub fn new(
+        underlying_symbol: String,
+        underlying_price: Positive,
+        call_side: Side,
+        put_strike: Positive,
+        call_strike: Positive,
+        expiration: ExpirationDate,
+        implied_volatility: Positive,
+        risk_free_rate: Decimal,
+        dividend_yield: Positive,
+        quantity: Positive,
+        premium_put: Positive,
+        premium_call: Positive,
+        open_fee: Positive,
+        close_fee: Positive,
+    ) -> Result<Self, StrategyError> {
+        let leg = |option_style, side, strike, premium| {
+            Position::new(
+                Options::new(
+                    OptionType::European,
+                    side,
+                    underlying_symbol.clone(),
+                    strike,
+                    expiration,
+                    implied_volatility,
+                    quantity,
+                    underlying_price,
+                    risk_free_rate,
+                    option_style,
+                    dividend_yield,
+                    None,
+                ),
+                premium,
+                Utc::now(),
+                open_fee,
+                close_fee,
+                None,
+                None,
+            )
+        };
+        Self::from_legs(
+            leg(
+                OptionStyle::Put,
+                opposite(call_side),
+                put_strike,
+                premium_put,
+            ),
+            leg(OptionStyle::Call, call_side, call_strike, premium_call),
+        )
+    }
+
+    /// Builds a zero-cost Risk Reversal from the quotes of `chain`.
+    ///
+    /// The bought option sits at `long_strike`: a call when `call_side` is
+    /// `Long`, a put otherwise. The sold option is the one on the other side
+    /// of `long_strike` whose premium best offsets it, with long legs filled
+    /// at the ask and short legs at the bid.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyError` if the chain has no expiration, `long_strike`
+    /// is not quoted or no strike on the other side produces a valid risk
+    /// reversal.
+    pub fn zero_cost(
+        chain: &OptionChain,
+        call_side: Side,
+        long_strike: Positive,
+        quantity: Positive,
+        open_fee: Positive,
+        close_fee: Positive,
+    ) -> Result<Self, StrategyError> {
+        let expiration = chain.get_expiration().ok_or_else(|| {
+            StrategyError::invalid_parameters("RiskReversal::zero_cost", "chain has no expiration")
+        })?;
+        let leg = |strike, option_style, side| {
+            chain_leg(
+                chain,
+                expiration,
+                strike,
+                option_style,
+                side,
+                quantity,
+                open_fee,
+                close_fee,
+            )
+        };
+        let candidates = chain
+            .options
+            .iter()
+            .map(|option| option.strike_price)
+            .filter(|strike| match call_side {
+                Side::Long => *strike <= long_strike,
+                Side::Short => *strike >= long_strike,
+            });
+        premium_neutral(
+            candidates,
+            |strike| match call_side {
+                Side::Long => Self::from_legs(
+                    leg(strike, OptionStyle::Put, Side::Short)?,
+                    leg(long_strike, OptionStyle::Call, Side::Long)?,
+                ),
+                Side::Short => Self::from_legs(
+                    leg(long_strike, OptionStyle::Put, Side::Long)?,
+                    leg(strike, OptionStyle::Call, Side::Short)?,
+                ),
+            },
+            |strategy| strategy.net_premium(),
+        )
+    }
+
+    fn from_legs(put: Position, call: Position) -> Result<Self, StrategyError> {
+        let name = match call.option.side {
+            Side::Long => "Bullish Risk Reversal",
+            Side::Short => "Bearish Risk Reversal",
+        };
+        let mut strategy = RiskReversal {
+            name: name.to_string(),
+            put,
+            call,
+            ..Default::default()
+        };
+        if !strategy.validate() {
+            return Err(StrategyError::invalid_parameters(
+                "RiskReversal",
+                "requires a call and a put on opposite sides with the put strike at or below \
+                 the call strike, sharing expiration and quantity",
+            ));
+        }
+        strategy.update_break_even_points()?;
+        Ok(strategy)
+    }
+
+    fn legs(&self) -> [&Position; 2] {
+        [&self.put, &self.call]
+    }
+
+    fn legs_mut(&mut self) -> [&mut Position; 2] {
+        [&mut self.put, &mut self.call]
+    }
+
+    /// Returns the side of the call, `Long` for a bullish risk reversal.
+    #[must_use]
+    pub fn direction(&self) -> Side {
+        self.call.option.side
+    }
+
+    /// Returns the put and call strikes.
+    #[must_use]
+    pub fn strikes(&self) -> (Positive, Positive) {
+        (self.put.option.strike_price, self.call.option.strike_price)
+    }
+
+    /// Premium collected minus premium paid, before fees. Zero for a
+    /// zero-cost quote.
+    #[must_use]
+    pub fn net_premium(&self) -> Decimal {
+        net_premium(&self.legs())
+    }
+
+    /// Expiration payoff at zero and at each strike, where its slope changes.
+    fn payoff_nodes(&self) -> Result<Vec<(Positive, Decimal)>, PricingError> {
+        let (put, call) = self.strikes();
+        let mut prices = vec![Positive::ZERO, put, call];
+        prices.dedup();
+        prices
+            .into_iter()
+            .map(|price| Ok((price, self.calculate_profit_at(&price)?)))
+            .collect()
+    }
+
+    /// Slope of the expiration payoff above the call strike.
+    fn upper_slope(&self) -> Decimal {
+        let quantity = self.call.option.quantity.to_dec();
+        match self.call.option.side {
+            Side::Long => quantity,
+            Side::Short => -quantity,
+        }
+    }
+
+    fn volatility_adjustment(&self) -> VolatilityAdjustment {
+        let (base_volatility, std_dev_adjustment) = mean_and_std(
+            self.legs()
+                .iter()
+                .map(|leg| leg.option.implied_volatility)
+                .collect(),
+        );
+        VolatilityAdjustment {
+            base_volatility,
+            std_dev_adjustment,
+        }
+    }
+
+    fn ranges(&self, profitable: bool) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        let option = &self.call.option;
+        let mut bounds: Vec<Option<Positive>> = vec![None];
+        bounds.extend(self.break_even_points.iter().copied().map(Some));
+        bounds.push(None);
+        let mut ranges = Vec::new();
+        for window in bounds.windows(2) {
+            let [lower, upper] = window else {
+                continue;
+            };
+            let probe = match (lower, upper) {
+                (Some(l), Some(u)) => (*l + *u) / Positive::TWO,
+                (None, Some(u)) => *u / Positive::TWO,
+                (Some(l), None) => *l * Positive::TWO,
+                (None, None) => option.underlying_price,
+            };
+            if (self.calculate_profit_at(&probe)? > Decimal::ZERO) != profitable {
+                continue;
+            }
+            let mut range = ProfitLossRange::new(*lower, *upper, Positive::ZERO)?;
+            range.calculate_probability(
+                &option.underlying_price,
+                Some(self.volatility_adjustment()),
+                None,
+                &option.expiration_date,
+                Some(option.risk_free_rate),
+            )?;
+            ranges.push(range);
+        }
+        Ok(ranges)
+    }
+}
+
+fn opposite(side: Side) -> Side {
+    match side {
+        Side::Long => Side::Short,
+        Side::Short => Side::Long,
+    }
+}
+
+impl StrategyConstructor for RiskReversal {
+    fn get_strategy(vec_positions: &[Position]) -> Result<Self, StrategyError> {
+        let (puts, calls): (Vec<&Position>, Vec<&Position>) = vec_positions
+            .iter()
+            .partition(|position| position.option.option_style == OptionStyle::Put);
+        let ([put], [call]) = (puts.as_slice(), calls.as_slice()) else {
+            return Err(StrategyError::invalid_parameters(
+                "Risk Reversal get_strategy",
+                "Must have exactly 1 put and 1 call",
+            ));
+        };
+        Self::from_legs((*put).clone(), (*call).clone())
+    }
+}
+
+impl BreakEvenable for RiskReversal {
+    fn get_break_even_points(&self) -> Result<&Vec<Positive>, StrategyError> {
+        Ok(&self.break_even_points)
+    }
+
+    fn update_break_even_points(&mut self) -> Result<(), StrategyError> {
+        self.break_even_points = expiration_break_evens(&self.payoff_nodes()?, self.upper_slope())?;
+        Ok(())
+    }
+}
+
+impl Validable for RiskReversal {
+    fn validate(&self) -> bool {
+        if !self.put.validate() || !self.call.validate() {
+            debug!("Risk Reversal legs failed validation");
+            return false;
+        }
+        if self.put.option.option_style != OptionStyle::Put
+            || self.call.option.option_style != OptionStyle::Call
+        {
+            debug!("Risk Reversal requires one put and one call");
+            return false;
+        }
+        let (put, call) = (&self.put.option, &self.call.option);
+        if put.side == call.side {
+            debug!("Risk Reversal put and call must be on opposite sides");
+            return false;
+        }
+        if put.expiration_date != call.expiration_date || put.quantity != call.quantity {
+            debug!("Risk Reversal legs must share expiration and quantity");
+            return false;
+        }
+        if put.strike_price > call.strike_price {
+            debug!("Risk Reversal put strike must be at or below the call strike");
+            return false;
+        }
+        true
+    }
+}
+
+impl Positionable for RiskReversal {
+    /// Replaces the leg with the same style.
+    fn add_position(&mut self, position: &Position) -> Result<(), PositionError> {
+        match position.option.option_style {
+            OptionStyle::Put => self.put = position.clone(),
+            OptionStyle::Call => self.call = position.clone(),
+        }
+        Ok(())
+    }
+
+    fn get_positions(&self) -> Result<Vec<&Position>, PositionError> {
+        Ok(self.legs().to_vec())
+    }
+
+    fn get_position(
+        &mut self,
+        option_style: &OptionStyle,
+        side: &Side,
+        strike: &Positive,
+    ) -> Result<Vec<&mut Position>, PositionError> {
+        let positions: Vec<&mut Position> = self
+            .legs_mut()
+            .into_iter()
+            .filter(|leg| {
+                leg.option.option_style == *option_style
+                    && leg.option.side == *side
+                    && leg.option.strike_price == *strike
+            })
+            .collect();
+        if positions.is_empty() {
+            return Err(PositionError::invalid_position_type(
+                *side,
+                "Strike not found in positions".to_string(),
+            ));
+        }
+        Ok(positions)
+    }
+
+    fn modify_position(&mut self, position: &Position) -> Result<(), PositionError> {
+        if !position.validate() {
+            return Err(PositionError::ValidationError(
+                PositionValidationErrorKind::InvalidPosition {
+                    reason: "Invalid position data".to_string(),
+                },
+            ));
+        }
+        let option = &position.option;
+        let slot = self.legs_mut().into_iter().find(|leg| {
+            leg.option.option_style == option.option_style
+                && leg.option.side == option.side
+                && leg.option.strike_price == option.strike_price
+        });
+        match slot {
+            Some(slot) => {
+                *slot = position.clone();
+                Ok(())
+            }
+            None => Err(PositionError::invalid_position_type(
+                option.side,
+                "Strike not found in positions".to_string(),
+            )),
+        }
+    }
+}
+
+impl Strategable for RiskReversal {
+    fn info(&self) -> Result<StrategyBasics, StrategyError> {
+        Ok(StrategyBasics {
+            name: self.name.clone(),
+            kind: self.kind.clone(),
+            description: self.description.clone(),
+        })
+    }
+}
+
+impl BasicAble for RiskReversal {
+    fn get_title(&self) -> String {
+        let legs: Vec<String> = self.legs().iter().map(|leg| leg.get_title()).collect();
+        format!("{} Strategy: \n\t{}", self.name, legs.join("\n\t"))
+    }
+
+    fn get_option_basic_type(&self) -> HashSet<OptionBasicType<'_>> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                OptionBasicType {
+                    option_style: &option.option_style,
+                    side: &option.side,
+                    strike_price: &option.strike_price,
+                    expiration_date: &option.expiration_date,
+                }
+            })
+            .collect()
+    }
+
+    fn get_implied_volatility(&self) -> HashMap<OptionBasicType<'_>, &Positive> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                (
+                    OptionBasicType {
+                        option_style: &option.option_style,
+                        side: &option.side,
+                        strike_price: &option.strike_price,
+                        expiration_date: &option.expiration_date,
+                    },
+                    &option.implied_volatility,
+                )
+            })
+            .collect()
+    }
+
+    fn get_quantity(&self) -> HashMap<OptionBasicType<'_>, &Positive> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                (
+                    OptionBasicType {
+                        option_style: &option.option_style,
+                        side: &option.side,
+                        strike_price: &option.strike_price,
+                        expiration_date: &option.expiration_date,
+                    },
+                    &option.quantity,
+                )
+            })
+            .collect()
+    }
+
+    fn one_option(&self) -> &Options {
+        self.call.one_option()
+    }
+
+    fn one_option_mut(&mut self) -> &mut Options {
+        self.call.one_option_mut()
+    }
+
+    fn set_expiration_date(
+        &mut self,
+        expiration_date: ExpirationDate,
+    ) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.expiration_date = expiration_date;
+        }
+        Ok(())
+    }
+
+    fn set_underlying_price(&mut self, price: &Positive) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.underlying_price = *price;
+            leg.premium = Positive::new_decimal(leg.option.calculate_price_black_scholes()?.abs())
+                .unwrap_or(Positive::ZERO);
+        }
+        Ok(())
+    }
+
+    fn set_implied_volatility(&mut self, volatility: &Positive) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.implied_volatility = *volatility;
+            leg.premium = Positive::new_decimal(leg.option.calculate_price_black_scholes()?.abs())
+                .unwrap_or(Positive::ZERO);
+        }
+        Ok(())
+    }
+}
+
+impl Strategies for RiskReversal {
+    /// Unbounded for a bullish risk reversal, otherwise the profit with the
+    /// underlying at zero.
+    fn get_max_profit(&self) -> Result<Positive, StrategyError> {
+        if self.upper_slope() > Decimal::ZERO {
+            return Ok(Positive::INFINITY);
+        }
+        let max = self
+            .payoff_nodes()?
+            .into_iter()
+            .map(|(_, profit)| profit)
+            .fold(Decimal::MIN, Decimal::max);
+        if max <= Decimal::ZERO {
+            return Err(StrategyError::ProfitLossError(
+                ProfitLossErrorKind::MaxProfitError {
+                    reason: "Max profit is negative".to_string(),
+                },
+            ));
+        }
+        Ok(Positive::new_decimal(max)?)
+    }
+
+    /// Unbounded for a bearish risk reversal, otherwise the loss with the
+    /// underlying at zero.
+    fn get_max_loss(&self) -> Result<Positive, StrategyError> {
+        if self.upper_slope() < Decimal::ZERO {
+            return Ok(Positive::INFINITY);
+        }
+        let min = self
+            .payoff_nodes()?
+            .into_iter()
+            .map(|(_, profit)| profit)
+            .fold(Decimal::MAX, Decimal::min);
+        Ok(Positive::new_decimal(min.min(Decimal::ZERO).abs())?)
+    }
+}
+
+impl Optimizable for RiskReversal {
+    type Strategy = RiskReversal;
+
+    /// Yields the (put, call) strike pairs of `option_chain` that satisfy
+    /// `side`, with the put strike below the call strike.
+    fn filter_combinations<'a>(
+        &'a self,
+        option_chain: &'a OptionChain,
+        side: FindOptimalSide,
+    ) -> impl Iterator<Item = OptionDataGroup<'a>> {
+        option_chain
+            .get_double_iter()
+            .filter(move |(put, call)| {
+                self.is_valid_optimal_option(put, &side)
+                    && self.is_valid_optimal_option(call, &side)
+            })
+            .map(|(put, call)| OptionDataGroup::Two(put, call))
+    }
+
+    /// Builds a risk reversal with the put at `first` and the call at
+    /// `second`, keeping this one's direction, quantity and fees and filling
+    /// at the chain's quotes.
+    fn create_strategy(
+        &self,
+        chain: &OptionChain,
+        legs: &StrategyLegs,
+    ) -> Result<Self::Strategy, StrategyError> {
+        let StrategyLegs::TwoLegs { first, second } = legs else {
+            return Err(StrategyError::operation_not_supported(
+                "create_strategy",
+                "RiskReversal requires exactly two legs (TwoLegs)",
+            ));
+        };
+        let expiration = chain
+            .get_expiration()
+            .unwrap_or(self.call.option.expiration_date);
+        let leg = |template: &Position, strike| {
+            chain_leg(
+                chain,
+                expiration,
+                strike,
+                template.option.option_style,
+                template.option.side,
+                template.option.quantity,
+                template.open_fee,
+                template.close_fee,
+            )
+        };
+        Self::from_legs(
+            leg(&self.put, first.strike_price)?,
+            leg(&self.call, second.strike_price)?,
+        )
+    }
+}
+
+impl Profit for RiskReversal {
+    fn calculate_profit_at(&self, price: &Positive) -> Result<Decimal, PricingError> {
+        let price = Some(price);
+        self.legs()
+            .iter()
+            .map(|leg| leg.pnl_at_expiration(&price))
+            .sum()
+    }
+}
+
+impl ProbabilityAnalysis for RiskReversal {
+    fn get_profit_ranges(&self) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        self.ranges(true)
+    }
+
+    fn get_loss_ranges(&self) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        self.ranges(false)
+    }
+}
+
+impl Greeks for RiskReversal {
+    fn get_options(&self) -> Result<Vec<&Options>, GreeksError> {
+        Ok(self.legs().map(|leg| &leg.option).to_vec())
+    }
+}
+
+impl DeltaNeutrality for RiskReversal {}
+
+impl PnLCalculator for RiskReversal {
+    fn calculate_pnl(
+        &self,
+        market_price: &Positive,
+        expiration_date: ExpirationDate,
+        implied_volatility: &Positive,
+    ) -> Result<PnL, PricingError> {
+        let mut pnl = PnL::default();
+        for leg in self.legs() {
+            pnl = pnl + leg.calculate_pnl(market_price, expiration_date, implied_volatility)?;
+        }
+        Ok(pnl)
+    }
+
+    fn calculate_pnl_at_expiration(
+        &self,
+        underlying_price: &Positive,
+    ) -> Result<PnL, PricingError> {
+        let mut pnl = PnL::default();
+        for leg in self.legs() {
+            pnl = pnl + leg.calculate_pnl_at_expiration(underlying_price)?;
+        }
+        Ok(pnl)
+    }
+}
+
+test_strategy_traits!(RiskReversal, test_risk_reversal_implementations);
+
+#[cfg(test)]
+mod tests_risk_reversal {
+    use super::*;
+    use crate::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
+    use positive::{pos_or_panic, spos};
+    use rust_decimal_macros::dec;
+
+    fn risk_reversal(call_side: Side, premium_put: f64, premium_call: f64) -> RiskReversal {
+        RiskReversal::new(
+            "EURUSD".to_string(),
+            Positive::HUNDRED,
+            call_side,
+            pos_or_panic!(95.0),
+            pos_or_panic!(105.0),
+            ExpirationDate::Days(pos_or_panic!(90.0)),
+            pos_or_panic!(0.1),
+            dec!(0.03),
+            Positive::ZERO,
+            Positive::ONE,
+            pos_or_panic!(premium_put),
+            pos_or_panic!(premium_call),
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+        .unwrap()
+    }
+
+    fn chain() -> OptionChain {
+        let params = OptionChainBuildParams::new(
+            "EURUSD".to_string(),
+            spos!(1000.0),
+            10,
+            spos!(2.5),
+            dec!(-0.2),
+            dec!(0.1),
+            pos_or_panic!(0.02),
+            2,
+            OptionDataPriceParams::new(
+                Some(Box::new(Positive::HUNDRED)),
+                Some(ExpirationDate::Days(pos_or_panic!(180.0))),
+                Some(dec!(0.03)),
+                spos!(0.0),
+                Some("EURUSD".to_string()),
+            ),
+            pos_or_panic!(0.15),
+        );
+        OptionChain::build_chain(&params).unwrap()
+    }
+
+    #[test]
+    fn test_bullish_risk_reversal() {
+        // Sells the 95 put for 2.5 and buys the 105 call for 2.0.
+        let strategy = risk_reversal(Side::Long, 2.5, 2.0);
+        assert_eq!(strategy.name, "Bullish Risk Reversal");
+        assert_eq!(strategy.direction(), Side::Long);
+        assert_eq!(strategy.net_premium(), dec!(0.5));
+        assert_eq!(
+            strategy.calculate_profit_at(&Positive::HUNDRED).unwrap(),
+            dec!(0.5)
+        );
+        assert_eq!(
+            strategy.get_break_even_points().unwrap(),
+            &vec![pos_or_panic!(94.5)]
+        );
+        assert_eq!(strategy.get_max_profit().unwrap(), Positive::INFINITY);
+        assert_eq!(strategy.get_max_loss().unwrap(), pos_or_panic!(94.5));
+    }
+
+    #[test]
+    fn test_bearish_risk_reversal() {
+        // Buys the 95 put for 2.5 and sells the 105 call for 2.0.
+        let strategy = risk_reversal(Side::Short, 2.5, 2.0);
+        assert_eq!(strategy.name, "Bearish Risk Reversal");
+        assert_eq!(strategy.net_premium(), dec!(-0.5));
+        assert_eq!(
+            strategy.get_break_even_points().unwrap(),
+            &vec![pos_or_panic!(94.5)]
+        );
+        assert_eq!(strategy.get_max_profit().unwrap(), pos_or_panic!(94.5));
+        assert_eq!(strategy.get_max_loss().unwrap(), Positive::INFINITY);
+    }
+
+    #[test]
+    fn test_rejects_put_above_call() {
+        let strategy = RiskReversal::new(
+            "EURUSD".to_string(),
+            Positive::HUNDRED,
+            Side::Long,
+            pos_or_panic!(105.0),
+            pos_or_panic!(95.0),
+            ExpirationDate::Days(pos_or_panic!(90.0)),
+            pos_or_panic!(0.1),
+            dec!(0.03),
+            Positive::ZERO,
+            Positive::ONE,
+            Positive::ONE,
+            Positive::ONE,
+            Positive::ZERO,
+            Positive::ZERO,
+        );
+        assert!(strategy.is_err());
+    }
+
+    #[test]
+    fn test_zero_cost_picks_the_closest_financing_strike() {
+        let chain = chain();
+        let long_strike = pos_or_panic!(105.0);
+        for call_side in [Side::Long, Side::Short] {
+            let strategy = RiskReversal::zero_cost(
+                &chain,
+                call_side,
+                long_strike,
+                Positive::ONE,
+                Positive::ZERO,
+                Positive::ZERO,
+            )
+            .unwrap();
+            assert!(strategy.validate());
+            assert_eq!(strategy.direction(), call_side);
+            let (put, call) = strategy.strikes();
+            let best = strategy.net_premium().abs();
+            for option in &chain.options {
+                let strike = option.strike_price;
+                let alternative = match call_side {
+                    Side::Long if strike <= long_strike && strike != put => {
+                        risk_reversal_from_chain(&chain, call_side, strike, call)
+                    }
+                    Side::Short if strike >= long_strike && strike != call => {
+                        risk_reversal_from_chain(&chain, call_side, put, strike)
+                    }
+                    _ => continue,
+                };
+                if let Some(alternative) = alternative {
+                    assert!(best <= alternative.net_premium().abs());
+                }
+            }
+        }
+    }
+
+    fn risk_reversal_from_chain(
+        chain: &OptionChain,
+        call_side: Side,
+        put_strike: Positive,
+        call_strike: Positive,
+    ) -> Option<RiskReversal> {
+        let expiration = chain.get_expiration()?;
+        let leg = |strike, style, side| {
+            chain_leg(
+                chain,
+                expiration,
+                strike,
+                style,
+                side,
+                Positive::ONE,
+                Positive::ZERO,
+                Positive::ZERO,
+            )
+        };
+        RiskReversal::from_legs(
+            leg(put_strike, OptionStyle::Put, opposite(call_side)).ok()?,
+            leg(call_strike, OptionStyle::Call, call_side).ok()?,
+        )
+        .ok()
+    }
+
+    #[test]
+    fn test_zero_cost_requires_a_quoted_strike() {
+        let chain = chain();
+        let result = RiskReversal::zero_cost(
+            &chain,
+            Side::Long,
+            pos_or_panic!(101.0),
+            Positive::ONE,
+            Positive::ZERO,
+            Positive::ZERO,
+        );
+        assert!(result.is_err());
+    }
+}
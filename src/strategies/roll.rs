@@ -0,0 +1,693 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 19/10/26
+******************************************************************************/
+
+//! Strategy roll engine.
+//!
+//! Moving an open strategy to a new expiration or strike used to mean building
+//! the new legs by hand and swapping them in with
+//! [`Positionable::replace_position`](crate::strategies::base::Positionable::replace_position).
+//! The engine in this module proposes the roll from the expirations of an
+//! [`OptionSeries`](crate::series::OptionSeries) following a [`RollRule`], prices the closing and opening
+//! [`Trade`](crate::model::Trade)s on the chain quotes with each leg's fees, and reports how the Greeks
+//! and the P&L profile of the strategy change.
+
+use crate::chains::OptionData;
+use crate::chains::chain::OptionChain;
+use crate::error::strategies::StrategyError;
+use crate::greeks::{Greeks, delta};
+use crate::model::position::Position;
+use crate::model::types::{OptionStyle, Side};
+use crate::model::{Trade, TradeStatusAble};
+use crate::series::OptionSeries;
+use crate::strategies::base::Strategies;
+use positive::Positive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use utoipa::ToSchema;
+
+/// Two expirations less than this many days apart are treated as the same expiry,
+/// so that legs opened from a chain's dated expiration still match its series key.
+const SAME_EXPIRY_TOLERANCE: Decimal = Decimal::ONE;
+
+/// Rule followed to propose a roll.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub enum RollRule {
+    /// Roll every leg out to the next expiration, keeping its strike.
+    SameStrikes,
+    /// Roll every leg out to the next expiration, at the strike whose delta is
+    /// closest to the leg's current delta.
+    SameDelta,
+    /// Keep the expiration and move the untested side (the short side farther
+    /// from the spot) up or down until its short delta matches the tested short.
+    /// Long wings on that side move by the same amount, keeping the width.
+    UntestedSide,
+    /// Roll out to the nearest expiration where a same-strike or same-delta roll
+    /// collects at least `min_credit`, net of fees.
+    ForCredit {
+        /// Minimum net credit required for the roll.
+        min_credit: Positive,
+    },
+}
+
+/// Greek and P&L profile of a strategy before or after a roll.
+///
+/// Greeks are evaluated at the spot price of the series.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct RollProfile {
+    /// Net delta.
+    pub delta: Decimal,
+    /// Net gamma.
+    pub gamma: Decimal,
+    /// Net theta.
+    pub theta: Decimal,
+    /// Net vega.
+    pub vega: Decimal,
+    /// Maximum profit at expiration.
+    pub max_profit: Positive,
+    /// Maximum loss at expiration.
+    pub max_loss: Positive,
+    /// Break-even prices at expiration.
+    pub break_even_points: Vec<Positive>,
+}
+
+impl RollProfile {
+    fn of<S: Strategies + Greeks>(strategy: &S) -> Result<Self, StrategyError> {
+        Ok(Self {
+            delta: Greeks::delta(strategy)?,
+            gamma: Greeks::gamma(strategy)?,
+            theta: Greeks::theta(strategy)?,
+            vega: Greeks::vega(strategy)?,
+            max_profit: strategy.get_max_profit()?,
+            max_loss: strategy.get_max_loss()?,
+            break_even_points: strategy.get_break_even_points()?.clone(),
+        })
+    }
+}
+
+/// Change of a [`RollProfile`] produced by a roll (`after - before`).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct RollChange {
+    /// Change in net delta.
+    pub delta: Decimal,
+    /// Change in net gamma.
+    pub gamma: Decimal,
+    /// Change in net theta.
+    pub theta: Decimal,
+    /// Change in net vega.
+    pub vega: Decimal,
+    /// Change in maximum profit.
+    pub max_profit: Decimal,
+    /// Change in maximum loss.
+    pub max_loss: Decimal,
+}
+
+/// A roll proposed by [`Strategies::roll`].
+#[derive(Debug, Clone)]
+pub struct RollProposal<S> {
+    /// Rule that produced the roll.
+    pub rule: RollRule,
+    /// The strategy after the roll.
+    pub strategy: S,
+    /// Trades closing the rolled legs at the current chain quotes.
+    pub close_trades: Vec<Trade>,
+    /// Trades opening the replacement legs.
+    pub open_trades: Vec<Trade>,
+    /// Profile of the strategy before the roll.
+    pub before: RollProfile,
+    /// Profile of the strategy after the roll. The cash flow of the roll itself
+    /// is reported by [`RollProposal::net_credit`], not in this profile.
+    pub after: RollProfile,
+}
+
+impl<S> RollProposal<S> {
+    /// Net cash collected by the roll after fees; negative for a debit.
+    #[must_use]
+    pub fn net_credit(&self) -> Decimal {
+        self.trades().map(Trade::net).sum()
+    }
+
+    /// Total fees paid by the closing and opening trades.
+    #[must_use]
+    pub fn fees(&self) -> Positive {
+        self.trades().fold(Positive::ZERO, |acc, trade| {
+            acc + trade.fee * trade.quantity
+        })
+    }
+
+    /// Change in Greeks and P&L profile produced by the roll.
+    #[must_use]
+    pub fn change(&self) -> RollChange {
+        RollChange {
+            delta: self.after.delta - self.before.delta,
+            gamma: self.after.gamma - self.before.gamma,
+            theta: self.after.theta - self.before.theta,
+            vega: self.after.vega - self.before.vega,
+            max_profit: self.after.max_profit.to_dec() - self.before.max_profit.to_dec(),
+            max_loss: self.after.max_loss.to_dec() - self.before.max_loss.to_dec(),
+        }
+    }
+
+    fn trades(&self) -> impl Iterator<Item = &Trade> {
+        self.close_trades.iter().chain(&self.open_trades)
+    }
+}
+
+/// Legs to close paired with the legs opened in their place.
+type RollPlan = Vec<(Position, Position)>;
+
+/// Builds the roll behind [`Strategies::roll`].
+pub(crate) fn propose_roll<S>(
+    strategy: &S,
+    series: &OptionSeries,
+    rule: RollRule,
+) -> Result<RollProposal<S>, StrategyError>
+where
+    S: Strategies + Greeks + Clone,
+{
+    let current = at_spot(strategy, series.underlying_price)?;
+    let legs: Vec<Position> = current.get_positions()?.into_iter().cloned().collect();
+    if legs.is_empty() {
+        return Err(StrategyError::empty_collection("roll: strategy positions"));
+    }
+    let expirations = sorted_expirations(series)?;
+
+    match rule {
+        RollRule::SameStrikes | RollRule::SameDelta => {
+            let plan = roll_out_plan(&legs, &expirations, 1, rule, series.underlying_price)?;
+            execute(&current, series, rule, &plan)
+        }
+        RollRule::UntestedSide => {
+            let plan = untested_side_plan(&legs, &expirations, series.underlying_price)?;
+            execute(&current, series, rule, &plan)
+        }
+        RollRule::ForCredit { min_credit } => {
+            for step in 1..=expirations.len() {
+                for strike_rule in [RollRule::SameStrikes, RollRule::SameDelta] {
+                    let Ok(plan) = roll_out_plan(
+                        &legs,
+                        &expirations,
+                        step,
+                        strike_rule,
+                        series.underlying_price,
+                    ) else {
+                        continue;
+                    };
+                    let proposal = match execute(&current, series, rule, &plan) {
+                        Ok(proposal) => proposal,
+                        Err(e) => {
+                            debug!("Skipping roll {} expirations out: {}", step, e);
+                            continue;
+                        }
+                    };
+                    if proposal.net_credit() >= min_credit.to_dec() {
+                        return Ok(proposal);
+                    }
+                }
+            }
+            Err(StrategyError::invalid_parameters(
+                "roll",
+                &format!("no roll in the series collects a net credit of {min_credit}"),
+            ))
+        }
+    }
+}
+
+/// Clones `strategy` with every leg marked at `spot`, keeping entry premiums.
+fn at_spot<S: Strategies + Clone>(strategy: &S, spot: Positive) -> Result<S, StrategyError> {
+    let mut marked = strategy.clone();
+    let legs: Vec<Position> = strategy.get_positions()?.into_iter().cloned().collect();
+    for leg in &legs {
+        for position in marked.get_position(
+            &leg.option.option_style,
+            &leg.option.side,
+            &leg.option.strike_price,
+        )? {
+            position.option.underlying_price = spot;
+        }
+    }
+    Ok(marked)
+}
+
+fn sorted_expirations(
+    series: &OptionSeries,
+) -> Result<Vec<(Positive, &OptionChain)>, StrategyError> {
+    let mut expirations = series
+        .chains
+        .iter()
+        .map(|(expiration, chain)| {
+            expiration
+                .get_days()
+                .map(|days| (days, chain))
+                .map_err(|e| StrategyError::invalid_parameters("roll", &e.to_string()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    expirations.sort_by_key(|(days, _)| *days);
+    Ok(expirations)
+}
+
+fn leg_days(leg: &Position) -> Result<Positive, StrategyError> {
+    leg.option
+        .expiration_date
+        .get_days()
+        .map_err(|e| StrategyError::invalid_parameters("roll", &e.to_string()))
+}
+
+fn same_expiry(a: Positive, b: Positive) -> bool {
+    (a.to_dec() - b.to_dec()).abs() < SAME_EXPIRY_TOLERANCE
+}
+
+/// Chain of the `step`-th expiration after `days` (`0` is the expiration itself).
+fn chain_after<'a>(
+    expirations: &[(Positive, &'a OptionChain)],
+    days: Positive,
+    step: usize,
+) -> Result<&'a OptionChain, StrategyError> {
+    let found = if step == 0 {
+        expirations.iter().find(|(d, _)| same_expiry(*d, days))
+    } else {
+        expirations
+            .iter()
+            .filter(|(d, _)| *d > days && !same_expiry(*d, days))
+            .nth(step - 1)
+    };
+    found.map(|(_, chain)| *chain).ok_or_else(|| {
+        StrategyError::invalid_parameters(
+            "roll",
+            &format!("no expiration {step} step(s) after {days} days in the series"),
+        )
+    })
+}
+
+fn has_quotes(data: &OptionData, style: OptionStyle) -> bool {
+    match style {
+        OptionStyle::Call => data.valid_call(),
+        OptionStyle::Put => data.valid_put(),
+    }
+}
+
+fn strike_data(
+    chain: &OptionChain,
+    strike: Positive,
+    style: OptionStyle,
+) -> Result<&OptionData, StrategyError> {
+    chain
+        .options
+        .iter()
+        .find(|data| data.strike_price == strike && has_quotes(data, style))
+        .ok_or_else(|| {
+            StrategyError::invalid_parameters(
+                "roll",
+                &format!("no quoted {style:?} at strike {strike} in the chain"),
+            )
+        })
+}
+
+/// Quoted strike whose delta for `style` is closest to `target`.
+fn delta_data(
+    chain: &OptionChain,
+    target: Decimal,
+    style: OptionStyle,
+) -> Result<&OptionData, StrategyError> {
+    chain
+        .options
+        .iter()
+        .filter(|data| has_quotes(data, style))
+        .filter_map(|data| {
+            let delta = match style {
+                OptionStyle::Call => data.delta_call,
+                OptionStyle::Put => data.delta_put,
+            }?;
+            Some(((delta - target).abs(), data))
+        })
+        .min_by_key(|(gap, _)| *gap)
+        .map(|(_, data)| data)
+        .ok_or_else(|| {
+            StrategyError::invalid_parameters("roll", "no quoted strike with a delta in the chain")
+        })
+}
+
+/// Delta of one long contract of `leg`.
+fn unit_delta(leg: &Position, spot: Positive) -> Result<Decimal, StrategyError> {
+    let mut option = leg.option.clone();
+    option.side = Side::Long;
+    option.quantity = Positive::ONE;
+    option.underlying_price = spot;
+    Ok(delta(&option)?)
+}
+
+/// Opens `leg` again on `data`, keeping its side, quantity and fees.
+fn reopen(leg: &Position, data: &OptionData) -> Result<Position, StrategyError> {
+    let mut position = data
+        .get_position(
+            leg.option.side,
+            leg.option.option_style,
+            None,
+            Some(leg.open_fee),
+            Some(leg.close_fee),
+        )
+        .map_err(|e| StrategyError::invalid_parameters("roll", &e.to_string()))?;
+    position.option.quantity = leg.option.quantity;
+    Ok(position)
+}
+
+fn roll_out_plan(
+    legs: &[Position],
+    expirations: &[(Positive, &OptionChain)],
+    step: usize,
+    strike_rule: RollRule,
+    spot: Positive,
+) -> Result<RollPlan, StrategyError> {
+    legs.iter()
+        .map(|leg| {
+            let chain = chain_after(expirations, leg_days(leg)?, step)?;
+            let style = leg.option.option_style;
+            let data = match strike_rule {
+                RollRule::SameDelta => delta_data(chain, unit_delta(leg, spot)?, style)?,
+                _ => strike_data(chain, leg.option.strike_price, style)?,
+            };
+            Ok((leg.clone(), reopen(leg, data)?))
+        })
+        .collect()
+}
+
+fn untested_side_plan(
+    legs: &[Position],
+    expirations: &[(Positive, &OptionChain)],
+    spot: Positive,
+) -> Result<RollPlan, StrategyError> {
+    let distance = |leg: &&Position| (leg.option.strike_price.to_dec() - spot.to_dec()).abs();
+    let nearest_short = |style: OptionStyle| {
+        legs.iter()
+            .filter(|leg| leg.option.side == Side::Short && leg.option.option_style == style)
+            .min_by_key(distance)
+    };
+    let (Some(short_call), Some(short_put)) = (
+        nearest_short(OptionStyle::Call),
+        nearest_short(OptionStyle::Put),
+    ) else {
+        return Err(StrategyError::invalid_parameters(
+            "roll",
+            "rolling the untested side requires both a short call and a short put",
+        ));
+    };
+    let (tested, untested) = if distance(&short_call) <= distance(&short_put) {
+        (short_call, short_put)
+    } else {
+        (short_put, short_call)
+    };
+
+    let style = untested.option.option_style;
+    let days = leg_days(untested)?;
+    let chain = chain_after(expirations, days, 0)?;
+    let tested_delta = unit_delta(tested, spot)?.abs();
+    let target = match style {
+        OptionStyle::Call => tested_delta,
+        OptionStyle::Put => -tested_delta,
+    };
+    let offset = delta_data(chain, target, style)?.strike_price.to_dec()
+        - untested.option.strike_price.to_dec();
+    if offset.is_zero() {
+        return Err(StrategyError::invalid_parameters(
+            "roll",
+            "the untested side already matches the delta of the tested side",
+        ));
+    }
+
+    let mut plan = RollPlan::new();
+    for leg in legs {
+        if leg.option.option_style != style || !same_expiry(leg_days(leg)?, days) {
+            continue;
+        }
+        let strike = Positive::new_decimal(leg.option.strike_price.to_dec() + offset)?;
+        plan.push((
+            leg.clone(),
+            reopen(leg, strike_data(chain, strike, style)?)?,
+        ));
+    }
+    Ok(plan)
+}
+
+/// Price at which `leg` can be closed: the chain quote at its own expiration
+/// when the series has it, its Black-Scholes value otherwise.
+fn closing_premium(
+    leg: &Position,
+    expirations: &[(Positive, &OptionChain)],
+    spot: Positive,
+) -> Result<Positive, StrategyError> {
+    let style = leg.option.option_style;
+    let quote = chain_after(expirations, leg_days(leg)?, 0)
+        .and_then(|chain| strike_data(chain, leg.option.strike_price, style))
+        .ok()
+        .and_then(|data| match (leg.option.side, style) {
+            (Side::Long, OptionStyle::Call) => data.get_call_sell_price(),
+            (Side::Short, OptionStyle::Call) => data.get_call_buy_price(),
+            (Side::Long, OptionStyle::Put) => data.get_put_sell_price(),
+            (Side::Short, OptionStyle::Put) => data.get_put_buy_price(),
+        });
+    if let Some(premium) = quote {
+        return Ok(premium);
+    }
+    let mut option = leg.option.clone();
+    option.quantity = Positive::ONE;
+    option.underlying_price = spot;
+    Ok(Positive::new_decimal(
+        option.calculate_price_black_scholes()?.abs(),
+    )?)
+}
+
+fn execute<S>(
+    current: &S,
+    series: &OptionSeries,
+    rule: RollRule,
+    plan: &RollPlan,
+) -> Result<RollProposal<S>, StrategyError>
+where
+    S: Strategies + Greeks + Clone,
+{
+    let expirations = sorted_expirations(series)?;
+    let mut rolled = current.clone();
+    let mut close_trades = Vec::with_capacity(plan.len());
+    let mut open_trades = Vec::with_capacity(plan.len());
+    for (old, new) in plan {
+        let mut close = old.close()?;
+        close.premium = closing_premium(old, &expirations, series.underlying_price)?;
+        close.fee = old.close_fee;
+        close.underlying_price = series.underlying_price;
+        close_trades.push(close);
+
+        let mut open = new.open()?;
+        open.fee = new.open_fee;
+        open_trades.push(open);
+
+        let slot = rolled
+            .get_position(
+                &old.option.option_style,
+                &old.option.side,
+                &old.option.strike_price,
+            )?
+            .into_iter()
+            .find(|position| **position == *old)
+            .ok_or_else(|| {
+                StrategyError::invalid_parameters("roll", "rolled leg not found in the strategy")
+            })?;
+        *slot = new.clone();
+    }
+    if !rolled.validate() {
+        return Err(StrategyError::invalid_parameters(
+            "roll",
+            "the rolled legs do not form a valid strategy",
+        ));
+    }
+    rolled.update_break_even_points()?;
+
+    Ok(RollProposal {
+        rule,
+        before: RollProfile::of(current)?,
+        after: RollProfile::of(&rolled)?,
+        strategy: rolled,
+        close_trades,
+        open_trades,
+    })
+}
+
+#[cfg(test)]
+mod tests_roll {
+    use super::*;
+    use crate::ExpirationDate;
+    use crate::chains::{OptionChainBuildParams, utils::OptionDataPriceParams};
+    use crate::series::OptionSeriesBuildParams;
+    use crate::strategies::base::Positionable;
+    use crate::strategies::{BasicAble, BullPutSpread, ShortStrangle};
+    use positive::{pos_or_panic, spos};
+    use rust_decimal_macros::dec;
+
+    fn series() -> OptionSeries {
+        let price_params = OptionDataPriceParams::new(
+            Some(Box::new(Positive::HUNDRED)),
+            Some(ExpirationDate::Days(pos_or_panic!(30.0))),
+            Some(dec!(0.05)),
+            spos!(0.0),
+            Some("SPY".to_string()),
+        );
+        let chain_params = OptionChainBuildParams::new(
+            "SPY".to_string(),
+            None,
+            8,
+            spos!(5.0),
+            dec!(-0.2),
+            dec!(0.1),
+            pos_or_panic!(0.02),
+            2,
+            price_params,
+            pos_or_panic!(0.2),
+        );
+        let expirations = vec![
+            pos_or_panic!(30.0),
+            pos_or_panic!(45.0),
+            pos_or_panic!(60.0),
+        ];
+        OptionSeries::build_series(&OptionSeriesBuildParams::new(chain_params, expirations))
+            .unwrap()
+    }
+
+    fn strangle(call_strike: Positive, put_strike: Positive) -> ShortStrangle {
+        ShortStrangle::new(
+            "SPY".to_string(),
+            Positive::HUNDRED,
+            call_strike,
+            put_strike,
+            ExpirationDate::Days(pos_or_panic!(30.0)),
+            pos_or_panic!(0.2),
+            pos_or_panic!(0.2),
+            dec!(0.05),
+            Positive::ZERO,
+            Positive::ONE,
+            pos_or_panic!(2.0),
+            pos_or_panic!(1.5),
+            pos_or_panic!(0.01),
+            pos_or_panic!(0.01),
+            pos_or_panic!(0.01),
+            pos_or_panic!(0.01),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_same_strikes_rolls_out_for_credit() {
+        let proposal = strangle(pos_or_panic!(110.0), pos_or_panic!(90.0))
+            .roll(&series(), RollRule::SameStrikes)
+            .unwrap();
+
+        assert_eq!(proposal.close_trades.len(), 2);
+        assert_eq!(proposal.open_trades.len(), 2);
+        assert!(proposal.net_credit() > Decimal::ZERO);
+        assert_eq!(proposal.fees(), pos_or_panic!(0.04));
+        for position in proposal.strategy.get_positions().unwrap() {
+            let days = position.option.expiration_date.get_days().unwrap();
+            assert!((days.to_dec() - dec!(45)).abs() < SAME_EXPIRY_TOLERANCE);
+        }
+        let mut strikes = proposal.strategy.get_strikes();
+        strikes.sort();
+        assert_eq!(strikes, vec![&pos_or_panic!(90.0), &pos_or_panic!(110.0)]);
+        assert_ne!(
+            proposal.before.break_even_points,
+            proposal.after.break_even_points
+        );
+    }
+
+    #[test]
+    fn test_same_delta_keeps_delta_close() {
+        let proposal = strangle(pos_or_panic!(110.0), pos_or_panic!(90.0))
+            .roll(&series(), RollRule::SameDelta)
+            .unwrap();
+
+        assert!(proposal.change().delta.abs() < dec!(0.1));
+        let strikes = proposal.strategy.get_strikes();
+        assert!(strikes.iter().any(|strike| **strike > Positive::HUNDRED));
+        assert!(strikes.iter().any(|strike| **strike < Positive::HUNDRED));
+    }
+
+    #[test]
+    fn test_untested_side_rolls_up_puts() {
+        let proposal = strangle(pos_or_panic!(105.0), pos_or_panic!(80.0))
+            .roll(&series(), RollRule::UntestedSide)
+            .unwrap();
+
+        assert_eq!(proposal.close_trades.len(), 1);
+        let closed = proposal.close_trades.first().unwrap();
+        assert_eq!(closed.option_style, OptionStyle::Put);
+        let opened = proposal.open_trades.first().unwrap();
+        assert!(opened.strike > pos_or_panic!(80.0));
+        assert!(proposal.net_credit() > Decimal::ZERO);
+        assert!(proposal.change().delta > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_invalid_rolled_strategy_is_rejected() {
+        // The put would roll above the deep in-the-money call, inverting the strangle.
+        let result = strangle(pos_or_panic!(90.0), pos_or_panic!(80.0))
+            .roll(&series(), RollRule::UntestedSide);
+        let error = result.err().unwrap().to_string();
+        assert!(error.contains("valid strategy"), "{error}");
+    }
+
+    #[test]
+    fn test_for_credit_requires_enough_credit() {
+        let strategy = strangle(pos_or_panic!(110.0), pos_or_panic!(90.0));
+        let proposal = strategy
+            .roll(
+                &series(),
+                RollRule::ForCredit {
+                    min_credit: Positive::ZERO,
+                },
+            )
+            .unwrap();
+        assert!(matches!(proposal.rule, RollRule::ForCredit { .. }));
+        assert!(proposal.net_credit() >= Decimal::ZERO);
+
+        let result = strategy.roll(
+            &series(),
+            RollRule::ForCredit {
+                min_credit: pos_or_panic!(1000.0),
+            },
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_propose_rolls_skips_rules_that_do_not_apply() {
+        let spread = BullPutSpread::new(
+            "SPY".to_string(),
+            Positive::HUNDRED,
+            pos_or_panic!(90.0),
+            Positive::HUNDRED,
+            ExpirationDate::Days(pos_or_panic!(30.0)),
+            pos_or_panic!(0.2),
+            dec!(0.05),
+            Positive::ZERO,
+            Positive::ONE,
+            pos_or_panic!(0.2),
+            pos_or_panic!(2.1),
+            Positive::ZERO,
+            Positive::ZERO,
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+        .unwrap();
+
+        let proposals = spread
+            .propose_rolls(&series(), &[RollRule::UntestedSide, RollRule::SameStrikes])
+            .unwrap();
+        assert_eq!(proposals.len(), 1);
+        assert!(
+            proposals
+                .iter()
+                .all(|proposal| proposal.rule == RollRule::SameStrikes)
+        );
+        assert!(spread.propose_rolls(&series(), &[]).is_err());
+    }
+}
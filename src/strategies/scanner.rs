@@ -0,0 +1,802 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! Cross-expiry strategy scanner.
+//!
+//! A [`StrategyScanner`] runs a set of [`ScanTemplate`]s over every expiration of
+//! one or more [`OptionSeries`](crate::series::OptionSeries). Each template is re-anchored on the expiry and
+//! searched with [`Optimizable::find_pareto_optimal`](crate::strategies::base::Optimizable::find_pareto_optimal); the candidates of every
+//! expiry and underlying are then ranked on a single [`OptimizationObjective`],
+//! near-identical structures are collapsed and the result is returned as a
+//! [`ScanReport`] that renders as a table, CSV or JSON.
+
+use crate::ExpirationDate;
+use crate::chains::chain::OptionChain;
+use crate::error::chains::ChainError;
+use crate::error::strategies::StrategyError;
+use crate::greeks::Greeks;
+use crate::series::OptionSeries;
+use crate::strategies::base::Optimizable;
+use crate::strategies::multi_objective::{
+    CandidateMetrics, MultiObjectiveConfig, OptimizationObjective,
+};
+use crate::strategies::probabilities::ProbabilityAnalysis;
+use crate::strategies::utils::FindOptimalSide;
+use csv::WriterBuilder;
+use positive::Positive;
+use prettytable::{Cell, Row, Table, format};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use tracing::{debug, warn};
+use utoipa::ToSchema;
+
+/// A candidate found by a template on one chain.
+struct TemplateCandidate {
+    strategy: String,
+    strikes: Vec<Positive>,
+    metrics: CandidateMetrics,
+    pareto_rank: usize,
+}
+
+type TemplateSearch = dyn Fn(
+        &OptionChain,
+        ExpirationDate,
+        &MultiObjectiveConfig,
+    ) -> Result<Vec<TemplateCandidate>, StrategyError>
+    + Send
+    + Sync;
+
+/// A strategy shape searched by the scanner on every expiry.
+///
+/// The template strategy supplies quantity, fees and any other parameter that is
+/// not taken from the chain; its expiration and underlying price are replaced by
+/// those of each scanned chain.
+pub struct ScanTemplate {
+    name: String,
+    search: Box<TemplateSearch>,
+}
+
+impl ScanTemplate {
+    /// Creates a template named `name` that searches `strategy`'s chain
+    /// combinations restricted by `side`.
+    pub fn new<S>(name: impl Into<String>, strategy: S, side: FindOptimalSide) -> Self
+    where
+        S: Optimizable<Strategy = S> + ProbabilityAnalysis + Greeks + Clone + Send + Sync + 'static,
+    {
+        let search = move |chain: &OptionChain,
+                           expiration: ExpirationDate,
+                           config: &MultiObjectiveConfig| {
+            let mut template = strategy.clone();
+            template.set_expiration_date(expiration)?;
+            template.set_underlying_price(&chain.underlying_price)?;
+            Ok(template
+                .find_pareto_optimal(chain, side, config)?
+                .into_iter()
+                .map(|candidate| {
+                    let mut strikes: Vec<Positive> = candidate
+                        .strategy
+                        .get_strikes()
+                        .into_iter()
+                        .copied()
+                        .collect();
+                    strikes.sort();
+                    TemplateCandidate {
+                        strategy: candidate
+                            .strategy
+                            .get_title()
+                            .split_whitespace()
+                            .collect::<Vec<_>>()
+                            .join(" "),
+                        strikes,
+                        metrics: candidate.metrics,
+                        pareto_rank: candidate.rank,
+                    }
+                })
+                .collect())
+        };
+        Self {
+            name: name.into(),
+            search: Box::new(search),
+        }
+    }
+
+    /// Name of the template, reported in every row it produces.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Debug for ScanTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScanTemplate")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Configuration of a [`StrategyScanner`].
+#[derive(Debug, Clone)]
+pub struct ScanConfig {
+    /// Shortest days to expiration scanned.
+    pub min_days: Option<Positive>,
+    /// Longest days to expiration scanned.
+    pub max_days: Option<Positive>,
+    /// Constraints, objectives and probability model of the per-expiry search.
+    pub optimizer: MultiObjectiveConfig,
+    /// Metric the report is sorted by, best first.
+    pub rank_by: OptimizationObjective,
+    /// Largest strike difference for two rows of the same underlying and
+    /// template to count as the same structure.
+    pub strike_tolerance: Positive,
+    /// Largest expiration difference, in days, for two rows to count as the
+    /// same structure.
+    pub days_tolerance: Positive,
+    /// Maximum number of rows in the report.
+    pub max_results: Option<usize>,
+}
+
+impl Default for ScanConfig {
+    fn default() -> Self {
+        Self {
+            min_days: None,
+            max_days: None,
+            optimizer: MultiObjectiveConfig::default(),
+            rank_by: OptimizationObjective::ExpectedValue,
+            strike_tolerance: Positive::ZERO,
+            days_tolerance: Positive::ZERO,
+            max_results: None,
+        }
+    }
+}
+
+/// A ranked candidate of the scan.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ScanRow {
+    /// Underlying symbol.
+    pub symbol: String,
+    /// Name of the template that produced the candidate.
+    pub template: String,
+    /// Title of the candidate strategy.
+    pub strategy: String,
+    /// Days to expiration of the candidate.
+    pub days_to_expiration: Positive,
+    /// Strikes of the candidate, ascending.
+    pub strikes: Vec<Positive>,
+    /// Risk and reward figures of the candidate.
+    pub metrics: CandidateMetrics,
+    /// Pareto front of the candidate within its own expiry.
+    pub pareto_rank: usize,
+    /// Value of the ranking metric.
+    pub score: Decimal,
+}
+
+impl ScanRow {
+    fn is_near(&self, other: &ScanRow, config: &ScanConfig) -> bool {
+        self.symbol == other.symbol
+            && self.template == other.template
+            && (self.days_to_expiration.to_dec() - other.days_to_expiration.to_dec()).abs()
+                <= config.days_tolerance.to_dec()
+            && self.strikes.len() == other.strikes.len()
+            && self
+                .strikes
+                .iter()
+                .zip(&other.strikes)
+                .all(|(a, b)| (a.to_dec() - b.to_dec()).abs() <= config.strike_tolerance.to_dec())
+    }
+}
+
+/// A template that failed on every scanned expiry of an underlying.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ScanFailure {
+    /// Underlying symbol.
+    pub symbol: String,
+    /// Name of the failing template.
+    pub template: String,
+    /// Error returned on the last scanned expiry.
+    pub reason: String,
+}
+
+/// Result of a scan, best candidate first.
+///
+/// `Display` renders the report as a table, followed by any failed template.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ScanReport {
+    /// Metric the rows are sorted by.
+    pub ranked_by: OptimizationObjective,
+    /// Ranked, deduplicated candidates.
+    pub rows: Vec<ScanRow>,
+    /// Templates that produced no candidate because they failed on every
+    /// scanned expiry of an underlying.
+    #[serde(default)]
+    pub failures: Vec<ScanFailure>,
+}
+
+impl ScanReport {
+    const HEADERS: [&'static str; 14] = [
+        "Symbol",
+        "Template",
+        "Strategy",
+        "DTE",
+        "Strikes",
+        "Score",
+        "Pareto Rank",
+        "Expected Value",
+        "P&L Std Dev",
+        "Probability of Profit",
+        "Net Delta",
+        "Margin",
+        "Capital",
+        "Net Credit",
+    ];
+
+    fn record(row: &ScanRow) -> [String; 14] {
+        let strikes: Vec<String> = row.strikes.iter().map(ToString::to_string).collect();
+        [
+            row.symbol.clone(),
+            row.template.clone(),
+            row.strategy.clone(),
+            row.days_to_expiration.to_string(),
+            strikes.join("/"),
+            row.score.round_dp(4).to_string(),
+            row.pareto_rank.to_string(),
+            row.metrics.expected_value.round_dp(4).to_string(),
+            row.metrics.pnl_std_dev.round_dp(4).to_string(),
+            row.metrics.probability_of_profit.round_to(4).to_string(),
+            row.metrics.net_delta.round_dp(4).to_string(),
+            row.metrics.margin.round_to(4).to_string(),
+            row.metrics.capital.round_to(4).to_string(),
+            row.metrics.net_credit.round_dp(4).to_string(),
+        ]
+    }
+
+    /// Renders the report as CSV, one row per candidate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChainError`] if a record cannot be written.
+    pub fn to_csv(&self) -> Result<String, ChainError> {
+        let mut wtr = WriterBuilder::new().from_writer(Vec::new());
+        wtr.write_record(Self::HEADERS)?;
+        for row in &self.rows {
+            wtr.write_record(Self::record(row))?;
+        }
+        let bytes = wtr
+            .into_inner()
+            .map_err(|e| ChainError::invalid_parameters("to_csv", &e.to_string()))?;
+        String::from_utf8(bytes)
+            .map_err(|e| ChainError::invalid_parameters("to_csv", &e.to_string()))
+    }
+
+    /// Renders the report as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChainError`] if serialization fails.
+    pub fn to_json(&self) -> Result<String, ChainError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+impl fmt::Display for ScanReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut table = Table::new();
+        table.set_format(*format::consts::FORMAT_BOX_CHARS);
+        table.set_titles(Row::new(
+            Self::HEADERS
+                .iter()
+                .map(|header| Cell::new(header))
+                .collect(),
+        ));
+        for row in &self.rows {
+            table.add_row(Row::new(
+                Self::record(row)
+                    .iter()
+                    .map(|value| Cell::new(value))
+                    .collect(),
+            ));
+        }
+        write!(f, "{table}")?;
+        for failure in &self.failures {
+            write!(
+                f,
+                "\n{} failed on every expiry of {}: {}",
+                failure.template, failure.symbol, failure.reason
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs strategy templates across the expirations of option series.
+#[derive(Debug, Default)]
+pub struct StrategyScanner {
+    templates: Vec<ScanTemplate>,
+    config: ScanConfig,
+}
+
+impl StrategyScanner {
+    /// Creates a scanner without templates.
+    #[must_use]
+    pub fn new(config: ScanConfig) -> Self {
+        Self {
+            templates: Vec::new(),
+            config,
+        }
+    }
+
+    /// Adds a template to the scan.
+    #[must_use]
+    pub fn with_template(mut self, template: ScanTemplate) -> Self {
+        self.templates.push(template);
+        self
+    }
+
+    /// Scans every expiration of `series`.
+    ///
+    /// # Errors
+    ///
+    /// See [`StrategyScanner::scan_all`].
+    pub fn scan(&self, series: &OptionSeries) -> Result<ScanReport, StrategyError> {
+        self.scan_all(std::slice::from_ref(series))
+    }
+
+    /// Scans every expiration of every series, ranks all candidates on
+    /// `config.rank_by` and drops near-identical structures, keeping the best
+    /// ranked one.
+    ///
+    /// A template that fails on an expiry, for instance because it cannot be
+    /// re-anchored on it, is skipped for that expiry. A template that fails
+    /// on every scanned expiry of an underlying is reported in
+    /// [`ScanReport::failures`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StrategyError::OperationError`] if the scanner has no template
+    /// or the optimizer configuration has no objective, and propagates failures
+    /// to compute the days to expiration of a chain.
+    pub fn scan_all(&self, series: &[OptionSeries]) -> Result<ScanReport, StrategyError> {
+        if self.templates.is_empty() {
+            return Err(StrategyError::invalid_parameters(
+                "scan",
+                "at least one template is required",
+            ));
+        }
+        if self.config.optimizer.objectives.is_empty() {
+            return Err(StrategyError::invalid_parameters(
+                "scan",
+                "at least one objective is required",
+            ));
+        }
+
+        let mut rows = Vec::new();
+        let mut failures = Vec::new();
+        for option_series in series {
+            let mut chains = Vec::new();
+            for (expiration, chain) in &option_series.chains {
+                let days = expiration
+                    .get_days()
+                    .map_err(|e| StrategyError::invalid_parameters("scan", &e.to_string()))?;
+                if self.config.min_days.is_some_and(|min| days < min)
+                    || self.config.max_days.is_some_and(|max| days > max)
+                {
+                    continue;
+                }
+                chains.push((*expiration, chain, days));
+            }
+            for template in &self.templates {
+                let mut searched = false;
+                let mut last_error = None;
+                for (expiration, chain, days) in &chains {
+                    let candidates =
+                        match (template.search)(chain, *expiration, &self.config.optimizer) {
+                            Ok(candidates) => candidates,
+                            Err(e) => {
+                                debug!("Skipping {} at {} DTE: {}", template.name, days, e);
+                                last_error = Some(e.to_string());
+                                continue;
+                            }
+                        };
+                    searched = true;
+                    rows.extend(candidates.into_iter().map(|candidate| ScanRow {
+                        symbol: option_series.symbol.clone(),
+                        template: template.name.clone(),
+                        strategy: candidate.strategy,
+                        days_to_expiration: *days,
+                        strikes: candidate.strikes,
+                        score: candidate.metrics.score(self.config.rank_by),
+                        metrics: candidate.metrics,
+                        pareto_rank: candidate.pareto_rank,
+                    }));
+                }
+                if !searched && let Some(reason) = last_error {
+                    warn!(
+                        "{} failed on every expiry of {}: {}",
+                        template.name, option_series.symbol, reason
+                    );
+                    failures.push(ScanFailure {
+                        symbol: option_series.symbol.clone(),
+                        template: template.name.clone(),
+                        reason,
+                    });
+                }
+            }
+        }
+
+        rows.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then_with(|| a.pareto_rank.cmp(&b.pareto_rank))
+        });
+        let mut kept: Vec<ScanRow> = Vec::with_capacity(rows.len());
+        for row in rows {
+            if !kept.iter().any(|other| row.is_near(other, &self.config)) {
+                kept.push(row);
+            }
+        }
+        if let Some(limit) = self.config.max_results {
+            kept.truncate(limit);
+        }
+        Ok(ScanReport {
+            ranked_by: self.config.rank_by,
+            rows: kept,
+            failures,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests_scanner {
+    use super::*;
+    use crate::chains::{OptionChainBuildParams, utils::OptionDataPriceParams};
+    use crate::model::types::{OptionStyle, Side};
+    use crate::series::OptionSeriesBuildParams;
+    use crate::strategies::{
+        BoxSpread, DiagonalSpread, Fence, LongCalendarSpread, RatioSpread, RiskReversal, Seagull,
+        ShortCalendarSpread, ShortStrangle,
+    };
+    use positive::{pos_or_panic, spos};
+    use rust_decimal_macros::dec;
+
+    fn series(symbol: &str) -> OptionSeries {
+        let price_params = OptionDataPriceParams::new(
+            Some(Box::new(Positive::HUNDRED)),
+            Some(ExpirationDate::Days(pos_or_panic!(30.0))),
+            Some(dec!(0.05)),
+            spos!(0.0),
+            Some(symbol.to_string()),
+        );
+        let chain_params = OptionChainBuildParams::new(
+            symbol.to_string(),
+            None,
+            6,
+            spos!(5.0),
+            dec!(-0.2),
+            dec!(0.1),
+            pos_or_panic!(0.02),
+            2,
+            price_params,
+            pos_or_panic!(0.2),
+        );
+        let expirations = vec![
+            pos_or_panic!(15.0),
+            pos_or_panic!(30.0),
+            pos_or_panic!(45.0),
+            pos_or_panic!(60.0),
+            pos_or_panic!(90.0),
+        ];
+        OptionSeries::build_series(&OptionSeriesBuildParams::new(chain_params, expirations))
+            .unwrap()
+    }
+
+    fn strangle() -> ShortStrangle {
+        ShortStrangle::new(
+            "SPY".to_string(),
+            Positive::HUNDRED,
+            pos_or_panic!(110.0),
+            pos_or_panic!(90.0),
+            ExpirationDate::Days(pos_or_panic!(30.0)),
+            pos_or_panic!(0.2),
+            pos_or_panic!(0.2),
+            dec!(0.05),
+            Positive::ZERO,
+            Positive::ONE,
+            pos_or_panic!(1.0),
+            pos_or_panic!(1.0),
+            Positive::ZERO,
+            Positive::ZERO,
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+        .unwrap()
+    }
+
+    fn strangle_template() -> ScanTemplate {
+        ScanTemplate::new("Short Strangle", strangle(), FindOptimalSide::Center)
+    }
+
+    fn scanner(config: ScanConfig) -> StrategyScanner {
+        StrategyScanner::new(config).with_template(strangle_template())
+    }
+
+    #[test]
+    fn test_scan_respects_dte_window_and_ranking() {
+        let report = scanner(ScanConfig {
+            min_days: Some(pos_or_panic!(30.0)),
+            max_days: Some(pos_or_panic!(60.0)),
+            ..Default::default()
+        })
+        .scan(&series("SPY"))
+        .unwrap();
+
+        assert!(!report.rows.is_empty());
+        assert!(report.rows.iter().all(|row| {
+            row.days_to_expiration >= pos_or_panic!(30.0)
+                && row.days_to_expiration <= pos_or_panic!(60.0)
+                && row.template == "Short Strangle"
+                && row.strikes.len() == 2
+        }));
+        assert!(report.rows.windows(2).all(|pair| match pair {
+            [a, b] => a.score >= b.score,
+            _ => true,
+        }));
+        let days: Vec<Positive> = report.rows.iter().map(|r| r.days_to_expiration).collect();
+        assert!(days.contains(&pos_or_panic!(45.0)));
+    }
+
+    #[test]
+    fn test_scan_deduplicates_near_identical_structures() {
+        let exact = scanner(ScanConfig::default()).scan(&series("SPY")).unwrap();
+        let loose = scanner(ScanConfig {
+            strike_tolerance: pos_or_panic!(1000.0),
+            days_tolerance: pos_or_panic!(1000.0),
+            ..Default::default()
+        })
+        .scan(&series("SPY"))
+        .unwrap();
+
+        assert!(exact.rows.len() > 1);
+        assert_eq!(loose.rows.len(), 1);
+        assert_eq!(loose.rows.first(), exact.rows.first());
+    }
+
+    #[test]
+    fn test_scan_all_covers_several_underlyings() {
+        let report = scanner(ScanConfig {
+            max_results: Some(50),
+            strike_tolerance: pos_or_panic!(1000.0),
+            ..Default::default()
+        })
+        .scan_all(&[series("SPY"), series("QQQ")])
+        .unwrap();
+        assert!(report.rows.iter().any(|row| row.symbol == "SPY"));
+        assert!(report.rows.iter().any(|row| row.symbol == "QQQ"));
+        assert!(report.rows.len() <= 50);
+    }
+
+    #[test]
+    fn test_scan_requires_template() {
+        assert!(
+            StrategyScanner::new(ScanConfig::default())
+                .scan(&series("SPY"))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_report_exports() {
+        let report = scanner(ScanConfig {
+            max_results: Some(3),
+            ..Default::default()
+        })
+        .scan(&series("SPY"))
+        .unwrap();
+
+        let csv = report.to_csv().unwrap();
+        let mut lines = csv.lines();
+        assert!(
+            lines
+                .next()
+                .unwrap()
+                .starts_with("Symbol,Template,Strategy,DTE")
+        );
+        assert_eq!(lines.count(), report.rows.len());
+
+        let parsed: ScanReport = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(parsed.rows.len(), report.rows.len());
+        assert_eq!(parsed.ranked_by, OptimizationObjective::ExpectedValue);
+
+        assert!(report.to_string().contains("Short Strangle"));
+    }
+
+    #[test]
+    fn test_scan_reports_template_failing_on_every_expiry() {
+        let report = scanner(ScanConfig::default())
+            .with_template(ScanTemplate::new(
+                "Out Of Range",
+                strangle(),
+                FindOptimalSide::Range(pos_or_panic!(1000.0), pos_or_panic!(2000.0)),
+            ))
+            .scan(&series("SPY"))
+            .unwrap();
+
+        assert!(!report.rows.is_empty());
+        assert!(
+            report
+                .rows
+                .iter()
+                .all(|row| row.template == "Short Strangle")
+        );
+        let [failure] = report.failures.as_slice() else {
+            panic!("expected one failure, got {:?}", report.failures);
+        };
+        assert_eq!(failure.symbol, "SPY");
+        assert_eq!(failure.template, "Out Of Range");
+        assert!(failure.reason.contains("no leg combinations"));
+        assert!(
+            report
+                .to_string()
+                .contains("Out Of Range failed on every expiry of SPY")
+        );
+
+        let parsed: ScanReport = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(parsed.failures, report.failures);
+    }
+
+    #[test]
+    fn test_scan_supports_chain_built_templates() {
+        let series = series("SPY");
+        let front = ExpirationDate::Days(pos_or_panic!(30.0));
+        let back = ExpirationDate::Days(pos_or_panic!(60.0));
+        let chain = series.chains.get(&front).unwrap();
+        let (quantity, fee) = (Positive::ONE, Positive::ZERO);
+        // Scoring a time spread values the back leg at the front expiry,
+        // which is slow; a narrow strike range keeps this test fast.
+        let time_spread_strikes = FindOptimalSide::Range(Positive::HUNDRED, pos_or_panic!(110.0));
+
+        let ratio = RatioSpread::new(
+            "SPY".to_string(),
+            Positive::HUNDRED,
+            OptionStyle::Call,
+            Positive::HUNDRED,
+            pos_or_panic!(110.0),
+            front,
+            pos_or_panic!(0.2),
+            dec!(0.05),
+            Positive::ZERO,
+            Positive::ONE,
+            Positive::TWO,
+            pos_or_panic!(5.0),
+            pos_or_panic!(1.5),
+            fee,
+            fee,
+            fee,
+            fee,
+        )
+        .unwrap();
+        let box_spread = BoxSpread::new(
+            "SPY".to_string(),
+            Positive::HUNDRED,
+            Side::Long,
+            pos_or_panic!(90.0),
+            pos_or_panic!(110.0),
+            front,
+            pos_or_panic!(0.2),
+            dec!(0.05),
+            Positive::ZERO,
+            quantity,
+            pos_or_panic!(7.5),
+            pos_or_panic!(2.5),
+            pos_or_panic!(2.0),
+            pos_or_panic!(6.9),
+            fee,
+            fee,
+        )
+        .unwrap();
+        let templates = [
+            ScanTemplate::new("Ratio Spread", ratio, FindOptimalSide::All),
+            ScanTemplate::new(
+                "Long Calendar",
+                LongCalendarSpread::from_series(
+                    &series,
+                    &front,
+                    &back,
+                    Positive::HUNDRED,
+                    OptionStyle::Call,
+                    quantity,
+                    fee,
+                    fee,
+                )
+                .unwrap(),
+                time_spread_strikes,
+            ),
+            ScanTemplate::new(
+                "Short Calendar",
+                ShortCalendarSpread::from_series(
+                    &series,
+                    &front,
+                    &back,
+                    Positive::HUNDRED,
+                    OptionStyle::Call,
+                    quantity,
+                    fee,
+                    fee,
+                )
+                .unwrap(),
+                time_spread_strikes,
+            ),
+            ScanTemplate::new(
+                "Diagonal",
+                DiagonalSpread::from_series(
+                    &series,
+                    &front,
+                    &back,
+                    pos_or_panic!(110.0),
+                    Positive::HUNDRED,
+                    OptionStyle::Call,
+                    quantity,
+                    fee,
+                    fee,
+                )
+                .unwrap(),
+                time_spread_strikes,
+            ),
+            ScanTemplate::new(
+                "Risk Reversal",
+                RiskReversal::zero_cost(
+                    chain,
+                    Side::Long,
+                    pos_or_panic!(110.0),
+                    quantity,
+                    fee,
+                    fee,
+                )
+                .unwrap(),
+                FindOptimalSide::All,
+            ),
+            ScanTemplate::new(
+                "Fence",
+                Fence::zero_cost(chain, Side::Long, pos_or_panic!(90.0), quantity, fee, fee)
+                    .unwrap(),
+                FindOptimalSide::All,
+            ),
+            ScanTemplate::new(
+                "Seagull",
+                Seagull::zero_cost(
+                    chain,
+                    OptionStyle::Call,
+                    Positive::HUNDRED,
+                    pos_or_panic!(110.0),
+                    quantity,
+                    fee,
+                    fee,
+                )
+                .unwrap(),
+                FindOptimalSide::All,
+            ),
+            ScanTemplate::new("Box Spread", box_spread, FindOptimalSide::All),
+        ];
+        let names: Vec<String> = templates.iter().map(|t| t.name().to_string()).collect();
+        let scanner = templates.into_iter().fold(
+            StrategyScanner::new(ScanConfig {
+                min_days: Some(pos_or_panic!(30.0)),
+                max_days: Some(pos_or_panic!(45.0)),
+                ..Default::default()
+            }),
+            |scanner, t| scanner.with_template(t),
+        );
+
+        let report = scanner.scan(&series).unwrap();
+        assert!(report.failures.is_empty(), "{:?}", report.failures);
+        for name in &names {
+            assert!(
+                report.rows.iter().any(|row| &row.template == name),
+                "{name} produced no candidate"
+            );
+        }
+    }
+}
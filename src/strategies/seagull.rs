@@ -0,0 +1,1025 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Seagull Strategy
+//!
+//! A seagull is a vertical spread financed by selling an option on the other
+//! side of the underlying:
+//!
+//! - a bullish seagull sells a put below a call spread, capping a rise in the
+//!   underlying for a consumer that is short of it;
+//! - a bearish seagull sells a call above a put spread, flooring a fall for a
+//!   producer that is long of it.
+//!
+//! Protection stops at the spread's short strike and the sold option exposes
+//! the position beyond its strike. [`Seagull::zero_cost`] fixes the spread
+//! and solves for the financing strike against an `OptionChain`. The hedged
+//! underlying can be added with [`Seagull::with_underlying`].
+
+use super::base::{
+    BreakEvenable, Optimizable, Positionable, Strategable, StrategyBasics, StrategyType, Validable,
+};
+use super::shared::{chain_leg, expiration_break_evens, net_premium, premium_neutral};
+use crate::chains::{StrategyLegs, chain::OptionChain, utils::OptionDataGroup};
+use crate::error::position::{PositionError, PositionValidationErrorKind};
+use crate::error::probability::ProbabilityError;
+use crate::error::strategies::{ProfitLossErrorKind, StrategyError};
+use crate::error::{GreeksError, PricingError};
+use crate::greeks::Greeks;
+use crate::model::ProfitLossRange;
+use crate::model::leg::traits::LegAble;
+use crate::model::leg::{Leg, SpotPosition};
+use crate::model::position::Position;
+use crate::model::types::{OptionBasicType, OptionStyle, OptionType, Side};
+use crate::model::utils::mean_and_std;
+use crate::pnl::{PnLCalculator, utils::PnL};
+use crate::pricing::payoff::Profit;
+use crate::strategies::delta_neutral::DeltaNeutrality;
+use crate::strategies::probabilities::{core::ProbabilityAnalysis, utils::VolatilityAdjustment};
+use crate::strategies::utils::FindOptimalSide;
+use crate::strategies::{BasicAble, Strategies, StrategyConstructor};
+use crate::{ExpirationDate, Options, test_strategy_traits};
+use chrono::Utc;
+use positive::Positive;
+use pretty_simple_display::{DebugPretty, DisplaySimple};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tracing::debug;
+use utoipa::ToSchema;
+
+/// Default description for the Seagull strategy.
+pub const SEAGULL_DESCRIPTION: &str = "A seagull buys a call spread and sells a put below it, or \
+    buys a put spread and sells a call above it. The sold option finances the spread, which \
+    protects against a move up to its short strike, with or without the hedged underlying in \
+    the position.";
+
+/// Represents a bullish or bearish Seagull.
+///
+/// Both directions sell a put below and a call above the bought option: a
+/// bought call makes the call spread of a bullish seagull and a bought put
+/// the put spread of a bearish one. The underlying, when held, is the
+/// exposure being hedged, short for a bullish seagull and long for a bearish
+/// one, with one unit per option.
+#[derive(Clone, DebugPretty, DisplaySimple, Serialize, Deserialize, ToSchema)]
+pub struct Seagull {
+    /// Name identifier for this specific strategy instance
+    pub name: String,
+    /// Type of strategy, always `Seagull`
+    pub kind: StrategyType,
+    /// Detailed description of this strategy instance
+    pub description: String,
+    /// Prices at expiration where the strategy neither makes nor loses money
+    pub break_even_points: Vec<Positive>,
+    /// The hedged position in the underlying, if held
+    pub underlying: Option<SpotPosition>,
+    /// The sold put at the lowest strike
+    pub(super) short_put: Position,
+    /// The bought call or put in the middle
+    pub(super) long_option: Position,
+    /// The sold call at the highest strike
+    pub(super) short_call: Position,
+}
+
+impl Seagull {
+    /// Creates a bullish Seagull when `long_style` is `Call` or a bearish
+    /// one when it is `Put`, without the underlying.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyError` if the strikes are out of order, a leg fails
+    /// validation or the break-even calculation fails.
+    #[allow(clippy::too_many_arguments)]
+    #[inline(never)]
+    pub fn new(
+        underlying_symbol: String,
+        underlying_price: Positive,
+        long_style: OptionStyle,
+        put_strike: Positive,
+        long_strike: Positive,
+        call_strike: Positive,
+        expiration: ExpirationDate,
+        implied_volatility: Positive,
+        risk_free_rate: Decimal,
+        dividend_yield: Positive,
+        quantity: Positive,
+        premium_put: Positive,
+        premium_long: Positive,
+        premium_call: Positive,
+        open_fee: Positive,
+        close_fee: Positive,
+    ) -> Result<Self, StrategyError> {
+        let leg = |option_style, side, strike, premium| {
+            Position::new(
+                Options::new(
+                    OptionType::European,
+                    side,
+                    underlying_symbol.clone(),
+                    strike,
+                    expiration,
+                    implied_volatility,
+                    quantity,
+                    underlying_price,
+                    risk_free_rate,
+                    option_style,
+                    dividend_yield,
+                    None,
+                ),
+                premium,
+                Utc::now(),
+                open_fee,
+                close_fee,
+                None,
+                None,
+            )
+        };
+        Self::from_legs(
+            None,
+            leg(OptionStyle::Put, Side::Short, put_strike, premium_put),
+            leg(long_style, Side::Long, long_strike, premium_long),
+            leg(OptionStyle::Call, Side::Short, call_strike, premium_call),
+        )
+    }
+
+    /// Builds a zero-cost Seagull from the quotes of `chain`, without the
+    /// underlying.
+    ///
+    /// The spread buys `long_style` at `long_strike` and sells the same
+    /// style at `spread_strike`. The financing option is the put below (for
+    /// a call spread) or the call above (for a put spread) `long_strike`
+    /// whose premium best offsets the spread, with long legs filled at the
+    /// ask and short legs at the bid.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyError` if the chain has no expiration, the spread
+    /// strikes are not quoted or out of order, or no financing strike
+    /// produces a valid seagull.
+    pub fn zero_cost(
+        chain: &OptionChain,
+        long_style: OptionStyle,
+        long_strike: Positive,
+        spread_strike: Positive,
+        quantity: Positive,
+        open_fee: Positive,
+        close_fee: Positive,
+    ) -> Result<Self, StrategyError> {
+        let expiration = chain.get_expiration().ok_or_else(|| {
+            StrategyError::invalid_parameters("Seagull::zero_cost", "chain has no expiration")
+        })?;
+        let leg = |strike, option_style, side| {
+            chain_leg(
+                chain,
+                expiration,
+                strike,
+                option_style,
+                side,
+                quantity,
+                open_fee,
+                close_fee,
+            )
+        };
+        let candidates = chain
+            .options
+            .iter()
+            .map(|option| option.strike_price)
+            .filter(|strike| match long_style {
+                OptionStyle::Call => *strike <= long_strike,
+                OptionStyle::Put => *strike >= long_strike,
+            });
+        premium_neutral(
+            candidates,
+            |strike| match long_style {
+                OptionStyle::Call => Self::from_legs(
+                    None,
+                    leg(strike, OptionStyle::Put, Side::Short)?,
+                    leg(long_strike, OptionStyle::Call, Side::Long)?,
+                    leg(spread_strike, OptionStyle::Call, Side::Short)?,
+                ),
+                OptionStyle::Put => Self::from_legs(
+                    None,
+                    leg(spread_strike, OptionStyle::Put, Side::Short)?,
+                    leg(long_strike, OptionStyle::Put, Side::Long)?,
+                    leg(strike, OptionStyle::Call, Side::Short)?,
+                ),
+            },
+            |strategy| strategy.net_premium(),
+        )
+    }
+
+    /// Adds the hedged position in the underlying.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyError` if `spot_leg` is not the exposure this
+    /// seagull hedges or its quantity differs from the options'.
+    pub fn with_underlying(self, spot_leg: SpotPosition) -> Result<Self, StrategyError> {
+        Self::from_legs(
+            Some(spot_leg),
+            self.short_put,
+            self.long_option,
+            self.short_call,
+        )
+    }
+
+    fn from_legs(
+        underlying: Option<SpotPosition>,
+        short_put: Position,
+        long_option: Position,
+        short_call: Position,
+    ) -> Result<Self, StrategyError> {
+        let name = match long_option.option.option_style {
+            OptionStyle::Call => "Bullish Seagull",
+            OptionStyle::Put => "Bearish Seagull",
+        };
+        let mut strategy = Seagull {
+            name: name.to_string(),
+            underlying,
+            short_put,
+            long_option,
+            short_call,
+            ..Default::default()
+        };
+        if !strategy.validate() {
+            return Err(StrategyError::invalid_parameters(
+                "Seagull",
+                "requires a short put, a long option and a short call in strike order forming \
+                 one vertical spread, sharing expiration and quantity, and any underlying on \
+                 the hedged side",
+            ));
+        }
+        strategy.update_break_even_points()?;
+        Ok(strategy)
+    }
+
+    fn legs(&self) -> [&Position; 3] {
+        [&self.short_put, &self.long_option, &self.short_call]
+    }
+
+    fn legs_mut(&mut self) -> [&mut Position; 3] {
+        [
+            &mut self.short_put,
+            &mut self.long_option,
+            &mut self.short_call,
+        ]
+    }
+
+    /// Returns the option legs and, if held, the underlying.
+    #[must_use]
+    pub fn get_legs(&self) -> Vec<Leg> {
+        let mut legs: Vec<Leg> = self.underlying.iter().cloned().map(Leg::Spot).collect();
+        legs.extend(self.legs().into_iter().cloned().map(Leg::option));
+        legs
+    }
+
+    /// Returns `Long` for a bullish seagull and `Short` for a bearish one.
+    #[must_use]
+    pub fn direction(&self) -> Side {
+        match self.long_option.option.option_style {
+            OptionStyle::Call => Side::Long,
+            OptionStyle::Put => Side::Short,
+        }
+    }
+
+    /// Returns the short put, long option and short call strikes.
+    #[must_use]
+    pub fn strikes(&self) -> (Positive, Positive, Positive) {
+        (
+            self.short_put.option.strike_price,
+            self.long_option.option.strike_price,
+            self.short_call.option.strike_price,
+        )
+    }
+
+    /// Premium collected minus premium paid on the options, before fees.
+    /// Zero for a zero-cost quote.
+    #[must_use]
+    pub fn net_premium(&self) -> Decimal {
+        net_premium(&self.legs())
+    }
+
+    /// Expiration payoff at zero and at each strike, where its slope changes.
+    fn payoff_nodes(&self) -> Result<Vec<(Positive, Decimal)>, PricingError> {
+        let (put, long, call) = self.strikes();
+        let mut prices = vec![Positive::ZERO, put, long, call];
+        prices.dedup();
+        prices
+            .into_iter()
+            .map(|price| Ok((price, self.calculate_profit_at(&price)?)))
+            .collect()
+    }
+
+    /// Slope of the expiration payoff above the call strike, including the
+    /// underlying.
+    fn upper_slope(&self) -> Decimal {
+        let quantity = self.short_call.option.quantity.to_dec();
+        let long_call = match self.long_option.option.option_style {
+            OptionStyle::Call => quantity,
+            OptionStyle::Put => Decimal::ZERO,
+        };
+        let spot = self
+            .underlying
+            .as_ref()
+            .map_or(Decimal::ZERO, |spot| match spot.side {
+                Side::Long => spot.quantity.to_dec(),
+                Side::Short => -spot.quantity.to_dec(),
+            });
+        long_call - quantity + spot
+    }
+
+    fn volatility_adjustment(&self) -> VolatilityAdjustment {
+        let (base_volatility, std_dev_adjustment) = mean_and_std(
+            self.legs()
+                .iter()
+                .map(|leg| leg.option.implied_volatility)
+                .collect(),
+        );
+        VolatilityAdjustment {
+            base_volatility,
+            std_dev_adjustment,
+        }
+    }
+
+    fn ranges(&self, profitable: bool) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        let option = &self.long_option.option;
+        let mut bounds: Vec<Option<Positive>> = vec![None];
+        bounds.extend(self.break_even_points.iter().copied().map(Some));
+        bounds.push(None);
+        let mut ranges = Vec::new();
+        for window in bounds.windows(2) {
+            let [lower, upper] = window else {
+                continue;
+            };
+            let probe = match (lower, upper) {
+                (Some(l), Some(u)) => (*l + *u) / Positive::TWO,
+                (None, Some(u)) => *u / Positive::TWO,
+                (Some(l), None) => *l * Positive::TWO,
+                (None, None) => option.underlying_price,
+            };
+            if (self.calculate_profit_at(&probe)? > Decimal::ZERO) != profitable {
+                continue;
+            }
+            let mut range = ProfitLossRange::new(*lower, *upper, Positive::ZERO)?;
+            range.calculate_probability(
+                &option.underlying_price,
+                Some(self.volatility_adjustment()),
+                None,
+                &option.expiration_date,
+                Some(option.risk_free_rate),
+            )?;
+            ranges.push(range);
+        }
+        Ok(ranges)
+    }
+
+    /// Profit and loss of the underlying at `price`, zero when not held.
+    fn spot_pnl(&self, price: &Positive) -> PnL {
+        match &self.underlying {
+            Some(spot) => PnL {
+                realized: None,
+                unrealized: Some(spot.pnl_at_price(*price)),
+                initial_costs: spot.total_cost(),
+                initial_income: Positive::ZERO,
+                date_time: Utc::now(),
+            },
+            None => PnL::default(),
+        }
+    }
+}
+
+impl StrategyConstructor for Seagull {
+    /// Builds the option legs; add the underlying with
+    /// [`Seagull::with_underlying`].
+    fn get_strategy(vec_positions: &[Position]) -> Result<Self, StrategyError> {
+        let (shorts, longs): (Vec<&Position>, Vec<&Position>) = vec_positions
+            .iter()
+            .partition(|position| position.option.side == Side::Short);
+        let short_put = shorts
+            .iter()
+            .find(|position| position.option.option_style == OptionStyle::Put);
+        let short_call = shorts
+            .iter()
+            .find(|position| position.option.option_style == OptionStyle::Call);
+        let (2, [long_option], Some(short_put), Some(short_call)) =
+            (shorts.len(), longs.as_slice(), short_put, short_call)
+        else {
+            return Err(StrategyError::invalid_parameters(
+                "Seagull get_strategy",
+                "Must have a short put, a short call and one long option",
+            ));
+        };
+        Self::from_legs(
+            None,
+            (*short_put).clone(),
+            (*long_option).clone(),
+            (*short_call).clone(),
+        )
+    }
+}
+
+impl BreakEvenable for Seagull {
+    fn get_break_even_points(&self) -> Result<&Vec<Positive>, StrategyError> {
+        Ok(&self.break_even_points)
+    }
+
+    fn update_break_even_points(&mut self) -> Result<(), StrategyError> {
+        self.break_even_points = expiration_break_evens(&self.payoff_nodes()?, self.upper_slope())?;
+        Ok(())
+    }
+}
+
+impl Validable for Seagull {
+    fn validate(&self) -> bool {
+        let legs = self.legs();
+        if !legs.iter().all(|leg| leg.validate()) {
+            debug!("Seagull legs failed validation");
+            return false;
+        }
+        let long_style = self.long_option.option.option_style;
+        let shape = [
+            (OptionStyle::Put, Side::Short),
+            (long_style, Side::Long),
+            (OptionStyle::Call, Side::Short),
+        ];
+        if legs
+            .iter()
+            .zip(shape)
+            .any(|(leg, (style, side))| leg.option.option_style != style || leg.option.side != side)
+        {
+            debug!("Seagull requires a short put, a long option and a short call");
+            return false;
+        }
+        let long = &self.long_option.option;
+        if legs.iter().any(|leg| {
+            leg.option.expiration_date != long.expiration_date
+                || leg.option.quantity != long.quantity
+        }) {
+            debug!("Seagull legs must share expiration and quantity");
+            return false;
+        }
+        let (put, long_strike, call) = self.strikes();
+        let ordered = match long_style {
+            OptionStyle::Call => put <= long_strike && long_strike < call,
+            OptionStyle::Put => put < long_strike && long_strike <= call,
+        };
+        if !ordered {
+            debug!("Seagull strikes must be ordered put, long option, call around one spread");
+            return false;
+        }
+        let hedged_side = match long_style {
+            OptionStyle::Call => Side::Short,
+            OptionStyle::Put => Side::Long,
+        };
+        if let Some(spot) = &self.underlying
+            && (spot.side != hedged_side || spot.quantity != long.quantity)
+        {
+            debug!("Seagull underlying must be the hedged exposure with the options' quantity");
+            return false;
+        }
+        true
+    }
+}
+
+impl Positionable for Seagull {
+    /// Replaces the long option for a long position, otherwise the short
+    /// option with the same style.
+    fn add_position(&mut self, position: &Position) -> Result<(), PositionError> {
+        let slot = match (position.option.side, position.option.option_style) {
+            (Side::Long, _) => &mut self.long_option,
+            (Side::Short, OptionStyle::Put) => &mut self.short_put,
+            (Side::Short, OptionStyle::Call) => &mut self.short_call,
+        };
+        *slot = position.clone();
+        Ok(())
+    }
+
+    fn get_positions(&self) -> Result<Vec<&Position>, PositionError> {
+        Ok(self.legs().to_vec())
+    }
+
+    fn get_position(
+        &mut self,
+        option_style: &OptionStyle,
+        side: &Side,
+        strike: &Positive,
+    ) -> Result<Vec<&mut Position>, PositionError> {
+        let positions: Vec<&mut Position> = self
+            .legs_mut()
+            .into_iter()
+            .filter(|leg| {
+                leg.option.option_style == *option_style
+                    && leg.option.side == *side
+                    && leg.option.strike_price == *strike
+            })
+            .collect();
+        if positions.is_empty() {
+            return Err(PositionError::invalid_position_type(
+                *side,
+                "Strike not found in positions".to_string(),
+            ));
+        }
+        Ok(positions)
+    }
+
+    fn modify_position(&mut self, position: &Position) -> Result<(), PositionError> {
+        if !position.validate() {
+            return Err(PositionError::ValidationError(
+                PositionValidationErrorKind::InvalidPosition {
+                    reason: "Invalid position data".to_string(),
+                },
+            ));
+        }
+        let option = &position.option;
+        let slot = self.legs_mut().into_iter().find(|leg| {
+            leg.option.option_style == option.option_style
+                && leg.option.side == option.side
+                && leg.option.strike_price == option.strike_price
+        });
+        match slot {
+            Some(slot) => {
+                *slot = position.clone();
+                Ok(())
+            }
+            None => Err(PositionError::invalid_position_type(
+                option.side,
+                "Strike not found in positions".to_string(),
+            )),
+        }
+    }
+}
+
+impl Strategable for Seagull {
+    fn info(&self) -> Result<StrategyBasics, StrategyError> {
+        Ok(StrategyBasics {
+            name: self.name.clone(),
+            kind: self.kind.clone(),
+            description: self.description.clone(),
+        })
+    }
+}
+
+impl BasicAble for Seagull {
+    fn get_title(&self) -> String {
+        let mut legs: Vec<String> = self
+            .underlying
+            .iter()
+            .map(|spot| {
+                format!(
+                    "{} {} {} @ {}",
+                    spot.side, spot.quantity, spot.symbol, spot.cost_basis
+                )
+            })
+            .collect();
+        legs.extend(self.legs().iter().map(|leg| leg.get_title()));
+        format!("{} Strategy: \n\t{}", self.name, legs.join("\n\t"))
+    }
+
+    fn get_option_basic_type(&self) -> HashSet<OptionBasicType<'_>> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                OptionBasicType {
+                    option_style: &option.option_style,
+                    side: &option.side,
+                    strike_price: &option.strike_price,
+                    expiration_date: &option.expiration_date,
+                }
+            })
+            .collect()
+    }
+
+    fn get_implied_volatility(&self) -> HashMap<OptionBasicType<'_>, &Positive> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                (
+                    OptionBasicType {
+                        option_style: &option.option_style,
+                        side: &option.side,
+                        strike_price: &option.strike_price,
+                        expiration_date: &option.expiration_date,
+                    },
+                    &option.implied_volatility,
+                )
+            })
+            .collect()
+    }
+
+    fn get_quantity(&self) -> HashMap<OptionBasicType<'_>, &Positive> {
+        self.legs()
+            .into_iter()
+            .map(|leg| {
+                let option = &leg.option;
+                (
+                    OptionBasicType {
+                        option_style: &option.option_style,
+                        side: &option.side,
+                        strike_price: &option.strike_price,
+                        expiration_date: &option.expiration_date,
+                    },
+                    &option.quantity,
+                )
+            })
+            .collect()
+    }
+
+    fn one_option(&self) -> &Options {
+        self.long_option.one_option()
+    }
+
+    fn one_option_mut(&mut self) -> &mut Options {
+        self.long_option.one_option_mut()
+    }
+
+    fn set_expiration_date(
+        &mut self,
+        expiration_date: ExpirationDate,
+    ) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.expiration_date = expiration_date;
+        }
+        Ok(())
+    }
+
+    /// Reprices the option legs; the underlying keeps its cost basis.
+    fn set_underlying_price(&mut self, price: &Positive) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.underlying_price = *price;
+            leg.premium = Positive::new_decimal(leg.option.calculate_price_black_scholes()?.abs())
+                .unwrap_or(Positive::ZERO);
+        }
+        Ok(())
+    }
+
+    fn set_implied_volatility(&mut self, volatility: &Positive) -> Result<(), StrategyError> {
+        for leg in self.legs_mut() {
+            leg.option.implied_volatility = *volatility;
+            leg.premium = Positive::new_decimal(leg.option.calculate_price_black_scholes()?.abs())
+                .unwrap_or(Positive::ZERO);
+        }
+        Ok(())
+    }
+}
+
+impl Strategies for Seagull {
+    fn underlying_pnl_at(&self, underlying_price: &Positive) -> Decimal {
+        self.underlying
+            .as_ref()
+            .map_or(Decimal::ZERO, |spot| spot.pnl_at_price(*underlying_price))
+    }
+
+    /// Unbounded when the payoff keeps rising above the call strike.
+    fn get_max_profit(&self) -> Result<Positive, StrategyError> {
+        if self.upper_slope() > Decimal::ZERO {
+            return Ok(Positive::INFINITY);
+        }
+        let max = self
+            .payoff_nodes()?
+            .into_iter()
+            .map(|(_, profit)| profit)
+            .fold(Decimal::MIN, Decimal::max);
+        if max <= Decimal::ZERO {
+            return Err(StrategyError::ProfitLossError(
+                ProfitLossErrorKind::MaxProfitError {
+                    reason: "Max profit is negative".to_string(),
+                },
+            ));
+        }
+        Ok(Positive::new_decimal(max)?)
+    }
+
+    /// Unbounded when the payoff keeps falling above the call strike.
+    fn get_max_loss(&self) -> Result<Positive, StrategyError> {
+        if self.upper_slope() < Decimal::ZERO {
+            return Ok(Positive::INFINITY);
+        }
+        let min = self
+            .payoff_nodes()?
+            .into_iter()
+            .map(|(_, profit)| profit)
+            .fold(Decimal::MAX, Decimal::min);
+        Ok(Positive::new_decimal(min.min(Decimal::ZERO).abs())?)
+    }
+}
+
+impl Optimizable for Seagull {
+    type Strategy = Seagull;
+
+    /// Yields the (short put, long option, short call) strike triples of
+    /// `option_chain` that satisfy `side`, in ascending strike order.
+    fn filter_combinations<'a>(
+        &'a self,
+        option_chain: &'a OptionChain,
+        side: FindOptimalSide,
+    ) -> impl Iterator<Item = OptionDataGroup<'a>> {
+        option_chain
+            .get_triple_iter()
+            .filter(move |(put, long, call)| {
+                self.is_valid_optimal_option(put, &side)
+                    && self.is_valid_optimal_option(long, &side)
+                    && self.is_valid_optimal_option(call, &side)
+            })
+            .map(|(put, long, call)| OptionDataGroup::Three(put, long, call))
+    }
+
+    /// Builds a seagull with the short put at `first`, the long option at
+    /// `second` and the short call at `third`, keeping this one's direction,
+    /// underlying, quantity and fees and filling at the chain's quotes.
+    fn create_strategy(
+        &self,
+        chain: &OptionChain,
+        legs: &StrategyLegs,
+    ) -> Result<Self::Strategy, StrategyError> {
+        let StrategyLegs::ThreeLegs {
+            first,
+            second,
+            third,
+        } = legs
+        else {
+            return Err(StrategyError::operation_not_supported(
+                "create_strategy",
+                "Seagull requires exactly three legs (ThreeLegs)",
+            ));
+        };
+        let expiration = chain
+            .get_expiration()
+            .unwrap_or(self.long_option.option.expiration_date);
+        let leg = |template: &Position, strike| {
+            chain_leg(
+                chain,
+                expiration,
+                strike,
+                template.option.option_style,
+                template.option.side,
+                template.option.quantity,
+                template.open_fee,
+                template.close_fee,
+            )
+        };
+        Self::from_legs(
+            self.underlying.clone(),
+            leg(&self.short_put, first.strike_price)?,
+            leg(&self.long_option, second.strike_price)?,
+            leg(&self.short_call, third.strike_price)?,
+        )
+    }
+}
+
+impl Profit for Seagull {
+    fn calculate_profit_at(&self, price: &Positive) -> Result<Decimal, PricingError> {
+        let spot_pnl = self
+            .underlying
+            .as_ref()
+            .map_or(Decimal::ZERO, |spot| spot.pnl_at_price(*price));
+        let option_pnl: Decimal = self
+            .legs()
+            .iter()
+            .map(|leg| leg.pnl_at_expiration(&Some(price)))
+            .sum::<Result<Decimal, PricingError>>()?;
+        Ok(spot_pnl + option_pnl)
+    }
+}
+
+impl ProbabilityAnalysis for Seagull {
+    fn get_profit_ranges(&self) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        self.ranges(true)
+    }
+
+    fn get_loss_ranges(&self) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        self.ranges(false)
+    }
+}
+
+impl Greeks for Seagull {
+    fn get_options(&self) -> Result<Vec<&Options>, GreeksError> {
+        Ok(self.legs().map(|leg| &leg.option).to_vec())
+    }
+
+    /// Includes the underlying when held.
+    fn delta(&self) -> Result<Decimal, GreeksError> {
+        let spot_delta = match &self.underlying {
+            Some(spot) => spot.delta()?,
+            None => Decimal::ZERO,
+        };
+        Ok(spot_delta
+            + self.short_put.delta()?
+            + self.long_option.delta()?
+            + self.short_call.delta()?)
+    }
+}
+
+impl DeltaNeutrality for Seagull {}
+
+impl PnLCalculator for Seagull {
+    fn calculate_pnl(
+        &self,
+        market_price: &Positive,
+        expiration_date: ExpirationDate,
+        implied_volatility: &Positive,
+    ) -> Result<PnL, PricingError> {
+        let mut pnl = self.spot_pnl(market_price);
+        for leg in self.legs() {
+            pnl = pnl + leg.calculate_pnl(market_price, expiration_date, implied_volatility)?;
+        }
+        Ok(pnl)
+    }
+
+    fn calculate_pnl_at_expiration(
+        &self,
+        underlying_price: &Positive,
+    ) -> Result<PnL, PricingError> {
+        let mut pnl = self.spot_pnl(underlying_price);
+        for leg in self.legs() {
+            pnl = pnl + leg.calculate_pnl_at_expiration(underlying_price)?;
+        }
+        Ok(pnl)
+    }
+}
+
+test_strategy_traits!(Seagull, test_seagull_implementations);
+
+#[cfg(test)]
+mod tests_seagull {
+    use super::*;
+    use crate::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
+    use positive::{pos_or_panic, spos};
+    use rust_decimal_macros::dec;
+
+    fn seagull(long_style: OptionStyle, premiums: [f64; 3]) -> Seagull {
+        let [premium_put, premium_long, premium_call] =
+            premiums.map(|premium| pos_or_panic!(premium));
+        Seagull::new(
+            "HG".to_string(),
+            Positive::HUNDRED,
+            long_style,
+            pos_or_panic!(90.0),
+            Positive::HUNDRED,
+            pos_or_panic!(110.0),
+            ExpirationDate::Days(pos_or_panic!(180.0)),
+            pos_or_panic!(0.25),
+            dec!(0.03),
+            Positive::ZERO,
+            Positive::ONE,
+            premium_put,
+            premium_long,
+            premium_call,
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+        .unwrap()
+    }
+
+    fn spot(side: Side) -> SpotPosition {
+        SpotPosition::new(
+            "HG".to_string(),
+            Positive::ONE,
+            Positive::HUNDRED,
+            side,
+            Utc::now(),
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+    }
+
+    fn chain() -> OptionChain {
+        let params = OptionChainBuildParams::new(
+            "HG".to_string(),
+            spos!(1000.0),
+            10,
+            spos!(2.5),
+            dec!(-0.2),
+            dec!(0.1),
+            pos_or_panic!(0.02),
+            2,
+            OptionDataPriceParams::new(
+                Some(Box::new(Positive::HUNDRED)),
+                Some(ExpirationDate::Days(pos_or_panic!(180.0))),
+                Some(dec!(0.03)),
+                spos!(0.0),
+                Some("HG".to_string()),
+            ),
+            pos_or_panic!(0.25),
+        );
+        OptionChain::build_chain(&params).unwrap()
+    }
+
+    #[test]
+    fn test_bullish_seagull() {
+        // Sells the 90 put for 3.5, buys the 100/110 call spread for 3.0.
+        let strategy = seagull(OptionStyle::Call, [3.5, 5.0, 2.0]);
+        assert_eq!(strategy.name, "Bullish Seagull");
+        assert_eq!(strategy.direction(), Side::Long);
+        assert_eq!(strategy.net_premium(), dec!(0.5));
+        assert_eq!(strategy.get_max_profit().unwrap(), pos_or_panic!(10.5));
+        assert_eq!(strategy.get_max_loss().unwrap(), pos_or_panic!(89.5));
+        assert_eq!(
+            strategy.get_break_even_points().unwrap(),
+            &vec![pos_or_panic!(89.5)]
+        );
+    }
+
+    #[test]
+    fn test_bullish_seagull_caps_a_short_exposure() {
+        let strategy = seagull(OptionStyle::Call, [3.5, 5.0, 2.0])
+            .with_underlying(spot(Side::Short))
+            .unwrap();
+        assert_eq!(strategy.get_legs().len(), 4);
+        // Hedged from the call strike to the top of the spread.
+        for price in [100.0, 105.0, 110.0] {
+            assert_eq!(
+                strategy.calculate_profit_at(&pos_or_panic!(price)).unwrap(),
+                dec!(0.5)
+            );
+        }
+        // Protection runs out above the spread.
+        assert_eq!(strategy.get_max_loss().unwrap(), Positive::INFINITY);
+        assert!(
+            seagull(OptionStyle::Call, [3.5, 5.0, 2.0])
+                .with_underlying(spot(Side::Long))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_bearish_seagull_floors_a_long_exposure() {
+        // Buys the 100/90 put spread for 3.0 and sells the 110 call for 3.5.
+        let strategy = seagull(OptionStyle::Put, [2.0, 5.0, 3.5]);
+        assert_eq!(strategy.name, "Bearish Seagull");
+        assert_eq!(strategy.direction(), Side::Short);
+        assert_eq!(strategy.get_max_loss().unwrap(), Positive::INFINITY);
+
+        let hedged = strategy.with_underlying(spot(Side::Long)).unwrap();
+        for price in [90.0, 95.0, 100.0] {
+            assert_eq!(
+                hedged.calculate_profit_at(&pos_or_panic!(price)).unwrap(),
+                dec!(0.5)
+            );
+        }
+        assert_eq!(hedged.get_max_profit().unwrap(), pos_or_panic!(10.5));
+        assert_eq!(hedged.get_max_loss().unwrap(), pos_or_panic!(89.5));
+    }
+
+    #[test]
+    fn test_rejects_strikes_out_of_order() {
+        let result = Seagull::new(
+            "HG".to_string(),
+            Positive::HUNDRED,
+            OptionStyle::Call,
+            pos_or_panic!(105.0),
+            Positive::HUNDRED,
+            pos_or_panic!(110.0),
+            ExpirationDate::Days(pos_or_panic!(180.0)),
+            pos_or_panic!(0.25),
+            dec!(0.03),
+            Positive::ZERO,
+            Positive::ONE,
+            Positive::ONE,
+            Positive::ONE,
+            Positive::ONE,
+            Positive::ZERO,
+            Positive::ZERO,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_zero_cost_seagull() {
+        let chain = chain();
+        let step_value = dec!(2.5);
+        let bullish = Seagull::zero_cost(
+            &chain,
+            OptionStyle::Call,
+            Positive::HUNDRED,
+            pos_or_panic!(110.0),
+            Positive::ONE,
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+        .unwrap();
+        let (put, long, call) = bullish.strikes();
+        assert!(put < long);
+        assert_eq!((long, call), (Positive::HUNDRED, pos_or_panic!(110.0)));
+        assert!(bullish.net_premium().abs() < step_value);
+
+        let bearish = Seagull::zero_cost(
+            &chain,
+            OptionStyle::Put,
+            Positive::HUNDRED,
+            pos_or_panic!(90.0),
+            Positive::ONE,
+            Positive::ZERO,
+            Positive::ZERO,
+        )
+        .unwrap();
+        let (put, long, call) = bearish.strikes();
+        assert_eq!((put, long), (pos_or_panic!(90.0), Positive::HUNDRED));
+        assert!(call > long);
+        assert!(bearish.net_premium().abs() < step_value);
+        assert!(bearish.with_underlying(spot(Side::Long)).is_ok());
+    }
+}
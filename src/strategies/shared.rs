@@ -15,16 +15,30 @@
 //! - **Butterfly strategies**: Three-strike strategies with wings and body
 //! - **Condor strategies**: Four-strike strategies
 //! - **Straddle/Strangle strategies**: Volatility-based strategies
+//! - **Time spread strategies**: Legs with different expirations
+//! - **Financing strategies**: Implied rates and early-assignment exposure
+//! - **Zero-cost hedges**: Net premium and premium-neutral strike selection
 //!
 //! ## Usage
 //!
 //! Strategies implement these traits to gain access to common calculations
 //! and reduce boilerplate code.
 
+use crate::Options;
+use crate::chains::chain::OptionChain;
+use crate::error::PricingError;
+use crate::error::probability::ProbabilityError;
 use crate::error::strategies::StrategyError;
 use crate::model::position::Position;
+use crate::model::types::{OptionStyle, OptionType, Side};
+use crate::model::{ExpirationDate, ProfitLossRange};
+use crate::strategies::probabilities::utils::VolatilityAdjustment;
+use chrono::Utc;
 use positive::Positive;
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, MathematicalOps};
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use utoipa::ToSchema;
 
 /// Trait for vertical spread strategies (two legs with different strikes).
 ///
@@ -187,6 +201,196 @@ pub trait StrangleStrategy {
     fn is_long(&self) -> bool;
 }
 
+/// Number of intervals used to scan the front-expiry payoff of time spreads.
+const TIME_SPREAD_SCAN_STEPS: usize = 400;
+
+/// Trait for time spread strategies (legs with different expirations).
+///
+/// Time spreads combine a front-month leg with a back-month leg. When the
+/// front leg expires the back leg still has time value, so the payoff is
+/// measured at the front expiry with the back leg marked to model (Black-Scholes
+/// with its own implied volatility and the time it has left). Break-even
+/// points and extremes have no closed form and are found numerically.
+///
+/// # Examples
+///
+/// - Long Calendar Spread
+/// - Short Calendar Spread
+/// - Diagonal Spread
+pub trait TimeSpreadStrategy {
+    /// Returns the leg expiring first.
+    fn front_leg(&self) -> &Position;
+
+    /// Returns the leg expiring last.
+    fn back_leg(&self) -> &Position;
+
+    /// Returns the days the back leg still has to run when the front leg
+    /// expires.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PricingError` if an expiration cannot be resolved or the back
+    /// leg does not expire after the front leg.
+    fn remaining_days(&self) -> Result<Positive, PricingError> {
+        let front = self.front_leg().option.expiration_date.get_days()?;
+        let back = self.back_leg().option.expiration_date.get_days()?;
+        if back <= front {
+            return Err(PricingError::method_error(
+                "remaining_days",
+                "the back leg must expire after the front leg",
+            ));
+        }
+        Ok(back - front)
+    }
+
+    /// Profit of the spread at the front expiry for an underlying `price`.
+    ///
+    /// The front leg is settled at intrinsic value and the back leg is
+    /// closed at its model value with the remaining time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PricingError` if the back leg cannot be priced.
+    fn profit_at_front_expiry(&self, price: &Positive) -> Result<Decimal, PricingError> {
+        let front = self.front_leg().pnl_at_expiration(&Some(price))?;
+        let back_leg = self.back_leg();
+        let mut back_option = back_leg.option.clone();
+        back_option.side = Side::Long;
+        back_option.underlying_price = *price;
+        back_option.expiration_date = ExpirationDate::Days(self.remaining_days()?);
+        let back_value = back_option
+            .calculate_price_black_scholes()?
+            .max(Decimal::ZERO);
+        let back = back_leg.unrealized_pnl(Positive::new_decimal(back_value)?)?;
+        Ok(front + back)
+    }
+
+    /// Samples [`TimeSpreadStrategy::profit_at_front_expiry`] between half the
+    /// lowest strike and one and a half times the highest strike.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PricingError` if any sample cannot be priced.
+    fn front_expiry_profile(&self) -> Result<Vec<(Positive, Decimal)>, PricingError> {
+        let strikes = [
+            self.front_leg().option.strike_price,
+            self.back_leg().option.strike_price,
+        ];
+        let low = strikes[0].min(strikes[1]).to_dec() / Decimal::TWO;
+        let high = strikes[0].max(strikes[1]).to_dec() * Decimal::new(15, 1);
+        let step = (high - low) / Decimal::from(TIME_SPREAD_SCAN_STEPS);
+        (0..=TIME_SPREAD_SCAN_STEPS)
+            .map(|i| {
+                let price = Positive::new_decimal(low + step * Decimal::from(i))?;
+                Ok((price, self.profit_at_front_expiry(&price)?))
+            })
+            .collect()
+    }
+
+    /// Returns the prices where the front-expiry profit changes sign,
+    /// interpolated linearly between the scanned samples.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PricingError` if the profile cannot be computed.
+    fn front_expiry_break_evens(&self) -> Result<Vec<Positive>, PricingError> {
+        let profile = self.front_expiry_profile()?;
+        let mut break_evens = Vec::new();
+        for pair in profile.windows(2) {
+            let [(x0, p0), (x1, p1)] = pair else { continue };
+            if p0.is_zero() {
+                break_evens.push(*x0);
+            } else if p0.is_sign_negative() != p1.is_sign_negative() && !p1.is_zero() {
+                let weight = p0.abs() / (p1 - p0).abs();
+                let price = x0.to_dec() + (x1.to_dec() - x0.to_dec()) * weight;
+                break_evens.push(Positive::new_decimal(price)?.round_to(2));
+            }
+        }
+        Ok(break_evens)
+    }
+
+    /// Returns the largest and smallest front-expiry profit over the scanned
+    /// range, in that order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PricingError` if the profile cannot be computed.
+    fn front_expiry_extremes(&self) -> Result<(Decimal, Decimal), PricingError> {
+        let profile = self.front_expiry_profile()?;
+        let max = profile.iter().map(|(_, p)| *p).max().unwrap_or_default();
+        let min = profile.iter().map(|(_, p)| *p).min().unwrap_or_default();
+        Ok((max, min))
+    }
+
+    /// Area under the positive part of the front-expiry profit curve, as a
+    /// percentage of the underlying price.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PricingError` if the profile cannot be computed.
+    fn front_expiry_profit_area(&self) -> Result<Decimal, PricingError> {
+        let profile = self.front_expiry_profile()?;
+        let area: Decimal = profile
+            .windows(2)
+            .filter_map(|pair| match pair {
+                [(x0, p0), (x1, _)] if *p0 > Decimal::ZERO => {
+                    Some(*p0 * (x1.to_dec() - x0.to_dec()))
+                }
+                _ => None,
+            })
+            .sum();
+        let underlying = self.front_leg().option.underlying_price.to_dec();
+        if underlying.is_zero() {
+            return Ok(Decimal::ZERO);
+        }
+        Ok(area / underlying * Decimal::ONE_HUNDRED)
+    }
+
+    /// Builds the profitable (`profitable = true`) or losing price ranges at
+    /// the front expiry and computes their probabilities.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProbabilityError` if the profile or a range probability
+    /// cannot be computed.
+    fn front_expiry_ranges(
+        &self,
+        profitable: bool,
+        volatility_adj: Option<VolatilityAdjustment>,
+    ) -> Result<Vec<ProfitLossRange>, ProbabilityError> {
+        let break_evens = self.front_expiry_break_evens()?;
+        let mut bounds: Vec<Option<Positive>> = vec![None];
+        bounds.extend(break_evens.iter().copied().map(Some));
+        bounds.push(None);
+
+        let front = &self.front_leg().option;
+        let mut ranges = Vec::new();
+        for pair in bounds.windows(2) {
+            let [lower, upper] = pair else { continue };
+            let probe = match (lower, upper) {
+                (Some(l), Some(u)) => (*l + *u) / Positive::TWO,
+                (None, Some(u)) => *u / Positive::TWO,
+                (Some(l), None) => *l * Positive::TWO,
+                (None, None) => front.strike_price,
+            };
+            let profit = self.profit_at_front_expiry(&probe)?;
+            if (profit > Decimal::ZERO) != profitable {
+                continue;
+            }
+            let mut range = ProfitLossRange::new(*lower, *upper, Positive::ZERO)?;
+            range.calculate_probability(
+                &front.underlying_price,
+                volatility_adj.clone(),
+                None,
+                &front.expiration_date,
+                Some(front.risk_free_rate),
+            )?;
+            ranges.push(range);
+        }
+        Ok(ranges)
+    }
+}
+
 /// Helper function to calculate break-even for a credit spread.
 ///
 /// # Arguments
@@ -300,6 +504,255 @@ pub fn aggregate_premiums(positions: &[&Position]) -> Positive {
         .fold(Positive::ZERO, |acc, premium| acc + premium)
 }
 
+/// Finds the break-even points of a piecewise-linear expiration payoff.
+///
+/// `nodes` holds `(price, profit)` pairs sorted by price at every point where
+/// the slope changes, and `upper_slope` is the slope beyond the last node.
+/// Crossings are interpolated exactly and rounded to two decimals.
+///
+/// # Errors
+///
+/// Returns `StrategyError` if a crossing cannot be represented as a
+/// `Positive` price.
+pub fn expiration_break_evens(
+    nodes: &[(Positive, Decimal)],
+    upper_slope: Decimal,
+) -> Result<Vec<Positive>, StrategyError> {
+    let mut break_even_points = Vec::new();
+    for pair in nodes.windows(2) {
+        if let [(x0, p0), (x1, p1)] = pair
+            && ((*p0 < Decimal::ZERO && *p1 >= Decimal::ZERO)
+                || (*p0 > Decimal::ZERO && *p1 <= Decimal::ZERO))
+        {
+            let x = x0.to_dec() + (x1.to_dec() - x0.to_dec()) * *p0 / (*p0 - *p1);
+            break_even_points.push(Positive::new_decimal(x)?.round_to(2));
+        }
+    }
+    if let Some((x, p)) = nodes.last()
+        && ((*p < Decimal::ZERO && upper_slope > Decimal::ZERO)
+            || (*p > Decimal::ZERO && upper_slope < Decimal::ZERO))
+    {
+        break_even_points.push(Positive::new_decimal(x.to_dec() - *p / upper_slope)?.round_to(2));
+    }
+    Ok(break_even_points)
+}
+
+/// Builds one strategy leg from the quotes of `chain`.
+///
+/// Long legs are filled at the ask and short legs at the bid, using the
+/// strike's implied volatility and the chain's rate and dividend yield.
+/// `expiration` is passed explicitly so legs of time spreads can be taken
+/// from different chains of a series.
+///
+/// # Errors
+///
+/// Returns `StrategyError::OperationError` if the strike is not quoted in
+/// the chain or the side needed for the fill has no price.
+#[allow(clippy::too_many_arguments)]
+pub fn chain_leg(
+    chain: &OptionChain,
+    expiration: ExpirationDate,
+    strike: Positive,
+    option_style: OptionStyle,
+    side: Side,
+    quantity: Positive,
+    open_fee: Positive,
+    close_fee: Positive,
+) -> Result<Position, StrategyError> {
+    let option_data = chain
+        .options
+        .iter()
+        .find(|option| option.strike_price == strike)
+        .ok_or_else(|| {
+            StrategyError::invalid_parameters(
+                "chain_leg",
+                &format!("strike {strike} is not quoted for {expiration}"),
+            )
+        })?;
+    let premium = match (option_style, side) {
+        (OptionStyle::Call, Side::Long) => option_data.call_ask,
+        (OptionStyle::Call, Side::Short) => option_data.call_bid,
+        (OptionStyle::Put, Side::Long) => option_data.put_ask,
+        (OptionStyle::Put, Side::Short) => option_data.put_bid,
+    }
+    .ok_or_else(|| {
+        StrategyError::invalid_parameters(
+            "chain_leg",
+            &format!("missing {side:?} {option_style:?} quote at strike {strike}"),
+        )
+    })?;
+    let option = Options::new(
+        OptionType::European,
+        side,
+        chain.symbol.clone(),
+        strike,
+        expiration,
+        option_data.implied_volatility,
+        quantity,
+        chain.underlying_price,
+        chain.risk_free_rate.unwrap_or(Decimal::ZERO),
+        option_style,
+        chain.dividend_yield.unwrap_or(Positive::ZERO),
+        None,
+    );
+    Ok(Position::new(
+        option,
+        premium,
+        Utc::now(),
+        open_fee,
+        close_fee,
+        None,
+        None,
+    ))
+}
+
+/// Net premium collected by `positions` before fees: premiums received on
+/// short legs minus premiums paid on long legs.
+#[must_use]
+pub fn net_premium(positions: &[&Position]) -> Decimal {
+    positions
+        .iter()
+        .map(|p| {
+            let premium = (p.premium * p.option.quantity).to_dec();
+            match p.option.side {
+                Side::Long => -premium,
+                Side::Short => premium,
+            }
+        })
+        .sum()
+}
+
+/// Builds a structure for every candidate strike and keeps the one whose
+/// net premium is closest to zero.
+///
+/// Zero-cost hedges fix their protection strikes and solve for the strike of
+/// the financing leg; `build` assembles the structure for one candidate and
+/// `premium` reports its net premium. Candidates that fail to build are
+/// skipped.
+///
+/// # Errors
+///
+/// Returns `StrategyError::OperationError` if no candidate builds.
+pub fn premium_neutral<T>(
+    candidates: impl IntoIterator<Item = Positive>,
+    mut build: impl FnMut(Positive) -> Result<T, StrategyError>,
+    premium: impl Fn(&T) -> Decimal,
+) -> Result<T, StrategyError> {
+    let mut best: Option<(Decimal, T)> = None;
+    for strike in candidates {
+        let structure = match build(strike) {
+            Ok(structure) => structure,
+            Err(e) => {
+                debug!("Skipping financing strike {}: {}", strike, e);
+                continue;
+            }
+        };
+        let distance = premium(&structure).abs();
+        if best.as_ref().is_none_or(|(best, _)| distance < *best) {
+            best = Some((distance, structure));
+        }
+    }
+    best.map(|(_, structure)| structure).ok_or_else(|| {
+        StrategyError::invalid_parameters(
+            "premium_neutral",
+            "no candidate strike produces a valid structure",
+        )
+    })
+}
+
+/// Annualized, continuously compounded rate that grows `present_value`
+/// into `future_value` over `years`.
+///
+/// Financing strategies lock in a known amount at expiration, so this is the
+/// rate implied by what they cost (or raise) today.
+///
+/// # Errors
+///
+/// Returns `StrategyError::OperationError` if either value is not positive
+/// or `years` is zero.
+pub fn implied_financing_rate(
+    present_value: Decimal,
+    future_value: Decimal,
+    years: Positive,
+) -> Result<Decimal, StrategyError> {
+    if present_value <= Decimal::ZERO || future_value <= Decimal::ZERO || years == Positive::ZERO {
+        return Err(StrategyError::invalid_parameters(
+            "implied_financing_rate",
+            &format!(
+                "requires positive values and time, got {present_value} -> {future_value} over {years} years"
+            ),
+        ));
+    }
+    Ok((future_value / present_value).ln() / years.to_dec())
+}
+
+/// Early-assignment exposure of one short American option.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct EarlyAssignmentRisk {
+    /// Style of the short option.
+    pub option_style: OptionStyle,
+    /// Strike of the short option.
+    pub strike: Positive,
+    /// Contracts that could be assigned.
+    pub quantity: Positive,
+    /// Intrinsic value per unit at the current underlying price.
+    pub intrinsic_value: Decimal,
+    /// Premium per unit above the intrinsic value.
+    pub extrinsic_value: Decimal,
+    /// What the holder gains per unit by exercising now: the dividends until
+    /// expiration for calls, the interest on the strike for puts.
+    pub carry: Decimal,
+    /// Whether the option is in the money with less extrinsic value than
+    /// carry, making early exercise worthwhile for the holder.
+    pub at_risk: bool,
+}
+
+/// Lists the early-assignment exposure of the short American legs in
+/// `positions`. European and long legs cannot be assigned early and are
+/// skipped.
+///
+/// # Errors
+///
+/// Returns `PricingError` if a leg's time to expiration cannot be resolved.
+pub fn early_assignment_exposure(
+    positions: &[&Position],
+) -> Result<Vec<EarlyAssignmentRisk>, PricingError> {
+    let mut exposure = Vec::new();
+    for position in positions {
+        let option = &position.option;
+        if option.side != Side::Short || !matches!(option.option_type, OptionType::American) {
+            continue;
+        }
+        let years = option
+            .expiration_date
+            .get_years()
+            .map_err(PricingError::from)?;
+        let spot = option.underlying_price.to_dec();
+        let strike = option.strike_price.to_dec();
+        let (intrinsic_value, carry) = match option.option_style {
+            OptionStyle::Call => (
+                (spot - strike).max(Decimal::ZERO),
+                spot * (Decimal::ONE - (-option.dividend_yield.to_dec() * years.to_dec()).exp()),
+            ),
+            OptionStyle::Put => (
+                (strike - spot).max(Decimal::ZERO),
+                strike * (Decimal::ONE - (-option.risk_free_rate * years.to_dec()).exp()),
+            ),
+        };
+        let extrinsic_value = (position.premium.to_dec() - intrinsic_value).max(Decimal::ZERO);
+        exposure.push(EarlyAssignmentRisk {
+            option_style: option.option_style,
+            strike: option.strike_price,
+            quantity: option.quantity,
+            intrinsic_value,
+            extrinsic_value,
+            carry,
+            at_risk: intrinsic_value > Decimal::ZERO && extrinsic_value < carry,
+        });
+    }
+    Ok(exposure)
+}
+
 #[cfg(test)]
 mod tests_shared {
     use super::*;
@@ -360,4 +813,47 @@ mod tests_shared {
         let ratio = calculate_profit_ratio(max_profit, max_loss).unwrap();
         assert_eq!(ratio, Decimal::ZERO);
     }
+
+    #[test]
+    fn test_net_premium() {
+        use crate::model::utils::create_sample_position;
+        let mut long = create_sample_position(
+            OptionStyle::Call,
+            Side::Long,
+            Positive::HUNDRED,
+            Positive::TWO,
+            Positive::HUNDRED,
+            Positive::new(0.2).unwrap(),
+        );
+        long.premium = Positive::new(3.0).unwrap();
+        let mut short = long.clone();
+        short.option.side = Side::Short;
+        short.option.quantity = Positive::ONE;
+        short.premium = Positive::new(4.5).unwrap();
+        assert_eq!(net_premium(&[&long, &short]), dec!(-1.5));
+    }
+
+    #[test]
+    fn test_premium_neutral_skips_failed_candidates() {
+        let strikes = [90.0, 95.0, 100.0, 105.0].map(|strike| Positive::new(strike).unwrap());
+        let best = premium_neutral(
+            strikes,
+            |strike| {
+                if strike == Positive::new(100.0).unwrap() {
+                    return Err(StrategyError::invalid_parameters("test", "not quoted"));
+                }
+                Ok(strike)
+            },
+            |strike| strike.to_dec() - dec!(99.0),
+        )
+        .unwrap();
+        assert_eq!(best, Positive::new(95.0).unwrap());
+
+        let none = premium_neutral(
+            strikes,
+            |_| Err::<Positive, _>(StrategyError::invalid_parameters("test", "not quoted")),
+            |strike| strike.to_dec(),
+        );
+        assert!(none.is_err());
+    }
 }
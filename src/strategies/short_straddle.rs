@@ -715,6 +715,16 @@ impl Optimizable for ShortStraddle {
             .map(OptionDataGroup::One)
     }
 
+    fn strategy_legs<'a>(&self, group: OptionDataGroup<'a>) -> Option<StrategyLegs<'a>> {
+        match group {
+            OptionDataGroup::One(both) => Some(StrategyLegs::TwoLegs {
+                first: both,
+                second: both,
+            }),
+            _ => None,
+        }
+    }
+
     fn find_optimal(
         &mut self,
         option_chain: &OptionChain,
@@ -1287,6 +1297,26 @@ mod tests_short_straddle {
         assert!(new_strategy.validate());
     }
 
+    #[test]
+    fn test_find_pareto_optimal_uses_single_strike() {
+        use crate::strategies::multi_objective::MultiObjectiveConfig;
+
+        let strategy = setup();
+        let chain = create_test_option_chain();
+        let candidates = strategy
+            .find_pareto_optimal(
+                &chain,
+                FindOptimalSide::All,
+                &MultiObjectiveConfig::default(),
+            )
+            .unwrap();
+        assert!(!candidates.is_empty());
+        assert!(candidates.iter().all(|candidate| {
+            candidate.strategy.short_call.option.strike_price
+                == candidate.strategy.short_put.option.strike_price
+        }));
+    }
+
     fn create_test_option_chain() -> OptionChain {
         let option_data_price_params = OptionDataPriceParams::new(
             Some(Box::new(Positive::HUNDRED)),
@@ -0,0 +1,933 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # FX Delta-Space Smile Quoting
+//!
+//! FX option markets quote volatility by delta rather than by strike: an
+//! at-the-money volatility plus 25Δ (and optionally 10Δ) risk reversals and
+//! butterflies. This module converts between that delta-space quoting and
+//! the strike-space [`OptionChain`] used elsewhere in the crate.
+//!
+//! ## Conventions
+//!
+//! With `F = S · e^{(r_d − r_f) T}`, `ω = +1` for calls and `−1` for puts:
+//!
+//! ```text
+//! spot delta                  ω · e^{−r_f T} · N(ω d1)
+//! forward delta               ω · N(ω d1)
+//! spot premium-adjusted       ω · e^{−r_f T} · (K / F) · N(ω d2)
+//! forward premium-adjusted    ω · (K / F) · N(ω d2)
+//! ```
+//!
+//! The ATM strike is either the delta-neutral straddle (DNS) strike, where
+//! call and put deltas cancel, or the forward. Butterflies follow the
+//! smile-strangle convention:
+//!
+//! ```text
+//! σ(25Δ call) = ATM + BF25 + RR25 / 2
+//! σ(25Δ put)  = ATM + BF25 − RR25 / 2
+//! ```
+//!
+//! ## Vanna-Volga
+//!
+//! [`FxSmile`] places the 25Δ put, ATM and 25Δ call pillars in strike space
+//! and fills every other strike with the second-order vanna-volga
+//! approximation of Castagna and Mercurio (2007), which reproduces the three
+//! pillars exactly. When 10Δ quotes are supplied, each wing beyond the 25Δ
+//! strikes gets its own fit around the ATM volatility (10Δ put, 25Δ put and
+//! ATM below the 25Δ put; ATM, 25Δ call and 10Δ call above the 25Δ call), so
+//! all five pillars are reproduced.
+//!
+//! As in [`crate::pricing::garman_kohlhagen`], the chain's `risk_free_rate`
+//! carries the domestic rate and its `dividend_yield` the foreign rate. The
+//! foreign rate may be negative (EUR, CHF and JPY have all traded below
+//! zero); such a rate cannot be stored in the chain's `dividend_yield`, so
+//! chains built from a negative-rate market leave it unset.
+//!
+//! ```rust
+//! use optionstratlib::volatility::{FxMarket, FxSmile, FxSmileQuote};
+//! use positive::pos_or_panic;
+//! use rust_decimal_macros::dec;
+//!
+//! let market = FxMarket::new(pos_or_panic!(1.10), dec!(0.03), dec!(0.01), pos_or_panic!(0.25));
+//! let quote = FxSmileQuote::new(pos_or_panic!(0.08), dec!(-0.01), dec!(0.002));
+//! let smile = FxSmile::from_quote(market, &quote).unwrap();
+//! let strikes: Vec<_> = (90..=130).map(|k| pos_or_panic!(k as f64 / 100.0)).collect();
+//! let chain = smile.to_option_chain("EURUSD", &strikes).unwrap();
+//!
+//! let extracted = FxSmileQuote::from_chain(&chain, &market).unwrap();
+//! assert!((extracted.rr25 - quote.rr25).abs() < dec!(0.0001));
+//! ```
+
+use crate::chains::chain::OptionChain;
+use crate::error::VolatilityError;
+use crate::model::decimal::finite_decimal;
+use crate::volatility::{SmileDynamics, StickySmile};
+use crate::{ExpirationDate, OptionStyle};
+use num_traits::ToPrimitive;
+use positive::Positive;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use statrs::distribution::{ContinuousCDF, Normal};
+use utoipa::ToSchema;
+
+/// Number of bisection steps used by the strike solvers.
+const SOLVER_ITERATIONS: usize = 200;
+
+/// Half-width, in standard deviations, of the log-strike search bracket.
+const SEARCH_WIDTH: f64 = 10.0;
+
+/// How FX option deltas are quoted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+pub enum FxDeltaConvention {
+    /// Spot delta, `ω e^{−r_f T} N(ω d1)`.
+    #[default]
+    Spot,
+    /// Forward delta, `ω N(ω d1)`.
+    Forward,
+    /// Premium-adjusted spot delta, used when the premium is paid in the
+    /// foreign currency.
+    SpotPremiumAdjusted,
+    /// Premium-adjusted forward delta.
+    ForwardPremiumAdjusted,
+}
+
+impl FxDeltaConvention {
+    fn is_premium_adjusted(self) -> bool {
+        matches!(
+            self,
+            Self::SpotPremiumAdjusted | Self::ForwardPremiumAdjusted
+        )
+    }
+
+    fn is_spot(self) -> bool {
+        matches!(self, Self::Spot | Self::SpotPremiumAdjusted)
+    }
+}
+
+/// Definition of the at-the-money strike.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+pub enum FxAtmConvention {
+    /// Delta-neutral straddle: the strike where call and put deltas cancel.
+    #[default]
+    DeltaNeutralStraddle,
+    /// At-the-money forward: `K = F`.
+    Forward,
+}
+
+/// Market inputs and quoting conventions for one FX expiry.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FxMarket {
+    /// Spot FX rate, in domestic currency per unit of foreign currency.
+    pub spot: Positive,
+    /// Domestic continuously-compounded interest rate `r_d`.
+    pub domestic_rate: Decimal,
+    /// Foreign continuously-compounded interest rate `r_f`, which may be
+    /// negative.
+    pub foreign_rate: Decimal,
+    /// Time to expiry in years.
+    pub years: Positive,
+    /// Delta convention used for the quoted pillars.
+    pub delta_convention: FxDeltaConvention,
+    /// Definition of the ATM strike.
+    pub atm_convention: FxAtmConvention,
+}
+
+impl FxMarket {
+    /// Creates market inputs with spot deltas and a delta-neutral straddle
+    /// ATM, the usual convention for G10 pairs up to one year.
+    #[must_use]
+    pub fn new(
+        spot: Positive,
+        domestic_rate: Decimal,
+        foreign_rate: Decimal,
+        years: Positive,
+    ) -> Self {
+        Self {
+            spot,
+            domestic_rate,
+            foreign_rate,
+            years,
+            delta_convention: FxDeltaConvention::default(),
+            atm_convention: FxAtmConvention::default(),
+        }
+    }
+
+    /// Reads spot, rates and time to expiry from an option chain, with the
+    /// default conventions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VolatilityError::InvalidTime`] if the chain expiration
+    /// cannot be parsed.
+    pub fn from_chain(chain: &OptionChain) -> Result<Self, VolatilityError> {
+        let years = chain
+            .get_expiration()
+            .and_then(|expiration| expiration.get_years().ok())
+            .ok_or_else(|| VolatilityError::InvalidTime {
+                time: Positive::ZERO,
+                reason: format!("invalid chain expiration {}", chain.get_expiration_date()),
+            })?;
+        Ok(Self::new(
+            chain.underlying_price,
+            chain.risk_free_rate.unwrap_or(Decimal::ZERO),
+            chain.dividend_yield.map_or(Decimal::ZERO, |q| q.to_dec()),
+            years,
+        ))
+    }
+
+    /// Outright forward rate `F = S · e^{(r_d − r_f) T}`.
+    #[must_use]
+    pub fn forward(&self) -> Positive {
+        Positive::new(self.forward_f64()).unwrap_or(self.spot)
+    }
+
+    /// Delta of a strike under the market's delta convention. Calls are
+    /// positive, puts negative.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VolatilityError::InvalidTime`] for a zero time to expiry
+    /// and [`VolatilityError::NonFinite`] if the result is not finite.
+    pub fn delta(
+        &self,
+        strike: Positive,
+        volatility: Positive,
+        style: OptionStyle,
+    ) -> Result<Decimal, VolatilityError> {
+        self.check_years()?;
+        let delta = self.delta_f64(strike.to_f64(), volatility.to_f64(), style);
+        finite_decimal(delta).ok_or_else(|| VolatilityError::non_finite("fx_smile::delta", delta))
+    }
+
+    /// Strike whose delta under the market's convention equals `delta`.
+    /// Pass a positive delta for calls and a negative one for puts
+    /// (e.g. `-0.25` for the 25Δ put).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VolatilityError::OptionError`] if the delta is out of
+    /// range or not attainable (premium-adjusted call deltas are bounded
+    /// away from one).
+    pub fn strike_for_delta(
+        &self,
+        delta: Decimal,
+        volatility: Positive,
+        style: OptionStyle,
+    ) -> Result<Positive, VolatilityError> {
+        self.check_years()?;
+        let target = delta.to_f64().unwrap_or(0.0);
+        let strike = self.strike_for_delta_f64(target, volatility.to_f64(), style)?;
+        Positive::new(strike).map_err(|_| VolatilityError::non_finite("fx_smile::strike", strike))
+    }
+
+    /// ATM strike for a given ATM volatility under the market's conventions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VolatilityError::InvalidTime`] for a zero time to expiry.
+    pub fn atm_strike(&self, volatility: Positive) -> Result<Positive, VolatilityError> {
+        self.check_years()?;
+        let strike = self.atm_strike_f64(volatility.to_f64());
+        Positive::new(strike).map_err(|_| VolatilityError::non_finite("fx_smile::atm", strike))
+    }
+
+    fn check_years(&self) -> Result<(), VolatilityError> {
+        if self.years.is_zero() {
+            return Err(VolatilityError::InvalidTime {
+                time: self.years,
+                reason: "FX delta quoting needs a positive time to expiry".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn domestic_rate_f64(&self) -> f64 {
+        self.domestic_rate.to_f64().unwrap_or(0.0)
+    }
+
+    fn foreign_rate_f64(&self) -> f64 {
+        self.foreign_rate.to_f64().unwrap_or(0.0)
+    }
+
+    fn forward_f64(&self) -> f64 {
+        let t = self.years.to_f64();
+        self.spot.to_f64() * ((self.domestic_rate_f64() - self.foreign_rate_f64()) * t).exp()
+    }
+
+    fn foreign_discount(&self) -> f64 {
+        if self.delta_convention.is_spot() {
+            (-self.foreign_rate_f64() * self.years.to_f64()).exp()
+        } else {
+            1.0
+        }
+    }
+
+    fn delta_f64(&self, strike: f64, vol: f64, style: OptionStyle) -> f64 {
+        let t = self.years.to_f64();
+        let forward = self.forward_f64();
+        let sd = vol * t.sqrt();
+        let d1 = ((forward / strike).ln() + 0.5 * vol * vol * t) / sd;
+        let d2 = d1 - sd;
+        let omega = omega(style);
+        let df = self.foreign_discount();
+        if self.delta_convention.is_premium_adjusted() {
+            omega * df * strike / forward * norm_cdf(omega * d2)
+        } else {
+            omega * df * norm_cdf(omega * d1)
+        }
+    }
+
+    /// Garman–Kohlhagen premium in domestic currency, written against the
+    /// forward so that negative foreign rates are priced correctly.
+    fn price_f64(&self, strike: f64, vol: f64, style: OptionStyle) -> f64 {
+        let t = self.years.to_f64();
+        let forward = self.forward_f64();
+        let sd = vol * t.sqrt();
+        let d1 = ((forward / strike).ln() + 0.5 * vol * vol * t) / sd;
+        let d2 = d1 - sd;
+        let omega = omega(style);
+        (-self.domestic_rate_f64() * t).exp()
+            * omega
+            * (forward * norm_cdf(omega * d1) - strike * norm_cdf(omega * d2))
+    }
+
+    fn strike_for_delta_f64(
+        &self,
+        target: f64,
+        vol: f64,
+        style: OptionStyle,
+    ) -> Result<f64, VolatilityError> {
+        let omega = omega(style);
+        let magnitude = target * omega;
+        let df = self.foreign_discount();
+        if magnitude <= 0.0 || magnitude >= df {
+            return Err(VolatilityError::OptionError {
+                reason: format!("delta {target} is out of range for a {style:?}"),
+            });
+        }
+        let t = self.years.to_f64();
+        let forward = self.forward_f64();
+        let sd = vol * t.sqrt();
+        let d1 = omega * norm_inv(magnitude / df);
+        let unadjusted = forward * (-d1 * sd + 0.5 * vol * vol * t).exp();
+        if !self.delta_convention.is_premium_adjusted() {
+            return Ok(unadjusted);
+        }
+
+        let delta_at = |log_strike: f64| self.delta_f64(log_strike.exp(), vol, style);
+        let lower = (forward * (-SEARCH_WIDTH * sd).exp()).ln();
+        let upper = (forward * (SEARCH_WIDTH * sd).exp()).ln();
+        let (lower, upper) = match style {
+            // Premium-adjusted call deltas are hump-shaped in the strike;
+            // quoted deltas live on the decreasing (out-of-the-money) side,
+            // to the right of the maximum and left of the unadjusted strike.
+            OptionStyle::Call => {
+                let peak = golden_section_max(delta_at, lower, unadjusted.ln());
+                if delta_at(peak) < target {
+                    return Err(VolatilityError::OptionError {
+                        reason: format!(
+                            "premium-adjusted call delta {target} is above the attainable maximum"
+                        ),
+                    });
+                }
+                (peak, unadjusted.ln())
+            }
+            OptionStyle::Put => (lower, upper),
+        };
+        Ok(bisect(|x| delta_at(x) - target, lower, upper).exp())
+    }
+
+    fn atm_strike_f64(&self, vol: f64) -> f64 {
+        let forward = self.forward_f64();
+        let half_variance = 0.5 * vol * vol * self.years.to_f64();
+        match (
+            self.atm_convention,
+            self.delta_convention.is_premium_adjusted(),
+        ) {
+            (FxAtmConvention::Forward, _) => forward,
+            (FxAtmConvention::DeltaNeutralStraddle, false) => forward * half_variance.exp(),
+            (FxAtmConvention::DeltaNeutralStraddle, true) => forward * (-half_variance).exp(),
+        }
+    }
+}
+
+/// A delta-space smile quote: ATM volatility plus risk reversals and
+/// smile-strangle butterflies, all as decimals (0.01 = 1 vol point).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FxSmileQuote {
+    /// At-the-money volatility.
+    pub atm: Positive,
+    /// 25Δ risk reversal, `σ(25Δ call) − σ(25Δ put)`.
+    pub rr25: Decimal,
+    /// 25Δ butterfly, `(σ(25Δ call) + σ(25Δ put)) / 2 − ATM`.
+    pub bf25: Decimal,
+    /// Optional 10Δ risk reversal.
+    pub rr10: Option<Decimal>,
+    /// Optional 10Δ butterfly.
+    pub bf10: Option<Decimal>,
+}
+
+impl FxSmileQuote {
+    /// Creates a quote from the ATM volatility and the 25Δ risk reversal and
+    /// butterfly.
+    #[must_use]
+    pub fn new(atm: Positive, rr25: Decimal, bf25: Decimal) -> Self {
+        Self {
+            atm,
+            rr25,
+            bf25,
+            rr10: None,
+            bf10: None,
+        }
+    }
+
+    /// Adds 10Δ risk reversal and butterfly quotes.
+    #[must_use]
+    pub fn with_10_delta(mut self, rr10: Decimal, bf10: Decimal) -> Self {
+        self.rr10 = Some(rr10);
+        self.bf10 = Some(bf10);
+        self
+    }
+
+    /// Extracts the quote from a strike-space chain: the chain smile is
+    /// interpolated linearly in strike and solved for the ATM, 25Δ (and,
+    /// when the chain is wide enough, 10Δ) strikes under `market`'s
+    /// conventions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VolatilityError::OptionError`] if the chain has no usable
+    /// implied volatility or the 25Δ strikes fall outside its strike range.
+    pub fn from_chain(chain: &OptionChain, market: &FxMarket) -> Result<Self, VolatilityError> {
+        market.check_years()?;
+        let smile = StickySmile::from_chain(chain, SmileDynamics::StickyStrike)?;
+        let strikes: Vec<f64> = chain
+            .get_single_iter()
+            .map(|option| option.strike_price.to_f64())
+            .collect();
+        let (Some(&low), Some(&high)) = (strikes.first(), strikes.last()) else {
+            return Err(VolatilityError::OptionError {
+                reason: "chain has no strikes".to_string(),
+            });
+        };
+        let vol_at = |strike: f64| {
+            smile
+                .reference_volatility(Positive::new(strike).unwrap_or(Positive::ZERO))
+                .to_f64()
+        };
+
+        let mut atm_strike = market.forward_f64();
+        for _ in 0..SOLVER_ITERATIONS {
+            let next = market.atm_strike_f64(vol_at(atm_strike));
+            if (next - atm_strike).abs() < 1e-12 * atm_strike {
+                break;
+            }
+            atm_strike = next;
+        }
+        let atm = vol_at(atm_strike);
+
+        let wing = |delta: f64, style: OptionStyle| -> Option<f64> {
+            let gap = |log_strike: f64| {
+                let strike = log_strike.exp();
+                market.delta_f64(strike, vol_at(strike), style) - delta
+            };
+            let (a, b) = (low.ln(), high.ln());
+            if gap(a).signum() == gap(b).signum() {
+                return None;
+            }
+            Some(vol_at(bisect(gap, a, b).exp()))
+        };
+        let pair = |delta: f64| -> Option<(f64, f64)> {
+            Some((
+                wing(delta, OptionStyle::Call)?,
+                wing(-delta, OptionStyle::Put)?,
+            ))
+        };
+        let (call25, put25) = pair(0.25).ok_or_else(|| VolatilityError::OptionError {
+            reason: "25-delta strikes fall outside the chain strike range".to_string(),
+        })?;
+        let to_decimal = |value: f64| {
+            finite_decimal(value)
+                .ok_or_else(|| VolatilityError::non_finite("fx_smile::quote", value))
+        };
+        let mut quote = Self::new(
+            Positive::new(atm).map_err(|_| VolatilityError::non_finite("fx_smile::atm", atm))?,
+            to_decimal(call25 - put25)?,
+            to_decimal(0.5 * (call25 + put25) - atm)?,
+        );
+        if let Some((call10, put10)) = pair(0.10) {
+            quote = quote.with_10_delta(
+                to_decimal(call10 - put10)?,
+                to_decimal(0.5 * (call10 + put10) - atm)?,
+            );
+        }
+        Ok(quote)
+    }
+
+    fn wing_volatilities(
+        &self,
+        rr: Decimal,
+        bf: Decimal,
+    ) -> Result<(Positive, Positive), VolatilityError> {
+        let atm = self.atm.to_dec();
+        let call = atm + bf + rr / dec!(2);
+        let put = atm + bf - rr / dec!(2);
+        let to_positive = |vol: Decimal| {
+            Positive::new_decimal(vol).map_err(|_| VolatilityError::OptionError {
+                reason: format!("quote implies a negative wing volatility {vol}"),
+            })
+        };
+        Ok((to_positive(call)?, to_positive(put)?))
+    }
+}
+
+/// One delta pillar of an [`FxSmile`], placed in strike space.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FxPillar {
+    /// Quoted delta of the pillar (negative for puts), or `None` for ATM.
+    pub delta: Option<Decimal>,
+    /// Strike solved from the delta and the pillar volatility.
+    pub strike: Positive,
+    /// Pillar volatility.
+    pub volatility: Positive,
+}
+
+/// A strike-space FX smile built from delta quotes, interpolated with the
+/// vanna-volga method.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FxSmile {
+    market: FxMarket,
+    pillars: Vec<FxPillar>,
+}
+
+impl FxSmile {
+    /// Converts a delta-space quote into strike-space pillars.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VolatilityError::OptionError`] if a wing volatility is
+    /// negative or a pillar strike cannot be solved.
+    pub fn from_quote(market: FxMarket, quote: &FxSmileQuote) -> Result<Self, VolatilityError> {
+        let mut pillars = vec![FxPillar {
+            delta: None,
+            strike: market.atm_strike(quote.atm)?,
+            volatility: quote.atm,
+        }];
+        let mut wings = vec![(dec!(0.25), quote.rr25, quote.bf25)];
+        if let (Some(rr10), Some(bf10)) = (quote.rr10, quote.bf10) {
+            wings.push((dec!(0.10), rr10, bf10));
+        }
+        for (delta, rr, bf) in wings {
+            let (call_vol, put_vol) = quote.wing_volatilities(rr, bf)?;
+            pillars.push(FxPillar {
+                delta: Some(delta),
+                strike: market.strike_for_delta(delta, call_vol, OptionStyle::Call)?,
+                volatility: call_vol,
+            });
+            pillars.push(FxPillar {
+                delta: Some(-delta),
+                strike: market.strike_for_delta(-delta, put_vol, OptionStyle::Put)?,
+                volatility: put_vol,
+            });
+        }
+        pillars.sort_by_key(|p| p.strike);
+        Ok(Self { market, pillars })
+    }
+
+    /// Market inputs the smile was built with.
+    #[must_use]
+    pub fn market(&self) -> &FxMarket {
+        &self.market
+    }
+
+    /// Pillars of the smile, sorted by strike.
+    #[must_use]
+    pub fn pillars(&self) -> &[FxPillar] {
+        &self.pillars
+    }
+
+    /// Vanna-volga implied volatility at `strike`.
+    ///
+    /// With only 25Δ quotes the 25Δ put, ATM and 25Δ call pillars are used.
+    /// With 10Δ quotes too, strikes below the 25Δ put use the 10Δ put, 25Δ put
+    /// and ATM pillars, and strikes above the 25Δ call the ATM, 25Δ call and
+    /// 10Δ call pillars.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VolatilityError::OptionError`] if the 25Δ pillars are
+    /// missing and [`VolatilityError::NonFinite`] if the result is not
+    /// finite.
+    pub fn volatility(&self, strike: Positive) -> Result<Positive, VolatilityError> {
+        let pillar = |delta: Option<Decimal>| {
+            self.pillars
+                .iter()
+                .find(|p| p.delta == delta)
+                .ok_or_else(|| VolatilityError::OptionError {
+                    reason: "vanna-volga needs the 25-delta and ATM pillars".to_string(),
+                })
+        };
+        let put = pillar(Some(dec!(-0.25)))?;
+        let atm = pillar(None)?;
+        let call = pillar(Some(dec!(0.25)))?;
+        let wings = (pillar(Some(dec!(-0.10))), pillar(Some(dec!(0.10))));
+        let fit = match wings {
+            (Ok(put10), Ok(_)) if strike < put.strike => [put10, put, atm],
+            (Ok(_), Ok(call10)) if strike > call.strike => [atm, call, call10],
+            _ => [put, atm, call],
+        };
+        let vol = vanna_volga(
+            strike.to_f64(),
+            fit.map(|p| (p.strike.to_f64(), p.volatility.to_f64())),
+            atm.volatility.to_f64(),
+            self.market.forward_f64(),
+            self.market.years.to_f64(),
+        );
+        Positive::new(vol).map_err(|_| VolatilityError::non_finite("fx_smile::vanna_volga", vol))
+    }
+
+    /// Builds an [`OptionChain`] with the given strikes, pricing calls and
+    /// puts with Garman–Kohlhagen at the vanna-volga volatility of each
+    /// strike. Bid and ask are both set to the model price. The chain's
+    /// `dividend_yield` holds the foreign rate, or is left unset when that
+    /// rate is negative.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`VolatilityError`] if the expiration cannot be formatted,
+    /// a volatility cannot be computed, or pricing fails.
+    pub fn to_option_chain(
+        &self,
+        symbol: &str,
+        strikes: &[Positive],
+    ) -> Result<OptionChain, VolatilityError> {
+        let market = &self.market;
+        let expiration = ExpirationDate::Days(market.years * Positive::new_decimal(dec!(365))?);
+        let date_string =
+            expiration
+                .get_date_string()
+                .map_err(|e| VolatilityError::InvalidTime {
+                    time: market.years,
+                    reason: e.to_string(),
+                })?;
+        let mut chain = OptionChain::new(
+            symbol,
+            market.spot,
+            date_string,
+            Some(market.domestic_rate),
+            Positive::new_decimal(market.foreign_rate).ok(),
+        );
+        for &strike in strikes {
+            let volatility = self.volatility(strike)?;
+            let price = |style: OptionStyle| -> Result<Option<Positive>, VolatilityError> {
+                let value = market.price_f64(strike.to_f64(), volatility.to_f64(), style);
+                let value = finite_decimal(value)
+                    .ok_or_else(|| VolatilityError::non_finite("fx_smile::price", value))?;
+                Ok(Some(Positive::new_decimal(value.max(Decimal::ZERO))?))
+            };
+            let call = price(OptionStyle::Call)?;
+            let put = price(OptionStyle::Put)?;
+            chain.add_option(
+                strike, call, call, put, put, volatility, None, None, None, None, None, None,
+            );
+        }
+        chain.update_greeks();
+        Ok(chain)
+    }
+}
+
+fn omega(style: OptionStyle) -> f64 {
+    match style {
+        OptionStyle::Call => 1.0,
+        OptionStyle::Put => -1.0,
+    }
+}
+
+fn standard_normal() -> Option<Normal> {
+    Normal::new(0.0, 1.0).ok()
+}
+
+fn norm_cdf(x: f64) -> f64 {
+    standard_normal().map_or(f64::NAN, |n| n.cdf(x))
+}
+
+fn norm_inv(p: f64) -> f64 {
+    standard_normal().map_or(f64::NAN, |n| n.inverse_cdf(p))
+}
+
+/// Root of `f` in `[lower, upper]` by bisection; `f` must change sign.
+fn bisect<F: Fn(f64) -> f64>(f: F, mut lower: f64, mut upper: f64) -> f64 {
+    let lower_sign = f(lower).signum();
+    for _ in 0..SOLVER_ITERATIONS {
+        let mid = 0.5 * (lower + upper);
+        if f(mid).signum() == lower_sign {
+            lower = mid;
+        } else {
+            upper = mid;
+        }
+        if (upper - lower).abs() < 1e-14 {
+            break;
+        }
+    }
+    0.5 * (lower + upper)
+}
+
+/// Maximiser of a unimodal `f` in `[lower, upper]`.
+fn golden_section_max<F: Fn(f64) -> f64>(f: F, mut lower: f64, mut upper: f64) -> f64 {
+    let ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+    for _ in 0..SOLVER_ITERATIONS {
+        let left = upper - ratio * (upper - lower);
+        let right = lower + ratio * (upper - lower);
+        if f(left) < f(right) {
+            lower = left;
+        } else {
+            upper = right;
+        }
+        if (upper - lower).abs() < 1e-12 {
+            break;
+        }
+    }
+    0.5 * (lower + upper)
+}
+
+/// Second-order vanna-volga volatility (Castagna–Mercurio) at `strike`
+/// given three `(strike, volatility)` pillars, expanded around the ATM
+/// volatility `atm_vol`. Every pillar is reproduced exactly, whether or not
+/// ATM is one of them.
+fn vanna_volga(
+    strike: f64,
+    pillars: [(f64, f64); 3],
+    atm_vol: f64,
+    forward: f64,
+    years: f64,
+) -> f64 {
+    let [(k1, v1), (k2, v2), (k3, v3)] = pillars;
+    let ln = |a: f64, b: f64| (a / b).ln();
+    let y1 = ln(k2, strike) * ln(k3, strike) / (ln(k2, k1) * ln(k3, k1));
+    let y2 = ln(strike, k1) * ln(k3, strike) / (ln(k2, k1) * ln(k3, k2));
+    let y3 = ln(strike, k1) * ln(strike, k2) / (ln(k3, k1) * ln(k3, k2));
+
+    let v0 = atm_vol;
+    let sd = v0 * years.sqrt();
+    let d1d2 = |k: f64| {
+        let d1 = (ln(forward, k) + 0.5 * v0 * v0 * years) / sd;
+        d1 * (d1 - sd)
+    };
+    let first_order = y1 * v1 + y2 * v2 + y3 * v3 - v0;
+    let second_order = y1 * d1d2(k1) * (v1 - v0).powi(2)
+        + y2 * d1d2(k2) * (v2 - v0).powi(2)
+        + y3 * d1d2(k3) * (v3 - v0).powi(2);
+    let dd = d1d2(strike);
+    let radicand = v0 * v0 + dd * (2.0 * v0 * first_order + second_order);
+    if dd.abs() < 1e-10 || radicand < 0.0 {
+        return v0 + first_order;
+    }
+    v0 + (-v0 + radicand.sqrt()) / dd
+}
+
+#[cfg(test)]
+mod tests_fx_smile {
+    use super::*;
+    use positive::{assert_pos_relative_eq, pos_or_panic};
+
+    fn market() -> FxMarket {
+        FxMarket::new(
+            pos_or_panic!(1.10),
+            dec!(0.03),
+            dec!(0.01),
+            pos_or_panic!(0.5),
+        )
+    }
+
+    fn quote() -> FxSmileQuote {
+        FxSmileQuote::new(pos_or_panic!(0.09), dec!(-0.012), dec!(0.003))
+    }
+
+    #[test]
+    fn test_delta_strike_round_trip_all_conventions() {
+        for convention in [
+            FxDeltaConvention::Spot,
+            FxDeltaConvention::Forward,
+            FxDeltaConvention::SpotPremiumAdjusted,
+            FxDeltaConvention::ForwardPremiumAdjusted,
+        ] {
+            let mut market = market();
+            market.delta_convention = convention;
+            for (delta, style) in [
+                (dec!(0.25), OptionStyle::Call),
+                (dec!(-0.25), OptionStyle::Put),
+                (dec!(0.10), OptionStyle::Call),
+                (dec!(-0.10), OptionStyle::Put),
+            ] {
+                let strike = market
+                    .strike_for_delta(delta, pos_or_panic!(0.1), style)
+                    .unwrap();
+                let back = market.delta(strike, pos_or_panic!(0.1), style).unwrap();
+                assert!(
+                    (back - delta).abs() < dec!(1e-9),
+                    "{convention:?} {style:?}: {back} vs {delta}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_premium_adjusted_call_strike_is_lower() {
+        let mut market = market();
+        let unadjusted = market
+            .strike_for_delta(dec!(0.25), pos_or_panic!(0.1), OptionStyle::Call)
+            .unwrap();
+        market.delta_convention = FxDeltaConvention::SpotPremiumAdjusted;
+        let adjusted = market
+            .strike_for_delta(dec!(0.25), pos_or_panic!(0.1), OptionStyle::Call)
+            .unwrap();
+        assert!(adjusted < unadjusted);
+    }
+
+    #[test]
+    fn test_delta_out_of_range() {
+        let market = market();
+        assert!(
+            market
+                .strike_for_delta(dec!(-0.25), pos_or_panic!(0.1), OptionStyle::Call)
+                .is_err()
+        );
+        assert!(
+            market
+                .strike_for_delta(dec!(1.5), pos_or_panic!(0.1), OptionStyle::Call)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_atm_dns_straddle_is_delta_neutral() {
+        for convention in [
+            FxDeltaConvention::Spot,
+            FxDeltaConvention::SpotPremiumAdjusted,
+        ] {
+            let mut market = market();
+            market.delta_convention = convention;
+            let vol = pos_or_panic!(0.12);
+            let strike = market.atm_strike(vol).unwrap();
+            let call = market.delta(strike, vol, OptionStyle::Call).unwrap();
+            let put = market.delta(strike, vol, OptionStyle::Put).unwrap();
+            assert!((call + put).abs() < dec!(1e-9), "{convention:?}");
+        }
+        let mut market = market();
+        market.atm_convention = FxAtmConvention::Forward;
+        assert_eq!(
+            market.atm_strike(pos_or_panic!(0.12)).unwrap(),
+            market.forward()
+        );
+    }
+
+    #[test]
+    fn test_wing_volatilities_from_rr_bf() {
+        let quote = quote();
+        let (call, put) = quote.wing_volatilities(quote.rr25, quote.bf25).unwrap();
+        assert_eq!(call, pos_or_panic!(0.087));
+        assert_eq!(put, pos_or_panic!(0.099));
+    }
+
+    #[test]
+    fn test_vanna_volga_reproduces_pillars() {
+        let smile = FxSmile::from_quote(market(), &quote()).unwrap();
+        for pillar in smile.pillars() {
+            let vol = smile.volatility(pillar.strike).unwrap();
+            assert_pos_relative_eq!(vol, pillar.volatility, pos_or_panic!(1e-9));
+        }
+    }
+
+    #[test]
+    fn test_vanna_volga_reproduces_10_delta_pillars() {
+        let quote = quote().with_10_delta(dec!(-0.022), dec!(0.009));
+        let smile = FxSmile::from_quote(market(), &quote).unwrap();
+        assert_eq!(smile.pillars().len(), 5);
+        for pillar in smile.pillars() {
+            let vol = smile.volatility(pillar.strike).unwrap();
+            assert_pos_relative_eq!(vol, pillar.volatility, pos_or_panic!(1e-9));
+        }
+    }
+
+    #[test]
+    fn test_vanna_volga_smile_shape() {
+        // A negative risk reversal puts the highest volatility on the put wing.
+        let smile = FxSmile::from_quote(market(), &quote()).unwrap();
+        let forward = smile.market().forward();
+        let low = smile.volatility(forward * pos_or_panic!(0.85)).unwrap();
+        let high = smile.volatility(forward * pos_or_panic!(1.15)).unwrap();
+        assert!(low > high);
+    }
+
+    #[test]
+    fn test_chain_round_trip() {
+        let quote = quote().with_10_delta(dec!(-0.022), dec!(0.009));
+        let market = market();
+        let smile = FxSmile::from_quote(market, &quote).unwrap();
+        assert_eq!(smile.pillars().len(), 5);
+        let forward = market.forward().to_f64();
+        let strikes: Vec<Positive> = (0..=120)
+            .map(|i| pos_or_panic!(forward * (0.8 + 0.4 * i as f64 / 120.0)))
+            .collect();
+        let chain = smile.to_option_chain("EURUSD", &strikes).unwrap();
+        assert_eq!(chain.options.len(), strikes.len());
+
+        let extracted = FxSmileQuote::from_chain(&chain, &market).unwrap();
+        assert_pos_relative_eq!(extracted.atm, quote.atm, pos_or_panic!(1e-4));
+        assert!((extracted.rr25 - quote.rr25).abs() < dec!(0.0002));
+        assert!((extracted.bf25 - quote.bf25).abs() < dec!(0.0002));
+        assert!((extracted.rr10.unwrap() - quote.rr10.unwrap()).abs() < dec!(0.0005));
+        assert!((extracted.bf10.unwrap() - quote.bf10.unwrap()).abs() < dec!(0.0005));
+    }
+
+    #[test]
+    fn test_negative_foreign_rate() {
+        let market = FxMarket::new(
+            pos_or_panic!(0.95),
+            dec!(0.02),
+            dec!(-0.0075),
+            pos_or_panic!(0.5),
+        );
+        assert!(market.forward() > market.spot);
+
+        let smile = FxSmile::from_quote(market, &quote()).unwrap();
+        let forward = market.forward().to_f64();
+        let strikes: Vec<Positive> = (0..=120)
+            .map(|i| pos_or_panic!(forward * (0.8 + 0.4 * i as f64 / 120.0)))
+            .collect();
+        let chain = smile.to_option_chain("EURCHF", &strikes).unwrap();
+        assert!(chain.dividend_yield.is_none());
+
+        let t = market.years.to_f64();
+        let discount = (-0.02 * t).exp();
+        for option in &chain.options {
+            let call = option.call_bid.unwrap().to_f64();
+            let put = option.put_bid.unwrap().to_f64();
+            let parity = discount * (forward - option.strike_price.to_f64());
+            assert!((call - put - parity).abs() < 1e-9);
+        }
+
+        let extracted = FxSmileQuote::from_chain(&chain, &market).unwrap();
+        assert_pos_relative_eq!(extracted.atm, quote().atm, pos_or_panic!(1e-4));
+        assert!((extracted.rr25 - quote().rr25).abs() < dec!(0.0002));
+        assert!((extracted.bf25 - quote().bf25).abs() < dec!(0.0002));
+    }
+
+    #[test]
+    fn test_from_chain_requires_wide_enough_chain() {
+        let smile = FxSmile::from_quote(market(), &quote()).unwrap();
+        let atm = smile
+            .pillars()
+            .iter()
+            .find(|p| p.delta.is_none())
+            .unwrap()
+            .strike;
+        let chain = smile.to_option_chain("EURUSD", &[atm]).unwrap();
+        assert!(FxSmileQuote::from_chain(&chain, &market()).is_err());
+    }
+}
@@ -0,0 +1,1317 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Heston Pricing and Calibration
+//!
+//! Semi-analytic European pricing under the Heston (1993) stochastic
+//! volatility model and a calibrator that fits the five Heston parameters
+//! (`kappa`, `theta`, `xi`, `rho`, `v0`) to the quotes of an
+//! [`OptionChain`] or an [`OptionSeries`].
+//!
+//! ## Pricing
+//!
+//! Prices are obtained with the Lewis (2000) single-integral formula using
+//! the "little trap" characteristic function popularised by Gatheral, which
+//! avoids the branch-cut discontinuity of the original Heston formulation:
+//!
+//! ```text
+//! C = S e^(-qT) - sqrt(S K) e^(-(r+q)T/2) / π ∫ Re[e^(iuk) φ_T(u - i/2)] / (u² + 1/4) du
+//! k = ln(S / K) + (r - q) T
+//! ```
+//!
+//! Puts follow from put–call parity.
+//!
+//! ## Calibration
+//!
+//! [`HestonCalibrator`] selects out-of-the-money mid quotes (calls at or
+//! above spot, puts below) and minimises either a vega-weighted price error
+//! or an implied-volatility error with a bounded Nelder–Mead search. The
+//! Feller condition `2κθ ≥ ξ²` can optionally be enforced by construction.
+//! The returned [`HestonCalibrationResult`] carries one [`HestonResidual`]
+//! per quote so the strikes where the model fails are visible.
+//!
+//! ## Example
+//!
+//! ```rust
+//! # fn run() -> Result<(), optionstratlib::error::Error> {
+//! use optionstratlib::chains::chain::OptionChain;
+//! use optionstratlib::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
+//! use optionstratlib::volatility::{HestonCalibrationConfig, HestonCalibrator};
+//! use optionstratlib::ExpirationDate;
+//! use positive::{pos_or_panic, spos, Positive};
+//! use rust_decimal_macros::dec;
+//! use tracing::info;
+//!
+//! let price_params = OptionDataPriceParams::new(
+//!     Some(Box::new(Positive::HUNDRED)),
+//!     Some(ExpirationDate::Days(pos_or_panic!(30.0))),
+//!     Some(dec!(0.05)),
+//!     spos!(0.0),
+//!     Some("SPY".to_string()),
+//! );
+//! let params = OptionChainBuildParams::new(
+//!     "SPY".to_string(), spos!(1000.0), 10, spos!(5.0), dec!(-0.2), dec!(0.1),
+//!     pos_or_panic!(0.02), 2, price_params, pos_or_panic!(0.2),
+//! );
+//! let chain = OptionChain::build_chain(&params)?;
+//!
+//! let config = HestonCalibrationConfig { max_iterations: 100, ..Default::default() };
+//! let result = HestonCalibrator::new(config).calibrate_chain(&chain)?;
+//! for residual in result.worst_residuals(3) {
+//!     info!("{} {:?}: {}", residual.strike, residual.option_style, residual.price_error);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::chains::chain::OptionChain;
+use crate::error::VolatilityError;
+use crate::greeks::vega;
+use crate::model::decimal::finite_decimal;
+use crate::series::OptionSeries;
+use crate::simulation::WalkType;
+use crate::volatility::utils::implied_volatility;
+use crate::{ExpirationDate, OptionStyle, OptionType, Options, Side};
+use num_traits::ToPrimitive;
+use positive::Positive;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+use std::ops::{Add, Div, Mul, Sub};
+use tracing::{debug, instrument};
+
+/// Parameters of the Heston stochastic volatility model.
+///
+/// The variance process follows `dv = κ(θ - v)dt + ξ√v dW₂` and is
+/// correlated with the underlying through `dW₁ dW₂ = ρ dt`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HestonParams {
+    /// Mean reversion speed of the variance (`κ`).
+    pub kappa: Positive,
+    /// Long-run variance level (`θ`).
+    pub theta: Positive,
+    /// Volatility of variance (`ξ`).
+    pub xi: Positive,
+    /// Correlation between the underlying and its variance (`ρ`), in `[-1, 1]`.
+    pub rho: Decimal,
+    /// Initial (spot) variance (`v₀`).
+    pub v0: Positive,
+}
+
+impl HestonParams {
+    /// Creates a new set of Heston parameters.
+    #[must_use]
+    pub fn new(kappa: Positive, theta: Positive, xi: Positive, rho: Decimal, v0: Positive) -> Self {
+        Self {
+            kappa,
+            theta,
+            xi,
+            rho,
+            v0,
+        }
+    }
+
+    /// Returns `true` when the Feller condition `2κθ ≥ ξ²` holds, i.e. the
+    /// variance process cannot reach zero.
+    #[must_use]
+    pub fn satisfies_feller(&self) -> bool {
+        Decimal::TWO * self.kappa.to_dec() * self.theta.to_dec()
+            >= self.xi.to_dec() * self.xi.to_dec()
+    }
+
+    /// Builds a [`WalkType::Heston`] random-walk description from the
+    /// calibrated parameters, so the fitted dynamics can drive simulations.
+    ///
+    /// `WalkType::Heston` expects an initial *volatility*, so `v₀` is
+    /// converted with a square root.
+    #[must_use]
+    pub fn to_walk_type(&self, dt: Positive, drift: Decimal) -> WalkType {
+        WalkType::Heston {
+            dt,
+            drift,
+            volatility: self.v0.sqrt(),
+            kappa: self.kappa,
+            theta: self.theta,
+            xi: self.xi,
+            rho: self.rho,
+        }
+    }
+
+    fn to_f64_array(self) -> [f64; 5] {
+        [
+            self.kappa.to_f64(),
+            self.theta.to_f64(),
+            self.xi.to_f64(),
+            self.rho.to_f64().unwrap_or(0.0),
+            self.v0.to_f64(),
+        ]
+    }
+
+    fn from_f64_array(values: [f64; 5]) -> Result<Self, VolatilityError> {
+        let [kappa, theta, xi, rho, v0] = values;
+        let rho =
+            finite_decimal(rho).ok_or_else(|| VolatilityError::non_finite("heston::rho", rho))?;
+        Ok(Self {
+            kappa: Positive::new(kappa)?,
+            theta: Positive::new(theta)?,
+            xi: Positive::new(xi)?,
+            rho,
+            v0: Positive::new(v0)?,
+        })
+    }
+}
+
+impl Default for HestonParams {
+    fn default() -> Self {
+        Self {
+            kappa: Positive::TWO,
+            theta: Positive::new_decimal(dec!(0.04)).unwrap_or(Positive::ZERO),
+            xi: Positive::new_decimal(dec!(0.5)).unwrap_or(Positive::ZERO),
+            rho: dec!(-0.7),
+            v0: Positive::new_decimal(dec!(0.04)).unwrap_or(Positive::ZERO),
+        }
+    }
+}
+
+/// Prices a European option under the Heston model.
+///
+/// # Arguments
+///
+/// * `params` - Heston model parameters.
+/// * `underlying_price` - Spot price of the underlying.
+/// * `strike` - Strike price of the option.
+/// * `years` - Time to expiration as a year fraction.
+/// * `risk_free_rate` - Continuously compounded risk-free rate.
+/// * `dividend_yield` - Continuously compounded dividend yield.
+/// * `option_style` - Call or put.
+///
+/// # Errors
+///
+/// Returns [`VolatilityError::InvalidPrice`] / [`VolatilityError::InvalidTime`]
+/// for zero spot, strike or maturity, and [`VolatilityError::NonFinite`] when
+/// the numerical integration produces a non-finite value.
+#[instrument(skip(params), fields(strike = %strike, years = %years))]
+pub fn heston_price(
+    params: &HestonParams,
+    underlying_price: Positive,
+    strike: Positive,
+    years: Positive,
+    risk_free_rate: Decimal,
+    dividend_yield: Positive,
+    option_style: OptionStyle,
+) -> Result<Positive, VolatilityError> {
+    if underlying_price.is_zero() {
+        return Err(VolatilityError::InvalidPrice {
+            price: underlying_price,
+            reason: "underlying price must be greater than zero".to_string(),
+        });
+    }
+    if strike.is_zero() {
+        return Err(VolatilityError::InvalidPrice {
+            price: strike,
+            reason: "strike must be greater than zero".to_string(),
+        });
+    }
+    if years.is_zero() {
+        return Err(VolatilityError::InvalidTime {
+            time: years,
+            reason: "time to expiration must be greater than zero".to_string(),
+        });
+    }
+    let slice = HestonSlice::new(
+        &params.to_f64_array(),
+        underlying_price.to_f64(),
+        years.to_f64(),
+        risk_free_rate.to_f64().unwrap_or(0.0),
+        dividend_yield.to_f64(),
+    );
+    let price = slice.price(strike.to_f64(), option_style);
+    let price =
+        finite_decimal(price).ok_or_else(|| VolatilityError::non_finite("heston::price", price))?;
+    Ok(Positive::new_decimal(price.max(Decimal::ZERO))?)
+}
+
+/// Error measure minimised by [`HestonCalibrator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HestonObjective {
+    /// Squared price errors weighted by each quote's Black–Scholes vega, so
+    /// liquid near-the-money strikes dominate the fit.
+    #[default]
+    VegaWeightedPrice,
+    /// Squared implied-volatility errors, approximated to first order as
+    /// `price error / vega`.
+    ImpliedVolatility,
+}
+
+/// Box constraints applied to each Heston parameter during calibration.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HestonBounds {
+    /// Lower and upper bound for `kappa`.
+    pub kappa: (Positive, Positive),
+    /// Lower and upper bound for `theta`.
+    pub theta: (Positive, Positive),
+    /// Lower and upper bound for `xi`.
+    pub xi: (Positive, Positive),
+    /// Lower and upper bound for `rho`.
+    pub rho: (Decimal, Decimal),
+    /// Lower and upper bound for `v0`.
+    pub v0: (Positive, Positive),
+}
+
+impl HestonBounds {
+    fn lower(&self) -> [f64; 5] {
+        [
+            self.kappa.0.to_f64(),
+            self.theta.0.to_f64(),
+            self.xi.0.to_f64(),
+            self.rho.0.to_f64().unwrap_or(-1.0),
+            self.v0.0.to_f64(),
+        ]
+    }
+
+    fn upper(&self) -> [f64; 5] {
+        [
+            self.kappa.1.to_f64(),
+            self.theta.1.to_f64(),
+            self.xi.1.to_f64(),
+            self.rho.1.to_f64().unwrap_or(1.0),
+            self.v0.1.to_f64(),
+        ]
+    }
+
+    fn validate(&self) -> Result<(), VolatilityError> {
+        let lower = self.lower();
+        let upper = self.upper();
+        if lower.iter().zip(upper.iter()).any(|(lo, hi)| lo >= hi) {
+            return Err(VolatilityError::CalibrationError {
+                reason: "every lower bound must be strictly below its upper bound".to_string(),
+            });
+        }
+        if self.rho.0 < Decimal::NEGATIVE_ONE || self.rho.1 > Decimal::ONE {
+            return Err(VolatilityError::CalibrationError {
+                reason: "rho bounds must lie within [-1, 1]".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Default for HestonBounds {
+    fn default() -> Self {
+        let p = |value: Decimal| Positive::new_decimal(value).unwrap_or(Positive::ZERO);
+        Self {
+            kappa: (p(dec!(0.01)), p(dec!(10.0))),
+            theta: (p(dec!(0.0001)), p(dec!(2.0))),
+            xi: (p(dec!(0.01)), p(dec!(3.0))),
+            rho: (dec!(-0.999), dec!(0.999)),
+            v0: (p(dec!(0.0001)), p(dec!(2.0))),
+        }
+    }
+}
+
+/// Configuration of a Heston calibration run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HestonCalibrationConfig {
+    /// Error measure to minimise.
+    pub objective: HestonObjective,
+    /// Box constraints on the parameters.
+    pub bounds: HestonBounds,
+    /// When `true`, `xi` is capped at `sqrt(2κθ)` so every candidate
+    /// satisfies the Feller condition.
+    pub enforce_feller: bool,
+    /// Maximum number of Nelder–Mead iterations.
+    pub max_iterations: usize,
+    /// Convergence tolerance on the spread of objective values in the simplex.
+    pub tolerance: Decimal,
+    /// Starting point. When `None`, `v0` and `theta` are seeded from the ATM
+    /// implied volatility and the remaining parameters from
+    /// [`HestonParams::default`].
+    pub initial_guess: Option<HestonParams>,
+}
+
+impl Default for HestonCalibrationConfig {
+    fn default() -> Self {
+        Self {
+            objective: HestonObjective::default(),
+            bounds: HestonBounds::default(),
+            enforce_feller: false,
+            max_iterations: 500,
+            tolerance: dec!(1e-10),
+            initial_guess: None,
+        }
+    }
+}
+
+/// Fit error of the calibrated model on a single market quote.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HestonResidual {
+    /// Expiration of the quote.
+    pub expiration: ExpirationDate,
+    /// Strike price of the quote.
+    pub strike: Positive,
+    /// Side of the quote used in the fit (OTM call or OTM put).
+    pub option_style: OptionStyle,
+    /// Observed mid price.
+    pub market_price: Positive,
+    /// Heston model price with the calibrated parameters.
+    pub model_price: Positive,
+    /// `model_price - market_price`.
+    pub price_error: Decimal,
+    /// Implied volatility quoted in the chain.
+    pub market_iv: Positive,
+    /// Black–Scholes implied volatility of the model price, when it can be
+    /// recovered.
+    pub model_iv: Option<Positive>,
+    /// `model_iv - market_iv`, when `model_iv` is available.
+    pub iv_error: Option<Decimal>,
+}
+
+/// Outcome of a Heston calibration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HestonCalibrationResult {
+    /// Best-fit parameters.
+    pub params: HestonParams,
+    /// Root mean square error in the units of the chosen objective (price
+    /// units for [`HestonObjective::VegaWeightedPrice`], volatility points for
+    /// [`HestonObjective::ImpliedVolatility`]).
+    pub rmse: Decimal,
+    /// Per-quote fit residuals, ordered by expiration then strike.
+    pub residuals: Vec<HestonResidual>,
+    /// Number of Nelder–Mead iterations performed.
+    pub iterations: usize,
+    /// Whether the simplex converged within the configured tolerance.
+    pub converged: bool,
+}
+
+impl HestonCalibrationResult {
+    /// Returns the `n` residuals with the largest absolute price error,
+    /// largest first.
+    #[must_use]
+    pub fn worst_residuals(&self, n: usize) -> Vec<&HestonResidual> {
+        let mut sorted: Vec<&HestonResidual> = self.residuals.iter().collect();
+        sorted.sort_by_key(|r| std::cmp::Reverse(r.price_error.abs()));
+        sorted.truncate(n);
+        sorted
+    }
+}
+
+/// Fits Heston parameters to option market quotes.
+#[derive(Debug, Clone, Default)]
+pub struct HestonCalibrator {
+    /// Calibration settings.
+    pub config: HestonCalibrationConfig,
+}
+
+impl HestonCalibrator {
+    /// Creates a calibrator with the given configuration.
+    #[must_use]
+    pub fn new(config: HestonCalibrationConfig) -> Self {
+        Self { config }
+    }
+
+    /// Calibrates the model to a single option chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VolatilityError::CalibrationError`] when the bounds are
+    /// inconsistent, the chain has no parseable expiration, or no usable
+    /// OTM mid quote is found; propagates numerical failures from pricing.
+    #[instrument(skip(self, chain), fields(symbol = %chain.symbol))]
+    pub fn calibrate_chain(
+        &self,
+        chain: &OptionChain,
+    ) -> Result<HestonCalibrationResult, VolatilityError> {
+        let expiration =
+            chain
+                .get_expiration()
+                .ok_or_else(|| VolatilityError::CalibrationError {
+                    reason: format!("cannot parse expiration date of chain {}", chain.symbol),
+                })?;
+        let slice = QuoteSlice::from_chain(chain, expiration)?;
+        let atm_iv = chain.get_atm_implied_volatility().ok().copied();
+        self.calibrate_slices(vec![slice], atm_iv)
+    }
+
+    /// Calibrates a single parameter set jointly across every expiration of
+    /// an option series.
+    ///
+    /// # Errors
+    ///
+    /// Same conditions as [`HestonCalibrator::calibrate_chain`], evaluated
+    /// over all chains of the series.
+    #[instrument(skip(self, series), fields(symbol = %series.symbol))]
+    pub fn calibrate_series(
+        &self,
+        series: &OptionSeries,
+    ) -> Result<HestonCalibrationResult, VolatilityError> {
+        let slices = series
+            .chains
+            .iter()
+            .map(|(expiration, chain)| QuoteSlice::from_chain(chain, *expiration))
+            .collect::<Result<Vec<_>, _>>()?;
+        let atm_iv = series
+            .chains
+            .values()
+            .next()
+            .and_then(|chain| chain.get_atm_implied_volatility().ok().copied());
+        self.calibrate_slices(slices, atm_iv)
+    }
+
+    fn calibrate_slices(
+        &self,
+        slices: Vec<QuoteSlice>,
+        atm_iv: Option<Positive>,
+    ) -> Result<HestonCalibrationResult, VolatilityError> {
+        self.config.bounds.validate()?;
+        let slices: Vec<QuoteSlice> = slices
+            .into_iter()
+            .filter(|s| !s.quotes.is_empty())
+            .collect();
+        if slices.is_empty() {
+            return Err(VolatilityError::CalibrationError {
+                reason: "no usable out-of-the-money mid quotes to calibrate against".to_string(),
+            });
+        }
+
+        let lower = self.config.bounds.lower();
+        let upper = self.config.bounds.upper();
+        let enforce_feller = self.config.enforce_feller;
+
+        let start = match self.config.initial_guess {
+            Some(guess) => guess,
+            None => {
+                let mut guess = HestonParams::default();
+                if let Some(iv) = atm_iv.filter(|iv| !iv.is_zero()) {
+                    guess.v0 = iv * iv;
+                    guess.theta = iv * iv;
+                }
+                guess
+            }
+        };
+        let x0 = to_unconstrained(&start.to_f64_array(), &lower, &upper);
+
+        let objective = self.config.objective;
+        let evaluate = |y: &[f64; 5]| -> f64 {
+            let params = to_constrained(y, &lower, &upper, enforce_feller);
+            objective_value(&slices, &params, objective)
+        };
+        let tolerance = self.config.tolerance.to_f64().unwrap_or(1e-10);
+        let outcome = nelder_mead(evaluate, x0, self.config.max_iterations, tolerance);
+        debug!(
+            iterations = outcome.iterations,
+            converged = outcome.converged,
+            objective = outcome.value,
+            "heston calibration finished"
+        );
+
+        let best = to_constrained(&outcome.point, &lower, &upper, enforce_feller);
+        let params = HestonParams::from_f64_array(best)?;
+        let residuals = build_residuals(&slices, &best)?;
+        let rmse = outcome.value.max(0.0).sqrt();
+        let rmse = finite_decimal(rmse)
+            .ok_or_else(|| VolatilityError::non_finite("heston::rmse", rmse))?;
+
+        Ok(HestonCalibrationResult {
+            params,
+            rmse,
+            residuals,
+            iterations: outcome.iterations,
+            converged: outcome.converged,
+        })
+    }
+}
+
+/// A market quote prepared for the calibration loop.
+#[derive(Debug, Clone)]
+struct CalibrationQuote {
+    strike: f64,
+    option_style: OptionStyle,
+    market_price: f64,
+    market_iv: f64,
+    /// Black–Scholes vega per unit of volatility.
+    vega: f64,
+}
+
+/// All quotes sharing one expiration and its market inputs.
+#[derive(Debug, Clone)]
+struct QuoteSlice {
+    symbol: String,
+    expiration: ExpirationDate,
+    spot: f64,
+    years: f64,
+    rate: f64,
+    dividend: f64,
+    quotes: Vec<CalibrationQuote>,
+}
+
+impl QuoteSlice {
+    fn from_chain(
+        chain: &OptionChain,
+        expiration: ExpirationDate,
+    ) -> Result<Self, VolatilityError> {
+        let years = expiration
+            .get_years()
+            .map_err(|e| VolatilityError::CalibrationError {
+                reason: format!("invalid expiration for chain {}: {e}", chain.symbol),
+            })?;
+        let risk_free_rate = chain.risk_free_rate.unwrap_or(Decimal::ZERO);
+        let dividend_yield = chain.dividend_yield.unwrap_or(Positive::ZERO);
+
+        let mut quotes = Vec::new();
+        for option_data in chain.options.iter() {
+            let (style, mid) = if option_data.strike_price >= chain.underlying_price {
+                (OptionStyle::Call, option_data.call_middle)
+            } else {
+                (OptionStyle::Put, option_data.put_middle)
+            };
+            let Some(mid) = mid.filter(|m| !m.is_zero()) else {
+                continue;
+            };
+            if option_data.implied_volatility.is_zero() || years.is_zero() {
+                continue;
+            }
+            let option = Options::new(
+                OptionType::European,
+                Side::Long,
+                chain.symbol.clone(),
+                option_data.strike_price,
+                ExpirationDate::Days(years * Positive::new_decimal(dec!(365))?),
+                option_data.implied_volatility,
+                Positive::ONE,
+                chain.underlying_price,
+                risk_free_rate,
+                style,
+                dividend_yield,
+                None,
+            );
+            let quote_vega = match vega(&option) {
+                Ok(v) => v.to_f64().unwrap_or(0.0) * 100.0,
+                Err(e) => {
+                    debug!(
+                        "skipping strike {}: vega failed: {}",
+                        option_data.strike_price, e
+                    );
+                    continue;
+                }
+            };
+            if quote_vega <= f64::EPSILON {
+                continue;
+            }
+            quotes.push(CalibrationQuote {
+                strike: option_data.strike_price.to_f64(),
+                option_style: style,
+                market_price: mid.to_f64(),
+                market_iv: option_data.implied_volatility.to_f64(),
+                vega: quote_vega,
+            });
+        }
+
+        Ok(Self {
+            symbol: chain.symbol.clone(),
+            expiration,
+            spot: chain.underlying_price.to_f64(),
+            years: years.to_f64(),
+            rate: risk_free_rate.to_f64().unwrap_or(0.0),
+            dividend: dividend_yield.to_f64(),
+            quotes,
+        })
+    }
+}
+
+fn objective_value(slices: &[QuoteSlice], params: &[f64; 5], objective: HestonObjective) -> f64 {
+    let mut weighted_sum = 0.0;
+    let mut weight_total = 0.0;
+    for slice in slices {
+        let heston = HestonSlice::new(params, slice.spot, slice.years, slice.rate, slice.dividend);
+        for quote in &slice.quotes {
+            let error = heston.price(quote.strike, quote.option_style) - quote.market_price;
+            let (term, weight) = match objective {
+                HestonObjective::VegaWeightedPrice => (error * error * quote.vega, quote.vega),
+                HestonObjective::ImpliedVolatility => {
+                    let iv_error = error / quote.vega;
+                    (iv_error * iv_error, 1.0)
+                }
+            };
+            weighted_sum += term;
+            weight_total += weight;
+        }
+    }
+    let value = weighted_sum / weight_total;
+    if value.is_finite() { value } else { f64::MAX }
+}
+
+fn build_residuals(
+    slices: &[QuoteSlice],
+    params: &[f64; 5],
+) -> Result<Vec<HestonResidual>, VolatilityError> {
+    let mut residuals = Vec::new();
+    for slice in slices {
+        let heston = HestonSlice::new(params, slice.spot, slice.years, slice.rate, slice.dividend);
+        for quote in &slice.quotes {
+            let model = heston.price(quote.strike, quote.option_style).max(0.0);
+            let model_price = Positive::new_decimal(
+                finite_decimal(model)
+                    .ok_or_else(|| VolatilityError::non_finite("heston::residual", model))?,
+            )?;
+            let market_price = Positive::new(quote.market_price)?;
+            let market_iv = Positive::new(quote.market_iv)?;
+            let strike = Positive::new(quote.strike)?;
+
+            let mut option = Options::new(
+                OptionType::European,
+                Side::Long,
+                slice.symbol.clone(),
+                strike,
+                ExpirationDate::Days(Positive::new(slice.years * 365.0)?),
+                market_iv,
+                Positive::ONE,
+                Positive::new(slice.spot)?,
+                finite_decimal(slice.rate).unwrap_or(Decimal::ZERO),
+                quote.option_style,
+                Positive::new(slice.dividend)?,
+                None,
+            );
+            let model_iv = if model_price.is_zero() {
+                None
+            } else {
+                implied_volatility(model_price, &mut option, 10).ok()
+            };
+
+            residuals.push(HestonResidual {
+                expiration: slice.expiration,
+                strike,
+                option_style: quote.option_style,
+                market_price,
+                model_price,
+                price_error: model_price.to_dec() - market_price.to_dec(),
+                market_iv,
+                model_iv,
+                iv_error: model_iv.map(|iv| iv.to_dec() - market_iv.to_dec()),
+            });
+        }
+    }
+    Ok(residuals)
+}
+
+/// Maps bounded parameters into the unconstrained optimisation space.
+fn to_unconstrained(x: &[f64; 5], lower: &[f64; 5], upper: &[f64; 5]) -> [f64; 5] {
+    let mut y = [0.0; 5];
+    for (((yi, xi), lo), hi) in y.iter_mut().zip(x).zip(lower).zip(upper) {
+        let width = hi - lo;
+        let fraction = ((xi - lo) / width).clamp(1e-6, 1.0 - 1e-6);
+        *yi = (fraction / (1.0 - fraction)).ln();
+    }
+    y
+}
+
+/// Maps an unconstrained point back into the parameter box with a logistic
+/// transform, optionally capping `xi` at the Feller limit.
+fn to_constrained(
+    y: &[f64; 5],
+    lower: &[f64; 5],
+    upper: &[f64; 5],
+    enforce_feller: bool,
+) -> [f64; 5] {
+    let mut x = [0.0; 5];
+    for (((xi, yi), lo), hi) in x.iter_mut().zip(y).zip(lower).zip(upper) {
+        *xi = lo + (hi - lo) / (1.0 + (-yi).exp());
+    }
+    if enforce_feller {
+        let [kappa, theta, xi, rho, v0] = x;
+        let [_, _, xi_lo, _, _] = *lower;
+        let [_, _, xi_hi, _, _] = *upper;
+        let feller = (2.0 * kappa * theta).sqrt();
+        let capped = if feller <= xi_lo {
+            feller
+        } else {
+            let fraction = (xi - xi_lo) / (xi_hi - xi_lo);
+            xi_lo + (xi_hi.min(feller) - xi_lo) * fraction
+        };
+        x = [kappa, theta, capped, rho, v0];
+    }
+    x
+}
+
+struct NelderMeadOutcome {
+    point: [f64; 5],
+    value: f64,
+    iterations: usize,
+    converged: bool,
+}
+
+/// Minimal Nelder–Mead simplex minimiser over five dimensions.
+fn nelder_mead<F>(f: F, start: [f64; 5], max_iterations: usize, tolerance: f64) -> NelderMeadOutcome
+where
+    F: Fn(&[f64; 5]) -> f64,
+{
+    const STEP: f64 = 0.5;
+    let mut simplex: Vec<([f64; 5], f64)> = Vec::with_capacity(6);
+    simplex.push((start, f(&start)));
+    for dim in 0..5 {
+        let mut vertex = start;
+        if let Some(v) = vertex.get_mut(dim) {
+            *v += STEP;
+        }
+        simplex.push((vertex, f(&vertex)));
+    }
+
+    let combine = |a: &[f64; 5], b: &[f64; 5], t: f64| -> [f64; 5] {
+        let mut out = [0.0; 5];
+        for ((o, ai), bi) in out.iter_mut().zip(a).zip(b) {
+            *o = ai + t * (bi - ai);
+        }
+        out
+    };
+
+    let mut iterations = 0;
+    let mut converged = false;
+    while iterations < max_iterations {
+        simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+        let (Some(&(best_point, best)), Some(&(worst_point, worst))) =
+            (simplex.first(), simplex.last())
+        else {
+            break;
+        };
+        if (worst - best).abs() <= tolerance * (best.abs() + tolerance) {
+            converged = true;
+            break;
+        }
+        iterations += 1;
+
+        let Some((_, kept)) = simplex.split_last() else {
+            break;
+        };
+        let mut centroid = [0.0; 5];
+        for (point, _) in kept {
+            for (c, p) in centroid.iter_mut().zip(point) {
+                *c += p / kept.len() as f64;
+            }
+        }
+        let second_worst = kept.last().map_or(best, |v| v.1);
+
+        let reflected = combine(&centroid, &worst_point, -1.0);
+        let reflected_value = f(&reflected);
+        let replacement = if reflected_value < best {
+            let expanded = combine(&centroid, &worst_point, -2.0);
+            let expanded_value = f(&expanded);
+            if expanded_value < reflected_value {
+                Some((expanded, expanded_value))
+            } else {
+                Some((reflected, reflected_value))
+            }
+        } else if reflected_value < second_worst {
+            Some((reflected, reflected_value))
+        } else {
+            let contracted = if reflected_value < worst {
+                combine(&centroid, &reflected, 0.5)
+            } else {
+                combine(&centroid, &worst_point, 0.5)
+            };
+            let contracted_value = f(&contracted);
+            if contracted_value < worst.min(reflected_value) {
+                Some((contracted, contracted_value))
+            } else {
+                None
+            }
+        };
+
+        match (replacement, simplex.last_mut()) {
+            (Some(vertex), Some(last)) => *last = vertex,
+            _ => {
+                for vertex in simplex.iter_mut().skip(1) {
+                    let shrunk = combine(&best_point, &vertex.0, 0.5);
+                    *vertex = (shrunk, f(&shrunk));
+                }
+            }
+        }
+    }
+
+    simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let (point, value) = simplex.first().copied().unwrap_or((start, f64::MAX));
+    NelderMeadOutcome {
+        point,
+        value,
+        iterations,
+        converged,
+    }
+}
+
+/// Minimal complex number used by the characteristic function.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Complex {
+    re: f64,
+    im: f64,
+}
+
+impl Complex {
+    const fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    fn exp(self) -> Self {
+        let scale = self.re.exp();
+        Self::new(scale * self.im.cos(), scale * self.im.sin())
+    }
+
+    fn ln(self) -> Self {
+        Self::new(self.re.hypot(self.im).ln(), self.im.atan2(self.re))
+    }
+
+    /// Principal square root (non-negative real part).
+    fn sqrt(self) -> Self {
+        let modulus = self.re.hypot(self.im);
+        let re = ((modulus + self.re) / 2.0).max(0.0).sqrt();
+        let im = ((modulus - self.re) / 2.0).max(0.0).sqrt();
+        Self::new(re, if self.im < 0.0 { -im } else { im })
+    }
+
+    fn scale(self, factor: f64) -> Self {
+        Self::new(self.re * factor, self.im * factor)
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Div for Complex {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let denominator = rhs.re * rhs.re + rhs.im * rhs.im;
+        Self::new(
+            (self.re * rhs.re + self.im * rhs.im) / denominator,
+            (self.im * rhs.re - self.re * rhs.im) / denominator,
+        )
+    }
+}
+
+/// Upper end of the finely sampled integration panel next to the origin.
+const INNER_PANEL: f64 = 10.0;
+
+/// Pre-computed integration grid for one maturity. The characteristic
+/// function does not depend on the strike, so it is evaluated once per
+/// maturity and reused for every strike in the slice.
+struct HestonSlice {
+    spot: f64,
+    years: f64,
+    rate: f64,
+    dividend: f64,
+    /// `(u, Simpson weight × φ_T(u - i/2) / (u² + 1/4))` pairs.
+    nodes: Vec<(f64, Complex)>,
+}
+
+impl HestonSlice {
+    fn new(params: &[f64; 5], spot: f64, years: f64, rate: f64, dividend: f64) -> Self {
+        let [kappa, theta, xi, rho, v0] = *params;
+        // The integrand decays at least like exp(-u² v T / 2); pick the upper
+        // limit so that this envelope is negligible.
+        let v_floor = v0.min(theta).max(1e-4);
+        let upper = (72.0 / (v_floor * years.max(1e-6)))
+            .sqrt()
+            .clamp(100.0, 5_000.0);
+        // The 1 / (u² + 1/4) kernel varies quickly near the origin, so the
+        // first panel uses a much finer step than the tail.
+        let panels = [
+            (0.0, INNER_PANEL, 400),
+            (
+                INNER_PANEL,
+                upper,
+                (((upper - INNER_PANEL) / 0.2).ceil() as usize).clamp(2, 25_000) & !1,
+            ),
+        ];
+
+        let mut nodes = Vec::new();
+        for (start, end, intervals) in panels {
+            let h = (end - start) / intervals as f64;
+            for j in 0..=intervals {
+                let u = start + j as f64 * h;
+                let simpson = if j == 0 || j == intervals {
+                    1.0
+                } else if j % 2 == 1 {
+                    4.0
+                } else {
+                    2.0
+                };
+                let phi = characteristic_function(
+                    Complex::new(u, -0.5),
+                    years,
+                    kappa,
+                    theta,
+                    xi,
+                    rho,
+                    v0,
+                );
+                nodes.push((u, phi.scale(simpson * h / 3.0 / (u * u + 0.25))));
+            }
+        }
+
+        Self {
+            spot,
+            years,
+            rate,
+            dividend,
+            nodes,
+        }
+    }
+
+    fn price(&self, strike: f64, option_style: OptionStyle) -> f64 {
+        let k = (self.spot / strike).ln() + (self.rate - self.dividend) * self.years;
+        let integral: f64 = self
+            .nodes
+            .iter()
+            .map(|(u, weighted_phi)| (Complex::new(0.0, u * k).exp() * *weighted_phi).re)
+            .sum();
+        let discounted_spot = self.spot * (-self.dividend * self.years).exp();
+        let call = discounted_spot
+            - (self.spot * strike).sqrt()
+                * (-(self.rate + self.dividend) * self.years / 2.0).exp()
+                * integral
+                / PI;
+        match option_style {
+            OptionStyle::Call => call,
+            OptionStyle::Put => call - discounted_spot + strike * (-self.rate * self.years).exp(),
+        }
+    }
+}
+
+/// Heston characteristic function of `ln(S_T / F_T)` in Gatheral's
+/// "little trap" form, evaluated at a complex argument.
+fn characteristic_function(
+    u: Complex,
+    years: f64,
+    kappa: f64,
+    theta: f64,
+    xi: f64,
+    rho: f64,
+    v0: f64,
+) -> Complex {
+    let i = Complex::new(0.0, 1.0);
+    let one = Complex::new(1.0, 0.0);
+    let alpha = (u * u).scale(-0.5) - (i * u).scale(0.5);
+    let beta = Complex::new(kappa, 0.0) - (i * u).scale(rho * xi);
+    let gamma = 0.5 * xi * xi;
+    let d = (beta * beta - alpha.scale(4.0 * gamma)).sqrt();
+    // r₋ = (β - d) / 2γ rewritten as 2α / (β + d) to stay stable for small ξ.
+    let r_minus = alpha.scale(2.0) / (beta + d);
+    let g = alpha.scale(4.0 * gamma) / ((beta + d) * (beta + d));
+    let decay = (d.scale(-years)).exp();
+    let d_term = r_minus * (one - decay) / (one - g * decay);
+    let c_term = (r_minus.scale(years) - ((one - g * decay) / (one - g)).ln().scale(1.0 / gamma))
+        .scale(kappa);
+    (c_term.scale(theta) + d_term.scale(v0)).exp()
+}
+
+#[cfg(test)]
+mod tests_heston {
+    use super::*;
+    use crate::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
+    use crate::pricing::black_scholes;
+    use positive::{pos_or_panic, spos};
+
+    fn lewis_params() -> HestonParams {
+        HestonParams::new(
+            Positive::FOUR,
+            pos_or_panic!(0.25),
+            Positive::ONE,
+            dec!(-0.5),
+            pos_or_panic!(0.04),
+        )
+    }
+
+    fn flat_chain(iv: f64) -> OptionChain {
+        let price_params = OptionDataPriceParams::new(
+            Some(Box::new(Positive::HUNDRED)),
+            Some(ExpirationDate::Days(pos_or_panic!(60.0))),
+            Some(dec!(0.03)),
+            spos!(0.0),
+            Some("TEST".to_string()),
+        );
+        let params = OptionChainBuildParams::new(
+            "TEST".to_string(),
+            spos!(1000.0),
+            8,
+            spos!(5.0),
+            Decimal::ZERO,
+            Decimal::ZERO,
+            pos_or_panic!(0.02),
+            4,
+            price_params,
+            pos_or_panic!(iv),
+        );
+        OptionChain::build_chain(&params).unwrap()
+    }
+
+    #[test]
+    fn test_lewis_reference_prices() {
+        // Lewis (2000) benchmark: S = 100, T = 1, r = 0.01, q = 0.02.
+        let params = lewis_params();
+        let expected = [
+            (80.0, 26.774758743998854),
+            (100.0, 16.070154917028834),
+            (120.0, 9.024913483457836),
+        ];
+        for (strike, reference) in expected {
+            let price = heston_price(
+                &params,
+                Positive::HUNDRED,
+                pos_or_panic!(strike),
+                Positive::ONE,
+                dec!(0.01),
+                pos_or_panic!(0.02),
+                OptionStyle::Call,
+            )
+            .unwrap();
+            assert!(
+                (price.to_f64() - reference).abs() < 1e-6,
+                "strike {strike}: {price} vs {reference}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_put_call_parity() {
+        let params = lewis_params();
+        let call = heston_price(
+            &params,
+            Positive::HUNDRED,
+            pos_or_panic!(110.0),
+            Positive::ONE,
+            dec!(0.01),
+            pos_or_panic!(0.02),
+            OptionStyle::Call,
+        )
+        .unwrap();
+        let put = heston_price(
+            &params,
+            Positive::HUNDRED,
+            pos_or_panic!(110.0),
+            Positive::ONE,
+            dec!(0.01),
+            pos_or_panic!(0.02),
+            OptionStyle::Put,
+        )
+        .unwrap();
+        let parity = 100.0 * (-0.02f64).exp() - 110.0 * (-0.01f64).exp();
+        assert!((call.to_f64() - put.to_f64() - parity).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_low_vol_of_vol_matches_black_scholes() {
+        let params = HestonParams::new(
+            Positive::TWO,
+            pos_or_panic!(0.04),
+            pos_or_panic!(0.01),
+            Decimal::ZERO,
+            pos_or_panic!(0.04),
+        );
+        let option = Options::new(
+            OptionType::European,
+            Side::Long,
+            "TEST".to_string(),
+            Positive::HUNDRED,
+            ExpirationDate::Days(pos_or_panic!(182.5)),
+            pos_or_panic!(0.2),
+            Positive::ONE,
+            Positive::HUNDRED,
+            dec!(0.05),
+            OptionStyle::Call,
+            Positive::ZERO,
+            None,
+        );
+        let bs = black_scholes(&option).unwrap().to_f64().unwrap();
+        let heston = heston_price(
+            &params,
+            Positive::HUNDRED,
+            Positive::HUNDRED,
+            pos_or_panic!(0.5),
+            dec!(0.05),
+            Positive::ZERO,
+            OptionStyle::Call,
+        )
+        .unwrap();
+        assert!((heston.to_f64() - bs).abs() < 1e-2, "{heston} vs {bs}");
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let params = HestonParams::default();
+        assert!(
+            heston_price(
+                &params,
+                Positive::ZERO,
+                Positive::HUNDRED,
+                Positive::ONE,
+                Decimal::ZERO,
+                Positive::ZERO,
+                OptionStyle::Call,
+            )
+            .is_err()
+        );
+        assert!(
+            heston_price(
+                &params,
+                Positive::HUNDRED,
+                Positive::HUNDRED,
+                Positive::ZERO,
+                Decimal::ZERO,
+                Positive::ZERO,
+                OptionStyle::Call,
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_feller_condition() {
+        let params = HestonParams::new(
+            Positive::TWO,
+            pos_or_panic!(0.04),
+            pos_or_panic!(0.3),
+            dec!(-0.5),
+            pos_or_panic!(0.04),
+        );
+        assert!(params.satisfies_feller());
+        let params = HestonParams {
+            xi: Positive::ONE,
+            ..params
+        };
+        assert!(!params.satisfies_feller());
+    }
+
+    #[test]
+    fn test_to_walk_type() {
+        let params = HestonParams::default();
+        match params.to_walk_type(pos_or_panic!(0.01), dec!(0.05)) {
+            WalkType::Heston {
+                volatility,
+                kappa,
+                rho,
+                ..
+            } => {
+                assert_eq!(volatility, pos_or_panic!(0.2));
+                assert_eq!(kappa, Positive::TWO);
+                assert_eq!(rho, dec!(-0.7));
+            }
+            other => panic!("unexpected walk type {other}"),
+        }
+    }
+
+    #[test]
+    fn test_calibrate_flat_chain() {
+        let chain = flat_chain(0.2);
+        let config = HestonCalibrationConfig {
+            max_iterations: 150,
+            ..Default::default()
+        };
+        let result = HestonCalibrator::new(config)
+            .calibrate_chain(&chain)
+            .unwrap();
+        assert!(!result.residuals.is_empty());
+        // A flat smile is reproduced with near-constant variance.
+        assert!(result.rmse < dec!(0.1), "rmse {}", result.rmse);
+        let worst = result.worst_residuals(2);
+        assert_eq!(worst.len(), 2);
+        assert!(worst[0].price_error.abs() >= worst[1].price_error.abs());
+    }
+
+    #[test]
+    fn test_calibrate_with_feller_and_iv_objective() {
+        let chain = flat_chain(0.25);
+        let config = HestonCalibrationConfig {
+            objective: HestonObjective::ImpliedVolatility,
+            enforce_feller: true,
+            max_iterations: 100,
+            ..Default::default()
+        };
+        let result = HestonCalibrator::new(config)
+            .calibrate_chain(&chain)
+            .unwrap();
+        assert!(
+            result.params.satisfies_feller() || {
+                // Allow for rounding on the boundary.
+                let lhs =
+                    Decimal::TWO * result.params.kappa.to_dec() * result.params.theta.to_dec();
+                let rhs = result.params.xi.to_dec() * result.params.xi.to_dec();
+                (lhs - rhs).abs() < dec!(1e-9)
+            }
+        );
+        assert!(result.rmse < dec!(0.02), "rmse {}", result.rmse);
+    }
+
+    #[test]
+    fn test_calibrate_empty_chain_fails() {
+        let chain = OptionChain::new(
+            "EMPTY",
+            Positive::HUNDRED,
+            "2030-01-01".to_string(),
+            None,
+            None,
+        );
+        let result = HestonCalibrator::default().calibrate_chain(&chain);
+        assert!(matches!(
+            result,
+            Err(VolatilityError::CalibrationError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_invalid_bounds() {
+        let chain = flat_chain(0.2);
+        let mut config = HestonCalibrationConfig::default();
+        config.bounds.kappa = (Positive::TEN, Positive::ONE);
+        let result = HestonCalibrator::new(config).calibrate_chain(&chain);
+        assert!(matches!(
+            result,
+            Err(VolatilityError::CalibrationError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_calibrate_series() {
+        let chain = flat_chain(0.2);
+        let mut series = OptionSeries::new("TEST".to_string(), Positive::HUNDRED);
+        series
+            .chains
+            .insert(ExpirationDate::Days(pos_or_panic!(60.0)), chain.clone());
+        series
+            .chains
+            .insert(ExpirationDate::Days(pos_or_panic!(90.0)), chain);
+        let config = HestonCalibrationConfig {
+            max_iterations: 60,
+            ..Default::default()
+        };
+        let result = HestonCalibrator::new(config)
+            .calibrate_series(&series)
+            .unwrap();
+        let expirations: std::collections::BTreeSet<String> = result
+            .residuals
+            .iter()
+            .map(|r| r.expiration.to_string())
+            .collect();
+        assert_eq!(expirations.len(), 2);
+    }
+}
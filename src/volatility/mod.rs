@@ -20,6 +20,10 @@ use positive::pos_or_panic;
 //! - EWMA (Exponentially Weighted Moving Average)
 //! - GARCH(1,1)
 //! - Heston Stochastic Volatility
+//! - Heston Semi-Analytic Pricing and Calibration to Chains / Series
+//! - ATM Volatility Term Structure, Forward Volatility and Event Variance
+//! - Sticky-Strike / Sticky-Delta / Sticky-Moneyness Smile Dynamics
+//! - FX Delta-Space Smile Quoting (ATM DNS, Risk Reversals, Butterflies, Vanna-Volga)
 //! - Implied Volatility
 //! - Uncertain Volatility Bounds
 //! - Volatility Surface Interpolation
@@ -169,9 +173,21 @@ use positive::pos_or_panic;
 //! - Heston (1993) stochastic volatility model
 //! - GARCH by Bollerslev (1986)
 
+mod fx_smile;
+mod heston;
+mod smile_dynamics;
+mod term_structure;
 mod traits;
 mod utils;
 
+pub use fx_smile::{FxAtmConvention, FxDeltaConvention, FxMarket, FxPillar, FxSmile, FxSmileQuote};
+pub use heston::{
+    HestonBounds, HestonCalibrationConfig, HestonCalibrationResult, HestonCalibrator,
+    HestonObjective, HestonParams, HestonResidual, heston_price,
+};
+pub use smile_dynamics::{SmileDynamics, StickySmile};
+pub use term_structure::{EventVariance, TermStructurePoint, VolatilityTermStructure};
+
 pub use utils::{
     adjust_volatility, annualized_volatility, calculate_iv, constant_volatility,
     de_annualized_volatility, ewma_volatility, garch_volatility, generate_ou_process,
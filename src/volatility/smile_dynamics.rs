@@ -0,0 +1,427 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # Smile Dynamics Regimes
+//!
+//! When a scenario moves the underlying, the implied volatility of every
+//! strike has to be re-read from the smile. How the smile itself moves with
+//! the spot is a modelling choice, captured by [`SmileDynamics`]:
+//!
+//! - **Sticky strike**: each strike keeps its volatility, `σ₁(K) = σ₀(K)`.
+//! - **Sticky moneyness**: the smile travels with the spot,
+//!   `σ₁(K) = σ₀(K · S₀ / S₁)`.
+//! - **Sticky delta**: each strike takes the volatility of the strike that
+//!   had the same Black–Scholes delta before the move. At an unchanged time
+//!   to expiry this coincides with sticky moneyness; the two differ once the
+//!   horizon moves as well.
+//! - **Skew beta**: a blend between the first two regimes,
+//!   `σ₁(K) = σ₀(K · (S₀ / S₁)^β)`; `β = 0` is sticky strike and `β = 1` is
+//!   sticky moneyness.
+//!
+//! [`StickySmile`] snapshots the smile of an [`OptionChain`] and applies the
+//! selected regime. It is consumed by the smile-aware stress metrics and by
+//! [`crate::strategies::Strategies::calculate_pnl_with_smile`].
+
+use crate::chains::chain::OptionChain;
+use crate::error::VolatilityError;
+use num_traits::ToPrimitive;
+use positive::Positive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Maximum number of fixed-point iterations used to solve the sticky-delta
+/// strike mapping.
+const STICKY_DELTA_MAX_ITERATIONS: usize = 50;
+
+/// Convergence threshold on the volatility for the sticky-delta solver.
+const STICKY_DELTA_TOLERANCE: f64 = 1e-12;
+
+/// How the implied volatility smile reacts to a move in the underlying.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize, ToSchema)]
+pub enum SmileDynamics {
+    /// Volatility is a fixed function of the strike.
+    #[default]
+    StickyStrike,
+    /// Volatility is a fixed function of spot moneyness `K / S`.
+    StickyMoneyness,
+    /// Volatility is a fixed function of the Black–Scholes call delta.
+    StickyDelta,
+    /// Partial smile shift: `β = 0` is sticky strike, `β = 1` is sticky
+    /// moneyness, intermediate values move the smile by a fraction of the
+    /// spot move in log-strike space.
+    SkewBeta(Decimal),
+}
+
+/// A snapshot of a volatility smile together with the dynamics used to
+/// re-read it after a move in the underlying.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct StickySmile {
+    /// Regime applied when the underlying moves.
+    pub dynamics: SmileDynamics,
+    reference_price: Positive,
+    years: Positive,
+    risk_free_rate: Decimal,
+    dividend_yield: Positive,
+    smile: Vec<(Positive, Positive)>,
+}
+
+impl StickySmile {
+    /// Creates a smile snapshot from `(strike, implied volatility)` pairs
+    /// observed with the underlying at `reference_price`.
+    ///
+    /// Pairs with a zero volatility are discarded; the remaining ones are
+    /// sorted by strike.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VolatilityError::OptionError`] if no strike with a
+    /// positive volatility is supplied.
+    pub fn new(
+        reference_price: Positive,
+        years: Positive,
+        risk_free_rate: Decimal,
+        dividend_yield: Positive,
+        smile: Vec<(Positive, Positive)>,
+        dynamics: SmileDynamics,
+    ) -> Result<Self, VolatilityError> {
+        let mut smile: Vec<(Positive, Positive)> =
+            smile.into_iter().filter(|(_, iv)| !iv.is_zero()).collect();
+        if smile.is_empty() {
+            return Err(VolatilityError::OptionError {
+                reason: "smile requires at least one strike with a positive volatility".to_string(),
+            });
+        }
+        smile.sort_by_key(|(strike, _)| *strike);
+        smile.dedup_by_key(|(strike, _)| *strike);
+        Ok(Self {
+            dynamics,
+            reference_price,
+            years,
+            risk_free_rate,
+            dividend_yield,
+            smile,
+        })
+    }
+
+    /// Snapshots the smile of an option chain at its current underlying
+    /// price.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VolatilityError::OptionError`] if the chain has no strike
+    /// with a positive implied volatility.
+    pub fn from_chain(
+        chain: &OptionChain,
+        dynamics: SmileDynamics,
+    ) -> Result<Self, VolatilityError> {
+        let years = chain
+            .get_expiration()
+            .and_then(|expiration| expiration.get_years().ok())
+            .unwrap_or(Positive::ZERO);
+        let smile = chain
+            .get_single_iter()
+            .map(|option| (option.strike_price, option.implied_volatility))
+            .collect();
+        Self::new(
+            chain.underlying_price,
+            years,
+            chain.risk_free_rate.unwrap_or(Decimal::ZERO),
+            chain.dividend_yield.unwrap_or(Positive::ZERO),
+            smile,
+            dynamics,
+        )
+    }
+
+    /// Returns a copy of this snapshot using another regime.
+    #[must_use]
+    pub fn with_dynamics(&self, dynamics: SmileDynamics) -> Self {
+        Self {
+            dynamics,
+            ..self.clone()
+        }
+    }
+
+    /// Underlying price at which the smile was observed.
+    #[must_use]
+    pub fn reference_price(&self) -> Positive {
+        self.reference_price
+    }
+
+    /// Time to expiry, in years, of the snapshot.
+    #[must_use]
+    pub fn years(&self) -> Positive {
+        self.years
+    }
+
+    /// Volatility of `strike` on the unshifted smile, interpolated linearly
+    /// between quoted strikes and held flat outside them.
+    #[must_use]
+    pub fn reference_volatility(&self, strike: Positive) -> Positive {
+        Positive::new(self.interpolate(strike.to_f64())).unwrap_or(Positive::ZERO)
+    }
+
+    /// Volatility of `strike` after the underlying moves to
+    /// `underlying_price`, with the snapshot's time to expiry.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VolatilityError::NonFinite`] if the mapped volatility is
+    /// not a finite number.
+    pub fn volatility(
+        &self,
+        strike: Positive,
+        underlying_price: Positive,
+    ) -> Result<Positive, VolatilityError> {
+        self.volatility_at(strike, underlying_price, self.years)
+    }
+
+    /// Volatility of `strike` after the underlying moves to
+    /// `underlying_price` and the time to expiry becomes `years`.
+    ///
+    /// Only [`SmileDynamics::StickyDelta`] depends on `years`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VolatilityError::NonFinite`] if the mapped volatility is
+    /// not a finite number.
+    pub fn volatility_at(
+        &self,
+        strike: Positive,
+        underlying_price: Positive,
+        years: Positive,
+    ) -> Result<Positive, VolatilityError> {
+        let strike = strike.to_f64();
+        let spot_ratio = if underlying_price.is_zero() {
+            1.0
+        } else {
+            self.reference_price.to_f64() / underlying_price.to_f64()
+        };
+        let vol = match self.dynamics {
+            SmileDynamics::StickyStrike => self.interpolate(strike),
+            SmileDynamics::StickyMoneyness => self.interpolate(strike * spot_ratio),
+            SmileDynamics::SkewBeta(beta) => {
+                let beta = beta.to_f64().unwrap_or(0.0);
+                self.interpolate(strike * spot_ratio.powf(beta))
+            }
+            SmileDynamics::StickyDelta => {
+                self.sticky_delta(strike, underlying_price.to_f64(), years.to_f64())
+            }
+        };
+        Positive::new(vol).map_err(|_| VolatilityError::non_finite("smile_dynamics::vol", vol))
+    }
+
+    /// Solves `d₁(K', S₀, σ₀(K'), T₀) = d₁(K, S₁, σ₀(K'), T₁)` by fixed-point
+    /// iteration on the volatility.
+    fn sticky_delta(&self, strike: f64, spot: f64, years: f64) -> f64 {
+        let reference_years = self.years.to_f64();
+        if years <= 0.0 || reference_years <= 0.0 || spot <= 0.0 || strike <= 0.0 {
+            return self
+                .interpolate(strike * self.reference_price.to_f64() / spot.max(f64::MIN_POSITIVE));
+        }
+        let carry = self.risk_free_rate.to_f64().unwrap_or(0.0) - self.dividend_yield.to_f64();
+        let reference_price = self.reference_price.to_f64();
+        let mut vol = self.interpolate(strike * reference_price / spot);
+        for _ in 0..STICKY_DELTA_MAX_ITERATIONS {
+            if vol <= 0.0 {
+                break;
+            }
+            let drift = carry + 0.5 * vol * vol;
+            let d1 = ((spot / strike).ln() + drift * years) / (vol * years.sqrt());
+            let mapped_strike = reference_price
+                * (drift * reference_years - d1 * vol * reference_years.sqrt()).exp();
+            let next = self.interpolate(mapped_strike);
+            if (next - vol).abs() < STICKY_DELTA_TOLERANCE {
+                return next;
+            }
+            vol = next;
+        }
+        vol
+    }
+
+    fn interpolate(&self, strike: f64) -> f64 {
+        let (Some(first), Some(last)) = (self.smile.first(), self.smile.last()) else {
+            return 0.0;
+        };
+        if strike <= first.0.to_f64() {
+            return first.1.to_f64();
+        }
+        if strike >= last.0.to_f64() {
+            return last.1.to_f64();
+        }
+        for pair in self.smile.windows(2) {
+            let [(k0, v0), (k1, v1)] = pair else { continue };
+            let (k0, k1) = (k0.to_f64(), k1.to_f64());
+            if strike <= k1 {
+                let weight = (strike - k0) / (k1 - k0);
+                return v0.to_f64() + weight * (v1.to_f64() - v0.to_f64());
+            }
+        }
+        last.1.to_f64()
+    }
+}
+
+#[cfg(test)]
+mod tests_smile_dynamics {
+    use super::*;
+    use crate::ExpirationDate;
+    use crate::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
+    use positive::{assert_pos_relative_eq, pos_or_panic, spos};
+    use rust_decimal_macros::dec;
+
+    /// Linear put skew: 30% at 80, 20% at 100, 10% at 120.
+    fn skewed_smile(dynamics: SmileDynamics) -> StickySmile {
+        StickySmile::new(
+            Positive::HUNDRED,
+            pos_or_panic!(0.25),
+            dec!(0.02),
+            Positive::ZERO,
+            vec![
+                (pos_or_panic!(80.0), pos_or_panic!(0.3)),
+                (Positive::HUNDRED, pos_or_panic!(0.2)),
+                (pos_or_panic!(120.0), pos_or_panic!(0.1)),
+            ],
+            dynamics,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_interpolation_and_flat_extrapolation() {
+        let smile = skewed_smile(SmileDynamics::StickyStrike);
+        assert_pos_relative_eq!(
+            smile.reference_volatility(pos_or_panic!(90.0)),
+            pos_or_panic!(0.25),
+            pos_or_panic!(1e-12)
+        );
+        assert_eq!(
+            smile.reference_volatility(pos_or_panic!(50.0)),
+            pos_or_panic!(0.3)
+        );
+        assert_eq!(
+            smile.reference_volatility(pos_or_panic!(200.0)),
+            pos_or_panic!(0.1)
+        );
+    }
+
+    #[test]
+    fn test_sticky_strike_ignores_spot() {
+        let smile = skewed_smile(SmileDynamics::StickyStrike);
+        let vol = smile
+            .volatility(Positive::HUNDRED, pos_or_panic!(110.0))
+            .unwrap();
+        assert_pos_relative_eq!(vol, pos_or_panic!(0.2), pos_or_panic!(1e-12));
+    }
+
+    #[test]
+    fn test_sticky_moneyness_moves_with_spot() {
+        let smile = skewed_smile(SmileDynamics::StickyMoneyness);
+        // Strike 110 with spot 110 is ATM again.
+        let vol = smile
+            .volatility(pos_or_panic!(110.0), pos_or_panic!(110.0))
+            .unwrap();
+        assert_pos_relative_eq!(vol, pos_or_panic!(0.2), pos_or_panic!(1e-12));
+    }
+
+    #[test]
+    fn test_skew_beta_interpolates_between_regimes() {
+        let strike = pos_or_panic!(105.0);
+        let spot = pos_or_panic!(95.0);
+        let sticky_strike = skewed_smile(SmileDynamics::StickyStrike)
+            .volatility(strike, spot)
+            .unwrap();
+        let sticky_moneyness = skewed_smile(SmileDynamics::StickyMoneyness)
+            .volatility(strike, spot)
+            .unwrap();
+        let beta_zero = skewed_smile(SmileDynamics::SkewBeta(Decimal::ZERO))
+            .volatility(strike, spot)
+            .unwrap();
+        let beta_one = skewed_smile(SmileDynamics::SkewBeta(Decimal::ONE))
+            .volatility(strike, spot)
+            .unwrap();
+        let beta_half = skewed_smile(SmileDynamics::SkewBeta(dec!(0.5)))
+            .volatility(strike, spot)
+            .unwrap();
+        assert_pos_relative_eq!(beta_zero, sticky_strike, pos_or_panic!(1e-12));
+        assert_pos_relative_eq!(beta_one, sticky_moneyness, pos_or_panic!(1e-12));
+        assert!(beta_half < sticky_strike && beta_half > sticky_moneyness);
+    }
+
+    #[test]
+    fn test_sticky_delta_matches_moneyness_at_same_horizon() {
+        let strike = pos_or_panic!(105.0);
+        let spot = pos_or_panic!(95.0);
+        let sticky_delta = skewed_smile(SmileDynamics::StickyDelta)
+            .volatility(strike, spot)
+            .unwrap();
+        let sticky_moneyness = skewed_smile(SmileDynamics::StickyMoneyness)
+            .volatility(strike, spot)
+            .unwrap();
+        assert_pos_relative_eq!(sticky_delta, sticky_moneyness, pos_or_panic!(1e-9));
+    }
+
+    #[test]
+    fn test_sticky_delta_depends_on_horizon() {
+        let smile = skewed_smile(SmileDynamics::StickyDelta);
+        let strike = pos_or_panic!(110.0);
+        let same = smile.volatility(strike, Positive::HUNDRED).unwrap();
+        // With less time left the same strike is further out in delta terms,
+        // so it reads a lower volatility off the call wing.
+        let shorter = smile
+            .volatility_at(strike, Positive::HUNDRED, pos_or_panic!(0.1))
+            .unwrap();
+        assert_pos_relative_eq!(same, pos_or_panic!(0.15), pos_or_panic!(1e-9));
+        assert!(shorter < same);
+    }
+
+    #[test]
+    fn test_new_rejects_empty_smile() {
+        let result = StickySmile::new(
+            Positive::HUNDRED,
+            Positive::ONE,
+            Decimal::ZERO,
+            Positive::ZERO,
+            vec![(Positive::HUNDRED, Positive::ZERO)],
+            SmileDynamics::StickyStrike,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_from_chain() {
+        let price_params = OptionDataPriceParams::new(
+            Some(Box::new(Positive::HUNDRED)),
+            Some(ExpirationDate::Days(pos_or_panic!(30.0))),
+            Some(dec!(0.03)),
+            spos!(0.0),
+            Some("TEST".to_string()),
+        );
+        let params = OptionChainBuildParams::new(
+            "TEST".to_string(),
+            spos!(1000.0),
+            5,
+            spos!(5.0),
+            dec!(-0.2),
+            Decimal::ZERO,
+            pos_or_panic!(0.02),
+            4,
+            price_params,
+            pos_or_panic!(0.2),
+        );
+        let chain = OptionChain::build_chain(&params).unwrap();
+        let smile = StickySmile::from_chain(&chain, SmileDynamics::StickyMoneyness).unwrap();
+        assert_eq!(smile.reference_price(), Positive::HUNDRED);
+        assert!(smile.years() > Positive::ZERO);
+        let atm = chain.get_atm_implied_volatility().unwrap();
+        assert_pos_relative_eq!(
+            smile.reference_volatility(Positive::HUNDRED),
+            *atm,
+            pos_or_panic!(1e-9)
+        );
+        let sticky_strike = smile.with_dynamics(SmileDynamics::StickyStrike);
+        assert_eq!(sticky_strike.dynamics, SmileDynamics::StickyStrike);
+    }
+}
@@ -0,0 +1,551 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! # ATM Volatility Term Structure
+//!
+//! [`VolatilityTermStructure`] collects the at-the-money implied volatility of
+//! every chain in an [`OptionSeries`] into a single cross-expiry object.
+//! Volatilities are stored together with their total implied variance
+//! `w(T) = σ²(T) · T`, which is the quantity interpolated between listed
+//! expiries:
+//!
+//! - Between two pillars `w` is linear in time, so forward variance is
+//!   piecewise constant.
+//! - Before the first pillar the first volatility is held flat
+//!   (`w` runs linearly from zero).
+//! - After the last pillar the last volatility is held flat.
+//!
+//! On top of the interpolation the structure exposes forward volatility
+//! between two dates and the extraction of a one-off event variance (for
+//! example an earnings release) from the expiries around the event.
+//!
+//! ```rust
+//! use optionstratlib::ExpirationDate;
+//! use optionstratlib::volatility::{TermStructurePoint, VolatilityTermStructure};
+//! use positive::pos_or_panic;
+//!
+//! let term_structure = VolatilityTermStructure::new(vec![
+//!     TermStructurePoint::new(ExpirationDate::Days(pos_or_panic!(30.0)), pos_or_panic!(0.25)).unwrap(),
+//!     TermStructurePoint::new(ExpirationDate::Days(pos_or_panic!(90.0)), pos_or_panic!(0.22)).unwrap(),
+//! ])
+//! .unwrap();
+//!
+//! let sixty_days = ExpirationDate::Days(pos_or_panic!(60.0));
+//! let vol_60d = term_structure.volatility_at(&sixty_days).unwrap();
+//! let forward = term_structure
+//!     .forward_volatility(&ExpirationDate::Days(pos_or_panic!(30.0)), &sixty_days)
+//!     .unwrap();
+//! assert!(vol_60d < pos_or_panic!(0.25) && vol_60d > pos_or_panic!(0.22));
+//! assert!(forward < vol_60d);
+//! ```
+
+use crate::ExpirationDate;
+use crate::error::VolatilityError;
+use crate::model::decimal::{d_add, d_div, d_mul, d_sub};
+use crate::series::OptionSeries;
+use positive::Positive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use utoipa::ToSchema;
+
+/// One pillar of a volatility term structure: an expiry and its ATM
+/// implied volatility.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TermStructurePoint {
+    /// Expiration of the chain the volatility was taken from.
+    pub expiration: ExpirationDate,
+    /// Time to expiration in years (Actual/365).
+    pub years: Positive,
+    /// At-the-money implied volatility for this expiry.
+    pub atm_volatility: Positive,
+}
+
+impl TermStructurePoint {
+    /// Creates a pillar from an expiration and its ATM implied volatility.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VolatilityError::InvalidTime`] if the expiration cannot be
+    /// converted to a year fraction or lies in the past.
+    pub fn new(
+        expiration: ExpirationDate,
+        atm_volatility: Positive,
+    ) -> Result<Self, VolatilityError> {
+        let years = years_of(&expiration)?;
+        Ok(Self {
+            expiration,
+            years,
+            atm_volatility,
+        })
+    }
+
+    /// Total implied variance `σ² · T` of this pillar.
+    #[must_use]
+    pub fn total_variance(&self) -> Positive {
+        self.atm_volatility * self.atm_volatility * self.years
+    }
+}
+
+/// Breakdown of the variance attributed to a single scheduled event.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct EventVariance {
+    /// Variance priced for the event day, on top of the diffusive variance.
+    pub event_variance: Positive,
+    /// Implied one-off move of the event, `sqrt(event_variance)`, expressed
+    /// as a fraction of the underlying price.
+    pub event_move: Positive,
+    /// Annualised volatility of the underlying outside the event.
+    pub diffusive_volatility: Positive,
+}
+
+/// ATM implied volatility term structure across the expiries of an
+/// [`OptionSeries`], interpolated linearly in total variance.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct VolatilityTermStructure {
+    points: Vec<TermStructurePoint>,
+}
+
+impl VolatilityTermStructure {
+    /// Builds a term structure from a set of pillars.
+    ///
+    /// Pillars are sorted by time to expiry. Calendar arbitrage (decreasing
+    /// total variance) is allowed here so that inverted markets can still be
+    /// inspected; it surfaces as an error from
+    /// [`VolatilityTermStructure::forward_volatility`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VolatilityError::TermStructureError`] if `points` is empty,
+    /// contains an expired pillar, or two pillars share the same time to
+    /// expiry.
+    pub fn new(mut points: Vec<TermStructurePoint>) -> Result<Self, VolatilityError> {
+        if points.is_empty() {
+            return Err(VolatilityError::TermStructureError {
+                reason: "at least one expiry is required".to_string(),
+            });
+        }
+        if points.iter().any(|p| p.years.is_zero()) {
+            return Err(VolatilityError::TermStructureError {
+                reason: "expired pillars cannot be part of a term structure".to_string(),
+            });
+        }
+        points.sort_by_key(|p| p.years);
+        if points.windows(2).any(|w| match w {
+            [a, b] => a.years == b.years,
+            _ => false,
+        }) {
+            return Err(VolatilityError::TermStructureError {
+                reason: "duplicate time to expiry".to_string(),
+            });
+        }
+        Ok(Self { points })
+    }
+
+    /// Builds the ATM term structure of an option series from each chain's
+    /// [`crate::chains::chain::OptionChain::get_atm_implied_volatility`].
+    ///
+    /// Chains that are already expired or whose ATM volatility is zero are
+    /// skipped.
+    ///
+    /// # Errors
+    ///
+    /// Propagates the ATM lookup failure of any chain and returns
+    /// [`VolatilityError::TermStructureError`] if no usable chain remains.
+    pub fn from_series(series: &OptionSeries) -> Result<Self, VolatilityError> {
+        let mut points = Vec::with_capacity(series.chains.len());
+        for (expiration, chain) in &series.chains {
+            let atm_volatility = *chain.get_atm_implied_volatility()?;
+            let point = TermStructurePoint::new(*expiration, atm_volatility)?;
+            if point.years.is_zero() || atm_volatility.is_zero() {
+                debug!("skipping expiry {expiration}: no time or ATM volatility left");
+                continue;
+            }
+            points.push(point);
+        }
+        Self::new(points)
+    }
+
+    /// Pillars of the term structure, sorted by time to expiry.
+    #[must_use]
+    pub fn points(&self) -> &[TermStructurePoint] {
+        &self.points
+    }
+
+    /// Interpolated total implied variance `w(T)` at `years`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`VolatilityError`] if the decimal arithmetic overflows.
+    pub fn total_variance(&self, years: Positive) -> Result<Positive, VolatilityError> {
+        let (Some(first), Some(last)) = (self.points.first(), self.points.last()) else {
+            return Ok(Positive::ZERO);
+        };
+        if years <= first.years {
+            return Ok(first.atm_volatility * first.atm_volatility * years);
+        }
+        if years >= last.years {
+            return Ok(last.atm_volatility * last.atm_volatility * years);
+        }
+        for pair in self.points.windows(2) {
+            let [left, right] = pair else { continue };
+            if years > right.years {
+                continue;
+            }
+            let w_left = left.total_variance().to_dec();
+            let w_right = right.total_variance().to_dec();
+            let weight = d_div(
+                d_sub(years.to_dec(), left.years.to_dec(), "term_structure::w::dt")?,
+                d_sub(
+                    right.years.to_dec(),
+                    left.years.to_dec(),
+                    "term_structure::w::span",
+                )?,
+                "term_structure::w::weight",
+            )?;
+            let slope = d_sub(w_right, w_left, "term_structure::w::slope")?;
+            let variance = d_add(
+                w_left,
+                d_mul(weight, slope, "term_structure::w::step")?,
+                "term_structure::w::total",
+            )?;
+            return Ok(Positive::new_decimal(variance.max(Decimal::ZERO))?);
+        }
+        Ok(last.atm_volatility * last.atm_volatility * years)
+    }
+
+    /// Interpolated ATM implied volatility at `years`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VolatilityError::InvalidTime`] if `years` is zero.
+    pub fn volatility(&self, years: Positive) -> Result<Positive, VolatilityError> {
+        if years.is_zero() {
+            return Err(VolatilityError::InvalidTime {
+                time: years,
+                reason: "volatility is undefined at zero time to expiry".to_string(),
+            });
+        }
+        let variance = self.total_variance(years)?;
+        Ok((variance / years).sqrt_checked()?)
+    }
+
+    /// Interpolated ATM implied volatility for an arbitrary, possibly
+    /// non-listed, expiration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VolatilityError::InvalidTime`] if the expiration is invalid
+    /// or already reached.
+    pub fn volatility_at(&self, expiration: &ExpirationDate) -> Result<Positive, VolatilityError> {
+        self.volatility(years_of(expiration)?)
+    }
+
+    /// Forward volatility between two expirations:
+    /// `sqrt((w(T₂) − w(T₁)) / (T₂ − T₁))`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VolatilityError::TermStructureError`] if `end` is not later
+    /// than `start`, or if the forward variance is negative (calendar
+    /// arbitrage between the two dates).
+    pub fn forward_volatility(
+        &self,
+        start: &ExpirationDate,
+        end: &ExpirationDate,
+    ) -> Result<Positive, VolatilityError> {
+        let t1 = years_of(start)?;
+        let t2 = years_of(end)?;
+        if t2 <= t1 {
+            return Err(VolatilityError::TermStructureError {
+                reason: format!("forward end {t2} must be after start {t1}"),
+            });
+        }
+        let w1 = self.total_variance(t1)?;
+        let w2 = self.total_variance(t2)?;
+        let forward_variance = d_sub(w2.to_dec(), w1.to_dec(), "term_structure::forward")?;
+        if forward_variance < Decimal::ZERO {
+            return Err(VolatilityError::TermStructureError {
+                reason: format!(
+                    "negative forward variance {forward_variance} between {t1} and {t2} years"
+                ),
+            });
+        }
+        let rate = d_div(
+            forward_variance,
+            d_sub(t2.to_dec(), t1.to_dec(), "term_structure::forward::span")?,
+            "term_structure::forward::rate",
+        )?;
+        Ok(Positive::new_decimal(rate)?.sqrt_checked()?)
+    }
+
+    /// Extracts the variance priced for a single event occurring at `event`.
+    ///
+    /// The event variance is read off the first expiry after the event:
+    /// `E = w(T_a) − σ_d² · T_a`, where the diffusive volatility `σ_d` is
+    ///
+    /// - the ATM volatility of the last expiry before the event, when one
+    ///   exists, or otherwise
+    /// - the forward volatility between the first two expiries after the
+    ///   event, both of which contain it.
+    ///
+    /// When the market prices no premium for the event the variance is
+    /// reported as zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VolatilityError::TermStructureError`] if no expiry follows
+    /// the event, or if neither a pre-event expiry nor a second post-event
+    /// expiry is available.
+    pub fn event_variance(&self, event: &ExpirationDate) -> Result<EventVariance, VolatilityError> {
+        let event_years = years_of(event)?;
+        let after_index = self
+            .points
+            .iter()
+            .position(|p| p.years > event_years)
+            .ok_or_else(|| VolatilityError::TermStructureError {
+                reason: format!("no expiry after the event at {event_years} years"),
+            })?;
+        let after =
+            self.points
+                .get(after_index)
+                .ok_or_else(|| VolatilityError::TermStructureError {
+                    reason: "missing post-event expiry".to_string(),
+                })?;
+        let before = after_index
+            .checked_sub(1)
+            .and_then(|index| self.points.get(index));
+        let diffusive_variance_rate = match (before, self.points.get(after_index + 1)) {
+            (Some(before), _) => before.atm_volatility * before.atm_volatility,
+            (None, Some(next)) => {
+                let rate = d_div(
+                    d_sub(
+                        next.total_variance().to_dec(),
+                        after.total_variance().to_dec(),
+                        "term_structure::event::dw",
+                    )?,
+                    d_sub(
+                        next.years.to_dec(),
+                        after.years.to_dec(),
+                        "term_structure::event::dt",
+                    )?,
+                    "term_structure::event::rate",
+                )?;
+                Positive::new_decimal(rate.max(Decimal::ZERO))?
+            }
+            (None, None) => {
+                return Err(VolatilityError::TermStructureError {
+                    reason: "event extraction needs a pre-event expiry or two post-event expiries"
+                        .to_string(),
+                });
+            }
+        };
+        let event_variance = after
+            .total_variance()
+            .sub_or_zero(&(diffusive_variance_rate * after.years).to_dec());
+        Ok(EventVariance {
+            event_variance,
+            event_move: event_variance.sqrt_checked()?,
+            diffusive_volatility: diffusive_variance_rate.sqrt_checked()?,
+        })
+    }
+}
+
+impl OptionSeries {
+    /// Builds the ATM implied volatility term structure of this series.
+    ///
+    /// Convenience wrapper around [`VolatilityTermStructure::from_series`].
+    ///
+    /// # Errors
+    ///
+    /// See [`VolatilityTermStructure::from_series`].
+    pub fn volatility_term_structure(&self) -> Result<VolatilityTermStructure, VolatilityError> {
+        VolatilityTermStructure::from_series(self)
+    }
+}
+
+fn years_of(expiration: &ExpirationDate) -> Result<Positive, VolatilityError> {
+    expiration
+        .get_years()
+        .map_err(|e| VolatilityError::InvalidTime {
+            time: Positive::ZERO,
+            reason: format!("invalid expiration {expiration}: {e}"),
+        })
+}
+
+#[cfg(test)]
+mod tests_term_structure {
+    use super::*;
+    use crate::chains::chain::OptionChain;
+    use crate::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
+    use positive::{assert_pos_relative_eq, pos_or_panic, spos};
+    use rust_decimal_macros::dec;
+
+    fn days(d: f64) -> ExpirationDate {
+        ExpirationDate::Days(pos_or_panic!(d))
+    }
+
+    fn point(d: f64, vol: f64) -> TermStructurePoint {
+        TermStructurePoint::new(days(d), pos_or_panic!(vol)).unwrap()
+    }
+
+    fn flat_chain(d: f64, iv: f64) -> OptionChain {
+        let price_params = OptionDataPriceParams::new(
+            Some(Box::new(Positive::HUNDRED)),
+            Some(days(d)),
+            Some(dec!(0.03)),
+            spos!(0.0),
+            Some("TEST".to_string()),
+        );
+        let params = OptionChainBuildParams::new(
+            "TEST".to_string(),
+            spos!(1000.0),
+            5,
+            spos!(5.0),
+            Decimal::ZERO,
+            Decimal::ZERO,
+            pos_or_panic!(0.02),
+            4,
+            price_params,
+            pos_or_panic!(iv),
+        );
+        OptionChain::build_chain(&params).unwrap()
+    }
+
+    #[test]
+    fn test_new_sorts_and_rejects_duplicates() {
+        let ts = VolatilityTermStructure::new(vec![point(90.0, 0.2), point(30.0, 0.3)]).unwrap();
+        assert_eq!(ts.points()[0].years, days(30.0).get_years().unwrap());
+
+        let duplicate = VolatilityTermStructure::new(vec![point(30.0, 0.2), point(30.0, 0.3)]);
+        assert!(matches!(
+            duplicate,
+            Err(VolatilityError::TermStructureError { .. })
+        ));
+        assert!(VolatilityTermStructure::new(vec![]).is_err());
+    }
+
+    #[test]
+    fn test_pillars_are_recovered() {
+        let ts = VolatilityTermStructure::new(vec![point(30.0, 0.3), point(90.0, 0.2)]).unwrap();
+        assert_pos_relative_eq!(
+            ts.volatility_at(&days(30.0)).unwrap(),
+            pos_or_panic!(0.3),
+            pos_or_panic!(1e-9)
+        );
+        assert_pos_relative_eq!(
+            ts.volatility_at(&days(90.0)).unwrap(),
+            pos_or_panic!(0.2),
+            pos_or_panic!(1e-9)
+        );
+    }
+
+    #[test]
+    fn test_total_variance_is_linear_between_pillars() {
+        let ts = VolatilityTermStructure::new(vec![point(30.0, 0.3), point(90.0, 0.2)]).unwrap();
+        let w30 = ts.points()[0].total_variance();
+        let w90 = ts.points()[1].total_variance();
+        let w60 = ts.total_variance(days(60.0).get_years().unwrap()).unwrap();
+        assert_pos_relative_eq!(w60, (w30 + w90) / Positive::TWO, pos_or_panic!(1e-9));
+    }
+
+    #[test]
+    fn test_flat_extrapolation() {
+        let ts = VolatilityTermStructure::new(vec![point(30.0, 0.3), point(90.0, 0.2)]).unwrap();
+        assert_pos_relative_eq!(
+            ts.volatility_at(&days(7.0)).unwrap(),
+            pos_or_panic!(0.3),
+            pos_or_panic!(1e-9)
+        );
+        assert_pos_relative_eq!(
+            ts.volatility_at(&days(365.0)).unwrap(),
+            pos_or_panic!(0.2),
+            pos_or_panic!(1e-9)
+        );
+    }
+
+    #[test]
+    fn test_forward_volatility() {
+        let ts = VolatilityTermStructure::new(vec![point(30.0, 0.2), point(90.0, 0.3)]).unwrap();
+        // w(90) - w(30) = (0.09 * 90 - 0.04 * 30) / 365 over 60 / 365 years.
+        let expected = ((0.09 * 90.0 - 0.04 * 30.0) / 60.0_f64).sqrt();
+        let forward = ts.forward_volatility(&days(30.0), &days(90.0)).unwrap();
+        assert_pos_relative_eq!(forward, pos_or_panic!(expected), pos_or_panic!(1e-9));
+
+        assert!(ts.forward_volatility(&days(90.0), &days(30.0)).is_err());
+    }
+
+    #[test]
+    fn test_forward_volatility_detects_calendar_arbitrage() {
+        let ts = VolatilityTermStructure::new(vec![point(30.0, 0.6), point(40.0, 0.2)]).unwrap();
+        assert!(matches!(
+            ts.forward_volatility(&days(30.0), &days(40.0)),
+            Err(VolatilityError::TermStructureError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_event_variance_with_pre_event_expiry() {
+        // Diffusive vol 20%; the 30-day expiry also carries an event of
+        // variance 0.0025 (a 5% move).
+        let t: f64 = 30.0 / 365.0;
+        let post_vol = ((0.04 * t + 0.0025) / t).sqrt();
+        let ts =
+            VolatilityTermStructure::new(vec![point(10.0, 0.2), point(30.0, post_vol)]).unwrap();
+        let event = ts.event_variance(&days(20.0)).unwrap();
+        assert_pos_relative_eq!(
+            event.event_variance,
+            pos_or_panic!(0.0025),
+            pos_or_panic!(1e-6)
+        );
+        assert_pos_relative_eq!(event.event_move, pos_or_panic!(0.05), pos_or_panic!(1e-6));
+        assert_pos_relative_eq!(
+            event.diffusive_volatility,
+            pos_or_panic!(0.2),
+            pos_or_panic!(1e-9)
+        );
+    }
+
+    #[test]
+    fn test_event_variance_with_two_post_event_expiries() {
+        let vol = |d: f64| {
+            let t = d / 365.0;
+            ((0.04 * t + 0.0025) / t).sqrt()
+        };
+        let ts = VolatilityTermStructure::new(vec![point(30.0, vol(30.0)), point(60.0, vol(60.0))])
+            .unwrap();
+        let event = ts.event_variance(&days(5.0)).unwrap();
+        assert_pos_relative_eq!(
+            event.event_variance,
+            pos_or_panic!(0.0025),
+            pos_or_panic!(1e-6)
+        );
+        assert_pos_relative_eq!(
+            event.diffusive_volatility,
+            pos_or_panic!(0.2),
+            pos_or_panic!(1e-6)
+        );
+    }
+
+    #[test]
+    fn test_event_variance_needs_enough_expiries() {
+        let ts = VolatilityTermStructure::new(vec![point(30.0, 0.2)]).unwrap();
+        assert!(ts.event_variance(&days(10.0)).is_err());
+        assert!(ts.event_variance(&days(60.0)).is_err());
+    }
+
+    #[test]
+    fn test_from_series() {
+        let mut series = OptionSeries::new("TEST".to_string(), Positive::HUNDRED);
+        series.chains.insert(days(30.0), flat_chain(30.0, 0.25));
+        series.chains.insert(days(90.0), flat_chain(90.0, 0.2));
+        let ts = series.volatility_term_structure().unwrap();
+        assert_eq!(ts.points().len(), 2);
+        assert_eq!(ts.points()[0].atm_volatility, pos_or_panic!(0.25));
+        assert_eq!(ts.points()[1].atm_volatility, pos_or_panic!(0.2));
+        assert!(VolatilityTermStructure::from_series(&OptionSeries::default()).is_err());
+    }
+}
@@ -10,14 +10,17 @@
 //! - Volatility Sensitivity Curve and Surface
 //! - Time Decay Profile Curve and Surface
 //! - Price Shock Impact Curve and Surface
+//! - Smile-aware Price Shock Curve and Surface
 
 use optionstratlib::chains::chain::OptionChain;
 use optionstratlib::chains::utils::{OptionChainBuildParams, OptionDataPriceParams};
 use optionstratlib::metrics::{
-    PriceShockCurve, PriceShockSurface, TimeDecayCurve, TimeDecaySurface,
-    VolatilitySensitivityCurve, VolatilitySensitivitySurface,
+    PriceShockCurve, PriceShockSurface, SmileAwarePriceShock, SmileAwareTimeDecay,
+    SmileAwareVolatilitySensitivity, TimeDecayCurve, TimeDecaySurface, VolatilitySensitivityCurve,
+    VolatilitySensitivitySurface,
 };
 use optionstratlib::model::ExpirationDate;
+use optionstratlib::volatility::SmileDynamics;
 use positive::{Positive, pos_or_panic, spos};
 use rust_decimal_macros::dec;
 
@@ -413,3 +416,196 @@ mod edge_case_tests {
         assert!(result.is_ok());
     }
 }
+
+// ============================================================================
+// Smile-aware Price Shock Tests
+// ============================================================================
+
+mod smile_aware_price_shock_tests {
+    use super::*;
+    use rust_decimal::Decimal;
+
+    fn total_pnl(chain: &OptionChain, dynamics: SmileDynamics) -> Decimal {
+        chain
+            .price_shock_curve_with_dynamics(dec!(-0.05), dynamics)
+            .unwrap()
+            .points
+            .iter()
+            .map(|p| p.y)
+            .sum()
+    }
+
+    #[test]
+    fn test_zero_shock_is_flat_for_every_regime() {
+        let chain = create_test_chain();
+        for dynamics in [
+            SmileDynamics::StickyStrike,
+            SmileDynamics::StickyMoneyness,
+            SmileDynamics::StickyDelta,
+            SmileDynamics::SkewBeta(dec!(0.5)),
+        ] {
+            let curve = chain
+                .price_shock_curve_with_dynamics(dec!(0.0), dynamics)
+                .unwrap();
+            assert!(!curve.points.is_empty());
+            for point in curve.points.iter() {
+                assert!(point.y.abs() < dec!(0.0001));
+            }
+        }
+    }
+
+    #[test]
+    fn test_sticky_moneyness_cheapens_calls_on_down_move_with_put_skew() {
+        // With a negative skew, sticky moneyness reads lower volatilities
+        // after a sell-off than sticky strike does.
+        let chain = create_test_chain();
+        let sticky_strike = total_pnl(&chain, SmileDynamics::StickyStrike);
+        let sticky_moneyness = total_pnl(&chain, SmileDynamics::StickyMoneyness);
+        assert!(sticky_moneyness < sticky_strike);
+    }
+
+    #[test]
+    fn test_surface_with_dynamics() {
+        let chain = create_test_chain();
+        let price_range = (pos_or_panic!(400.0), pos_or_panic!(500.0));
+        let surface = chain
+            .price_shock_surface_with_dynamics(price_range, 4, SmileDynamics::StickyDelta)
+            .unwrap();
+        let strikes = chain.get_single_iter().count();
+        assert_eq!(surface.points.len(), 5 * strikes);
+    }
+
+    #[test]
+    fn test_empty_chain() {
+        let chain = create_empty_chain();
+        assert!(
+            chain
+                .price_shock_curve_with_dynamics(dec!(-0.10), SmileDynamics::StickyStrike)
+                .is_err()
+        );
+        assert!(
+            chain
+                .price_shock_surface_with_dynamics(
+                    (pos_or_panic!(400.0), pos_or_panic!(500.0)),
+                    4,
+                    SmileDynamics::StickyStrike,
+                )
+                .is_err()
+        );
+    }
+}
+
+// ============================================================================
+// Smile-aware Volatility Sensitivity and Time Decay Tests
+// ============================================================================
+
+mod smile_aware_sensitivity_tests {
+    use super::*;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_unshocked_sticky_strike_matches_fixed_volatility() {
+        let chain = create_test_chain();
+        let pairs = [
+            (
+                chain.volatility_sensitivity_curve().unwrap(),
+                chain
+                    .volatility_sensitivity_curve_with_dynamics(
+                        Decimal::ZERO,
+                        SmileDynamics::StickyStrike,
+                    )
+                    .unwrap(),
+            ),
+            (
+                chain.time_decay_curve().unwrap(),
+                chain
+                    .time_decay_curve_with_dynamics(Decimal::ZERO, SmileDynamics::StickyStrike)
+                    .unwrap(),
+            ),
+        ];
+        for (fixed, smile) in pairs {
+            assert_eq!(fixed.points.len(), smile.points.len());
+            for (a, b) in fixed.points.iter().zip(smile.points.iter()) {
+                assert_eq!(a.x, b.x);
+                assert!((a.y - b.y).abs() < dec!(0.0001));
+            }
+        }
+    }
+
+    #[test]
+    fn test_regimes_diverge_after_a_shock() {
+        let chain = create_test_chain();
+        let total = |dynamics| -> Decimal {
+            chain
+                .volatility_sensitivity_curve_with_dynamics(dec!(-0.05), dynamics)
+                .unwrap()
+                .points
+                .iter()
+                .map(|p| p.y)
+                .sum()
+        };
+        assert_ne!(
+            total(SmileDynamics::StickyStrike),
+            total(SmileDynamics::StickyMoneyness)
+        );
+    }
+
+    #[test]
+    fn test_surfaces_with_dynamics() {
+        let chain = create_test_chain();
+        let price_range = (pos_or_panic!(400.0), pos_or_panic!(500.0));
+        let vol_surface = chain
+            .volatility_sensitivity_surface_with_dynamics(
+                price_range,
+                (dec!(-0.05), dec!(0.05)),
+                4,
+                2,
+                SmileDynamics::StickyDelta,
+            )
+            .unwrap();
+        assert_eq!(vol_surface.points.len(), 5 * 3);
+
+        let days = vec![pos_or_panic!(7.0), pos_or_panic!(30.0)];
+        let decay_surface = chain
+            .time_decay_surface_with_dynamics(price_range, days, 4, SmileDynamics::StickyDelta)
+            .unwrap();
+        assert_eq!(decay_surface.points.len(), 5 * 2);
+    }
+
+    #[test]
+    fn test_empty_chain() {
+        let chain = create_empty_chain();
+        let price_range = (pos_or_panic!(400.0), pos_or_panic!(500.0));
+        assert!(
+            chain
+                .volatility_sensitivity_curve_with_dynamics(dec!(-0.1), SmileDynamics::StickyStrike)
+                .is_err()
+        );
+        assert!(
+            chain
+                .volatility_sensitivity_surface_with_dynamics(
+                    price_range,
+                    (Decimal::ZERO, dec!(0.05)),
+                    4,
+                    2,
+                    SmileDynamics::StickyStrike,
+                )
+                .is_err()
+        );
+        assert!(
+            chain
+                .time_decay_curve_with_dynamics(dec!(-0.1), SmileDynamics::StickyStrike)
+                .is_err()
+        );
+        assert!(
+            chain
+                .time_decay_surface_with_dynamics(
+                    price_range,
+                    vec![Positive::ONE],
+                    4,
+                    SmileDynamics::StickyStrike,
+                )
+                .is_err()
+        );
+    }
+}